@@ -3,7 +3,8 @@ use crate::{
     trace::chiplets::{
         bitwise::{NUM_DECOMP_BITS, NUM_SELECTORS, OP_CYCLE_LEN},
         BITWISE_A_COL_IDX, BITWISE_A_COL_RANGE, BITWISE_B_COL_IDX, BITWISE_B_COL_RANGE,
-        BITWISE_OUTPUT_COL_IDX, BITWISE_PREV_OUTPUT_COL_IDX, BITWISE_SELECTOR_COL_IDX,
+        BITWISE_MASK_LEN_COL_IDX, BITWISE_OUTPUT_COL_IDX, BITWISE_PREV_OUTPUT_COL_IDX,
+        BITWISE_SELECTOR_COL_RANGE,
     },
     utils::{are_equal, binary_not, is_binary, is_zero, EvaluationResult},
     ONE, ZERO,
@@ -18,7 +19,7 @@ pub mod tests;
 // ================================================================================================
 
 /// The number of transition constraints on the bitwise chiplet.
-pub const NUM_CONSTRAINTS: usize = 17;
+pub const NUM_CONSTRAINTS: usize = 25;
 
 // PERIODIC COLUMNS
 // ================================================================================================
@@ -38,13 +39,15 @@ pub fn get_periodic_column_values() -> Vec<Vec<Felt>> {
 /// Builds the transition constraint degrees for the bitwise chiplet.
 pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
     // The degrees of constraints on the bitwise chiplet. The degree of all bitwise
-    // constraints is increased by 3 due to the chiplet selector flag (degree 2) and the internal
-    // selector flag specifying the bitwise operation (degree 1). The degree of internal selector
-    // is also increased by 2 due to the selector flag from the Chiplets.
+    // constraints is increased by 2 due to the chiplet selector flag, and the degree of the
+    // constraints specific to a bitwise operation is increased by 2 more due to the internal
+    // selector flag specifying the operation.
     let degrees: [TransitionConstraintDegree; NUM_CONSTRAINTS] = [
-        // Internal Selector flag should be binary.
+        // Internal selector flags should be binary.
         TransitionConstraintDegree::new(4),
-        // Internal selector flag should remain the same throughout the cycle.
+        TransitionConstraintDegree::new(4),
+        // Internal selector flags should remain the same throughout the cycle.
+        TransitionConstraintDegree::with_cycles(3, vec![OP_CYCLE_LEN]),
         TransitionConstraintDegree::with_cycles(3, vec![OP_CYCLE_LEN]),
         // Input decomposition values should be binary.
         TransitionConstraintDegree::new(4),
@@ -61,10 +64,18 @@ pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
         // Enforce correct aggregation of a and b columns during transitions.
         TransitionConstraintDegree::with_cycles(3, vec![OP_CYCLE_LEN]),
         TransitionConstraintDegree::with_cycles(3, vec![OP_CYCLE_LEN]),
+        // The bits of the mask should be set only below the set bits.
+        TransitionConstraintDegree::new(6),
+        TransitionConstraintDegree::new(6),
+        TransitionConstraintDegree::new(6),
+        TransitionConstraintDegree::with_cycles(6, vec![OP_CYCLE_LEN]),
+        // Enforce correct accumulation of the number of set bits of the mask.
+        TransitionConstraintDegree::with_cycles(5, vec![OP_CYCLE_LEN]),
+        TransitionConstraintDegree::with_cycles(5, vec![OP_CYCLE_LEN]),
         // Ensure correct output aggregation.
         TransitionConstraintDegree::with_cycles(3, vec![OP_CYCLE_LEN]),
         TransitionConstraintDegree::with_cycles(3, vec![OP_CYCLE_LEN]),
-        TransitionConstraintDegree::new(5),
+        TransitionConstraintDegree::new(6),
     ];
 
     degrees.into()
@@ -83,18 +94,20 @@ pub fn enforce_constraints<E: FieldElement>(
     result: &mut [E],
     bitwise_flag: E,
 ) {
-    // Enforce that the internal selector is binary & remain the same throughout the cycle.
+    // Enforce that the internal selectors are binary & remain the same throughout the cycle.
     let mut index = enforce_selectors(frame, periodic_values, result, bitwise_flag);
     // Enforce correct decomposition of the input values into the a and b columns.
     index +=
         enforce_input_decomposition(frame, periodic_values, &mut result[index..], bitwise_flag);
+    // Enforce that the input `b` of the MASK operation is a mask of its number of set bits.
+    index += enforce_mask(frame, periodic_values, &mut result[index..], bitwise_flag);
 
     // Enforce that the operation result is aggregated into the output column correctly.
     enforce_output_aggregation(frame, periodic_values, &mut result[index..], bitwise_flag);
 }
 
-/// Constraint evaluation function to enforce that the Bitwise internal selector column
-/// must be binary and remain the same throughout the cycle.
+/// Constraint evaluation function to enforce that the Bitwise internal selector columns must be
+/// binary and remain the same throughout the cycle.
 fn enforce_selectors<E: FieldElement>(
     frame: &EvaluationFrame<E>,
     periodic_values: &[E],
@@ -103,14 +116,18 @@ fn enforce_selectors<E: FieldElement>(
 ) -> usize {
     let k1 = periodic_values[1];
     let mut constraint_offset = 0;
-    // Selector must be binary for the entire table.
-    result[0] = processor_flag * is_binary(frame.selector());
+    // Selectors must be binary for the entire table.
+    for (idx, result) in result.iter_mut().take(NUM_SELECTORS).enumerate() {
+        *result = processor_flag * is_binary(frame.selector(idx));
+    }
     constraint_offset += NUM_SELECTORS;
 
     // Selector values should stay the same for the entire cycle. In other words, the value can
     // only change when there is a transition to a new cycle i.e. from the last row of a cycle &
     // the first row of the new cycle when periodic column k1=0.
-    result[constraint_offset] = processor_flag * k1 * (frame.selector() - frame.selector_next());
+    for (idx, result) in result[constraint_offset..].iter_mut().take(NUM_SELECTORS).enumerate() {
+        *result = processor_flag * k1 * (frame.selector(idx) - frame.selector_next(idx));
+    }
     constraint_offset += NUM_SELECTORS;
 
     constraint_offset
@@ -173,6 +190,52 @@ fn enforce_input_decomposition<E: FieldElement>(
     constraint_offset
 }
 
+/// Enforces that the input `b` of the MASK operation is a mask of `k` ones in its least
+/// significant bits, where `k` is accumulated in the `mask_len` column. This requires the
+/// following constraints:
+/// - Within a row, a bit of `b` can be set only if all the less significant bits of the row are
+///   set.
+/// - For every row except the last, the least significant bit of `b` in the row can be set only if
+///   the most significant bit of `b` in the next row is set. Since the rows contain the 4-bit limbs
+///   of `b` starting with the most significant limb, this extends the first constraint to all the
+///   bits of `b`.
+/// - In the first row, `mask_len` should be the number of set bits of `b` in the row.
+/// - For every row except the last, the next value of `mask_len` should be the current value plus
+///   the number of set bits of `b` in the next row.
+fn enforce_mask<E: FieldElement>(
+    frame: &EvaluationFrame<E>,
+    periodic_values: &[E],
+    result: &mut [E],
+    processor_flag: E,
+) -> usize {
+    let mut constraint_offset = 0;
+    let mask_flag = processor_flag * frame.bitwise_mask_flag();
+
+    // A bit of b in the row can be set only if the next less significant bit is set.
+    for (idx, result) in result.iter_mut().take(NUM_DECOMP_BITS - 1).enumerate() {
+        *result = mask_flag * frame.b_bit(idx + 1) * binary_not(frame.b_bit(idx));
+    }
+    constraint_offset += NUM_DECOMP_BITS - 1;
+
+    // During a transition between rows, the least significant bit of b can be set only if the
+    // most significant bit of b in the next row is set.
+    let transition_flag = mask_flag * periodic_values[1];
+    result[constraint_offset] =
+        transition_flag * frame.b_bit(0) * binary_not(frame.b_bit_next(NUM_DECOMP_BITS - 1));
+    constraint_offset += 1;
+
+    // The number of set bits of b is accumulated into the mask_len column.
+    result[constraint_offset] =
+        mask_flag * periodic_values[0] * (frame.mask_len() - bitwise_popcnt(frame.b_bits()));
+    constraint_offset += 1;
+
+    result[constraint_offset] = transition_flag
+        * (frame.mask_len_next() - (frame.mask_len() + bitwise_popcnt(frame.b_bits_next())));
+    constraint_offset += 1;
+
+    constraint_offset
+}
+
 /// Enforces correct output aggregation for the operation. This requires the following 3 constraints
 /// for each operation:
 /// - In the first row, `output_prev` should be set to 0.
@@ -180,9 +243,12 @@ fn enforce_input_decomposition<E: FieldElement>(
 ///   the current value of `output`.
 /// - For all rows, the current output value (`output`) should equal 16 times the output value
 ///   copied from the previous row (`output_prev`) plus the aggregated result of the bitwise
-///   operation applied to the current row's set of bits.
+///   operation applied to the current row's set of bits. For the POPCNT operation, the output
+///   value should instead equal the output value copied from the previous row plus the number of
+///   set bits among the current row's bits of `a`. The MASK operation aggregates its output in the
+///   same way as the AND operation.
 ///
-/// Because the selectors for the AND and XOR operations are mutually exclusive, the
+/// Because the flags for the AND, XOR, POPCNT, and MASK operations are mutually exclusive, the
 /// constraints for different operations can be aggregated into the same result indices.
 fn enforce_output_aggregation<E: FieldElement>(
     frame: &EvaluationFrame<E>,
@@ -197,6 +263,8 @@ fn enforce_output_aggregation<E: FieldElement>(
     // Operator flags
     let bitwise_and_flag = processor_flag * frame.bitwise_and_flag();
     let bitwise_xor_flag = processor_flag * frame.bitwise_xor_flag();
+    let bitwise_popcnt_flag = processor_flag * frame.bitwise_popcnt_flag();
+    let bitwise_mask_flag = processor_flag * frame.bitwise_mask_flag();
     // Enforce value of `output_prev` is 0 for the first row.
     result[constraint_offset] = k0_flag * processor_flag * is_zero(frame.output_prev());
     constraint_offset += 1;
@@ -220,6 +288,16 @@ fn enforce_output_aggregation<E: FieldElement>(
         bitwise_xor_flag,
         frame.output() - (shifted_output + bitwise_xor(frame.bit_decomp())),
     );
+    result.agg_constraint(
+        constraint_offset,
+        bitwise_popcnt_flag,
+        frame.output() - (frame.output_prev() + bitwise_popcnt(frame.bit_decomp())),
+    );
+    result.agg_constraint(
+        constraint_offset,
+        bitwise_mask_flag,
+        frame.output() - (shifted_output + bitwise_and(frame.bit_decomp())),
+    );
     constraint_offset += 1;
 
     constraint_offset
@@ -251,15 +329,24 @@ pub fn bitwise_xor<E: FieldElement>(decomposed_values: &[E]) -> E {
     result
 }
 
+/// Calculates the number of set bits among the first 4 bits in the provided array, e.g., among the
+/// decomposed bits of `a` when the array holds the bit decomposition of both inputs.
+pub fn bitwise_popcnt<E: FieldElement>(decomposed_values: &[E]) -> E {
+    decomposed_values
+        .iter()
+        .take(NUM_DECOMP_BITS)
+        .fold(E::ZERO, |acc, &bit| acc + bit)
+}
+
 // BITWISE FRAME EXTENSION TRAIT
 // ================================================================================================
 trait EvaluationFrameExt<E: FieldElement> {
     // --- Column accessors -----------------------------------------------------------------------
 
     /// Gets the current value of the specified selector column.
-    fn selector(&self) -> E;
+    fn selector(&self, index: usize) -> E;
     /// Gets the next value of the specified selector column.
-    fn selector_next(&self) -> E;
+    fn selector_next(&self, index: usize) -> E;
     /// Gets the current value of the aggregated `a` input.
     fn a(&self) -> E;
     /// Gets the value of the aggregated `a` input in the next row.
@@ -272,6 +359,12 @@ trait EvaluationFrameExt<E: FieldElement> {
     fn b_next(&self) -> E;
     /// Gets the value of the decomposed bit of `b` at the specified index in the current row.
     fn b_bit(&self, index: usize) -> E;
+    /// Gets the value of the decomposed bit of `b` at the specified index in the next row.
+    fn b_bit_next(&self, index: usize) -> E;
+    /// Gets the decomposed bits of `b` in the current row.
+    fn b_bits(&self) -> &[E];
+    /// Gets the decomposed bits of `b` in the next row.
+    fn b_bits_next(&self) -> &[E];
     /// Gets the entire range of decomposed input values for `a` and `b` in the current row.
     fn bit_decomp(&self) -> &[E];
     /// Gets the value of the aggregated output in the previous row.
@@ -281,6 +374,10 @@ trait EvaluationFrameExt<E: FieldElement> {
     fn output_prev_next(&self) -> E;
     /// Gets the value of the aggregated output in the current row.
     fn output(&self) -> E;
+    /// Gets the accumulated number of set bits of `b` for the MASK operation in the current row.
+    fn mask_len(&self) -> E;
+    /// Gets the accumulated number of set bits of `b` for the MASK operation in the next row.
+    fn mask_len_next(&self) -> E;
 
     // --- Intermediate variables & helpers -------------------------------------------------------
     /// The aggregated value of the decomposed bits from `a` in the current row.
@@ -298,19 +395,23 @@ trait EvaluationFrameExt<E: FieldElement> {
     fn bitwise_and_flag(&self) -> E;
     /// The selector flag for the bitwise XOR operation.
     fn bitwise_xor_flag(&self) -> E;
+    /// The selector flag for the bitwise POPCNT operation.
+    fn bitwise_popcnt_flag(&self) -> E;
+    /// The selector flag for the bitwise MASK operation.
+    fn bitwise_mask_flag(&self) -> E;
 }
 
 impl<E: FieldElement> EvaluationFrameExt<E> for &EvaluationFrame<E> {
     // --- Column accessors -----------------------------------------------------------------------
 
     #[inline(always)]
-    fn selector(&self) -> E {
-        self.current()[BITWISE_SELECTOR_COL_IDX]
+    fn selector(&self, index: usize) -> E {
+        self.current()[BITWISE_SELECTOR_COL_RANGE.start + index]
     }
 
     #[inline(always)]
-    fn selector_next(&self) -> E {
-        self.next()[BITWISE_SELECTOR_COL_IDX]
+    fn selector_next(&self, index: usize) -> E {
+        self.next()[BITWISE_SELECTOR_COL_RANGE.start + index]
     }
 
     #[inline(always)]
@@ -343,6 +444,21 @@ impl<E: FieldElement> EvaluationFrameExt<E> for &EvaluationFrame<E> {
         self.current()[BITWISE_B_COL_RANGE.start + index]
     }
 
+    #[inline(always)]
+    fn b_bit_next(&self, index: usize) -> E {
+        self.next()[BITWISE_B_COL_RANGE.start + index]
+    }
+
+    #[inline(always)]
+    fn b_bits(&self) -> &[E] {
+        &self.current()[BITWISE_B_COL_RANGE]
+    }
+
+    #[inline(always)]
+    fn b_bits_next(&self) -> &[E] {
+        &self.next()[BITWISE_B_COL_RANGE]
+    }
+
     #[inline(always)]
     fn bit_decomp(&self) -> &[E] {
         &self.current()[BITWISE_A_COL_RANGE.start..BITWISE_B_COL_RANGE.end]
@@ -363,6 +479,16 @@ impl<E: FieldElement> EvaluationFrameExt<E> for &EvaluationFrame<E> {
         self.current()[BITWISE_OUTPUT_COL_IDX]
     }
 
+    #[inline(always)]
+    fn mask_len(&self) -> E {
+        self.current()[BITWISE_MASK_LEN_COL_IDX]
+    }
+
+    #[inline(always)]
+    fn mask_len_next(&self) -> E {
+        self.next()[BITWISE_MASK_LEN_COL_IDX]
+    }
+
     // --- Intermediate variables & helpers -------------------------------------------------------
     #[inline(always)]
     fn a_agg_bits(&self) -> E {
@@ -388,12 +514,22 @@ impl<E: FieldElement> EvaluationFrameExt<E> for &EvaluationFrame<E> {
 
     #[inline(always)]
     fn bitwise_and_flag(&self) -> E {
-        binary_not(self.current()[BITWISE_SELECTOR_COL_IDX])
+        binary_not(self.selector(0)) * binary_not(self.selector(1))
     }
 
     #[inline(always)]
     fn bitwise_xor_flag(&self) -> E {
-        self.current()[BITWISE_SELECTOR_COL_IDX]
+        self.selector(0) * binary_not(self.selector(1))
+    }
+
+    #[inline(always)]
+    fn bitwise_popcnt_flag(&self) -> E {
+        binary_not(self.selector(0)) * self.selector(1)
+    }
+
+    #[inline(always)]
+    fn bitwise_mask_flag(&self) -> E {
+        self.selector(0) * self.selector(1)
     }
 }
 
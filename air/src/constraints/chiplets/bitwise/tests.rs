@@ -1,13 +1,13 @@
 use super::{
     enforce_constraints, get_periodic_values, EvaluationFrame, BITWISE_A_COL_IDX,
-    BITWISE_A_COL_RANGE, BITWISE_B_COL_IDX, BITWISE_B_COL_RANGE, BITWISE_OUTPUT_COL_IDX,
-    BITWISE_PREV_OUTPUT_COL_IDX, BITWISE_SELECTOR_COL_IDX, NUM_CONSTRAINTS, NUM_DECOMP_BITS, ONE,
-    OP_CYCLE_LEN, ZERO,
+    BITWISE_A_COL_RANGE, BITWISE_B_COL_IDX, BITWISE_B_COL_RANGE, BITWISE_MASK_LEN_COL_IDX,
+    BITWISE_OUTPUT_COL_IDX, BITWISE_PREV_OUTPUT_COL_IDX, BITWISE_SELECTOR_COL_RANGE,
+    NUM_CONSTRAINTS, NUM_DECOMP_BITS, ONE, OP_CYCLE_LEN, ZERO,
 };
 use crate::{
     trace::{
         chiplets::{
-            bitwise::{BITWISE_AND, BITWISE_XOR},
+            bitwise::{Selectors, BITWISE_AND, BITWISE_MASK, BITWISE_POPCNT, BITWISE_XOR},
             BITWISE_TRACE_RANGE,
         },
         TRACE_WIDTH,
@@ -34,11 +34,60 @@ fn test_bitwise_change_ops_fail() {
     let frame = get_test_frame_with_two_ops(BITWISE_XOR, BITWISE_AND, a, b, cycle_row);
     let result = get_constraint_evaluation(frame, cycle_row);
 
-    // The selector flag changes, so that constraint should fail.
-    assert_ne!(result[1], expected[1]);
+    // The first selector flag changes, so that constraint should fail.
+    assert_ne!(result[2], expected[2]);
     // All other constraints should evaluate to zero.
-    assert_eq!(result[0..1], expected[0..1]);
-    assert_eq!(result[2..], expected[2..]);
+    assert_eq!(result[0..2], expected[0..2]);
+    assert_eq!(result[3..], expected[3..]);
+
+    let frame = get_test_frame_with_two_ops(BITWISE_POPCNT, BITWISE_AND, a, 0, cycle_row);
+    let result = get_constraint_evaluation(frame, cycle_row);
+
+    // The second selector flag changes, so that constraint should fail.
+    assert_ne!(result[3], expected[3]);
+    // All other constraints should evaluate to zero.
+    assert_eq!(result[0..3], expected[0..3]);
+    assert_eq!(result[4..], expected[4..]);
+}
+
+/// Tests that the bitwise constraints do not all evaluate to zero if the input `b` of the MASK
+/// operation has a set bit above an unset bit.
+#[test]
+fn test_bitwise_invalid_mask_fail() {
+    let a = rand_value::<u32>();
+
+    // within the first row, bit 29 of b is set while bit 28 is not.
+    let frame = get_test_frame(BITWISE_MASK, a, 0x2fff_ffff, 0);
+    let result = get_constraint_evaluation(frame, 0);
+    assert_ne!(ZERO, result[16]);
+    assert!(result[..16].iter().chain(&result[17..]).all(|&value| value == ZERO));
+
+    // the least significant bit of b in the first row is set, while the most significant bit of b
+    // in the second row is not.
+    let frame = get_test_frame(BITWISE_MASK, a, 0x17ff_ffff, 0);
+    let result = get_constraint_evaluation(frame, 0);
+    assert_ne!(ZERO, result[19]);
+    assert!(result[..19].iter().chain(&result[20..]).all(|&value| value == ZERO));
+}
+
+/// Tests that the prover cannot set a number of set bits of the mask in the MASK operation which
+/// is different from the actual number.
+#[test]
+fn test_bitwise_invalid_mask_len_fail() {
+    let a = rand_value::<u32>();
+
+    let mut frame = get_test_frame(BITWISE_MASK, a, 0x0fff_ffff, 0);
+    frame.current_mut()[BITWISE_MASK_LEN_COL_IDX] += ONE;
+    frame.next_mut()[BITWISE_MASK_LEN_COL_IDX] += ONE;
+    let result = get_constraint_evaluation(frame, 0);
+    assert_ne!(ZERO, result[20]);
+    assert!(result[..20].iter().chain(&result[21..]).all(|&value| value == ZERO));
+
+    let mut frame = get_test_frame(BITWISE_MASK, a, 0x0fff_ffff, 3);
+    frame.next_mut()[BITWISE_MASK_LEN_COL_IDX] += ONE;
+    let result = get_constraint_evaluation(frame, 3);
+    assert_ne!(ZERO, result[21]);
+    assert!(result[..21].iter().chain(&result[22..]).all(|&value| value == ZERO));
 }
 
 /// Tests that the prover cannot set an incorrect output during BITWISE_AND at the first row in the
@@ -52,8 +101,9 @@ fn output_aggregation_and() {
     let mut next = vec![ZERO; TRACE_WIDTH];
 
     let current_bitwise = [
-        // selector
-        BITWISE_AND,
+        // selectors
+        BITWISE_AND[0],
+        BITWISE_AND[1],
         // a
         ONE,
         // b
@@ -72,11 +122,14 @@ fn output_aggregation_and() {
         ZERO,
         // assert a false output
         Felt::new(1337),
+        // mask length
+        ZERO,
     ];
 
     let next_bitwise = [
-        // selector
-        BITWISE_AND,
+        // selectors
+        BITWISE_AND[0],
+        BITWISE_AND[1],
         // a
         Felt::new(19),
         // b
@@ -95,6 +148,8 @@ fn output_aggregation_and() {
         Felt::new(1337),
         // output
         Felt::new(21393),
+        // mask length
+        ZERO,
     ];
 
     current[BITWISE_TRACE_RANGE].copy_from_slice(&current_bitwise);
@@ -107,6 +162,23 @@ fn output_aggregation_and() {
     assert_ne!(ZERO, result[NUM_CONSTRAINTS - 1]);
 }
 
+/// Tests that the prover cannot set an output during BITWISE_POPCNT which is different from the
+/// number of set bits of `a`.
+#[test]
+fn output_aggregation_popcnt() {
+    let a = rand_value::<u32>();
+    let cycle_row: usize = rand_value::<u8>() as usize % (OP_CYCLE_LEN - 1);
+
+    let mut frame = get_test_frame(BITWISE_POPCNT, a, 0, cycle_row);
+    frame.current_mut()[BITWISE_OUTPUT_COL_IDX] += ONE;
+    frame.next_mut()[BITWISE_PREV_OUTPUT_COL_IDX] += ONE;
+    let result = get_constraint_evaluation(frame, cycle_row);
+
+    // expect a failure for the output aggregation constraint (the last one) only
+    assert_ne!(ZERO, result[NUM_CONSTRAINTS - 1]);
+    assert!(result[..NUM_CONSTRAINTS - 1].iter().all(|&value| value == ZERO));
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -130,6 +202,27 @@ proptest! {
         let result = get_constraint_evaluation(frame, cycle_row);
         assert_eq!(expected, result);
     }
+
+    /// Tests that the bitwise constraints evaluate to zero on valid frames within a cycle which
+    /// compute the number of set bits of `a`.
+    #[test]
+    fn test_bitwise_popcnt(a in any::<u32>(), cycle_row in 0..(OP_CYCLE_LEN - 1)) {
+        let expected = [ZERO; NUM_CONSTRAINTS];
+        let frame = get_test_frame(BITWISE_POPCNT, a, 0, cycle_row);
+        let result = get_constraint_evaluation(frame, cycle_row);
+        assert_eq!(expected, result);
+    }
+
+    /// Tests that the bitwise constraints evaluate to zero on valid frames within a cycle which
+    /// compute the `k` least significant bits of `a`.
+    #[test]
+    fn test_bitwise_mask(a in any::<u32>(), k in 0..=32_u32, cycle_row in 0..(OP_CYCLE_LEN - 1)) {
+        let expected = [ZERO; NUM_CONSTRAINTS];
+        let mask = ((1_u64 << k) - 1) as u32;
+        let frame = get_test_frame(BITWISE_MASK, a, mask, cycle_row);
+        let result = get_constraint_evaluation(frame, cycle_row);
+        assert_eq!(expected, result);
+    }
 }
 
 // TEST HELPERS
@@ -154,7 +247,7 @@ fn get_constraint_evaluation(frame: EvaluationFrame<Felt>, row: usize) -> [Felt;
 /// It expects the specified `cycle_row_num` for the current row to be such that the next row will
 /// still be in the same cycle. It will fail if the row number input is >= OP_CYCLE_LEN - 1.
 pub fn get_test_frame(
-    operation: Felt,
+    operation: Selectors,
     a: u32,
     b: u32,
     cycle_row_num: usize,
@@ -169,23 +262,22 @@ pub fn get_test_frame(
     let mut next = vec![ZERO; TRACE_WIDTH];
 
     // Set the operation selectors.
-    current[BITWISE_SELECTOR_COL_IDX] = operation;
-    next[BITWISE_SELECTOR_COL_IDX] = operation;
+    current[BITWISE_SELECTOR_COL_RANGE].copy_from_slice(&operation);
+    next[BITWISE_SELECTOR_COL_RANGE].copy_from_slice(&operation);
 
     // Set the input aggregation and decomposition values.
     set_frame_inputs(&mut current, &mut next, a, b, cycle_row_num);
 
     // Compute the output for the specified operation and inputs and shift it for each row.
     let (previous_shift, current_shift, next_shift) = get_row_shifts(cycle_row_num);
-    let result = get_output(operation, a, b);
-    let output_current = result >> current_shift;
-    let output_next = result >> next_shift;
+    let output_current = get_output(operation, a, b, current_shift);
+    let output_next = get_output(operation, a, b, next_shift);
 
     // Set the previous output.
     let output_prev = if cycle_row_num == 0 {
         ZERO
     } else {
-        Felt::new((result >> previous_shift) as u64)
+        Felt::new(get_output(operation, a, b, previous_shift) as u64)
     };
     current[BITWISE_PREV_OUTPUT_COL_IDX] = output_prev;
     next[BITWISE_PREV_OUTPUT_COL_IDX] = Felt::new((output_current) as u64);
@@ -205,8 +297,8 @@ pub fn get_test_frame(
 /// It expects the specified `cycle_row_num` for the current row to be such that the next row will
 /// still be in the same cycle. It will fail if the row number input is >= OP_CYCLE_LEN - 1.
 pub fn get_test_frame_with_two_ops(
-    op_current: Felt,
-    op_next: Felt,
+    op_current: Selectors,
+    op_next: Selectors,
     a: u32,
     b: u32,
     cycle_row_num: usize,
@@ -220,24 +312,23 @@ pub fn get_test_frame_with_two_ops(
     let mut current = vec![ZERO; TRACE_WIDTH];
     let mut next = vec![ZERO; TRACE_WIDTH];
 
-    // Set the operation selector.
-    current[BITWISE_SELECTOR_COL_IDX] = op_current;
-    next[BITWISE_SELECTOR_COL_IDX] = op_next;
+    // Set the operation selectors.
+    current[BITWISE_SELECTOR_COL_RANGE].copy_from_slice(&op_current);
+    next[BITWISE_SELECTOR_COL_RANGE].copy_from_slice(&op_next);
 
     // Set the input aggregation and decomposition values.
     set_frame_inputs(&mut current, &mut next, a, b, cycle_row_num);
 
     // Compute the outputs for the specified operations and inputs and shift them for each row.
     let (previous_shift, current_shift, next_shift) = get_row_shifts(cycle_row_num);
-    let result_op_current = get_output(op_current, a, b);
-    let output_current = result_op_current >> current_shift;
-    let output_next = get_output(op_next, a, b) >> next_shift;
+    let output_current = get_output(op_current, a, b, current_shift);
+    let output_next = get_output(op_next, a, b, next_shift);
 
     // Set the previous output.
     let output_prev = if cycle_row_num == 0 {
         ZERO
     } else {
-        Felt::new((result_op_current >> previous_shift) as u64)
+        Felt::new(get_output(op_current, a, b, previous_shift) as u64)
     };
     current[BITWISE_PREV_OUTPUT_COL_IDX] = output_prev;
     next[BITWISE_PREV_OUTPUT_COL_IDX] = Felt::new((output_current) as u64);
@@ -262,7 +353,8 @@ fn get_row_shifts(cycle_row_num: usize) -> (usize, usize, usize) {
 
 /// Sets the input aggregation and decomposition columns in the provided current and next rows with
 /// the correct values corresponding to the provided inputs `a` and `b` and the specified
-/// `cycle_row_num`, which is the number of the `current` row within the operation cycle.
+/// `cycle_row_num`, which is the number of the `current` row within the operation cycle. The
+/// accumulated number of set bits of `b` is set as well, as required by the MASK operation.
 fn set_frame_inputs(current: &mut [Felt], next: &mut [Felt], a: u32, b: u32, cycle_row_num: usize) {
     // Get the shift amounts for the specified rows.
     let (_, current_shift, next_shift) = get_row_shifts(cycle_row_num);
@@ -277,6 +369,8 @@ fn set_frame_inputs(current: &mut [Felt], next: &mut [Felt], a: u32, b: u32, cyc
     next[BITWISE_A_COL_IDX] = Felt::new(next_a);
     current[BITWISE_B_COL_IDX] = Felt::new(current_b);
     next[BITWISE_B_COL_IDX] = Felt::new(next_b);
+    current[BITWISE_MASK_LEN_COL_IDX] = Felt::from(current_b.count_ones());
+    next[BITWISE_MASK_LEN_COL_IDX] = Felt::from(next_b.count_ones());
 
     // Set the input decomposition values.
     for idx in 0..NUM_DECOMP_BITS {
@@ -287,12 +381,17 @@ fn set_frame_inputs(current: &mut [Felt], next: &mut [Felt], a: u32, b: u32, cyc
     }
 }
 
-/// Returns the final output result of applying the specified operation to the provided inputs.
-fn get_output(operation: Felt, a: u32, b: u32) -> u32 {
+/// Returns the output accumulated in the row in which the provided inputs are shifted by `shift`
+/// bits, i.e., the result of applying the specified operation to the high bits of the inputs.
+fn get_output(operation: Selectors, a: u32, b: u32, shift: usize) -> u32 {
     if operation == BITWISE_AND {
-        a & b
+        (a & b) >> shift
     } else if operation == BITWISE_XOR {
-        a ^ b
+        (a ^ b) >> shift
+    } else if operation == BITWISE_POPCNT {
+        (a >> shift).count_ones()
+    } else if operation == BITWISE_MASK {
+        (a & b) >> shift
     } else {
         panic!("Test bitwise EvaluationFrame requested for unrecognized operation.");
     }
@@ -0,0 +1,317 @@
+use super::{EvaluationFrame, EvaluationFrameExt as ChipletsEvaluationFrameExt, FieldElement};
+use crate::{
+    trace::{
+        chiplets::{
+            kernel_rom::KERNEL_PROC_LABEL, CHIPLETS_VTABLE_COL_IDX, KERNEL_ROM_ADDR_COL_IDX,
+            KERNEL_ROM_BUS_COL_IDX, KERNEL_ROM_ROOT_COL_RANGE, KERNEL_ROM_S0_COL_IDX,
+        },
+        decoder::{
+            DECODER_HASHER_STATE_OFFSET, DECODER_OP_BITS_EXTRA_COLS_OFFSET, DECODER_OP_BITS_OFFSET,
+        },
+    },
+    utils::{are_equal, binary_not, is_binary},
+    Assertion, Felt,
+};
+use alloc::vec::Vec;
+use vm_core::{ExtensionOf, Kernel};
+use winter_air::TransitionConstraintDegree;
+
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The number of constraints on the management of the kernel ROM chiplet.
+pub const NUM_CONSTRAINTS: usize = 6;
+/// The degrees of constraints on the management of the kernel ROM chiplet. All constraint degrees
+/// are increased by 4 due to the selectors for the kernel ROM chiplet.
+pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
+    6, // Enforce that the s0 column is binary.
+    6, // Enforce that the addr column either stays the same or is incremented by 1.
+    6, 6, 6, 6, // Enforce that the procedure root does not change while addr stays the same.
+];
+
+/// The number of transition constraints on the kernel procedure table in the chiplets virtual
+/// table column and on the kernel ROM bus.
+pub const NUM_AUX_CONSTRAINTS: usize = 2;
+/// The degrees of transition constraints on the kernel procedure table and the kernel ROM bus.
+pub const AUX_CONSTRAINT_DEGREES: [usize; NUM_AUX_CONSTRAINTS] = [
+    8, // The kernel procedure table; increased by 4 due to the selectors for the kernel ROM chiplet.
+    7, // The kernel ROM bus.
+];
+/// The number of boundary assertions on the kernel ROM bus.
+pub const NUM_BUS_ASSERTIONS: usize = 2;
+
+// KERNEL ROM TRANSITION CONSTRAINTS
+// ================================================================================================
+
+/// Builds the transition constraint degrees for the kernel ROM chiplet.
+pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    CONSTRAINT_DEGREES
+        .iter()
+        .map(|&degree| TransitionConstraintDegree::new(degree))
+        .collect()
+}
+
+/// Returns the number of transition constraints for the kernel ROM chiplet.
+pub fn get_transition_constraint_count() -> usize {
+    NUM_CONSTRAINTS
+}
+
+/// Enforces constraints for the kernel ROM chiplet.
+///
+/// The `kernel_rom_flag` is expected to exclude the last row of the kernel ROM trace, so that the
+/// constraints on the `addr` and root columns are not enforced against the first row of the
+/// subsequent padding section.
+pub fn enforce_constraints<E: FieldElement>(
+    frame: &EvaluationFrame<E>,
+    result: &mut [E],
+    kernel_rom_flag: E,
+) {
+    // s0 is binary.
+    result[0] = kernel_rom_flag * is_binary(frame.s0());
+
+    // addr either stays the same or is incremented by 1.
+    result[1] = kernel_rom_flag * is_binary(frame.addr_change());
+
+    // if addr stays the same, the procedure root must stay the same as well.
+    let not_addr_change = binary_not(frame.addr_change());
+    for i in 0..4 {
+        result[2 + i] =
+            kernel_rom_flag * not_addr_change * are_equal(frame.root_next(i), frame.root(i));
+    }
+}
+
+// KERNEL PROCEDURE TABLE
+// ================================================================================================
+
+/// Returns the transition constraint degrees for the kernel procedure table.
+pub fn get_aux_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    AUX_CONSTRAINT_DEGREES
+        .iter()
+        .map(|&degree| TransitionConstraintDegree::new(degree))
+        .collect()
+}
+
+/// Enforces that every unique procedure of the kernel ROM is included into the kernel procedure
+/// table exactly once, and that the kernel ROM bus is updated correctly.
+///
+/// A procedure is included in the last row of its section of the kernel ROM trace, i.e., when the
+/// value in the `addr` column changes or when the next row is no longer part of the kernel ROM.
+/// The `kernel_rom_flag` is expected to include the last row of the kernel ROM trace.
+///
+/// Constraint degree: 8
+pub fn enforce_aux_constraints<F, E>(
+    main_frame: &EvaluationFrame<F>,
+    aux_frame: &EvaluationFrame<E>,
+    alphas: &[E],
+    result: &mut [E],
+    kernel_rom_flag: F,
+) where
+    F: FieldElement<BaseField = Felt>,
+    E: FieldElement<BaseField = Felt> + ExtensionOf<F>,
+{
+    let vt = aux_frame.current()[CHIPLETS_VTABLE_COL_IDX];
+    let vt_next = aux_frame.next()[CHIPLETS_VTABLE_COL_IDX];
+
+    // the last row of a procedure section is either followed by a row with a different address, or
+    // by the first row of the padding section, where chiplet selector s3 is set.
+    let s3_next = main_frame.s_next(3);
+    let include = s3_next + binary_not(s3_next) * main_frame.addr_change();
+
+    let mut root = [F::ZERO; 4];
+    for (i, value) in root.iter_mut().enumerate() {
+        *value = main_frame.root(i);
+    }
+    let value = build_table_row_value(alphas, main_frame.addr(), &root);
+
+    result[0] = are_equal(vt_next, vt * (value.mul_base(include) + E::from(binary_not(include))))
+        .mul_base(kernel_rom_flag);
+
+    enforce_bus_constraint(main_frame, aux_frame, alphas, &mut result[1..], kernel_rom_flag);
+}
+
+/// Enforces that every kernel procedure requested by a `SYSCALL` operation is provided by the
+/// kernel ROM, and that the kernel ROM provides each procedure only as many times as it is
+/// requested.
+///
+/// The decoder requests the procedure whose root is in the first 4 registers of the decoder's
+/// hasher state when a `SYSCALL` operation is executed, and the kernel ROM provides the procedure
+/// of each row in which its `s0` column is set. Together with the kernel procedure table, this
+/// ensures that only procedures of the kernel can be invoked via `SYSCALL`.
+///
+/// Constraint degree: 7
+fn enforce_bus_constraint<F, E>(
+    main_frame: &EvaluationFrame<F>,
+    aux_frame: &EvaluationFrame<E>,
+    alphas: &[E],
+    result: &mut [E],
+    kernel_rom_flag: F,
+) where
+    F: FieldElement<BaseField = Felt>,
+    E: FieldElement<BaseField = Felt> + ExtensionOf<F>,
+{
+    let b_krom = aux_frame.current()[KERNEL_ROM_BUS_COL_IDX];
+    let b_krom_next = aux_frame.next()[KERNEL_ROM_BUS_COL_IDX];
+
+    // the request sent by a SYSCALL operation.
+    let syscall_flag = main_frame.syscall_flag();
+    let mut fn_hash = [F::ZERO; 4];
+    for (i, value) in fn_hash.iter_mut().enumerate() {
+        *value = main_frame.fn_hash(i);
+    }
+    let request = build_bus_value(alphas, &fn_hash);
+    let request = (request - E::ONE).mul_base(syscall_flag) + E::ONE;
+
+    // the response provided by the kernel ROM.
+    let response_flag = kernel_rom_flag * main_frame.s0();
+    let mut root = [F::ZERO; 4];
+    for (i, value) in root.iter_mut().enumerate() {
+        *value = main_frame.root(i);
+    }
+    let response = build_bus_value(alphas, &root);
+    let response = (response - E::ONE).mul_base(response_flag) + E::ONE;
+
+    result[0] = are_equal(b_krom_next * request, b_krom * response);
+}
+
+/// Returns the value which the kernel procedure table should have after all of the procedures of
+/// the specified kernel have been included into it.
+pub fn get_kernel_table_final_value<E: FieldElement<BaseField = Felt>>(
+    kernel: &Kernel,
+    alphas: &[E],
+) -> E {
+    kernel.proc_hashes().iter().enumerate().fold(E::ONE, |acc, (addr, proc_hash)| {
+        let root: [Felt; 4] = (*proc_hash).into();
+        acc * build_table_row_value(alphas, Felt::from(addr as u16), &root)
+    })
+}
+
+/// Returns the boundary assertion for the chiplets virtual table column at the last step, which
+/// ties the kernel procedure table to the specified kernel.
+pub fn get_aux_assertions_last_step<E: FieldElement<BaseField = Felt>>(
+    result: &mut Vec<Assertion<E>>,
+    alphas: &[E],
+    kernel: &Kernel,
+    step: usize,
+) {
+    let value = get_kernel_table_final_value(kernel, alphas);
+    result.push(Assertion::single(CHIPLETS_VTABLE_COL_IDX, step, value));
+}
+
+/// Returns the boundary assertions for the kernel ROM bus at the first step.
+pub fn get_bus_assertions_first_step<E: FieldElement>(result: &mut Vec<Assertion<E>>) {
+    result.push(Assertion::single(KERNEL_ROM_BUS_COL_IDX, 0, E::ONE));
+}
+
+/// Returns the boundary assertions for the kernel ROM bus at the last step. At the end of the
+/// trace, all procedures requested from the kernel ROM must have been provided by it.
+pub fn get_bus_assertions_last_step<E: FieldElement>(result: &mut Vec<Assertion<E>>, step: usize) {
+    result.push(Assertion::single(KERNEL_ROM_BUS_COL_IDX, step, E::ONE));
+}
+
+/// Reduces a row of the kernel procedure table to a single value using the provided random
+/// elements.
+fn build_table_row_value<F, E>(alphas: &[E], addr: F, root: &[F; 4]) -> E
+where
+    F: FieldElement<BaseField = Felt>,
+    E: FieldElement<BaseField = Felt> + ExtensionOf<F>,
+{
+    let mut value = alphas[0] + alphas[1].mul_base(addr);
+    for (i, &element) in root.iter().enumerate() {
+        value += alphas[i + 2].mul_base(element);
+    }
+    value
+}
+
+/// Reduces a kernel procedure call message sent over the kernel ROM bus to a single value using
+/// the provided random elements.
+fn build_bus_value<F, E>(alphas: &[E], root: &[F; 4]) -> E
+where
+    F: FieldElement<BaseField = Felt>,
+    E: FieldElement<BaseField = Felt> + ExtensionOf<F>,
+{
+    let mut value = alphas[0] + alphas[1].mul_base(KERNEL_PROC_LABEL);
+    for (i, &element) in root.iter().enumerate() {
+        value += alphas[i + 2].mul_base(element);
+    }
+    value
+}
+
+// KERNEL ROM FRAME EXTENSION TRAIT
+// ================================================================================================
+
+/// Trait to allow easy access to column values and intermediate variables used in constraint
+/// calculations for the kernel ROM chiplet.
+trait EvaluationFrameExt<E: FieldElement> {
+    // --- Column accessors -----------------------------------------------------------------------
+
+    /// The current value in the s0 column.
+    fn s0(&self) -> E;
+    /// The current procedure index.
+    fn addr(&self) -> E;
+    /// The value at the specified index (0, 1, 2, 3) of the procedure root in the current row.
+    fn root(&self, index: usize) -> E;
+    /// The value at the specified index (0, 1, 2, 3) of the procedure root in the next row.
+    fn root_next(&self, index: usize) -> E;
+    /// The value at the specified index (0, 1, 2, 3) of the root of the procedure called by the
+    /// operation executed in the current row, which is held in the decoder's hasher state.
+    fn fn_hash(&self, index: usize) -> E;
+
+    // --- Intermediate variables & helpers -------------------------------------------------------
+
+    /// The difference between the next procedure index and the current one.
+    fn addr_change(&self) -> E;
+
+    /// Flag indicating whether a `SYSCALL` operation is executed in the current row. This flag is
+    /// also defined in the stack's op flags; it is redefined here to avoid computing all of the op
+    /// flags when this is the only one needed.
+    fn syscall_flag(&self) -> E;
+}
+
+impl<E: FieldElement> EvaluationFrameExt<E> for &EvaluationFrame<E> {
+    // --- Column accessors -----------------------------------------------------------------------
+
+    #[inline(always)]
+    fn s0(&self) -> E {
+        self.current()[KERNEL_ROM_S0_COL_IDX]
+    }
+
+    #[inline(always)]
+    fn addr(&self) -> E {
+        self.current()[KERNEL_ROM_ADDR_COL_IDX]
+    }
+
+    #[inline(always)]
+    fn root(&self, index: usize) -> E {
+        self.current()[KERNEL_ROM_ROOT_COL_RANGE.start + index]
+    }
+
+    #[inline(always)]
+    fn root_next(&self, index: usize) -> E {
+        self.next()[KERNEL_ROM_ROOT_COL_RANGE.start + index]
+    }
+
+    #[inline(always)]
+    fn fn_hash(&self, index: usize) -> E {
+        self.current()[DECODER_HASHER_STATE_OFFSET + index]
+    }
+
+    // --- Intermediate variables & helpers -------------------------------------------------------
+
+    #[inline(always)]
+    fn addr_change(&self) -> E {
+        self.next()[KERNEL_ROM_ADDR_COL_IDX] - self.current()[KERNEL_ROM_ADDR_COL_IDX]
+    }
+
+    #[inline(always)]
+    fn syscall_flag(&self) -> E {
+        // SYSCALL is a degree 4 operation with the op bits [_, _, 0, 1, 0].
+        let op_bit = |index| self.current()[DECODER_OP_BITS_OFFSET + index];
+        self.current()[DECODER_OP_BITS_EXTRA_COLS_OFFSET + 1]
+            * binary_not(op_bit(4))
+            * op_bit(3)
+            * binary_not(op_bit(2))
+    }
+}
@@ -0,0 +1,110 @@
+use super::{
+    EvaluationFrame, KERNEL_ROM_ADDR_COL_IDX, KERNEL_ROM_ROOT_COL_RANGE, KERNEL_ROM_S0_COL_IDX,
+};
+use crate::trace::TRACE_WIDTH;
+use crate::{chiplets::kernel_rom, Felt, ONE, ZERO};
+use rand_utils::rand_array;
+
+// UNIT TESTS
+// ================================================================================================
+
+#[test]
+fn test_kernel_rom_same_proc() {
+    let expected = [ZERO; kernel_rom::NUM_CONSTRAINTS];
+    let root = rand_array::<Felt, 4>();
+
+    // a procedure accessed multiple times is provided to the bus in every row after the first one.
+    let frame = get_test_frame([ZERO, ONE], [ZERO, ZERO], [root, root]);
+    let result = get_constraint_evaluation(frame);
+    assert_eq!(expected, result);
+
+    let frame = get_test_frame([ONE, ONE], [ONE, ONE], [root, root]);
+    let result = get_constraint_evaluation(frame);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_kernel_rom_next_proc() {
+    let expected = [ZERO; kernel_rom::NUM_CONSTRAINTS];
+    let roots = [rand_array::<Felt, 4>(), rand_array::<Felt, 4>()];
+
+    let frame = get_test_frame([ONE, ZERO], [ZERO, ONE], roots);
+    let result = get_constraint_evaluation(frame);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_kernel_rom_invalid_s0() {
+    let root = rand_array::<Felt, 4>();
+
+    let frame = get_test_frame([Felt::new(2), ONE], [ZERO, ZERO], [root, root]);
+    let result = get_constraint_evaluation(frame);
+    assert_ne!(ZERO, result[0]);
+    assert!(result[1..].iter().all(|&value| value == ZERO));
+}
+
+#[test]
+fn test_kernel_rom_invalid_addr_change() {
+    let roots = [rand_array::<Felt, 4>(), rand_array::<Felt, 4>()];
+
+    // the procedure index may not be incremented by more than 1.
+    let frame = get_test_frame([ONE, ONE], [ZERO, Felt::new(2)], roots);
+    let result = get_constraint_evaluation(frame);
+    assert_ne!(ZERO, result[1]);
+
+    // the procedure index may not decrease.
+    let frame = get_test_frame([ONE, ONE], [ONE, ZERO], roots);
+    let result = get_constraint_evaluation(frame);
+    assert_ne!(ZERO, result[1]);
+}
+
+#[test]
+fn test_kernel_rom_invalid_root_change() {
+    let root = rand_array::<Felt, 4>();
+
+    // the procedure root may not change while the procedure index stays the same.
+    for i in 0..4 {
+        let mut next_root = root;
+        next_root[i] += ONE;
+
+        let frame = get_test_frame([ONE, ONE], [ONE, ONE], [root, next_root]);
+        let result = get_constraint_evaluation(frame);
+        for (j, &value) in result.iter().enumerate() {
+            if j == 2 + i {
+                assert_ne!(ZERO, value);
+            } else {
+                assert_eq!(ZERO, value);
+            }
+        }
+    }
+}
+
+// TEST HELPERS
+// ================================================================================================
+
+/// Returns the evaluation of the kernel ROM constraints on the provided frame.
+fn get_constraint_evaluation(frame: EvaluationFrame<Felt>) -> [Felt; kernel_rom::NUM_CONSTRAINTS] {
+    let mut result = [ZERO; kernel_rom::NUM_CONSTRAINTS];
+
+    kernel_rom::enforce_constraints(&frame, &mut result, ONE);
+
+    result
+}
+
+/// Generates an EvaluationFrame with kernel ROM trace data built from the specified values of the
+/// s0, addr, and procedure root columns in the current and the next rows.
+fn get_test_frame(s0: [Felt; 2], addr: [Felt; 2], roots: [[Felt; 4]; 2]) -> EvaluationFrame<Felt> {
+    let mut current = vec![ZERO; TRACE_WIDTH];
+    let mut next = vec![ZERO; TRACE_WIDTH];
+
+    current[KERNEL_ROM_S0_COL_IDX] = s0[0];
+    next[KERNEL_ROM_S0_COL_IDX] = s0[1];
+
+    current[KERNEL_ROM_ADDR_COL_IDX] = addr[0];
+    next[KERNEL_ROM_ADDR_COL_IDX] = addr[1];
+
+    current[KERNEL_ROM_ROOT_COL_RANGE].copy_from_slice(&roots[0]);
+    next[KERNEL_ROM_ROOT_COL_RANGE].copy_from_slice(&roots[1]);
+
+    EvaluationFrame::<Felt>::from_rows(current, next)
+}
@@ -24,7 +24,7 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     5, 5, // Enforce that the memory selectors are binary.
     9, 8, // Enforce s1 is set to 1 when reading existing memory and 0 otherwise.
     7, 6, 9, 8, // Constrain the values in the d inverse column.
-    8, // Enforce values in ctx, addr, clk transition correctly.
+    9, // Enforce values in ctx, addr, clk transition correctly.
     6, 6, 6, 6, // Enforce correct memory initialization when reading from new memory.
     5, 5, 5, 5, // Enforce correct memory copy when reading from existing memory
 ];
@@ -128,7 +128,7 @@ fn enforce_delta<E: FieldElement>(
     // If the context changed, include the difference.
     result.agg_constraint(0, memory_flag * frame.n0(), frame.ctx_change());
     // If the context is the same, include the address difference if it changed or else include the
-    // clock change.
+    // clock change. A write may follow a read of the same address in the same clock cycle.
     result.agg_constraint(
         0,
         memory_flag * frame.not_n0(),
@@ -220,7 +220,8 @@ trait EvaluationFrameExt<E: FieldElement> {
     fn ctx_change(&self) -> E;
     /// The difference between the next address and the current address.
     fn addr_change(&self) -> E;
-    /// The difference between the next clock value and the current one, minus 1.
+    /// The difference between the next clock value and the current one, minus 1. The 1 is not
+    /// subtracted when a write follows a read, since both can happen in the same clock cycle.
     fn clk_change(&self) -> E;
     /// The delta between two consecutive context IDs, addresses, or clock cycles.
     fn delta_next(&self) -> E;
@@ -338,6 +339,7 @@ impl<E: FieldElement> EvaluationFrameExt<E> for &EvaluationFrame<E> {
     #[inline(always)]
     fn clk_change(&self) -> E {
         self.change(MEMORY_CLK_COL_IDX) - E::ONE
+            + self.selector(0) * binary_not(self.selector_next(0))
     }
 
     #[inline(always)]
@@ -86,6 +86,37 @@ fn test_memory_read() {
     assert_eq!(expected, result);
 }
 
+#[test]
+fn test_memory_write_after_read() {
+    let expected = [ZERO; memory::NUM_CONSTRAINTS];
+
+    let old_values = vec![1, 2, 3, 4];
+    let new_values = vec![1, 2, 5, 4];
+
+    // Write to the same context and address in the same clock cycle as the preceding read.
+    let result = get_same_cycle_constraint_evaluation(
+        MEMORY_COPY_READ,
+        MEMORY_WRITE,
+        &old_values,
+        &new_values,
+    );
+    assert_eq!(expected, result);
+
+    // A write cannot follow another write in the same clock cycle.
+    let result =
+        get_same_cycle_constraint_evaluation(MEMORY_WRITE, MEMORY_WRITE, &old_values, &new_values);
+    assert_ne!(expected, result);
+
+    // A read cannot follow a write in the same clock cycle.
+    let result = get_same_cycle_constraint_evaluation(
+        MEMORY_WRITE,
+        MEMORY_COPY_READ,
+        &old_values,
+        &old_values,
+    );
+    assert_ne!(expected, result);
+}
+
 // TEST HELPERS
 // ================================================================================================
 
@@ -123,6 +154,40 @@ fn get_constraint_evaluation(
     result
 }
 
+/// Generates a frame with two accesses to the same context and address in the same clock cycle,
+/// using the specified selectors in the current and the next rows, then returns the evaluation of
+/// the memory constraints on this frame. The delta columns in the next row are set to zero.
+fn get_same_cycle_constraint_evaluation(
+    current_selectors: Selectors,
+    next_selectors: Selectors,
+    old_values: &[u32],
+    new_values: &[u32],
+) -> [Felt; memory::NUM_CONSTRAINTS] {
+    let mut current = vec![ZERO; TRACE_WIDTH];
+    let mut next = vec![ZERO; TRACE_WIDTH];
+
+    current[MEMORY_TRACE_OFFSET] = current_selectors[0];
+    current[MEMORY_TRACE_OFFSET + 1] = current_selectors[1];
+    next[MEMORY_TRACE_OFFSET] = next_selectors[0];
+    next[MEMORY_TRACE_OFFSET + 1] = next_selectors[1];
+
+    let clk = Felt::new(rand_value::<u32>() as u64);
+    current[MEMORY_CLK_COL_IDX] = clk;
+    next[MEMORY_CLK_COL_IDX] = clk;
+
+    for idx in 0..NUM_ELEMENTS {
+        current[MEMORY_V_COL_RANGE.start + idx] = Felt::new(old_values[idx] as u64);
+        next[MEMORY_V_COL_RANGE.start + idx] = Felt::new(new_values[idx] as u64);
+    }
+
+    let frame = EvaluationFrame::<Felt>::from_rows(current, next);
+    let mut result = [ZERO; memory::NUM_CONSTRAINTS];
+
+    memory::enforce_constraints(&frame, &mut result, ONE);
+
+    result
+}
+
 /// Generates an EvaluationFrame with memory trace data as specified by the inputs. The frame treats
 /// the current row as the first row of a memory execution trace with context, address, clock, old
 /// values, delta (d1, d0), and delta inverse set to zero. The provided inputs determine the values
@@ -1,23 +1,43 @@
 use super::super::{
-    EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree, CHIPLETS_OFFSET,
+    Assertion, EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree, CHIPLETS_OFFSET,
+};
+use crate::{
+    trace::chiplets::CHIPLETS_VTABLE_COL_IDX,
+    utils::{are_equal, binary_not, is_binary},
 };
-use crate::utils::{are_equal, binary_not, is_binary};
 use alloc::vec::Vec;
+use vm_core::{ExtensionOf, Kernel};
+use winter_air::AuxTraceRandElements;
 
 mod bitwise;
 mod hasher;
+mod kernel_rom;
 mod memory;
 
 // CONSTANTS
 // ================================================================================================
 /// The number of constraints on the management of the Chiplets module. This does not include
 /// constraints for the individual chiplet components.
-pub const NUM_CONSTRAINTS: usize = 6;
+pub const NUM_CONSTRAINTS: usize = 8;
 /// The degrees of constraints on the management of the Chiplets module. This does not include
 /// constraint degrees for the individual chiplet components.
 pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
-    2, 3, 4, // Selector flags must be binary.
-    2, 3, 4, // Selector flags can only change from 0 -> 1.
+    2, 3, 4, 5, // Selector flags must be binary.
+    2, 3, 4, 5, // Selector flags can only change from 0 -> 1.
+];
+
+// --- Auxiliary column constraints for multiset checks -------------------------------------------
+
+/// The number of auxiliary assertions on the chiplets virtual table and the kernel ROM bus.
+pub const NUM_AUX_ASSERTIONS: usize = 2 + kernel_rom::NUM_BUS_ASSERTIONS;
+/// The number of transition constraints on the chiplets virtual table. This does not include
+/// constraints for the individual chiplet components.
+pub const NUM_AUX_CONSTRAINTS: usize = 2;
+/// The degrees of the transition constraints on the chiplets virtual table. This does not include
+/// constraint degrees for the individual chiplet components.
+pub const AUX_CONSTRAINT_DEGREES: [usize; NUM_AUX_CONSTRAINTS] = [
+    7, // The virtual table is reset when the kernel ROM section of the trace begins.
+    5, // The virtual table does not change in the padding section of the trace.
 ];
 
 // PERIODIC COLUMNS
@@ -46,6 +66,8 @@ pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
 
     degrees.append(&mut memory::get_transition_constraint_degrees());
 
+    degrees.append(&mut kernel_rom::get_transition_constraint_degrees());
+
     degrees
 }
 
@@ -55,6 +77,7 @@ pub fn get_transition_constraint_count() -> usize {
         + hasher::get_transition_constraint_count()
         + bitwise::get_transition_constraint_count()
         + memory::get_transition_constraint_count()
+        + kernel_rom::get_transition_constraint_count()
 }
 
 /// Enforces constraints for the chiplets module and all chiplet components.
@@ -87,6 +110,85 @@ pub fn enforce_constraints<E: FieldElement<BaseField = Felt>>(
 
     // memory transition constraints
     memory::enforce_constraints(frame, &mut result[constraint_offset..], frame.memory_flag(false));
+    constraint_offset += memory::get_transition_constraint_count();
+
+    // kernel ROM transition constraints
+    kernel_rom::enforce_constraints(
+        frame,
+        &mut result[constraint_offset..],
+        frame.kernel_rom_flag(false),
+    );
+}
+
+// CHIPLETS VIRTUAL TABLE
+// ================================================================================================
+
+/// Returns the boundary assertions for the chiplets virtual table and the kernel ROM bus at the
+/// first step.
+pub fn get_aux_assertions_first_step<E: FieldElement>(result: &mut Vec<Assertion<E>>) {
+    result.push(Assertion::single(CHIPLETS_VTABLE_COL_IDX, 0, E::ONE));
+    kernel_rom::get_bus_assertions_first_step(result);
+}
+
+/// Returns the boundary assertions for the chiplets virtual table and the kernel ROM bus at the
+/// last step. At the end of the trace, the virtual table must contain exactly the procedures of
+/// the specified kernel, and all procedures requested from the kernel ROM must have been provided.
+pub fn get_aux_assertions_last_step<E: FieldElement<BaseField = Felt>>(
+    result: &mut Vec<Assertion<E>>,
+    aux_rand_elements: &AuxTraceRandElements<E>,
+    kernel: &Kernel,
+    step: usize,
+) {
+    let alphas = aux_rand_elements.get_segment_elements(0);
+    kernel_rom::get_aux_assertions_last_step(result, alphas, kernel, step);
+    kernel_rom::get_bus_assertions_last_step(result, step);
+}
+
+/// Returns the transition constraint degrees for the chiplets virtual table.
+pub fn get_aux_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+    let mut degrees: Vec<TransitionConstraintDegree> = AUX_CONSTRAINT_DEGREES
+        .iter()
+        .map(|&degree| TransitionConstraintDegree::new(degree))
+        .collect();
+
+    degrees.append(&mut kernel_rom::get_aux_transition_constraint_degrees());
+
+    degrees
+}
+
+/// Enforces constraints on the chiplets virtual table.
+///
+/// The sibling table portion of the virtual table is not constrained here, since its updates
+/// depend on the row preceding the current one. Instead, the virtual table is required to be
+/// reset to ONE when the kernel ROM section of the trace begins, and from there on its value must
+/// be equal to the product of the kernel procedure table entries.
+pub fn enforce_aux_constraints<F, E>(
+    main_frame: &EvaluationFrame<F>,
+    aux_frame: &EvaluationFrame<E>,
+    aux_rand_elements: &AuxTraceRandElements<E>,
+    result: &mut [E],
+) where
+    F: FieldElement<BaseField = Felt>,
+    E: FieldElement<BaseField = Felt> + ExtensionOf<F>,
+{
+    let vt = aux_frame.current()[CHIPLETS_VTABLE_COL_IDX];
+    let vt_next = aux_frame.next()[CHIPLETS_VTABLE_COL_IDX];
+
+    // the virtual table is ONE in the first row of the kernel ROM (or padding, if the kernel ROM is
+    // empty) section of the trace.
+    result[0] = binary_not(vt_next).mul_base(main_frame.kernel_rom_start_flag());
+
+    // the virtual table does not change in the padding section of the trace.
+    result[1] = are_equal(vt_next, vt).mul_base(main_frame.padding_flag());
+
+    // kernel procedure table constraints
+    kernel_rom::enforce_aux_constraints(
+        main_frame,
+        aux_frame,
+        aux_rand_elements.get_segment_elements(0),
+        &mut result[NUM_AUX_CONSTRAINTS..],
+        main_frame.kernel_rom_flag(true),
+    );
 }
 
 // TRANSITION CONSTRAINT HELPERS
@@ -106,23 +208,31 @@ fn enforce_selectors<E: FieldElement>(frame: &EvaluationFrame<E>, result: &mut [
     // When selectors s0 and s1 are set, s2 is binary.
     result[2] = frame.s(0) * frame.s(1) * is_binary(frame.s(2));
 
+    // When selectors s0, s1 and s2 are set, s3 is binary.
+    result[3] = frame.s(0) * frame.s(1) * frame.s(2) * is_binary(frame.s(3));
+
     // --- Selector flags can only stay the same or change from 0 -> 1 ----------------------------
 
     // Selector flag s0 must either be 0 in the current row or 1 in both rows.
-    result[3] = frame.s(0) * are_equal(frame.s(0), frame.s_next(0));
+    result[4] = frame.s(0) * are_equal(frame.s(0), frame.s_next(0));
 
     // When s0 is set, selector flag s1 must either be 0 in the current row or 1 in both rows.
-    result[4] = frame.s(0) * frame.s(1) * are_equal(frame.s(1), frame.s_next(1));
+    result[5] = frame.s(0) * frame.s(1) * are_equal(frame.s(1), frame.s_next(1));
 
     // When selectors s0 and s1 are set, s2 must either be 0 in the current row or 1 in both rows.
-    result[5] = frame.s(0) * frame.s(1) * frame.s(2) * are_equal(frame.s(2), frame.s_next(2));
+    result[6] = frame.s(0) * frame.s(1) * frame.s(2) * are_equal(frame.s(2), frame.s_next(2));
+
+    // When selectors s0, s1 and s2 are set, s3 must either be 0 in the current row or 1 in both
+    // rows.
+    result[7] =
+        frame.s(0) * frame.s(1) * frame.s(2) * frame.s(3) * are_equal(frame.s(3), frame.s_next(3));
 }
 
 // CHIPLETS FRAME EXTENSION TRAIT
 // ================================================================================================
 
 /// Trait to allow easy access to column values and intermediate variables used in constraint
-/// calculations for the Chiplets module and its Hasher, Bitwise, and Memory chiplets.
+/// calculations for the Chiplets module and its Hasher, Bitwise, Memory, and Kernel ROM chiplets.
 trait EvaluationFrameExt<E: FieldElement> {
     // --- Column accessors -----------------------------------------------------------------------
 
@@ -148,6 +258,18 @@ trait EvaluationFrameExt<E: FieldElement> {
     /// transition constraints with `include_last_row = false`, they will not be applied to the
     /// final row of the memory trace.
     fn memory_flag(&self, include_last_row: bool) -> E;
+
+    /// Flag to indicate whether the frame is in the kernel ROM portion of the Chiplets trace.
+    /// When `include_last_row` is true, the kernel ROM flag is true for every row where the kernel
+    /// ROM selectors are set. When false, the last row is excluded.
+    fn kernel_rom_flag(&self, include_last_row: bool) -> E;
+
+    /// Flag to indicate whether the next row of the frame is the first row of the kernel ROM
+    /// portion of the Chiplets trace, or the first row of the padding if the kernel ROM is empty.
+    fn kernel_rom_start_flag(&self) -> E;
+
+    /// Flag to indicate whether the frame is in the padding portion of the Chiplets trace.
+    fn padding_flag(&self) -> E;
 }
 
 impl<E: FieldElement> EvaluationFrameExt<E> for &EvaluationFrame<E> {
@@ -181,6 +303,28 @@ impl<E: FieldElement> EvaluationFrameExt<E> for &EvaluationFrame<E> {
             self.s(0) * self.s(1) * binary_not(self.s_next(2))
         }
     }
+
+    #[inline(always)]
+    fn kernel_rom_flag(&self, include_last_row: bool) -> E {
+        if include_last_row {
+            self.s(0) * self.s(1) * self.s(2) * binary_not(self.s(3))
+        } else {
+            self.s(0) * self.s(1) * self.s(2) * binary_not(self.s_next(3))
+        }
+    }
+
+    #[inline(always)]
+    fn kernel_rom_start_flag(&self) -> E {
+        self.s_next(0)
+            * self.s_next(1)
+            * self.s_next(2)
+            * binary_not(self.s(0) * self.s(1) * self.s(2))
+    }
+
+    #[inline(always)]
+    fn padding_flag(&self) -> E {
+        self.s(0) * self.s(1) * self.s(2) * self.s(3)
+    }
 }
 
 // EXTERNAL ACCESSORS
@@ -1,5 +1,8 @@
 use super::{op_flags::OpFlags, EvaluationFrame, FieldElement, TransitionConstraintDegree};
-use crate::{stack::EvaluationFrameExt, utils::are_equal};
+use crate::{
+    stack::EvaluationFrameExt,
+    utils::{are_equal, binary_not, is_binary},
+};
 use alloc::vec::Vec;
 
 #[cfg(test)]
@@ -9,13 +12,18 @@ pub mod tests;
 // ================================================================================================
 
 /// The number of unique transition constraints in the input/output operations.
-pub const NUM_CONSTRAINTS: usize = 1;
+pub const NUM_CONSTRAINTS: usize = 4;
 
 /// The degrees of constraints in the individual constraints of the input/output ops.
 pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     // Given it is a degree 7 operation, 7 is added to all the individual constraints
     // degree.
     8, // constraint for SDEPTH operation.
+    // MLOADE and MSTOREE are degree 5 operations which differ only in the first op bit, so the
+    // sum of their flags is of degree 4. Thus, 4 is added to the degree of the constraints shared
+    // by both operations, and 5 is added to the degree of the MLOADE constraint.
+    6, 6, // constraints for the element index bits of MLOADE and MSTOREE operations.
+    8, // constraint for the element loaded by MLOADE operation.
 ];
 
 // INPUT/OUTPUT OPERATIONS TRANSITION CONSTRAINTS
@@ -44,6 +52,14 @@ pub fn enforce_constraints<E: FieldElement>(
 
     index += enforce_sdepth_constraint(frame, result, op_flag.sdepth());
 
+    index += enforce_element_index_constraints(
+        frame,
+        &mut result[index..],
+        op_flag.mloade() + op_flag.mstoree(),
+    );
+
+    index += enforce_mloade_constraint(frame, &mut result[index..], op_flag.mloade());
+
     index
 }
 
@@ -60,3 +76,42 @@ pub fn enforce_sdepth_constraint<E: FieldElement>(
 
     1
 }
+
+/// Enforces constraints on the element index of the MLOADE and MSTOREE operations. Both operations
+/// split the element address at the top of the stack into a word address and an element index
+/// within that word; the two bits of the index are placed into user op helper registers 4 and 5.
+/// Therefore, the following constraints are enforced:
+/// - The values in helper registers 4 and 5 must be binary.
+pub fn enforce_element_index_constraints<E: FieldElement>(
+    frame: &EvaluationFrame<E>,
+    result: &mut [E],
+    op_flag: E,
+) -> usize {
+    result[0] = op_flag * is_binary(frame.user_op_helper(4));
+    result[1] = op_flag * is_binary(frame.user_op_helper(5));
+
+    2
+}
+
+/// Enforces constraints of the MLOADE operation. The MLOADE operation reads the word which
+/// contains the element at the specified element address into the first 4 user op helper
+/// registers (in memory order), and pushes the element selected by the index bits in helper
+/// registers 4 and 5 onto the stack. Therefore, the following constraints are enforced:
+/// - The top element in the next frame should be equal to the selected element of the word.
+pub fn enforce_mloade_constraint<E: FieldElement>(
+    frame: &EvaluationFrame<E>,
+    result: &mut [E],
+    op_flag: E,
+) -> usize {
+    let b0 = frame.user_op_helper(4);
+    let b1 = frame.user_op_helper(5);
+
+    let element = binary_not(b0) * binary_not(b1) * frame.user_op_helper(0)
+        + b0 * binary_not(b1) * frame.user_op_helper(1)
+        + binary_not(b0) * b1 * frame.user_op_helper(2)
+        + b0 * b1 * frame.user_op_helper(3);
+
+    result[0] = op_flag * are_equal(frame.stack_item_next(0), element);
+
+    1
+}
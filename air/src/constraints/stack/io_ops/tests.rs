@@ -3,8 +3,9 @@ use crate::stack::{
     op_flags::{generate_evaluation_frame, OpFlags},
     B0_COL_IDX, STACK_TRACE_OFFSET,
 };
-use rand_utils::rand_value;
-use vm_core::{Felt, Operation, ZERO};
+use crate::trace::{decoder::USER_OP_HELPERS_OFFSET, DECODER_TRACE_OFFSET};
+use rand_utils::{rand_array, rand_value};
+use vm_core::{Felt, Operation, ONE, ZERO};
 
 // UNIT TESTS
 // ================================================================================================
@@ -19,6 +20,42 @@ fn test_sdepth_operation() {
     assert_eq!(expected, result);
 }
 
+#[test]
+fn test_mloade_operation() {
+    let expected = [ZERO; NUM_CONSTRAINTS];
+    let word = rand_array::<Felt, 4>();
+
+    for idx in 0..4 {
+        let frame = get_mloade_test_frame(word, idx, word[idx]);
+        let result = get_constraint_evaluation(frame);
+        assert_eq!(expected, result);
+
+        // an element other than the one selected by the index bits must be rejected.
+        let frame = get_mloade_test_frame(word, idx, word[idx] + ONE);
+        let result = get_constraint_evaluation(frame);
+        assert_ne!(ZERO, result[3]);
+    }
+}
+
+#[test]
+fn test_element_index_bits() {
+    let word = rand_array::<Felt, 4>();
+
+    // the index bits of MSTOREE must be binary.
+    let mut frame = get_element_index_test_frame(Operation::MStoreE, 1);
+    frame.current_mut()[DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET + 4] = Felt::new(2);
+    let result = get_constraint_evaluation(frame);
+    assert_ne!(ZERO, result[1]);
+    assert_eq!(ZERO, result[2]);
+
+    // the index bits of MLOADE must be binary.
+    let mut frame = get_mloade_test_frame(word, 2, word[2]);
+    frame.current_mut()[DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET + 5] = Felt::new(3);
+    let result = get_constraint_evaluation(frame);
+    assert_eq!(ZERO, result[1]);
+    assert_ne!(ZERO, result[2]);
+}
+
 // TEST HELPERS
 // ================================================================================================
 
@@ -46,3 +83,30 @@ pub fn get_sdepth_test_frame(depth: u64) -> EvaluationFrame<Felt> {
 
     frame
 }
+
+/// Generates a frame for the specified element operation with the bits of the provided element
+/// index in the user op helper registers 4 and 5.
+fn get_element_index_test_frame(op: Operation, idx: usize) -> EvaluationFrame<Felt> {
+    let mut frame = generate_evaluation_frame(op.op_code() as usize);
+
+    let helpers = DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET;
+    frame.current_mut()[helpers + 4] = Felt::new((idx & 1) as u64);
+    frame.current_mut()[helpers + 5] = Felt::new((idx >> 1) as u64);
+
+    frame
+}
+
+/// Generates the current and next rows for the MLOADE operation which reads the provided word
+/// from memory and pushes `output` onto the stack, and returns an EvaluationFrame for testing.
+fn get_mloade_test_frame(word: [Felt; 4], idx: usize, output: Felt) -> EvaluationFrame<Felt> {
+    let mut frame = get_element_index_test_frame(Operation::MLoadE, idx);
+
+    // the word read from memory is placed into the first 4 helper registers in memory order.
+    let helpers = DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET;
+    for (i, &value) in word.iter().enumerate() {
+        frame.current_mut()[helpers + i] = value;
+    }
+    frame.next_mut()[STACK_TRACE_OFFSET] = output;
+
+    frame
+}
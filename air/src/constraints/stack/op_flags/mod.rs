@@ -241,7 +241,8 @@ impl<E: FieldElement> OpFlags<E> {
         degree5_op_flags[6] = not_0_yes_1 * frame.op_bit(2); // SPAN
         degree5_op_flags[7] = yes_0_yes_1 * frame.op_bit(2); // JOIN
 
-        // the second half of the degree 5 flags share the same lower 3 bits as the first half.
+        // the second half of the degree 5 flags share the same lower 3 bits as the first half
+        // (DYN, RCOMBBASE, MLOADE, MSTOREE, MLEND, MLOADWB, U32SHL, U32SHR).
         degree5_op_flags.copy_within(0..8, 8);
 
         // update the intermediate values of the degree 5 operation flags with the values of
@@ -287,13 +288,21 @@ impl<E: FieldElement> OpFlags<E> {
             + degree5_op_flags[1] // MPVERIFY
             + degree5_op_flags[6] // SPAN
             + degree5_op_flags[7] // JOIN
+            + degree5_op_flags[12] // MLEND
             + degree4_op_flags[6] // RESPAN
             + degree4_op_flags[7] // HALT
             + degree4_op_flags[3] // CALL
             + degree4_op_flags[4] * binary_not(frame.is_loop_end()); // END
 
-        no_shift_flags[1] = no_shift_flags[0] + no_change_1_flag;
-        no_shift_flags[2] = no_shift_flags[1] + degree7_op_flags[8] + f1000; // SWAP
+        no_shift_flags[1] = no_shift_flags[0]
+            + no_change_1_flag
+            + degree7_op_flags[31] // U32POPCNT
+            + degree5_op_flags[10]; // MLOADE
+        no_shift_flags[2] = no_shift_flags[1]
+            + degree7_op_flags[8] // SWAP
+            + f1000 // U32ADD; U32SUB; U32MUL; U32DIV
+            + degree5_op_flags[14] // U32SHL
+            + degree5_op_flags[15]; // U32SHR
         no_shift_flags[3] = no_shift_flags[2] + mov2_flag;
         no_shift_flags[4] = no_shift_flags[3]
             + mov3_flag
@@ -341,6 +350,7 @@ impl<E: FieldElement> OpFlags<E> {
             + degree7_op_flags[45]
             + degree7_op_flags[47]
             + degree7_op_flags[46]
+            + degree5_op_flags[11] // MSTOREE
             + split_loop_flag
             + shift_left_on_end;
 
@@ -348,7 +358,8 @@ impl<E: FieldElement> OpFlags<E> {
         left_shift_flags[3] =
             left_shift_flags[2] + add3_madd_flag + degree7_op_flags[42] - degree7_op_flags[11];
         left_shift_flags[4] = left_shift_flags[3] - degree7_op_flags[13];
-        left_shift_flags[5] = left_shift_flags[4] + degree7_op_flags[44] - degree7_op_flags[17];
+        left_shift_flags[5] = left_shift_flags[4] + degree7_op_flags[44] - degree7_op_flags[17]
+            + degree5_op_flags[13]; // MLOADWB
         left_shift_flags[6] = left_shift_flags[5] - degree7_op_flags[19];
         left_shift_flags[7] = left_shift_flags[6] - degree7_op_flags[21];
         left_shift_flags[8] = left_shift_flags[7] - degree7_op_flags[23];
@@ -393,8 +404,13 @@ impl<E: FieldElement> OpFlags<E> {
         let right_shift = f011 + degree4_op_flags[1] + degree6_op_flags[4]; // PUSH; U32SPLIT
 
         // Flag if the stack has been shifted to the left.
-        let left_shift =
-            f010 + add3_madd_flag + split_loop_flag + degree4_op_flags[5] + shift_left_on_end;
+        let left_shift = f010
+            + add3_madd_flag
+            + split_loop_flag
+            + degree5_op_flags[11] // MSTOREE
+            + degree5_op_flags[13] // MLOADWB
+            + degree4_op_flags[5]
+            + shift_left_on_end;
 
         // Flag if the current operation being executed is a control flow operation.
         // first row: SPAN, JOIN, SPLIT, LOOP
@@ -619,6 +635,12 @@ impl<E: FieldElement> OpFlags<E> {
         self.degree7_op_flags[get_op_index(Operation::Ext2Mul.op_code())]
     }
 
+    /// Operation Flag of U32POPCNT operation.
+    #[inline(always)]
+    pub fn u32popcnt(&self) -> E {
+        self.degree7_op_flags[get_op_index(Operation::U32popcnt.op_code())]
+    }
+
     // ------ Degree 7 operations with left shift -------------------------------------------------
 
     /// Operation Flag of ASSERT operation.
@@ -891,6 +913,42 @@ impl<E: FieldElement> OpFlags<E> {
         self.degree5_op_flags[get_op_index(Operation::Join.op_code())]
     }
 
+    /// Operation Flag of MLOADE operation.
+    #[inline(always)]
+    pub fn mloade(&self) -> E {
+        self.degree5_op_flags[get_op_index(Operation::MLoadE.op_code())]
+    }
+
+    /// Operation Flag of MSTOREE operation.
+    #[inline(always)]
+    pub fn mstoree(&self) -> E {
+        self.degree5_op_flags[get_op_index(Operation::MStoreE.op_code())]
+    }
+
+    /// Operation Flag of MLEND operation.
+    #[inline(always)]
+    pub fn mlend(&self) -> E {
+        self.degree5_op_flags[get_op_index(Operation::MLend.op_code())]
+    }
+
+    /// Operation Flag of MLOADWB operation.
+    #[inline(always)]
+    pub fn mloadwb(&self) -> E {
+        self.degree5_op_flags[get_op_index(Operation::MLoadWB.op_code())]
+    }
+
+    /// Operation Flag of U32SHL operation.
+    #[inline(always)]
+    pub fn u32shl(&self) -> E {
+        self.degree5_op_flags[get_op_index(Operation::U32shl.op_code())]
+    }
+
+    /// Operation Flag of U32SHR operation.
+    #[inline(always)]
+    pub fn u32shr(&self) -> E {
+        self.degree5_op_flags[get_op_index(Operation::U32shr.op_code())]
+    }
+
     // ------ Degree 4 stack operations  ----------------------------------------------------------
 
     /// Operation Flag of MRUPDATE operation.
@@ -1069,10 +1127,10 @@ pub const fn get_op_index(opcode: u8) -> usize {
     if opcode < 64 {
         // index of a degree 7 operation in the degree 7 flag's array.
         opcode
-    } else if opcode < DEGREE_6_OPCODE_ENDS {
+    } else if opcode <= DEGREE_6_OPCODE_ENDS {
         // index of a degree 6 operation in the degree 6 flag's array.
         (opcode - DEGREE_6_OPCODE_STARTS) / 2
-    } else if opcode < DEGREE_5_OPCODE_ENDS {
+    } else if opcode <= DEGREE_5_OPCODE_ENDS {
         // index of a degree 5 operation in the degree 5 flag's array.
         opcode - DEGREE_5_OPCODE_STARTS
     } else {
@@ -145,7 +145,7 @@ fn degree_4_op_flags() {
 fn composite_flags() {
     // ------ no change 0 ---------------------------------------------------------------------
 
-    let op_no_change_0 = [Operation::MpVerify, Operation::Span, Operation::Halt];
+    let op_no_change_0 = [Operation::MpVerify, Operation::MLend, Operation::Span, Operation::Halt];
     for op in op_no_change_0 {
         // frame initialised with an op operation.
         let frame = generate_evaluation_frame(op.op_code().into());
@@ -169,7 +169,7 @@ fn composite_flags() {
         assert_eq!(op_flags.left_shift(), ZERO);
         assert_eq!(op_flags.top_binary(), ZERO);
 
-        if op == Operation::MpVerify {
+        if op == Operation::MpVerify || op == Operation::MLend {
             assert_eq!(op_flags.control_flow(), ZERO);
         } else if op == Operation::Span || op == Operation::Halt {
             assert_eq!(op_flags.control_flow(), ONE);
@@ -180,34 +180,36 @@ fn composite_flags() {
 
     // ------ No change 1 ---------------------------------------------------------------------
 
-    let op = Operation::Incr;
-    // frame initialised with an op operation.
-    let frame = generate_evaluation_frame(op.op_code().into());
+    let op_no_change_1 = [Operation::Incr, Operation::U32popcnt, Operation::MLoadE];
+    for op in op_no_change_1 {
+        // frame initialised with an op operation.
+        let frame = generate_evaluation_frame(op.op_code().into());
 
-    // All the operation flags are generated for the given frame.
-    let op_flags = OpFlags::new(&frame);
+        // All the operation flags are generated for the given frame.
+        let op_flags = OpFlags::new(&frame);
 
-    assert_eq!(op_flags.no_shift_at(0), ZERO);
-    for i in 1..16 {
-        assert_eq!(op_flags.no_shift_at(i), ONE);
-    }
+        assert_eq!(op_flags.no_shift_at(0), ZERO);
+        for i in 1..16 {
+            assert_eq!(op_flags.no_shift_at(i), ONE);
+        }
 
-    for i in 1..16 {
-        assert_eq!(op_flags.left_shift_at(i), ZERO);
-    }
+        for i in 1..16 {
+            assert_eq!(op_flags.left_shift_at(i), ZERO);
+        }
 
-    for i in 0..15 {
-        assert_eq!(op_flags.right_shift_at(i), ZERO);
-    }
+        for i in 0..15 {
+            assert_eq!(op_flags.right_shift_at(i), ZERO);
+        }
 
-    assert_eq!(op_flags.right_shift(), ZERO);
-    assert_eq!(op_flags.left_shift(), ZERO);
-    assert_eq!(op_flags.control_flow(), ZERO);
-    assert_eq!(op_flags.top_binary(), ZERO);
+        assert_eq!(op_flags.right_shift(), ZERO);
+        assert_eq!(op_flags.left_shift(), ZERO);
+        assert_eq!(op_flags.control_flow(), ZERO);
+        assert_eq!(op_flags.top_binary(), ZERO);
+    }
 
     // ------ no change 2 ---------------------------------------------------------------------
 
-    let op_no_change_2 = [Operation::Swap, Operation::U32div];
+    let op_no_change_2 = [Operation::Swap, Operation::U32div, Operation::U32shl, Operation::U32shr];
     for op in op_no_change_2 {
         // frame initialised with an op operation.
         let frame = generate_evaluation_frame(op.op_code().into());
@@ -320,17 +322,14 @@ fn composite_flags() {
     assert_eq!(op_flags.control_flow(), ONE);
     assert_eq!(op_flags.top_binary(), ZERO);
 
-    // ------ Left shift 2 ---------------------------------------------------------------------
-
-    let op = Operation::And;
+    let op = Operation::MStoreE;
     // frame initialised with an op operation.
     let frame = generate_evaluation_frame(op.op_code().into());
 
     // All the operation flags are generated for the given frame.
     let op_flags = OpFlags::new(&frame);
 
-    assert_eq!(op_flags.left_shift_at(1), ZERO);
-    for i in 2..16 {
+    for i in 1..16 {
         assert_eq!(op_flags.left_shift_at(i), ONE);
     }
 
@@ -345,11 +344,11 @@ fn composite_flags() {
     assert_eq!(op_flags.right_shift(), ZERO);
     assert_eq!(op_flags.left_shift(), ONE);
     assert_eq!(op_flags.control_flow(), ZERO);
-    assert_eq!(op_flags.top_binary(), ONE);
+    assert_eq!(op_flags.top_binary(), ZERO);
 
-    // ------ Left shift 3 ---------------------------------------------------------------------
+    // ------ Left shift 2 ---------------------------------------------------------------------
 
-    let op = Operation::U32add3;
+    let op = Operation::And;
     // frame initialised with an op operation.
     let frame = generate_evaluation_frame(op.op_code().into());
 
@@ -357,8 +356,7 @@ fn composite_flags() {
     let op_flags = OpFlags::new(&frame);
 
     assert_eq!(op_flags.left_shift_at(1), ZERO);
-    assert_eq!(op_flags.left_shift_at(2), ZERO);
-    for i in 3..16 {
+    for i in 2..16 {
         assert_eq!(op_flags.left_shift_at(i), ONE);
     }
 
@@ -373,22 +371,20 @@ fn composite_flags() {
     assert_eq!(op_flags.right_shift(), ZERO);
     assert_eq!(op_flags.left_shift(), ONE);
     assert_eq!(op_flags.control_flow(), ZERO);
-    assert_eq!(op_flags.top_binary(), ZERO);
+    assert_eq!(op_flags.top_binary(), ONE);
 
-    // ------ Left shift 5 ---------------------------------------------------------------------
+    // ------ Left shift 3 ---------------------------------------------------------------------
 
-    let op = Operation::MLoadW;
+    let op = Operation::U32add3;
     // frame initialised with an op operation.
     let frame = generate_evaluation_frame(op.op_code().into());
 
     // All the operation flags are generated for the given frame.
     let op_flags = OpFlags::new(&frame);
 
-    for i in 0..5 {
-        assert_eq!(op_flags.left_shift_at(i), ZERO);
-    }
-
-    for i in 5..16 {
+    assert_eq!(op_flags.left_shift_at(1), ZERO);
+    assert_eq!(op_flags.left_shift_at(2), ZERO);
+    for i in 3..16 {
         assert_eq!(op_flags.left_shift_at(i), ONE);
     }
 
@@ -405,6 +401,38 @@ fn composite_flags() {
     assert_eq!(op_flags.control_flow(), ZERO);
     assert_eq!(op_flags.top_binary(), ZERO);
 
+    // ------ Left shift 5 ---------------------------------------------------------------------
+
+    let op_left_shift_5 = [Operation::MLoadW, Operation::MLoadWB];
+    for op in op_left_shift_5 {
+        // frame initialised with an op operation.
+        let frame = generate_evaluation_frame(op.op_code().into());
+
+        // All the operation flags are generated for the given frame.
+        let op_flags = OpFlags::new(&frame);
+
+        for i in 0..5 {
+            assert_eq!(op_flags.left_shift_at(i), ZERO);
+        }
+
+        for i in 5..16 {
+            assert_eq!(op_flags.left_shift_at(i), ONE);
+        }
+
+        for i in 0..16 {
+            assert_eq!(op_flags.no_shift_at(i), ZERO);
+        }
+
+        for i in 0..15 {
+            assert_eq!(op_flags.right_shift_at(i), ZERO);
+        }
+
+        assert_eq!(op_flags.right_shift(), ZERO);
+        assert_eq!(op_flags.left_shift(), ONE);
+        assert_eq!(op_flags.control_flow(), ZERO);
+        assert_eq!(op_flags.top_binary(), ZERO);
+    }
+
     // ------ Right shift 0 ---------------------------------------------------------------------
 
     let op_no_change_0 = [Operation::MovUp2, Operation::Dup1, Operation::Push(ONE)];
@@ -12,7 +12,7 @@ pub mod tests;
 // ================================================================================================
 
 /// The number of unique transition constraints in stack manipulation operations.
-pub const NUM_CONSTRAINTS: usize = 13;
+pub const NUM_CONSTRAINTS: usize = 17;
 
 // The co-efficient of the most significant 16-bit limb in the helper register during aggregation.
 pub const TWO_48: Felt = Felt::new(2u64.pow(48));
@@ -39,6 +39,10 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     8, // constraint for U32MUL operation
     8, // constraint for U32MADD operation
     8, 7, 7, // constraint for U32DIV operation
+    // Given they are degree 5 operations, 5 is added to the degrees of the individual constraints
+    // of the U32SHL and U32SHR operations.
+    7, 7, // constraints for U32SHL operation
+    7, 7, // constraints for U32SHR operation
 ];
 
 // U32 OPERATIONS TRANSITION CONSTRAINTS
@@ -93,6 +97,12 @@ pub fn enforce_constraints<E: FieldElement<BaseField = Felt>>(
     // Enforce constaints of the U32DIV operations.
     index += enforce_u32div_constraints(frame, &mut result[index..], op_flag.u32div(), &limbs);
 
+    // Enforce constaints of the U32SHL operations.
+    index += enforce_u32shl_constraints(frame, &mut result[index..], op_flag.u32shl());
+
+    // Enforce constaints of the U32SHR operations.
+    index += enforce_u32shr_constraints(frame, &mut result[index..], op_flag.u32shr());
+
     index
 }
 
@@ -260,6 +270,63 @@ pub fn enforce_u32div_constraints<E: FieldElement<BaseField = Felt>>(
     3
 }
 
+/// Enforces constraints of the U32SHL operation. The U32SHL operation shifts the second element in
+/// the current trace to the left by the number of bits specified by the first element `b`, and
+/// splits the result into upper and lower 32-bit limbs. The first helper register contains the
+/// `32 - b` least significant bits of the second element, and the second helper register contains
+/// 2^(32 - b); these values are checked by the bitwise chiplet. Therefore, the following
+/// constraints are enforced:
+/// - The product of the first element in the next trace and the second helper register is equal to
+///   the second element in the current trace without its `32 - b` least significant bits.
+/// - The product of the second element in the next trace and the second helper register is equal
+///   to the `32 - b` least significant bits of the second element in the current trace multiplied
+///   by 2^32.
+pub fn enforce_u32shl_constraints<E: FieldElement<BaseField = Felt>>(
+    frame: &EvaluationFrame<E>,
+    result: &mut [E],
+    op_flag: E,
+) -> usize {
+    let a = frame.stack_item(1);
+    let low_bits = frame.user_op_helper(0);
+    let pow2 = frame.user_op_helper(1);
+
+    // Enforces that the upper limb contains the bits shifted out of the lower 32 bits.
+    result[0] = op_flag * are_equal(frame.stack_item_next(0) * pow2, a - low_bits);
+
+    // Enforces that the lower limb contains the remaining bits shifted to the left.
+    result[1] = op_flag * are_equal(frame.stack_item_next(1) * pow2, low_bits * E::from(TWO_32));
+
+    2
+}
+
+/// Enforces constraints of the U32SHR operation. The U32SHR operation shifts the second element in
+/// the current trace to the right by the number of bits specified by the first element `b`. The
+/// first helper register contains the `b` least significant bits of the second element, and the
+/// second helper register contains 2^b; these values are checked by the bitwise chiplet.
+/// Therefore, the following constraints are enforced:
+/// - The product of the second element in the next trace and the second helper register is equal
+///   to the second element in the current trace without its `b` least significant bits.
+/// - The product of the first element in the next trace and the second helper register is equal to
+///   the `b` least significant bits of the second element in the current trace multiplied by 2^32.
+pub fn enforce_u32shr_constraints<E: FieldElement<BaseField = Felt>>(
+    frame: &EvaluationFrame<E>,
+    result: &mut [E],
+    op_flag: E,
+) -> usize {
+    let a = frame.stack_item(1);
+    let low_bits = frame.user_op_helper(0);
+    let pow2 = frame.user_op_helper(1);
+
+    // Enforces that the second element in the next trace is the shifted value.
+    result[0] = op_flag * are_equal(frame.stack_item_next(1) * pow2, a - low_bits);
+
+    // Enforces that the first element in the next trace contains the bits shifted out of the value
+    // moved into the most significant bits.
+    result[1] = op_flag * are_equal(frame.stack_item_next(0) * pow2, low_bits * E::from(TWO_32));
+
+    2
+}
+
 // GENERAL U32 OPERATION CONSTRAINTS
 // ===============================================================================================================
 
@@ -86,6 +86,44 @@ proptest! {
         }
 
     }
+
+    // -------------------------------- U32SHL test --------------------------------------------------
+
+    #[test]
+    fn test_u32shl_operation(a in any::<u32>(), b in 0..=32_u32) {
+        let expected = [ZERO; NUM_CONSTRAINTS];
+        let frame = get_u32shl_test_frame(a, b);
+        let result = get_constraint_evaluation(frame);
+        assert_eq!(expected, result);
+    }
+
+    // -------------------------------- U32SHR test --------------------------------------------------
+
+    #[test]
+    fn test_u32shr_operation(a in any::<u32>(), b in 0..=32_u32) {
+        let expected = [ZERO; NUM_CONSTRAINTS];
+        let frame = get_u32shr_test_frame(a, b);
+        let result = get_constraint_evaluation(frame);
+        assert_eq!(expected, result);
+    }
+}
+
+// UNIT TESTS
+// ================================================================================================
+
+/// Tests that the U32SHL and U32SHR constraints fail when the results are not consistent with the
+/// helper registers.
+#[test]
+fn test_u32shift_invalid_result_fail() {
+    for (frame, result_idx) in [
+        (get_u32shl_test_frame(0xdead_beef, 7), 0),
+        (get_u32shr_test_frame(0xdead_beef, 7), 1),
+    ] {
+        let mut frame = frame;
+        frame.next_mut()[STACK_TRACE_OFFSET + result_idx] += Felt::new(1);
+        let result = get_constraint_evaluation(frame);
+        assert!(result.iter().any(|&value| value != ZERO));
+    }
 }
 
 // TEST HELPERS
@@ -277,6 +315,55 @@ pub fn get_u32sub_test_frame(a: u32, b: u32) -> EvaluationFrame<Felt> {
     frame
 }
 
+/// Generates the correct current and next rows for the U32SHL operation and inputs and
+/// returns an EvaluationFrame for testing.
+pub fn get_u32shl_test_frame(a: u32, b: u32) -> EvaluationFrame<Felt> {
+    // frame initialised with a u32shl operation using it's unique opcode.
+    let mut frame = generate_evaluation_frame(Operation::U32shl.op_code() as usize);
+
+    // the shifted value is split into two 32-bit limbs.
+    let shifted = (a as u64) << b;
+    let (hi, lo) = (shifted >> 32, shifted as u32 as u64);
+
+    // Set the output.
+    frame.current_mut()[STACK_TRACE_OFFSET] = Felt::from(b);
+    frame.current_mut()[STACK_TRACE_OFFSET + 1] = Felt::from(a);
+    frame.next_mut()[STACK_TRACE_OFFSET] = Felt::new(hi);
+    frame.next_mut()[STACK_TRACE_OFFSET + 1] = Felt::new(lo);
+
+    // set the helper registers in the decoder to the 32 - b least significant bits of a and
+    // 2^(32 - b).
+    let pow2 = 1_u64 << (32 - b);
+    frame.current_mut()[DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET] = Felt::new(a as u64 % pow2);
+    frame.current_mut()[DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET + 1] = Felt::new(pow2);
+
+    frame
+}
+
+/// Generates the correct current and next rows for the U32SHR operation and inputs and
+/// returns an EvaluationFrame for testing.
+pub fn get_u32shr_test_frame(a: u32, b: u32) -> EvaluationFrame<Felt> {
+    // frame initialised with a u32shr operation using it's unique opcode.
+    let mut frame = generate_evaluation_frame(Operation::U32shr.op_code() as usize);
+
+    // the bits shifted out of a are moved into the most significant bits of a 32-bit value.
+    let pow2 = 1_u64 << b;
+    let low_bits = a as u64 % pow2;
+    let spill = (low_bits << 32) >> b;
+
+    // Set the output.
+    frame.current_mut()[STACK_TRACE_OFFSET] = Felt::from(b);
+    frame.current_mut()[STACK_TRACE_OFFSET + 1] = Felt::from(a);
+    frame.next_mut()[STACK_TRACE_OFFSET] = Felt::new(spill);
+    frame.next_mut()[STACK_TRACE_OFFSET + 1] = Felt::new(a as u64 >> b);
+
+    // set the helper registers in the decoder to the b least significant bits of a and 2^b.
+    frame.current_mut()[DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET] = Felt::new(low_bits);
+    frame.current_mut()[DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET + 1] = Felt::new(pow2);
+
+    frame
+}
+
 /// Generates the correct current and next rows for the U32DIV operation and inputs and
 /// returns an EvaluationFrame for testing.
 pub fn get_u32div_test_frame(a: u32, b: u32) -> EvaluationFrame<Felt> {
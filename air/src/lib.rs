@@ -10,7 +10,7 @@ use alloc::vec::Vec;
 
 use vm_core::{
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
-    ExtensionOf, ProgramInfo, StackInputs, StackOutputs, ONE, ZERO,
+    ExtensionOf, Kernel, ProgramInfo, StackInputs, StackOutputs, ONE, ZERO,
 };
 use winter_air::{
     Air, AirContext, Assertion, AuxTraceRandElements, EvaluationFrame,
@@ -37,7 +37,7 @@ use utils::TransitionConstraintRange;
 
 pub use errors::ExecutionOptionsError;
 pub use options::{ExecutionOptions, ProvingOptions};
-pub use proof::{ExecutionProof, HashFunction};
+pub use proof::{ExecutionProof, HashFunction, ProofReport, ProofSizes};
 pub use vm_core::{
     utils::{DeserializationError, ToElements},
     Felt, FieldElement, StarkField,
@@ -52,6 +52,7 @@ pub struct ProcessorAir {
     context: AirContext<Felt>,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
+    kernel: Kernel,
     constraint_ranges: TransitionConstraintRange,
 }
 
@@ -80,12 +81,15 @@ impl Air for ProcessorAir {
         let mut range_checker_degrees = range::get_transition_constraint_degrees();
         main_degrees.append(&mut range_checker_degrees);
 
-        let aux_degrees = range::get_aux_transition_constraint_degrees();
+        let mut aux_degrees = range::get_aux_transition_constraint_degrees();
 
-        // --- chiplets (hasher, bitwise, memory) -------------------------
+        // --- chiplets (hasher, bitwise, memory, kernel ROM) -------------------------------------
         let mut chiplets_degrees = chiplets::get_transition_constraint_degrees();
         main_degrees.append(&mut chiplets_degrees);
 
+        let mut chiplets_aux_degrees = chiplets::get_aux_transition_constraint_degrees();
+        aux_degrees.append(&mut chiplets_aux_degrees);
+
         // Define the transition constraint ranges.
         let constraint_ranges = TransitionConstraintRange::new(
             1,
@@ -99,7 +103,8 @@ impl Air for ProcessorAir {
         let num_main_assertions = 2 + stack::NUM_ASSERTIONS + range::NUM_ASSERTIONS;
 
         // Define the number of boundary constraints for the auxiliary execution trace segment.
-        let num_aux_assertions = stack::NUM_AUX_ASSERTIONS + range::NUM_AUX_ASSERTIONS;
+        let num_aux_assertions =
+            stack::NUM_AUX_ASSERTIONS + range::NUM_AUX_ASSERTIONS + chiplets::NUM_AUX_ASSERTIONS;
 
         // Create the context and set the number of transition constraint exemptions to two; this
         // allows us to inject random values into the last row of the execution trace.
@@ -117,6 +122,7 @@ impl Air for ProcessorAir {
             context,
             stack_inputs: pub_inputs.stack_inputs,
             stack_outputs: pub_inputs.stack_outputs,
+            kernel: pub_inputs.program_info.kernel().clone(),
             constraint_ranges,
         }
     }
@@ -179,6 +185,9 @@ impl Air for ProcessorAir {
         // Add initial assertions for the range checker's auxiliary columns.
         range::get_aux_assertions_first_step::<E>(&mut result);
 
+        // Add initial assertions for the chiplets virtual table and the kernel ROM bus.
+        chiplets::get_aux_assertions_first_step::<E>(&mut result);
+
         // --- set assertions for the last step ---------------------------------------------------
         let last_step = self.last_step();
 
@@ -193,6 +202,14 @@ impl Air for ProcessorAir {
         // Add the range checker's auxiliary column assertions for the last step.
         range::get_aux_assertions_last_step::<E>(&mut result, last_step);
 
+        // Add the chiplets virtual table and kernel ROM bus assertions for the last step.
+        chiplets::get_aux_assertions_last_step(
+            &mut result,
+            aux_rand_elements,
+            &self.kernel,
+            last_step,
+        );
+
         result
     }
 
@@ -224,7 +241,7 @@ impl Air for ProcessorAir {
             select_result_range!(result, self.constraint_ranges.range_checker),
         );
 
-        // --- chiplets (hasher, bitwise, memory) -------------------------
+        // --- chiplets (hasher, bitwise, memory, kernel ROM) -------------------------------------
         chiplets::enforce_constraints::<E>(
             frame,
             periodic_values,
@@ -245,6 +262,14 @@ impl Air for ProcessorAir {
     {
         // --- range checker ----------------------------------------------------------------------
         range::enforce_aux_constraints::<F, E>(main_frame, aux_frame, aux_rand_elements, result);
+
+        // --- chiplets ---------------------------------------------------------------------------
+        chiplets::enforce_aux_constraints::<F, E>(
+            main_frame,
+            aux_frame,
+            aux_rand_elements,
+            &mut result[range::NUM_AUX_CONSTRAINTS..],
+        );
     }
 
     fn context(&self) -> &AirContext<Felt> {
@@ -146,11 +146,15 @@ impl From<ProvingOptions> for WinterProofOptions {
 ///
 /// - `max_cycles` specifies the maximum number of cycles a program is allowed to execute.
 /// - `expected_cycles` specifies the number of cycles a program is expected to execute.
+/// - `enable_tracing` specifies whether the `trace` instructions should be handled by the host.
+/// - `enable_memory_tracing` specifies whether all memory accesses made during execution should be
+///   recorded.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ExecutionOptions {
     max_cycles: u32,
     expected_cycles: u32,
     enable_tracing: bool,
+    enable_memory_tracing: bool,
 }
 
 impl Default for ExecutionOptions {
@@ -159,6 +163,7 @@ impl Default for ExecutionOptions {
             max_cycles: u32::MAX,
             expected_cycles: MIN_TRACE_LEN as u32,
             enable_tracing: false,
+            enable_memory_tracing: false,
         }
     }
 }
@@ -191,6 +196,7 @@ impl ExecutionOptions {
             max_cycles,
             expected_cycles,
             enable_tracing,
+            enable_memory_tracing: false,
         })
     }
 
@@ -200,6 +206,15 @@ impl ExecutionOptions {
         self
     }
 
+    /// Enables recording of all memory accesses made during execution.
+    ///
+    /// Recorded accesses are not used for proof generation, and can be used to analyze how a
+    /// program uses memory.
+    pub fn with_memory_tracing(mut self) -> Self {
+        self.enable_memory_tracing = true;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -217,4 +232,9 @@ impl ExecutionOptions {
     pub fn enable_tracing(&self) -> bool {
         self.enable_tracing
     }
+
+    /// Returns a flag indicating whether all memory accesses should be recorded during execution.
+    pub fn enable_memory_tracing(&self) -> bool {
+        self.enable_memory_tracing
+    }
 }
@@ -2,8 +2,9 @@ use alloc::vec::Vec;
 use vm_core::{
     crypto::hash::{Blake3_192, Blake3_256, Hasher, Rpo256},
     utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Felt,
 };
-use winter_air::proof::StarkProof;
+use winter_air::{proof::StarkProof, FieldExtension};
 
 // EXECUTION PROOF
 // ================================================================================================
@@ -50,6 +51,18 @@ impl ExecutionProof {
         }
     }
 
+    /// Decodes the components of this proof and returns a report describing them.
+    ///
+    /// # Errors
+    /// Returns an error if the commitments of the proof cannot be parsed.
+    pub fn inspect(&self) -> Result<ProofReport, DeserializationError> {
+        match self.hash_fn {
+            HashFunction::Blake3_192 => ProofReport::new::<Blake3_192>(self),
+            HashFunction::Blake3_256 => ProofReport::new::<Blake3_256>(self),
+            HashFunction::Rpo256 => ProofReport::new::<Rpo256>(self),
+        }
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
@@ -81,6 +94,278 @@ impl ExecutionProof {
     }
 }
 
+// PROOF REPORT
+// ================================================================================================
+
+/// A description of the parameters and components of an [ExecutionProof].
+///
+/// Commitment roots are reported as serialized digests of the hash function used to generate the
+/// proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofReport {
+    hash_fn: HashFunction,
+    num_queries: usize,
+    blowup_factor: usize,
+    grinding_factor: u32,
+    field_extension: FieldExtension,
+    fri_folding_factor: usize,
+    fri_remainder_max_degree: usize,
+    trace_length: usize,
+    main_trace_width: usize,
+    aux_segment_widths: Vec<usize>,
+    lde_domain_size: usize,
+    num_unique_queries: usize,
+    num_fri_layers: usize,
+    num_fri_remainder_elements: usize,
+    trace_roots: Vec<Vec<u8>>,
+    constraint_root: Vec<u8>,
+    fri_layer_roots: Vec<Vec<u8>>,
+    fri_remainder_root: Vec<u8>,
+    pow_nonce: u64,
+    conjectured_security: u32,
+    proven_security: u32,
+    sizes: ProofSizes,
+}
+
+impl ProofReport {
+    /// Returns a report describing the specified proof generated using hash function `H`.
+    fn new<H: Hasher>(proof: &ExecutionProof) -> Result<Self, DeserializationError> {
+        let stark_proof = &proof.proof;
+        let options = stark_proof.options();
+        let fri_options = options.to_fri_options();
+        let layout = stark_proof.trace_layout();
+
+        let num_fri_layers = stark_proof.fri_proof.num_layers();
+        let (trace_roots, constraint_root, mut fri_layer_roots) =
+            stark_proof
+                .commitments
+                .clone()
+                .parse::<H>(layout.num_segments(), num_fri_layers)?;
+        // the last FRI commitment is the commitment to the remainder
+        let fri_remainder_root = fri_layer_roots.pop().expect("no FRI remainder commitment");
+
+        let extension_degree = options.field_extension().degree() as usize;
+        let sizes = ProofSizes {
+            total: proof.to_bytes().len(),
+            context: stark_proof.context.to_bytes().len(),
+            commitments: stark_proof.commitments.to_bytes().len(),
+            trace_queries: stark_proof
+                .trace_queries
+                .iter()
+                .map(|queries| queries.to_bytes().len())
+                .collect(),
+            constraint_queries: stark_proof.constraint_queries.to_bytes().len(),
+            ood_frame: stark_proof.ood_frame.to_bytes().len(),
+            fri_proof: stark_proof.fri_proof.to_bytes().len(),
+        };
+
+        Ok(Self {
+            hash_fn: proof.hash_fn,
+            num_queries: options.num_queries(),
+            blowup_factor: options.blowup_factor(),
+            grinding_factor: options.grinding_factor(),
+            field_extension: options.field_extension(),
+            fri_folding_factor: fri_options.folding_factor(),
+            fri_remainder_max_degree: fri_options.remainder_max_degree(),
+            trace_length: stark_proof.trace_length(),
+            main_trace_width: layout.main_trace_width(),
+            aux_segment_widths: (0..layout.num_aux_segments())
+                .map(|idx| layout.get_aux_segment_width(idx))
+                .collect(),
+            lde_domain_size: stark_proof.lde_domain_size(),
+            num_unique_queries: stark_proof.num_unique_queries as usize,
+            num_fri_layers,
+            num_fri_remainder_elements: stark_proof.fri_proof.num_remainder_elements::<Felt>()
+                / extension_degree,
+            trace_roots: trace_roots.iter().map(|root| root.to_bytes()).collect(),
+            constraint_root: constraint_root.to_bytes(),
+            fri_layer_roots: fri_layer_roots.iter().map(|root| root.to_bytes()).collect(),
+            fri_remainder_root: fri_remainder_root.to_bytes(),
+            pow_nonce: stark_proof.pow_nonce,
+            conjectured_security: stark_proof.security_level::<H>(true),
+            proven_security: stark_proof.security_level::<H>(false),
+            sizes,
+        })
+    }
+
+    // PROOF OPTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash function used to generate the proof.
+    pub fn hash_fn(&self) -> HashFunction {
+        self.hash_fn
+    }
+
+    /// Returns the number of queries the proof was generated for.
+    pub fn num_queries(&self) -> usize {
+        self.num_queries
+    }
+
+    /// Returns the blowup factor of the low-degree extension domain.
+    pub fn blowup_factor(&self) -> usize {
+        self.blowup_factor
+    }
+
+    /// Returns the number of bits of proof-of-work required for query seed grinding.
+    pub fn grinding_factor(&self) -> u32 {
+        self.grinding_factor
+    }
+
+    /// Returns the field extension used for the composition and FRI protocol.
+    pub fn field_extension(&self) -> FieldExtension {
+        self.field_extension
+    }
+
+    /// Returns the factor by which the domain is reduced in each FRI layer.
+    pub fn fri_folding_factor(&self) -> usize {
+        self.fri_folding_factor
+    }
+
+    /// Returns the maximum degree of the FRI remainder polynomial.
+    pub fn fri_remainder_max_degree(&self) -> usize {
+        self.fri_remainder_max_degree
+    }
+
+    // TRACE DIMENSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the length of the execution trace.
+    pub fn trace_length(&self) -> usize {
+        self.trace_length
+    }
+
+    /// Returns the number of columns in the main segment of the execution trace.
+    pub fn main_trace_width(&self) -> usize {
+        self.main_trace_width
+    }
+
+    /// Returns the number of columns in each auxiliary segment of the execution trace.
+    pub fn aux_segment_widths(&self) -> &[usize] {
+        &self.aux_segment_widths
+    }
+
+    /// Returns the size of the low-degree extension domain.
+    pub fn lde_domain_size(&self) -> usize {
+        self.lde_domain_size
+    }
+
+    // PROOF COMPONENTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of distinct query positions; this can be smaller than the number of
+    /// queries since randomly drawn positions may repeat.
+    pub fn num_unique_queries(&self) -> usize {
+        self.num_unique_queries
+    }
+
+    /// Returns the number of FRI layers, not counting the remainder.
+    pub fn num_fri_layers(&self) -> usize {
+        self.num_fri_layers
+    }
+
+    /// Returns the number of elements (in the extension field) in the FRI remainder.
+    pub fn num_fri_remainder_elements(&self) -> usize {
+        self.num_fri_remainder_elements
+    }
+
+    /// Returns the commitment roots of each segment of the execution trace.
+    pub fn trace_roots(&self) -> &[Vec<u8>] {
+        &self.trace_roots
+    }
+
+    /// Returns the commitment root of the constraint composition polynomial evaluations.
+    pub fn constraint_root(&self) -> &[u8] {
+        &self.constraint_root
+    }
+
+    /// Returns the commitment roots of each FRI layer.
+    pub fn fri_layer_roots(&self) -> &[Vec<u8>] {
+        &self.fri_layer_roots
+    }
+
+    /// Returns the commitment root of the FRI remainder.
+    pub fn fri_remainder_root(&self) -> &[u8] {
+        &self.fri_remainder_root
+    }
+
+    /// Returns the proof-of-work nonce used for query seed grinding.
+    pub fn pow_nonce(&self) -> u64 {
+        self.pow_nonce
+    }
+
+    /// Returns the sizes of the serialized components of the proof.
+    pub fn sizes(&self) -> &ProofSizes {
+        &self.sizes
+    }
+
+    // SECURITY
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the conjectured security level of the proof in bits.
+    pub fn conjectured_security(&self) -> u32 {
+        self.conjectured_security
+    }
+
+    /// Returns the proven security level of the proof in bits.
+    pub fn proven_security(&self) -> u32 {
+        self.proven_security
+    }
+}
+
+/// Sizes in bytes of the serialized components of an [ExecutionProof].
+///
+/// The total size additionally includes the hash function identifier and the number of unique
+/// queries (a single byte each), as well as the proof-of-work nonce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofSizes {
+    total: usize,
+    context: usize,
+    commitments: usize,
+    trace_queries: Vec<usize>,
+    constraint_queries: usize,
+    ood_frame: usize,
+    fri_proof: usize,
+}
+
+impl ProofSizes {
+    /// Returns the size of the entire proof.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the size of the proof context, i.e., the trace layout and the proof options.
+    pub fn context(&self) -> usize {
+        self.context
+    }
+
+    /// Returns the size of the commitments to the trace, the constraints, and the FRI layers.
+    pub fn commitments(&self) -> usize {
+        self.commitments
+    }
+
+    /// Returns the size of the queried rows and their Merkle authentication paths for each
+    /// segment of the execution trace.
+    pub fn trace_queries(&self) -> &[usize] {
+        &self.trace_queries
+    }
+
+    /// Returns the size of the queried constraint evaluations and their Merkle authentication
+    /// paths.
+    pub fn constraint_queries(&self) -> usize {
+        self.constraint_queries
+    }
+
+    /// Returns the size of the out-of-domain evaluation frame.
+    pub fn ood_frame(&self) -> usize {
+        self.ood_frame
+    }
+
+    /// Returns the size of the FRI proof, including the remainder.
+    pub fn fri_proof(&self) -> usize {
+        self.fri_proof
+    }
+}
+
 // HASH FUNCTION
 // ================================================================================================
 
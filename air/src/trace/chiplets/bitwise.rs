@@ -4,10 +4,10 @@ use super::{create_range, Felt, Range, ONE, ZERO};
 // ================================================================================================
 
 /// Number of selector columns in the trace.
-pub const NUM_SELECTORS: usize = 1;
+pub const NUM_SELECTORS: usize = 2;
 
 /// Number of columns needed to record an execution trace of the bitwise chiplet.
-pub const TRACE_WIDTH: usize = NUM_SELECTORS + 12;
+pub const TRACE_WIDTH: usize = NUM_SELECTORS + 13;
 
 /// The number of rows required to compute an operation in the Bitwise chiplet.
 pub const OP_CYCLE_LEN: usize = 8;
@@ -15,16 +15,29 @@ pub const OP_CYCLE_LEN: usize = 8;
 // --- OPERATION SELECTORS ------------------------------------------------------------------------
 
 /// Specifies a bitwise AND operation.
-pub const BITWISE_AND: Felt = ZERO;
+pub const BITWISE_AND: Selectors = [ZERO, ZERO];
 /// Unique label computed as 1 plus the full chiplet selector with the bits reversed.
-/// bitwise_and selector=[1, 0, 0] rev(selector)=[0, 0, 1] +1=[0, 1, 0]
-pub const BITWISE_AND_LABEL: Felt = Felt::new(0b010);
+/// bitwise_and selector=[1, 0, 0, 0] rev(selector)=[0, 0, 0, 1] +1=[0, 0, 1, 0]
+pub const BITWISE_AND_LABEL: Felt = Felt::new(0b0010);
 
 /// Specifies a bitwise XOR operation.
-pub const BITWISE_XOR: Felt = ONE;
+pub const BITWISE_XOR: Selectors = [ONE, ZERO];
 /// Unique label computed as 1 plus the full chiplet selector with the bits reversed.
-/// bitwise_xor selector=[1, 0, 1] rev(selector)=[1, 0, 1] +1=[1, 1, 0]
-pub const BITWISE_XOR_LABEL: Felt = Felt::new(0b110);
+/// bitwise_xor selector=[1, 0, 1, 0] rev(selector)=[0, 1, 0, 1] +1=[0, 1, 1, 0]
+pub const BITWISE_XOR_LABEL: Felt = Felt::new(0b0110);
+
+/// Specifies a population count (the number of set bits) operation.
+pub const BITWISE_POPCNT: Selectors = [ZERO, ONE];
+/// Unique label computed as 1 plus the full chiplet selector with the bits reversed.
+/// bitwise_popcnt selector=[1, 0, 0, 1] rev(selector)=[1, 0, 0, 1] +1=[1, 0, 1, 0]
+pub const BITWISE_POPCNT_LABEL: Felt = Felt::new(0b1010);
+
+/// Specifies a mask operation, which computes the `k` least significant bits of `a` (i.e., `a`
+/// modulo 2^k) using a mask of `k` ones as `b`.
+pub const BITWISE_MASK: Selectors = [ONE, ONE];
+/// Unique label computed as 1 plus the full chiplet selector with the bits reversed.
+/// bitwise_mask selector=[1, 0, 1, 1] rev(selector)=[1, 1, 0, 1] +1=[1, 1, 1, 0]
+pub const BITWISE_MASK_LABEL: Felt = Felt::new(0b1110);
 
 // --- INPUT DECOMPOSITION ------------------------------------------------------------------------
 
@@ -55,6 +68,10 @@ pub const PREV_OUTPUT_COL_IDX: usize = B_COL_IDX + 1 + 2 * NUM_DECOMP_BITS;
 /// execution trace.
 pub const OUTPUT_COL_IDX: usize = PREV_OUTPUT_COL_IDX + 1;
 
+/// The index of the column containing the accumulated number of set bits of `b` for the mask
+/// operation within the bitwise chiplet execution trace.
+pub const MASK_LEN_COL_IDX: usize = OUTPUT_COL_IDX + 1;
+
 // TYPE ALIASES
 // ================================================================================================
 
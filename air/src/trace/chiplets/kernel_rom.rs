@@ -1,4 +1,4 @@
-use super::Felt;
+use super::{create_range, Felt, Range};
 
 // CONSTANTS
 // ================================================================================================
@@ -13,3 +13,13 @@ pub const TRACE_WIDTH: usize = 6;
 /// reversed.
 /// kernel ROM selector=[1, 1, 1, 0] +1=[0, 0, 0, 1]
 pub const KERNEL_PROC_LABEL: Felt = Felt::new(0b1000);
+
+// --- COLUMN ACCESSOR INDICES WITHIN THE CHIPLET -------------------------------------------------
+
+/// Column to hold the flag indicating whether a procedure in a given row is provided to the
+/// kernel ROM bus.
+pub const S0_COL_IDX: usize = 0;
+/// Column to hold the index of a procedure in the kernel.
+pub const ADDR_COL_IDX: usize = S0_COL_IDX + 1;
+/// Columns to hold the root of a kernel procedure.
+pub const ROOT_COL_RANGE: Range<usize> = create_range(ADDR_COL_IDX + 1, 4);
@@ -1,4 +1,4 @@
-use super::{CHIPLETS_OFFSET, HASHER_AUX_TRACE_OFFSET};
+use super::{CHIPLETS_OFFSET, HASHER_AUX_TRACE_OFFSET, KERNEL_ROM_AUX_TRACE_OFFSET};
 use core::ops::Range;
 use vm_core::{utils::range as create_range, Felt, ONE, ZERO};
 
@@ -25,6 +25,8 @@ pub const HASHER_TRACE_OFFSET: usize = CHIPLETS_OFFSET + NUM_HASHER_SELECTORS;
 pub const BITWISE_TRACE_OFFSET: usize = CHIPLETS_OFFSET + NUM_BITWISE_SELECTORS;
 /// The first column of the memory chiplet.
 pub const MEMORY_TRACE_OFFSET: usize = CHIPLETS_OFFSET + NUM_MEMORY_SELECTORS;
+/// The first column of the kernel ROM chiplet.
+pub const KERNEL_ROM_TRACE_OFFSET: usize = CHIPLETS_OFFSET + NUM_KERNEL_ROM_SELECTORS;
 
 // --- GLOBALLY-INDEXED CHIPLET COLUMN ACCESSORS: HASHER ------------------------------------------
 
@@ -52,9 +54,12 @@ pub const HASHER_NODE_INDEX_COL_IDX: usize = HASHER_STATE_COL_RANGE.end;
 
 // --- GLOBALLY-INDEXED CHIPLET COLUMN ACCESSORS: BITWISE -----------------------------------------
 
-/// The index within the main trace of the bitwise column containing selector indicating the
-/// type of bitwise operation (AND or XOR)
-pub const BITWISE_SELECTOR_COL_IDX: usize = BITWISE_TRACE_OFFSET;
+/// The column index range in the execution trace containing the selector columns indicating the
+/// type of bitwise operation (AND, XOR, POPCNT, or MASK).
+pub const BITWISE_SELECTOR_COL_RANGE: Range<usize> =
+    create_range(BITWISE_TRACE_OFFSET, bitwise::NUM_SELECTORS);
+/// The index within the main trace of the bitwise column containing the first selector.
+pub const BITWISE_SELECTOR_COL_IDX: usize = BITWISE_SELECTOR_COL_RANGE.start;
 /// The index within the main trace of the bitwise column holding the aggregated value of input `a`.
 pub const BITWISE_A_COL_IDX: usize = BITWISE_TRACE_OFFSET + bitwise::A_COL_IDX;
 /// The index within the main trace of the bitwise column holding the aggregated value of input `b`.
@@ -73,7 +78,7 @@ pub const BITWISE_B_COL_RANGE: Range<usize> = Range {
 /// The column index range for the main trace of the bitwise column
 pub const BITWISE_TRACE_RANGE: Range<usize> = Range {
     start: BITWISE_TRACE_OFFSET,
-    end: BITWISE_TRACE_OFFSET + bitwise::OUTPUT_COL_IDX + 1,
+    end: BITWISE_TRACE_OFFSET + bitwise::TRACE_WIDTH,
 };
 
 /// The index within the main trace of the bitwise column containing the aggregated output value of
@@ -81,6 +86,9 @@ pub const BITWISE_TRACE_RANGE: Range<usize> = Range {
 pub const BITWISE_PREV_OUTPUT_COL_IDX: usize = BITWISE_TRACE_OFFSET + bitwise::PREV_OUTPUT_COL_IDX;
 /// The index within the main trace of the bitwise column containing the aggregated output value.
 pub const BITWISE_OUTPUT_COL_IDX: usize = BITWISE_TRACE_OFFSET + bitwise::OUTPUT_COL_IDX;
+/// The index within the main trace of the bitwise column containing the accumulated number of set
+/// bits of `b` for the mask operation.
+pub const BITWISE_MASK_LEN_COL_IDX: usize = BITWISE_TRACE_OFFSET + bitwise::MASK_LEN_COL_IDX;
 
 // --- GLOBALLY-INDEXED CHIPLET COLUMN ACCESSORS: MEMORY ------------------------------------------
 
@@ -109,3 +117,25 @@ pub const MEMORY_D1_COL_IDX: usize = MEMORY_TRACE_OFFSET + memory::D1_COL_IDX;
 /// memory context IDs, addresses, or clock cycles, used to enforce that changes are correctly
 /// constrained.
 pub const MEMORY_D_INV_COL_IDX: usize = MEMORY_TRACE_OFFSET + memory::D_INV_COL_IDX;
+
+// --- GLOBALLY-INDEXED CHIPLET COLUMN ACCESSORS: KERNEL ROM --------------------------------------
+
+/// The index within the main trace of the kernel ROM column indicating whether a procedure is
+/// provided to the kernel ROM bus.
+pub const KERNEL_ROM_S0_COL_IDX: usize = KERNEL_ROM_TRACE_OFFSET + kernel_rom::S0_COL_IDX;
+/// The index within the main trace of the kernel ROM column containing the procedure index.
+pub const KERNEL_ROM_ADDR_COL_IDX: usize = KERNEL_ROM_TRACE_OFFSET + kernel_rom::ADDR_COL_IDX;
+/// The column index range within the main trace which holds the kernel procedure roots.
+pub const KERNEL_ROM_ROOT_COL_RANGE: Range<usize> = Range {
+    start: KERNEL_ROM_TRACE_OFFSET + kernel_rom::ROOT_COL_RANGE.start,
+    end: KERNEL_ROM_TRACE_OFFSET + kernel_rom::ROOT_COL_RANGE.end,
+};
+
+// --- GLOBALLY-INDEXED CHIPLET COLUMN ACCESSORS: AUXILIARY TRACE ---------------------------------
+
+/// The index of the auxiliary trace column holding the chiplets virtual table, which combines the
+/// hasher's sibling table and the kernel ROM's kernel procedure table.
+pub const CHIPLETS_VTABLE_COL_IDX: usize = HASHER_AUX_TRACE_OFFSET;
+/// The index of the auxiliary trace column holding the kernel ROM bus, which matches the kernel
+/// procedures requested by `SYSCALL` operations against the procedures provided by the kernel ROM.
+pub const KERNEL_ROM_BUS_COL_IDX: usize = KERNEL_ROM_AUX_TRACE_OFFSET;
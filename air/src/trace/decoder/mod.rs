@@ -102,3 +102,6 @@ pub const P3_COL_IDX: usize = DECODER_AUX_TRACE_OFFSET + 2;
 pub const DECODER_OP_BITS_OFFSET: usize = super::DECODER_TRACE_OFFSET + OP_BITS_OFFSET;
 pub const DECODER_USER_OP_HELPERS_OFFSET: usize =
     super::DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET;
+pub const DECODER_HASHER_STATE_OFFSET: usize = super::DECODER_TRACE_OFFSET + HASHER_STATE_OFFSET;
+pub const DECODER_OP_BITS_EXTRA_COLS_OFFSET: usize =
+    super::DECODER_TRACE_OFFSET + OP_BITS_EXTRA_COLS_OFFSET;
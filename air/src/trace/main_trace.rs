@@ -2,9 +2,9 @@ use super::{
     super::ColMatrix,
     chiplets::{
         hasher::{DIGEST_LEN, HASH_CYCLE_LEN, STATE_WIDTH},
-        BITWISE_A_COL_IDX, BITWISE_B_COL_IDX, BITWISE_OUTPUT_COL_IDX, HASHER_NODE_INDEX_COL_IDX,
-        HASHER_STATE_COL_RANGE, MEMORY_ADDR_COL_IDX, MEMORY_CLK_COL_IDX, MEMORY_CTX_COL_IDX,
-        MEMORY_V_COL_RANGE,
+        BITWISE_A_COL_IDX, BITWISE_B_COL_IDX, BITWISE_MASK_LEN_COL_IDX, BITWISE_OUTPUT_COL_IDX,
+        HASHER_NODE_INDEX_COL_IDX, HASHER_STATE_COL_RANGE, MEMORY_ADDR_COL_IDX, MEMORY_CLK_COL_IDX,
+        MEMORY_CTX_COL_IDX, MEMORY_V_COL_RANGE,
     },
     decoder::{
         GROUP_COUNT_COL_IDX, HASHER_STATE_OFFSET, IN_SPAN_COL_IDX, IS_CALL_FLAG_COL_IDX,
@@ -52,6 +52,11 @@ impl MainTrace {
         self.columns.num_rows()
     }
 
+    /// Returns the columns of this trace.
+    pub fn into_columns(self) -> ColMatrix<Felt> {
+        self.columns
+    }
+
     #[cfg(any(test, feature = "internals"))]
     pub fn get_column_range(&self, range: Range<usize>) -> Vec<Vec<Felt>> {
         range.fold(vec![], |mut acc, col_idx| {
@@ -213,6 +218,10 @@ impl MainTrace {
         ([b6, b5, b4, b3, b2] == [ONE, ZERO, ZERO, ONE, ONE]) ||
         // SPLIT or LOOP block
         ([e0, b3, b2, b1] == [ONE, ZERO, ONE, ZERO]) ||
+        // MSTOREE
+        ([b6, b5, b4, b3, b2, b1, b0] == [ONE, ZERO, ONE, ONE, ZERO, ONE, ONE]) ||
+        // MLOADWB
+        ([b6, b5, b4, b3, b2, b1, b0] == [ONE, ZERO, ONE, ONE, ONE, ZERO, ONE]) ||
         // REPEAT
         ([b6, b5, b4, b3, b2, b1, b0] == [ONE, ONE, ONE, ZERO, ONE, ZERO, ZERO]) ||
         // END of a loop
@@ -321,6 +330,12 @@ impl MainTrace {
         self.columns.get_column(BITWISE_OUTPUT_COL_IDX)[i]
     }
 
+    /// Returns the bitwise column holding the accumulated number of set bits of `b` for the mask
+    /// operation at row i.
+    pub fn chiplet_bitwise_mask_len(&self, i: usize) -> Felt {
+        self.columns.get_column(BITWISE_MASK_LEN_COL_IDX)[i]
+    }
+
     /// Returns the i-th row of the chiplet column containing memory context.
     pub fn chiplet_memory_ctx(&self, i: usize) -> Felt {
         self.columns.get_column(MEMORY_CTX_COL_IDX)[i]
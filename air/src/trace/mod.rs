@@ -57,9 +57,9 @@ pub const TRACE_WIDTH: usize = CHIPLETS_OFFSET + CHIPLETS_WIDTH;
 // AUXILIARY COLUMNS LAYOUT
 // ------------------------------------------------------------------------------------------------
 
-//      decoder         stack       range checks      hasher         chiplets
-//    (3 columns)     (1 column)     (1 column)    (1 column)      (1 column)
-// ├───────────────┴──────────────┴──────────────┴───────────────┴───────────────┤
+//      decoder         stack       range checks      hasher         chiplets      kernel ROM
+//    (3 columns)     (1 column)     (1 column)    (1 column)      (1 column)     (1 column)
+// ├───────────────┴──────────────┴──────────────┴───────────────┴───────────────┴──────────────┤
 
 // Decoder auxiliary columns
 pub const DECODER_AUX_TRACE_OFFSET: usize = 0;
@@ -91,7 +91,13 @@ pub const HASHER_AUX_TRACE_WIDTH: usize = 1;
 pub const HASHER_AUX_TRACE_RANGE: Range<usize> =
     range(HASHER_AUX_TRACE_OFFSET, HASHER_AUX_TRACE_WIDTH);
 
-pub const AUX_TRACE_WIDTH: usize = CHIPLETS_AUX_TRACE_RANGE.end;
+// Kernel ROM auxiliary columns
+pub const KERNEL_ROM_AUX_TRACE_OFFSET: usize = CHIPLETS_AUX_TRACE_RANGE.end;
+pub const KERNEL_ROM_AUX_TRACE_WIDTH: usize = 1;
+pub const KERNEL_ROM_AUX_TRACE_RANGE: Range<usize> =
+    range(KERNEL_ROM_AUX_TRACE_OFFSET, KERNEL_ROM_AUX_TRACE_WIDTH);
+
+pub const AUX_TRACE_WIDTH: usize = KERNEL_ROM_AUX_TRACE_RANGE.end;
 
 /// Number of random elements available to the prover after the commitment to the main trace
 /// segment.
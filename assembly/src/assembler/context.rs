@@ -329,6 +329,11 @@ impl AssemblyContext {
         self.module_stack.last().and_then(|m| m.proc_stack.last())
     }
 
+    /// Returns true if the module currently being compiled is the executable module of a program.
+    pub(crate) fn is_executable_module(&self) -> bool {
+        self.module_stack.last().is_some_and(|module| module.is_executable())
+    }
+
     /// Returns the name of the current procedure, or the reserved name for the main block.
     pub(crate) fn current_context_name(&self) -> &str {
         self.current_proc_context()
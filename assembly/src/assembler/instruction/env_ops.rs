@@ -54,7 +54,7 @@ pub fn locaddr(
 /// current SYSCALL onto the stack.
 ///
 /// # Errors
-/// Returns an error if the instruction is being executed outside of kernel context.
+/// Returns an error if the instruction is being compiled outside of kernel context.
 pub fn caller(
     span: &mut SpanBuilder,
     context: &AssemblyContext,
@@ -93,6 +93,62 @@ pub fn mem_write_imm(
     Ok(None)
 }
 
+/// Appends operations to the span needed to execute an element-addressed memory read
+/// instruction. Specifically, this handles mem_loade instruction.
+///
+/// VM cycles per operation:
+/// - mem_loade: 1 cycle
+/// - mem_loade.a: 2 cycles
+pub fn mem_read_element(
+    span: &mut SpanBuilder,
+    addr: Option<u32>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    // if the address was provided as an immediate value, put it onto the stack
+    if let Some(addr) = addr {
+        push_u32_value(span, addr);
+    }
+
+    span.add_op(MLoadE)
+}
+
+/// Appends operations to the span needed to execute an element-addressed memory write
+/// instruction. Specifically, this handles mem_storee instruction.
+///
+/// VM cycles per operation:
+/// - mem_storee: 2 cycles
+/// - mem_storee.a:
+///   - 4 cycles if a = 1
+///   - 3 cycles if a != 1
+pub fn mem_write_element(
+    span: &mut SpanBuilder,
+    addr: Option<u32>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    // if the address was provided as an immediate value, put it onto the stack
+    if let Some(addr) = addr {
+        push_u32_value(span, addr);
+    }
+
+    span.add_ops([MStoreE, Drop])
+}
+
+/// Appends operations to the span needed to execute a memory read from the memory segment lent
+/// to the current context. Specifically, this handles mem_loadw_borrowed instruction.
+///
+/// VM cycles per operation:
+/// - mem_loadw_borrowed: 1 cycle
+/// - mem_loadw_borrowed.a: 2 cycles
+pub fn mem_read_borrowed(
+    span: &mut SpanBuilder,
+    addr: Option<u32>,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    // if the address was provided as an immediate value, put it onto the stack
+    if let Some(addr) = addr {
+        push_u32_value(span, addr);
+    }
+
+    span.add_op(MLoadWB)
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1,5 +1,5 @@
 use super::{
-    Assembler, AssemblyContext, AssemblyError, CodeBlock, Felt, Instruction, Operation,
+    Assembler, AssemblyContext, AssemblyError, CodeBlock, Felt, Instruction, MemoryMode, Operation,
     ProcedureId, RpoDigest, SpanBuilder, ONE, ZERO,
 };
 use crate::utils::bound_into_included_u64;
@@ -145,7 +145,7 @@ impl Assembler {
             Instruction::U32RotlImm(v) => u32_ops::u32rotl(span, Some(*v)),
             Instruction::U32Rotr => u32_ops::u32rotr(span, None),
             Instruction::U32RotrImm(v) => u32_ops::u32rotr(span, Some(*v)),
-            Instruction::U32Popcnt => u32_ops::u32popcnt(span),
+            Instruction::U32Popcnt => span.add_op(U32popcnt),
             Instruction::U32Clz => u32_ops::u32clz(span),
             Instruction::U32Ctz => u32_ops::u32ctz(span),
             Instruction::U32Clo => u32_ops::u32clo(span),
@@ -259,18 +259,37 @@ impl Assembler {
             Instruction::MemStream => span.add_op(MStream),
 
             Instruction::Locaddr(v) => env_ops::locaddr(span, *v, ctx),
+            Instruction::MemLoad if self.uses_element_memory(ctx) => {
+                mem_ops::mem_read_element(span, None)
+            }
+            Instruction::MemLoadImm(v) if self.uses_element_memory(ctx) => {
+                mem_ops::mem_read_element(span, Some(*v))
+            }
             Instruction::MemLoad => mem_ops::mem_read(span, ctx, None, false, true),
             Instruction::MemLoadImm(v) => mem_ops::mem_read(span, ctx, Some(*v), false, true),
+            Instruction::MemLoadE => mem_ops::mem_read_element(span, None),
+            Instruction::MemLoadEImm(v) => mem_ops::mem_read_element(span, Some(*v)),
             Instruction::MemLoadW => mem_ops::mem_read(span, ctx, None, false, false),
             Instruction::MemLoadWImm(v) => mem_ops::mem_read(span, ctx, Some(*v), false, false),
             Instruction::LocLoad(v) => mem_ops::mem_read(span, ctx, Some(*v as u32), true, true),
             Instruction::LocLoadW(v) => mem_ops::mem_read(span, ctx, Some(*v as u32), true, false),
+            Instruction::MemStore if self.uses_element_memory(ctx) => {
+                mem_ops::mem_write_element(span, None)
+            }
+            Instruction::MemStoreImm(v) if self.uses_element_memory(ctx) => {
+                mem_ops::mem_write_element(span, Some(*v))
+            }
             Instruction::MemStore => span.add_ops([MStore, Drop]),
             Instruction::MemStoreW => span.add_op(MStoreW),
             Instruction::MemStoreImm(v) => mem_ops::mem_write_imm(span, ctx, *v, false, true),
             Instruction::MemStoreWImm(v) => mem_ops::mem_write_imm(span, ctx, *v, false, false),
             Instruction::LocStore(v) => mem_ops::mem_write_imm(span, ctx, *v as u32, true, true),
             Instruction::LocStoreW(v) => mem_ops::mem_write_imm(span, ctx, *v as u32, true, false),
+            Instruction::MemStoreE => mem_ops::mem_write_element(span, None),
+            Instruction::MemStoreEImm(v) => mem_ops::mem_write_element(span, Some(*v)),
+            Instruction::MemLend => span.add_ops([MLend, Drop, Drop]),
+            Instruction::MemLoadWBorrowed => mem_ops::mem_read_borrowed(span, None),
+            Instruction::MemLoadWBorrowedImm(v) => mem_ops::mem_read_borrowed(span, Some(*v)),
 
             Instruction::AdvInject(injector) => adv_ops::adv_inject(span, injector),
 
@@ -335,6 +354,12 @@ impl Assembler {
 
         result
     }
+
+    /// Returns true if `mem_load` and `mem_store` instructions in the current context should be
+    /// compiled using element addresses.
+    fn uses_element_memory(&self, ctx: &AssemblyContext) -> bool {
+        self.memory_mode() == MemoryMode::Element && ctx.is_executable_module()
+    }
 }
 
 // HELPER FUNCTIONS
@@ -241,65 +241,89 @@ pub fn u32not(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError
 
 /// Translates u32shl assembly instructions to VM operations.
 ///
-/// The operation is implemented by putting a power of 2 on the stack, then multiplying it with
-/// the value to be shifted and splitting the result.
+/// The immediate form is implemented by putting a power of 2 on the stack, then multiplying it
+/// with the value to be shifted and splitting the result. The base form is implemented with the
+/// U32SHL operation, which splits the shifted value into two 32-bit limbs, and then dropping the
+/// upper limb.
 ///
 /// VM cycles per mode:
-/// - u32shl: 18 cycles
+/// - u32shl: 2 cycles
 /// - u32shl.b: 3 cycles
 pub fn u32shl(span: &mut SpanBuilder, imm: Option<u8>) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm)?;
-    if imm != Some(0) {
-        span.add_ops([U32mul, Drop])
-    } else {
-        Ok(None)
+    match imm {
+        Some(0) => {
+            // if shift is performed by 0, do nothing (Noop)
+            span.push_op(Noop);
+            Ok(None)
+        }
+        Some(imm) => {
+            push_pow2::<MAX_U32_SHIFT_VALUE>(span, imm)?;
+            span.add_ops([U32mul, Drop])
+        }
+        None => span.add_ops([U32shl, Drop]),
     }
 }
 
 /// Translates u32shr assembly instructions to VM operations.
 ///
-/// The operation is implemented by putting a power of 2 on the stack, then dividing the value to
-/// be shifted by it and returning the quotient.
+/// The immediate form is implemented by putting a power of 2 on the stack, then dividing the value
+/// to be shifted by it and returning the quotient. The base form is implemented with the U32SHR
+/// operation, which computes the shifted value along with the bits shifted out of it, and then
+/// dropping the shifted out bits.
 ///
 /// VM cycles per mode:
-/// - u32shr: 18 cycles
+/// - u32shr: 2 cycles
 /// - u32shr.b: 3 cycles
 pub fn u32shr(span: &mut SpanBuilder, imm: Option<u8>) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_SHIFT_VALUE>(span, imm)?;
-    if imm != Some(0) {
-        span.add_ops([U32div, Drop])
-    } else {
-        Ok(None)
+    match imm {
+        Some(0) => {
+            // if shift is performed by 0, do nothing (Noop)
+            span.push_op(Noop);
+            Ok(None)
+        }
+        Some(imm) => {
+            push_pow2::<MAX_U32_SHIFT_VALUE>(span, imm)?;
+            span.add_ops([U32div, Drop])
+        }
+        None => span.add_ops([U32shr, Drop]),
     }
 }
 
 /// Translates u32rotl assembly instructions to VM operations.
 ///
-/// The base operation is implemented by putting a power of 2 on the stack, then multiplying the
-/// value to be shifted by it and adding the overflow limb to the shifted limb.
+/// The immediate form is implemented by putting a power of 2 on the stack, then multiplying the
+/// value to be shifted by it and adding the overflow limb to the shifted limb. The base form is
+/// implemented with the U32SHL operation, followed by adding the two limbs it outputs.
 ///
 /// VM cycles per mode:
-/// - u32rotl: 18 cycles
+/// - u32rotl: 2 cycles
 /// - u32rotl.b: 3 cycles
 pub fn u32rotl(
     span: &mut SpanBuilder,
     imm: Option<u8>,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
-    prepare_bitwise::<MAX_U32_ROTATE_VALUE>(span, imm)?;
-    if imm != Some(0) {
-        span.add_ops([U32mul, Add])
-    } else {
-        Ok(None)
+    match imm {
+        Some(0) => {
+            // if rotation is performed by 0, do nothing (Noop)
+            span.push_op(Noop);
+            Ok(None)
+        }
+        Some(imm) => {
+            push_pow2::<MAX_U32_ROTATE_VALUE>(span, imm)?;
+            span.add_ops([U32mul, Add])
+        }
+        None => span.add_ops([U32shl, Add]),
     }
 }
 
 /// Translates u32rotr assembly instructions to VM operations.
 ///
-/// The base operation is implemented by multiplying the value to be shifted by 2^(32-b), where
-/// b is the shift amount, then adding the overflow limb to the shifted limb.
+/// The immediate form is implemented by multiplying the value to be shifted by 2^(32-b), where b
+/// is the shift amount, then adding the overflow limb to the shifted limb. The base form is
+/// implemented with the U32SHR operation, followed by adding the two limbs it outputs.
 ///
 /// VM cycles per mode:
-/// - u32rotr: 22 cycles
+/// - u32rotr: 2 cycles
 /// - u32rotr.b: 3 cycles
 pub fn u32rotr(
     span: &mut SpanBuilder,
@@ -309,53 +333,15 @@ pub fn u32rotr(
         Some(0) => {
             // if rotation is performed by 0, do nothing (Noop)
             span.push_op(Noop);
-            return Ok(None);
+            Ok(None)
         }
         Some(imm) => {
             validate_param(imm, 1..=MAX_U32_ROTATE_VALUE)?;
             span.push_op(Push(Felt::new(1 << (32 - imm))));
+            span.add_ops([U32mul, Add])
         }
-        None => {
-            span.push_ops([Push(Felt::new(32)), Swap, U32sub, Drop]);
-            append_pow2_op(span);
-        }
+        None => span.add_ops([U32shr, Add]),
     }
-    span.add_ops([U32mul, Add])
-}
-
-/// Translates u32popcnt assembly instructions to VM operations.
-///
-/// This operation takes 33 cycles.
-pub fn u32popcnt(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
-    #[rustfmt::skip]
-    let ops = [
-        // i = i - ((i >> 1) & 0x55555555);
-        Dup0,
-        Push(Felt::new(1 << 1)), U32div, Drop,
-        Push(Felt::new(0x55555555)),
-        U32and,
-        U32sub, Drop,
-        // i = (i & 0x33333333) + ((i >> 2) & 0x33333333);
-        Dup0,
-        Push(Felt::new(1 << 2)), U32div, Drop,
-        Push(Felt::new(0x33333333)),
-        U32and,
-        Swap,
-        Push(Felt::new(0x33333333)),
-        U32and,
-        U32add, Drop,
-        // i = (i + (i >> 4)) & 0x0F0F0F0F;
-        Dup0,
-        Push(Felt::new(1 << 4)), U32div, Drop,
-        U32add, Drop,
-        Push(Felt::new(0x0F0F0F0F)),
-        U32and,
-        // return (i * 0x01010101) >> 24;
-        Push(Felt::new(0x01010101)),
-        U32mul, Drop,
-        Push(Felt::new(1 << 24)), U32div, Drop
-    ];
-    span.add_ops(ops)
 }
 
 /// Translates `u32clz` assembly instruction to VM operations. `u32clz` counts the number of
@@ -409,25 +395,10 @@ pub fn u32cto(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError
 // BITWISE OPERATIONS - HELPERS
 // ================================================================================================
 
-/// Mutate the first two elements of the stack from `[b, a, ..]` into `[2^b, a, ..]`, with `b`
-/// either as a provided immediate value, or as an element that already exists in the stack.
-fn prepare_bitwise<const MAX_VALUE: u8>(
-    span: &mut SpanBuilder,
-    imm: Option<u8>,
-) -> Result<(), AssemblyError> {
-    match imm {
-        Some(0) => {
-            // if shift/rotation is performed by 0, do nothing (Noop)
-            span.push_op(Noop);
-        }
-        Some(imm) => {
-            validate_param(imm, 1..=MAX_VALUE)?;
-            span.push_op(Push(Felt::new(1 << imm)));
-        }
-        None => {
-            append_pow2_op(span);
-        }
-    }
+/// Pushes `2^imm` onto the stack, where `imm` is the provided immediate shift/rotation value.
+fn push_pow2<const MAX_VALUE: u8>(span: &mut SpanBuilder, imm: u8) -> Result<(), AssemblyError> {
+    validate_param(imm, 1..=MAX_VALUE)?;
+    span.push_op(Push(Felt::new(1 << imm)));
     Ok(())
 }
 
@@ -1,9 +1,9 @@
 use super::{
     ast::{instrument, Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
     crypto::hash::RpoDigest,
-    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, Library, LibraryError,
-    LibraryPath, Module, NamedProcedure, Operation, Procedure, ProcedureId, ProcedureName, Program,
-    ONE, ZERO,
+    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, KernelPackage, Library,
+    LibraryError, LibraryPath, Module, NamedProcedure, Operation, Procedure, ProcedureId,
+    ProcedureName, Program, Version, ONE, ZERO,
 };
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
@@ -32,15 +32,44 @@ mod tests;
 /// Miden Assembler which can be used to convert Miden assembly source code into program MAST.
 ///
 /// The assembler can be instantiated in several ways using a "builder" pattern. Specifically:
-/// - If `with_kernel()` or `with_kernel_module()` methods are not used, the assembler will be
+/// - If `with_kernel()`, `with_kernel_module()`, or `with_kernel_package()` methods are not used, the assembler will be
 ///   instantiated with a default empty kernel. Programs compiled using such assembler
 ///   cannot make calls to kernel procedures via `syscall` instruction.
+/// - If `with_memory_mode()` method is not used, the assembler will compile `mem_load` and
+///   `mem_store` instructions using word addresses (see [MemoryMode]).
 #[derive(Default)]
 pub struct Assembler {
     kernel: Kernel,
+    kernel_module: Option<ModuleAst>,
     module_provider: ModuleProvider,
     proc_cache: RefCell<ProcedureCache>,
     in_debug_mode: bool,
+    memory_mode: MemoryMode,
+}
+
+// MEMORY MODE
+// ================================================================================================
+
+/// Specifies how the assembler interprets addresses of the single-element memory instructions
+/// `mem_load` and `mem_store` (including their immediate variants).
+///
+/// The memory mode applies only to the program being compiled (i.e., the program body and its
+/// local procedures). Procedures imported from libraries are always compiled using word
+/// addresses, so that libraries (including the standard library) behave identically regardless
+/// of the mode of the program which uses them. Library code can access individual elements via
+/// `mem_loade` and `mem_storee` instructions, which are available in both modes.
+///
+/// Word-oriented instructions (e.g., `mem_loadw`, `mem_storew`, `mem_stream`, `adv_pipe`) as well
+/// as procedure locals and `locaddr` always use word addresses. Element address `a` refers to
+/// element `a mod 4` of the word at word address `a / 4`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryMode {
+    /// `mem_load` and `mem_store` take a word address and access the first element of the word.
+    #[default]
+    Word,
+    /// `mem_load` and `mem_store` take an element address, and are equivalent to `mem_loade` and
+    /// `mem_storee` respectively.
+    Element,
 }
 
 impl Assembler {
@@ -53,6 +82,12 @@ impl Assembler {
         self
     }
 
+    /// Sets the memory mode used to compile `mem_load` and `mem_store` instructions of programs.
+    pub fn with_memory_mode(mut self, memory_mode: MemoryMode) -> Self {
+        self.memory_mode = memory_mode;
+        self
+    }
+
     /// Adds the library to provide modules for the compilation.
     pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
     where
@@ -96,10 +131,39 @@ impl Assembler {
         // convert the context into Kernel; this builds the kernel from hashes of procedures
         // exported form the kernel module
         self.kernel = context.into_kernel();
+        self.kernel_module = Some(kernel.ast);
 
         Ok(self)
     }
 
+    /// Sets the kernel for the assembler to the kernel defined by the provided kernel package.
+    ///
+    /// `syscall` targets of programs compiled by this assembler are resolved against the names
+    /// of the procedures exported from the package.
+    ///
+    /// # Errors
+    /// Returns an error if compiling the kernel module of the package results in an error, or if
+    /// the compiled kernel procedures do not match the names and MAST roots recorded in the
+    /// package.
+    pub fn with_kernel_package(self, package: &KernelPackage) -> Result<Self, AssemblyError> {
+        let assembler = self.with_kernel_module(package.module().clone())?;
+
+        let compiled =
+            assembler.kernel_package(*package.version()).expect("kernel module was not set");
+        for (name, root) in package.procedures() {
+            if compiled.get_proc_root(name) != Some(*root) {
+                return Err(AssemblyError::kernel_package_mismatch(name));
+            }
+        }
+        if let Some((name, _)) =
+            compiled.procedures().find(|(name, _)| package.get_proc_root(name).is_none())
+        {
+            return Err(AssemblyError::kernel_package_mismatch(name));
+        }
+
+        Ok(assembler)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -108,6 +172,12 @@ impl Assembler {
         self.in_debug_mode
     }
 
+    /// Returns the memory mode used to compile `mem_load` and `mem_store` instructions of
+    /// programs.
+    pub fn memory_mode(&self) -> MemoryMode {
+        self.memory_mode
+    }
+
     /// Returns a reference to the kernel for this assembler.
     ///
     /// If the assembler was instantiated without a kernel, the internal kernel will be empty.
@@ -115,6 +185,30 @@ impl Assembler {
         &self.kernel
     }
 
+    /// Returns a kernel package with the specified version built from the kernel of this
+    /// assembler.
+    ///
+    /// The package pairs the names of all procedures exported from the kernel module with their
+    /// MAST roots. Returns None if the assembler was instantiated without a kernel.
+    pub fn kernel_package(&self, version: Version) -> Option<KernelPackage> {
+        let module = self.kernel_module.as_ref()?;
+
+        let proc_cache = self.proc_cache.borrow();
+        let procedures = module
+            .procs()
+            .iter()
+            .filter(|proc| proc.is_export)
+            .filter_map(|proc| {
+                let proc_id = ProcedureId::from_kernel_name(&proc.name);
+                proc_cache
+                    .get_by_id(&proc_id)
+                    .map(|procedure| (proc.name.clone(), procedure.mast_root()))
+            })
+            .collect();
+
+        Some(KernelPackage::new(version, module.clone(), procedures))
+    }
+
     // PROGRAM COMPILER
     // --------------------------------------------------------------------------------------------
 
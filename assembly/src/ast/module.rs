@@ -90,6 +90,7 @@ impl ModuleAst {
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
             num_proc_locals: 0,
+            is_program: false,
         };
         context.parse_procedures(&mut tokens, true)?;
 
@@ -20,6 +20,8 @@ use vm_core::{AdviceInjector, Felt, SignatureKind, ZERO};
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum AdviceInjectorNode {
     PushU64Div,
+    PushU256MontInv,
+    PushU256MontQuot,
     PushExt2intt,
     PushSmtGet,
     PushSmtSet,
@@ -41,6 +43,8 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
         use AdviceInjectorNode::*;
         match value {
             PushU64Div => Self::U64Div,
+            PushU256MontInv => Self::U256MontInv,
+            PushU256MontQuot => Self::U256MontQuot,
             PushExt2intt => Self::Ext2Intt,
             PushSmtGet => Self::SmtGet,
             PushSmtSet => Self::SmtSet,
@@ -78,6 +82,8 @@ impl fmt::Display for AdviceInjectorNode {
         use AdviceInjectorNode::*;
         match self {
             PushU64Div => write!(f, "push_u64div"),
+            PushU256MontInv => write!(f, "push_u256montinv"),
+            PushU256MontQuot => write!(f, "push_u256montquot"),
             PushExt2intt => write!(f, "push_ext2intt"),
             PushSmtGet => write!(f, "push_smtget"),
             PushSmtSet => write!(f, "push_smtset"),
@@ -114,12 +120,16 @@ const INSERT_HDWORD: u8 = 11;
 const INSERT_HDWORD_IMM: u8 = 12;
 const INSERT_HPERM: u8 = 13;
 const PUSH_SIG: u8 = 14;
+const PUSH_U256MONTINV: u8 = 15;
+const PUSH_U256MONTQUOT: u8 = 16;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         use AdviceInjectorNode::*;
         match self {
             PushU64Div => target.write_u8(PUSH_U64DIV),
+            PushU256MontInv => target.write_u8(PUSH_U256MONTINV),
+            PushU256MontQuot => target.write_u8(PUSH_U256MONTQUOT),
             PushExt2intt => target.write_u8(PUSH_EXT2INTT),
             PushSmtGet => target.write_u8(PUSH_SMTGET),
             PushSmtSet => target.write_u8(PUSH_SMTSET),
@@ -154,6 +164,8 @@ impl Deserializable for AdviceInjectorNode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         match source.read_u8()? {
             PUSH_U64DIV => Ok(AdviceInjectorNode::PushU64Div),
+            PUSH_U256MONTINV => Ok(AdviceInjectorNode::PushU256MontInv),
+            PUSH_U256MONTQUOT => Ok(AdviceInjectorNode::PushU256MontQuot),
             PUSH_EXT2INTT => Ok(AdviceInjectorNode::PushExt2intt),
             PUSH_SMTGET => Ok(AdviceInjectorNode::PushSmtGet),
             PUSH_SMTSET => Ok(AdviceInjectorNode::PushSmtSet),
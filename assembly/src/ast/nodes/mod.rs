@@ -243,6 +243,10 @@ pub enum Instruction {
     MemLoadImm(u32),
     MemLoadW,
     MemLoadWImm(u32),
+    MemLoadE,
+    MemLoadEImm(u32),
+    MemLoadWBorrowed,
+    MemLoadWBorrowedImm(u32),
     LocLoad(u16),
     LocLoadW(u16),
 
@@ -251,7 +255,10 @@ pub enum Instruction {
     LocStore(u16),
     MemStoreW,
     MemStoreWImm(u32),
+    MemStoreE,
+    MemStoreEImm(u32),
     LocStoreW(u16),
+    MemLend,
 
     MemStream,
     AdvPipe,
@@ -509,6 +516,10 @@ impl fmt::Display for Instruction {
             Self::MemLoadImm(value) => write!(f, "mem_load.{value}"),
             Self::MemLoadW => write!(f, "mem_loadw"),
             Self::MemLoadWImm(value) => write!(f, "mem_loadw.{value}"),
+            Self::MemLoadE => write!(f, "mem_loade"),
+            Self::MemLoadEImm(value) => write!(f, "mem_loade.{value}"),
+            Self::MemLoadWBorrowed => write!(f, "mem_loadw_borrowed"),
+            Self::MemLoadWBorrowedImm(value) => write!(f, "mem_loadw_borrowed.{value}"),
             Self::LocLoad(value) => write!(f, "loc_load.{value}"),
             Self::LocLoadW(value) => write!(f, "loc_loadw.{value}"),
 
@@ -517,7 +528,10 @@ impl fmt::Display for Instruction {
             Self::LocStore(value) => write!(f, "loc_store.{value}"),
             Self::MemStoreW => write!(f, "mem_storew"),
             Self::MemStoreWImm(value) => write!(f, "mem_storew.{value}"),
+            Self::MemStoreE => write!(f, "mem_storee"),
+            Self::MemStoreEImm(value) => write!(f, "mem_storee.{value}"),
             Self::LocStoreW(value) => write!(f, "loc_storew.{value}"),
+            Self::MemLend => write!(f, "mem_lend"),
 
             Self::MemStream => write!(f, "mem_stream"),
             Self::AdvPipe => write!(f, "adv_pipe"),
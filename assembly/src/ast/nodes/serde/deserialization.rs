@@ -300,12 +300,19 @@ impl Deserializable for Instruction {
             OpCode::MemLoadWImm => Ok(Instruction::MemLoadWImm(source.read_u32()?)),
             OpCode::LocLoad => Ok(Instruction::LocLoad(source.read_u16()?)),
             OpCode::LocLoadW => Ok(Instruction::LocLoadW(source.read_u16()?)),
+            OpCode::MemLoadE => Ok(Instruction::MemLoadE),
+            OpCode::MemLoadEImm => Ok(Instruction::MemLoadEImm(source.read_u32()?)),
+            OpCode::MemLoadWBorrowed => Ok(Instruction::MemLoadWBorrowed),
+            OpCode::MemLoadWBorrowedImm => Ok(Instruction::MemLoadWBorrowedImm(source.read_u32()?)),
             OpCode::MemStore => Ok(Instruction::MemStore),
             OpCode::MemStoreImm => Ok(Instruction::MemStoreImm(source.read_u32()?)),
             OpCode::LocStore => Ok(Instruction::LocStore(source.read_u16()?)),
             OpCode::MemStoreW => Ok(Instruction::MemStoreW),
             OpCode::MemStoreWImm => Ok(Instruction::MemStoreWImm(source.read_u32()?)),
+            OpCode::MemStoreE => Ok(Instruction::MemStoreE),
+            OpCode::MemStoreEImm => Ok(Instruction::MemStoreEImm(source.read_u32()?)),
             OpCode::LocStoreW => Ok(Instruction::LocStoreW(source.read_u16()?)),
+            OpCode::MemLend => Ok(Instruction::MemLend),
 
             OpCode::MemStream => Ok(Instruction::MemStream),
             OpCode::AdvPipe => Ok(Instruction::AdvPipe),
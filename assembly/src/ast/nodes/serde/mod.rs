@@ -268,6 +268,17 @@ pub enum OpCode {
     Emit = 227,
     Trace = 228,
 
+    // ----- element-addressed memory -------------------------------------------------------------
+    MemLoadE = 229,
+    MemLoadEImm = 230,
+    MemStoreE = 231,
+    MemStoreEImm = 232,
+
+    // ----- borrowed memory ----------------------------------------------------------------------
+    MemLend = 233,
+    MemLoadWBorrowed = 234,
+    MemLoadWBorrowedImm = 235,
+
     // ----- control flow -------------------------------------------------------------------------
     IfElse = 253,
     Repeat = 254,
@@ -387,6 +387,16 @@ impl Serializable for Instruction {
                 OpCode::LocLoadW.write_into(target);
                 target.write_u16(*v);
             }
+            Self::MemLoadE => OpCode::MemLoadE.write_into(target),
+            Self::MemLoadEImm(v) => {
+                OpCode::MemLoadEImm.write_into(target);
+                target.write_u32(*v);
+            }
+            Self::MemLoadWBorrowed => OpCode::MemLoadWBorrowed.write_into(target),
+            Self::MemLoadWBorrowedImm(v) => {
+                OpCode::MemLoadWBorrowedImm.write_into(target);
+                target.write_u32(*v);
+            }
             Self::MemStore => OpCode::MemStore.write_into(target),
             Self::MemStoreImm(v) => {
                 OpCode::MemStoreImm.write_into(target);
@@ -401,10 +411,16 @@ impl Serializable for Instruction {
                 OpCode::MemStoreWImm.write_into(target);
                 target.write_u32(*v);
             }
+            Self::MemStoreE => OpCode::MemStoreE.write_into(target),
+            Self::MemStoreEImm(v) => {
+                OpCode::MemStoreEImm.write_into(target);
+                target.write_u32(*v);
+            }
             Self::LocStoreW(v) => {
                 OpCode::LocStoreW.write_into(target);
                 target.write_u16(*v);
             }
+            Self::MemLend => OpCode::MemLend.write_into(target),
 
             Self::MemStream => OpCode::MemStream.write_into(target),
             Self::AdvPipe => OpCode::AdvPipe.write_into(target),
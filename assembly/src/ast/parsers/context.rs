@@ -17,6 +17,7 @@ pub struct ParserContext<'a> {
     pub reexported_procs: ReExportedProcMap,
     pub local_constants: LocalConstMap,
     pub num_proc_locals: u16,
+    pub is_program: bool,
 }
 
 impl ParserContext<'_> {
@@ -457,6 +458,19 @@ impl ParserContext<'_> {
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Parses the `caller` instruction, which can be used only in kernel procedures. Since a
+    /// program cannot contain kernel procedures, the instruction is rejected when parsing a
+    /// program.
+    ///
+    /// Whether a module is a kernel module is not known at parse time, and thus, the instruction
+    /// in library modules is rejected by the assembler.
+    fn parse_caller(&self, op: &Token) -> Result<Node, ParsingError> {
+        if self.is_program {
+            return Err(ParsingError::caller_in_program(op));
+        }
+        simple_instruction(op, Instruction::Caller)
+    }
+
     /// Parses a token into an instruction node.
     fn parse_op_token(&mut self, op: &Token) -> Result<Node, ParsingError> {
         use Instruction::*;
@@ -582,21 +596,26 @@ impl ParserContext<'_> {
 
             "sdepth" => simple_instruction(op, Sdepth),
             "locaddr" => io_ops::parse_locaddr(op, &self.local_constants),
-            "caller" => simple_instruction(op, Caller), // TODO: error if not in SYSCALL (issue #551)
+            "caller" => self.parse_caller(op),
             "clk" => simple_instruction(op, Clk),
 
             "mem_load" => io_ops::parse_mem_load(op, &self.local_constants),
             "loc_load" => io_ops::parse_loc_load(op, &self.local_constants),
 
             "mem_loadw" => io_ops::parse_mem_loadw(op, &self.local_constants),
+            "mem_loade" => io_ops::parse_mem_loade(op, &self.local_constants),
             "loc_loadw" => io_ops::parse_loc_loadw(op, &self.local_constants),
 
             "mem_store" => io_ops::parse_mem_store(op, &self.local_constants),
             "loc_store" => io_ops::parse_loc_store(op, &self.local_constants),
 
             "mem_storew" => io_ops::parse_mem_storew(op, &self.local_constants),
+            "mem_storee" => io_ops::parse_mem_storee(op, &self.local_constants),
             "loc_storew" => io_ops::parse_loc_storew(op, &self.local_constants),
 
+            "mem_lend" => simple_instruction(op, MemLend),
+            "mem_loadw_borrowed" => io_ops::parse_mem_loadw_borrowed(op, &self.local_constants),
+
             "mem_stream" => simple_instruction(op, MemStream),
             "adv_pipe" => simple_instruction(op, AdvPipe),
 
@@ -112,6 +112,47 @@ pub fn parse_mem_load(op: &Token, constants: &LocalConstMap) -> Result<Node, Par
     }
 }
 
+/// Returns `MemLoadE` instruction node if no immediate value is provided, or `MemLoadEImm`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains a wrong number of parameters, or if
+/// the provided parameter is not a u32 value.
+pub fn parse_mem_loade(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "mem_loade");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(MemLoadE)),
+        2 => {
+            let address = parse_param_with_constant_lookup::<u32>(op, 1, constants)?;
+            Ok(Instruction(MemLoadEImm(address)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `MemLoadWBorrowed` instruction node if no immediate value is provided, or
+/// `MemLoadWBorrowedImm` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains a wrong number of parameters, or if
+/// the provided parameter is not a u32 value.
+pub fn parse_mem_loadw_borrowed(
+    op: &Token,
+    constants: &LocalConstMap,
+) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "mem_loadw_borrowed");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(MemLoadWBorrowed)),
+        2 => {
+            let address = parse_param_with_constant_lookup::<u32>(op, 1, constants)?;
+            Ok(Instruction(MemLoadWBorrowedImm(address)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `LocLoad` instruction node.
 ///
 /// # Errors
@@ -186,6 +227,25 @@ pub fn parse_mem_store(op: &Token, constants: &LocalConstMap) -> Result<Node, Pa
     }
 }
 
+/// Returns `MemStoreE` instruction node if no immediate value is provided, or `MemStoreEImm`
+/// instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains a wrong number of parameters, or if
+/// the provided parameter is not a u32 value.
+pub fn parse_mem_storee(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "mem_storee");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(MemStoreE)),
+        2 => {
+            let address = parse_param_with_constant_lookup::<u32>(op, 1, constants)?;
+            Ok(Instruction(MemStoreEImm(address)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `LocStore` instruction node.
 ///
 /// # Errors
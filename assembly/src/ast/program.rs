@@ -1,4 +1,7 @@
-use alloc::vec::Vec;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use crate::ast::MAX_BODY_LEN;
 
@@ -119,6 +122,32 @@ impl ProgramAst {
         &self.import_info
     }
 
+    // FORMATTING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the internal procedures of this program, each formatted as MASM code in the same
+    /// way as when the whole program is formatted.
+    ///
+    /// # Panics
+    /// Panics if import info is not associated with this program.
+    pub fn format_procedures(&self) -> Vec<String> {
+        let context = AstFormatterContext::new(&self.local_procs, self.import_info.invoked_procs());
+        self.local_procs
+            .iter()
+            .map(|proc| FormattableProcedureAst::new(proc, &context).to_string())
+            .collect()
+    }
+
+    /// Returns the body of this program formatted as MASM code, without the enclosing `begin` and
+    /// `end`.
+    ///
+    /// # Panics
+    /// Panics if import info is not associated with this program.
+    pub fn format_body(&self) -> String {
+        let context = AstFormatterContext::new(&self.local_procs, self.import_info.invoked_procs());
+        FormattableCodeBody::new(&self.body, &context).to_string()
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses the provided source into a [ProgramAst].
@@ -136,6 +165,7 @@ impl ProgramAst {
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
             num_proc_locals: 0,
+            is_program: true,
         };
 
         context.parse_procedures(&mut tokens, false)?;
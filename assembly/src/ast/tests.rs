@@ -228,9 +228,11 @@ fn test_ast_parsing_adv_injection() {
     use super::AdviceInjectorNode::*;
     use Instruction::AdvInject;
 
-    let source = "begin adv.push_u64div adv.push_mapval adv.push_smtget adv.insert_mem end";
+    let source = "begin adv.push_u64div adv.push_u256montinv adv.push_u256montquot adv.push_mapval adv.push_smtget adv.insert_mem end";
     let nodes: Vec<Node> = vec![
         Node::Instruction(AdvInject(PushU64Div)),
+        Node::Instruction(AdvInject(PushU256MontInv)),
+        Node::Instruction(AdvInject(PushU256MontQuot)),
         Node::Instruction(AdvInject(PushMapVal)),
         Node::Instruction(AdvInject(PushSmtGet)),
         Node::Instruction(AdvInject(InsertMem)),
@@ -784,6 +786,18 @@ fn test_ast_program_serde_simple() {
     assert_correct_program_serialization(source, true);
 }
 
+#[test]
+fn test_ast_program_serde_element_memory() {
+    let source = "begin mem_loade mem_loade.7 push.1 mem_storee mem_storee.9 end";
+    assert_correct_program_serialization(source, true);
+}
+
+#[test]
+fn test_ast_program_serde_borrowed_memory() {
+    let source = "begin push.4.2 mem_lend mem_loadw_borrowed mem_loadw_borrowed.3 end";
+    assert_correct_program_serialization(source, true);
+}
+
 #[test]
 fn test_ast_program_serde_local_procs() {
     let source = "\
@@ -29,6 +29,7 @@ pub enum AssemblyError {
     InvalidProgramAssemblyContext,
     Io(String),
     KernelError(KernelError),
+    KernelPackageMismatch(String),
     KernelProcNotFound(ProcedureId),
     LibraryError(String),
     LocalProcNotFound(u16, String),
@@ -91,6 +92,10 @@ impl AssemblyError {
         Self::ImportedProcNotFoundInModule(*proc_id, module_path.to_string())
     }
 
+    pub fn kernel_package_mismatch(proc_name: &str) -> Self {
+        Self::KernelPackageMismatch(proc_name.to_string())
+    }
+
     pub fn kernel_proc_not_found(kernel_proc_id: &ProcedureId) -> Self {
         Self::KernelProcNotFound(*kernel_proc_id)
     }
@@ -153,6 +158,7 @@ impl fmt::Display for AssemblyError {
             InvalidProgramAssemblyContext => write!(f, "assembly context improperly initialized for program compilation"),
             Io(description) => write!(f, "I/O error: {description}"),
             KernelError(error) => write!(f, "{}", error),
+            KernelPackageMismatch(proc_name) => write!(f, "kernel procedure '{proc_name}' does not match the kernel package"),
             KernelProcNotFound(proc_id) => write!(f, "procedure {proc_id} not found in kernel"),
             LibraryError(err) | ParsingError(err) | ProcedureNameError(err) => write!(f, "{err}"),
             LocalProcNotFound(proc_idx, module_path) => write!(f, "procedure at index {proc_idx} not found in module {module_path}"),
@@ -403,6 +409,14 @@ impl ParsingError {
         }
     }
 
+    pub fn caller_in_program(token: &Token) -> Self {
+        ParsingError {
+            message: "caller instruction can be used only in kernel procedures".to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
     pub fn not_a_library_module(token: &Token) -> Self {
         ParsingError {
             message: "not a module: `begin` instruction found".to_string(),
@@ -17,7 +17,10 @@ use vm_core::{
 };
 
 mod library;
-pub use library::{Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, Version};
+pub use library::{
+    KernelCompatibilityReport, KernelPackage, Library, LibraryNamespace, LibraryPath, MaslLibrary,
+    Module, SyscallIncompatibility, Version,
+};
 
 mod procedures;
 use procedures::{CallSet, NamedProcedure, Procedure};
@@ -33,7 +36,7 @@ mod errors;
 pub use errors::{AssemblyError, LabelError, LibraryError, ParsingError, PathError};
 
 mod assembler;
-pub use assembler::{Assembler, AssemblyContext};
+pub use assembler::{Assembler, AssemblyContext, MemoryMode};
 
 #[cfg(test)]
 mod tests;
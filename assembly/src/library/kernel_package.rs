@@ -0,0 +1,358 @@
+use super::{
+    AstSerdeOptions, ByteReader, ByteWriter, CodeBlock, Deserializable, DeserializationError,
+    ModuleAst, ProcedureName, Program, RpoDigest, Serializable, Version,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::fmt;
+
+// CONSTANT DEFINITIONS
+// ================================================================================================
+
+/// Serialization options for the kernel [ModuleAst]. Kernel modules are self-contained, but
+/// imports are serialized so that a package can be compiled without any additional information.
+const KERNEL_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions {
+    serialize_imports: true,
+};
+
+/// Maximum number of procedures which can be exported from a kernel package.
+const MAX_KERNEL_PROCEDURES: usize = u16::MAX as usize;
+
+// KERNEL PACKAGE
+// ================================================================================================
+
+/// A versioned kernel which pairs the names of exported kernel procedures with their MAST roots.
+///
+/// A package is built from an assembler which has a kernel via
+/// [Assembler::kernel_package()](crate::Assembler::kernel_package), and can be used to set the
+/// kernel of another assembler via
+/// [Assembler::with_kernel_package()](crate::Assembler::with_kernel_package). Programs compiled
+/// by such an assembler resolve `syscall` targets against the procedure names of the package.
+///
+/// Two versions of a kernel can be compared with respect to a given program via
+/// [KernelPackage::check_compatibility()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelPackage {
+    version: Version,
+    module: ModuleAst,
+    procedures: BTreeMap<ProcedureName, RpoDigest>,
+}
+
+impl KernelPackage {
+    /// File extension for kernel packages.
+    pub const PACKAGE_EXTENSION: &'static str = "masp";
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [KernelPackage] instantiated from the specified parameters.
+    pub(crate) fn new(
+        version: Version,
+        module: ModuleAst,
+        procedures: BTreeMap<ProcedureName, RpoDigest>,
+    ) -> Self {
+        Self {
+            version,
+            module,
+            procedures,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the version of this kernel package.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns the AST of the kernel module of this package.
+    pub fn module(&self) -> &ModuleAst {
+        &self.module
+    }
+
+    /// Returns an iterator over the names and MAST roots of the procedures exported from the
+    /// kernel, ordered by name.
+    pub fn procedures(&self) -> impl Iterator<Item = (&ProcedureName, &RpoDigest)> {
+        self.procedures.iter()
+    }
+
+    /// Returns the number of procedures exported from the kernel.
+    pub fn num_procedures(&self) -> usize {
+        self.procedures.len()
+    }
+
+    /// Returns the MAST root of the kernel procedure with the specified name, or None if the
+    /// kernel does not export a procedure with this name.
+    pub fn get_proc_root(&self, name: &str) -> Option<RpoDigest> {
+        self.procedures.iter().find(|(n, _)| n.as_ref() == name).map(|(_, root)| *root)
+    }
+
+    /// Returns the name of the kernel procedure with the specified MAST root, or None if the
+    /// kernel does not export a procedure with this root.
+    ///
+    /// If several procedures have the same root, the first one by name is returned.
+    pub fn get_proc_name(&self, mast_root: &RpoDigest) -> Option<&ProcedureName> {
+        self.procedures
+            .iter()
+            .find(|(_, root)| *root == mast_root)
+            .map(|(name, _)| name)
+    }
+
+    // COMPATIBILITY CHECK
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks which `syscall`s of the specified program would break if the program, compiled
+    /// against this kernel, were executed against the `new` kernel.
+    ///
+    /// Every syscall target of the program is looked up in this kernel by its MAST root. A
+    /// syscall breaks if the procedure it targets is no longer exported from the `new` kernel, or
+    /// if the procedure with the same name in the `new` kernel has a different MAST root (in
+    /// which case the program needs to be recompiled). Syscall targets which are not part of this
+    /// kernel are reported as unknown.
+    pub fn check_compatibility(
+        &self,
+        new: &KernelPackage,
+        program: &Program,
+    ) -> KernelCompatibilityReport {
+        let syscalls = collect_syscall_targets(program);
+
+        let mut issues = Vec::new();
+        for target in syscalls.iter() {
+            match self.get_proc_name(target) {
+                None => issues.push(SyscallIncompatibility::UnknownProcedure(*target)),
+                Some(name) => match new.procedures.get(name) {
+                    None => issues.push(SyscallIncompatibility::ProcedureRemoved {
+                        name: name.clone(),
+                        mast_root: *target,
+                    }),
+                    Some(new_root) if new_root != target => {
+                        issues.push(SyscallIncompatibility::ProcedureChanged {
+                            name: name.clone(),
+                            old_mast_root: *target,
+                            new_mast_root: *new_root,
+                        })
+                    }
+                    Some(_) => (),
+                },
+            }
+        }
+
+        KernelCompatibilityReport {
+            old_version: self.version,
+            new_version: new.version,
+            num_syscalls: syscalls.len(),
+            issues,
+        }
+    }
+}
+
+impl Serializable for KernelPackage {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.version.write_into(target);
+        self.module.write_into(target, KERNEL_SERDE_OPTIONS);
+        self.module.write_source_locations(target);
+
+        // this assertion should pass because a kernel cannot have more than u16::MAX procedures
+        assert!(self.procedures.len() <= MAX_KERNEL_PROCEDURES, "too many kernel procedures");
+        target.write_u16(self.procedures.len() as u16);
+        for (name, root) in self.procedures.iter() {
+            name.write_into(target);
+            root.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for KernelPackage {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let version = Version::read_from(source)?;
+        let mut module = ModuleAst::read_from(source, KERNEL_SERDE_OPTIONS)?;
+        module.load_source_locations(source)?;
+
+        let num_procedures = source.read_u16()? as usize;
+        let mut procedures = BTreeMap::new();
+        for _ in 0..num_procedures {
+            let name = ProcedureName::read_from(source)?;
+            let root = RpoDigest::read_from(source)?;
+            if procedures.insert(name.clone(), root).is_some() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "duplicate kernel procedure '{name}'"
+                )));
+            }
+        }
+
+        Ok(Self::new(version, module, procedures))
+    }
+}
+
+// KERNEL COMPATIBILITY REPORT
+// ================================================================================================
+
+/// Describes the `syscall`s of a program which would break when switching from one version of a
+/// kernel to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KernelCompatibilityReport {
+    old_version: Version,
+    new_version: Version,
+    num_syscalls: usize,
+    issues: Vec<SyscallIncompatibility>,
+}
+
+impl KernelCompatibilityReport {
+    /// Returns true if none of the program syscalls would break with the new kernel.
+    pub fn is_compatible(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the version of the kernel the program was compiled against.
+    pub fn old_version(&self) -> &Version {
+        &self.old_version
+    }
+
+    /// Returns the version of the kernel the program was checked against.
+    pub fn new_version(&self) -> &Version {
+        &self.new_version
+    }
+
+    /// Returns the number of distinct syscall targets in the program.
+    pub fn num_syscalls(&self) -> usize {
+        self.num_syscalls
+    }
+
+    /// Returns the list of syscalls which would break with the new kernel.
+    pub fn issues(&self) -> &[SyscallIncompatibility] {
+        &self.issues
+    }
+}
+
+impl fmt::Display for KernelCompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "kernel {} -> {}: {} of {} syscall targets incompatible",
+            self.old_version,
+            self.new_version,
+            self.issues.len(),
+            self.num_syscalls
+        )?;
+        for issue in self.issues.iter() {
+            write!(f, "\n  - {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A `syscall` which would break when switching to a new version of a kernel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SyscallIncompatibility {
+    /// The procedure targeted by the syscall is not exported from the new kernel.
+    ProcedureRemoved {
+        name: ProcedureName,
+        mast_root: RpoDigest,
+    },
+    /// The procedure targeted by the syscall has a different MAST root in the new kernel.
+    ProcedureChanged {
+        name: ProcedureName,
+        old_mast_root: RpoDigest,
+        new_mast_root: RpoDigest,
+    },
+    /// The syscall targets a procedure which is not exported from the old kernel.
+    UnknownProcedure(RpoDigest),
+}
+
+impl fmt::Display for SyscallIncompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProcedureRemoved { name, mast_root } => {
+                write!(f, "procedure '{name}' with MAST root {mast_root} was removed")
+            }
+            Self::ProcedureChanged {
+                name,
+                old_mast_root,
+                new_mast_root,
+            } => write!(
+                f,
+                "MAST root of procedure '{name}' changed from {old_mast_root} to {new_mast_root}"
+            ),
+            Self::UnknownProcedure(mast_root) => {
+                write!(f, "procedure with MAST root {mast_root} is not part of the old kernel")
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the set of MAST roots targeted by the `syscall`s of the specified program.
+///
+/// Procedures invoked via `call` are stored in the code block table of the program, and are
+/// traversed as well.
+fn collect_syscall_targets(program: &Program) -> BTreeSet<RpoDigest> {
+    let mut targets = BTreeSet::new();
+    let mut visited = BTreeSet::new();
+    let mut stack = vec![program.root()];
+
+    while let Some(block) = stack.pop() {
+        match block {
+            CodeBlock::Join(block) => {
+                stack.push(block.first());
+                stack.push(block.second());
+            }
+            CodeBlock::Split(block) => {
+                stack.push(block.on_true());
+                stack.push(block.on_false());
+            }
+            CodeBlock::Loop(block) => stack.push(block.body()),
+            CodeBlock::Call(block) if block.is_syscall() => {
+                targets.insert(block.fn_hash());
+            }
+            CodeBlock::Call(block) => {
+                if visited.insert(block.fn_hash()) {
+                    if let Some(callee) = program.cb_table().get(block.fn_hash()) {
+                        stack.push(callee);
+                    }
+                }
+            }
+            CodeBlock::Span(_) | CodeBlock::Dyn(_) | CodeBlock::Proxy(_) => (),
+        }
+    }
+
+    targets
+}
+
+#[cfg(feature = "std")]
+mod use_std {
+    use super::{super::LibraryError, *};
+    use alloc::string::ToString;
+    use std::{fs, io, path::Path};
+
+    impl KernelPackage {
+        /// Reads a kernel package from a file.
+        pub fn read_from_file<P>(path: P) -> Result<KernelPackage, LibraryError>
+        where
+            P: AsRef<Path>,
+        {
+            // convert path to str
+            let path_str = path.as_ref().to_str().unwrap_or("path contains invalid unicode");
+
+            // read bytes from file
+            let contents =
+                fs::read(&path).map_err(|e| LibraryError::file_error(path_str, &e.to_string()))?;
+
+            // read kernel package from bytes
+            Self::read_from_bytes(&contents)
+                .map_err(|e| LibraryError::deserialization_error(path_str, &e.to_string()))
+        }
+
+        /// Writes the kernel package to the specified file.
+        pub fn write_to_file<P>(&self, path: P) -> io::Result<()>
+        where
+            P: AsRef<Path>,
+        {
+            fs::write(path, self.to_bytes())
+        }
+    }
+}
@@ -1,10 +1,14 @@
 use super::{
     ast::{AstSerdeOptions, ModuleAst},
-    ByteReader, ByteWriter, Deserializable, DeserializationError, LibraryError, PathError,
-    Serializable, MAX_LABEL_LEN, NAMESPACE_LABEL_PARSER,
+    crypto::hash::RpoDigest,
+    ByteReader, ByteWriter, CodeBlock, Deserializable, DeserializationError, LibraryError,
+    PathError, ProcedureName, Program, Serializable, MAX_LABEL_LEN, NAMESPACE_LABEL_PARSER,
 };
 use core::{cmp::Ordering, fmt, ops::Deref, str::from_utf8};
 
+mod kernel_package;
+pub use kernel_package::{KernelCompatibilityReport, KernelPackage, SyscallIncompatibility};
+
 mod masl;
 use alloc::string::{String, ToString};
 pub use masl::MaslLibrary;
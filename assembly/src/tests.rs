@@ -1,7 +1,8 @@
 use crate::{
     ast::{ModuleAst, ProgramAst},
-    Assembler, AssemblyContext, AssemblyError, Library, LibraryNamespace, LibraryPath, MaslLibrary,
-    Module, ProcedureName, Version,
+    Assembler, AssemblyContext, AssemblyError, Deserializable, KernelPackage, Library,
+    LibraryNamespace, LibraryPath, MaslLibrary, MemoryMode, Module, ProcedureName, Serializable,
+    SyscallIncompatibility, Version,
 };
 use alloc::{string::ToString, vec::Vec};
 use core::slice::Iter;
@@ -744,6 +745,87 @@ fn const_conversion_failed_to_u32() {
     assert_eq!(expected_error, err.to_string());
 }
 
+// MEMORY MODE
+// ================================================================================================
+
+#[test]
+fn element_memory_instructions() {
+    let assembler = Assembler::default();
+    let source = "begin mem_loade mem_loade.5 mem_storee mem_storee.6 end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span mloade push(5) mloade mstoree drop push(6) mstoree drop end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn borrowed_memory_instructions() {
+    let assembler = Assembler::default();
+    let source = "begin mem_lend mem_loadw_borrowed mem_loadw_borrowed.5 end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span mlend drop drop mloadwb push(5) mloadwb end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn memory_mode() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE: &str = "mem";
+    const PROCEDURE: &str = r#"
+        export.load_first
+            mem_load
+        end"#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(PROCEDURE).unwrap();
+    let modules = vec![Module { path, ast }];
+    let library = DummyLibrary::new(namespace, modules);
+
+    let source = format!(
+        r#"
+        use.{NAMESPACE}::{MODULE}
+
+        proc.store_local
+            mem_store.5
+        end
+
+        begin
+            mem_load.4
+            exec.store_local
+            exec.mem::load_first
+        end"#
+    );
+
+    // in word mode, mem_load and mem_store access the first element of a word
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    assert_eq!(MemoryMode::Word, assembler.memory_mode());
+    let program = assembler.compile(&source).unwrap();
+    let expected = "\
+        begin \
+            span push(4) mload push(5) mstore drop mload end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // in element mode, mem_load and mem_store of the program take element addresses, but the
+    // library procedure is still compiled using word addresses
+    let assembler = Assembler::default()
+        .with_memory_mode(MemoryMode::Element)
+        .with_library(&library)
+        .unwrap();
+    let program = assembler.compile(&source).unwrap();
+    let expected = "\
+        begin \
+            span push(4) mloade push(5) mstoree drop mload end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
 // ASSERTIONS
 // ================================================================================================
 
@@ -1023,6 +1105,177 @@ fn program_with_exported_procedure() {
     assert!(assembler.compile(source).is_err());
 }
 
+// KERNEL INSTRUCTIONS
+// ================================================================================================
+
+#[test]
+fn caller_instruction() {
+    // caller can be used in kernel procedures
+    let kernel = "\
+        export.foo
+            caller
+        end";
+    let assembler = Assembler::default().with_kernel(kernel).unwrap();
+    assert!(assembler.compile("begin syscall.foo end").is_ok());
+
+    // caller cannot be used in programs
+    let source = "proc.foo caller end begin call.foo end";
+    let err = Assembler::default().compile(source).unwrap_err();
+    assert!(matches!(err, AssemblyError::ParsingError(_)));
+}
+
+#[test]
+fn caller_instruction_in_library() {
+    const NAMESPACE: &str = "dummy";
+    const MODULE: &str = "sys";
+    const PROCEDURE: &str = r#"
+        export.get_caller
+            caller
+        end"#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let path = LibraryPath::try_from(MODULE.to_string()).unwrap().prepend(&namespace).unwrap();
+    let ast = ModuleAst::parse(PROCEDURE).unwrap();
+    let modules = vec![Module { path, ast }];
+    let library = DummyLibrary::new(namespace, modules);
+
+    // whether a module is a kernel module is not known at parse time, and thus, caller in library
+    // modules is rejected when the library procedure is compiled
+    let source = format!("use.{NAMESPACE}::{MODULE} begin exec.{MODULE}::get_caller end");
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    assert_eq!(AssemblyError::CallerOutOKernel, assembler.compile(source).unwrap_err());
+}
+
+// KERNEL PACKAGES
+// ================================================================================================
+
+#[test]
+fn kernel_package_serde() {
+    let kernel = "export.foo add end export.bar mul end proc.baz push.1 drop end";
+    let assembler = Assembler::default().with_kernel(kernel).unwrap();
+    let version = Version {
+        major: 1,
+        minor: 2,
+        patch: 3,
+    };
+    let package = assembler.kernel_package(version).unwrap();
+
+    // only exported procedures are included in the package
+    assert_eq!(&version, package.version());
+    assert_eq!(2, package.num_procedures());
+    assert_eq!(assembler.kernel().proc_hashes()[0], package.get_proc_root("foo").unwrap());
+    assert_eq!(assembler.kernel().proc_hashes()[1], package.get_proc_root("bar").unwrap());
+    assert!(package.get_proc_root("baz").is_none());
+
+    let bytes = package.to_bytes();
+    let deserialized = KernelPackage::read_from_bytes(&bytes).unwrap();
+    assert_eq!(package, deserialized);
+
+    // an assembler without a kernel has no kernel package
+    assert!(Assembler::default().kernel_package(version).is_none());
+}
+
+#[test]
+fn kernel_package_syscalls() {
+    let kernel = "export.foo add end export.bar mul end";
+    let package = Assembler::default().with_kernel(kernel).unwrap().kernel_package(Version::MIN);
+    let package = package.unwrap();
+
+    // syscall targets are resolved against the procedure names of the package
+    let assembler = Assembler::default().with_kernel_package(&package).unwrap();
+    assert_eq!(package.get_proc_root("foo").unwrap(), assembler.kernel().proc_hashes()[0]);
+    let program = assembler.compile("begin syscall.foo syscall.bar end").unwrap();
+    let expected = format!(
+        "begin join syscall.{} syscall.{} end end",
+        package.get_proc_root("foo").unwrap(),
+        package.get_proc_root("bar").unwrap()
+    );
+    assert_eq!(expected, format!("{program}"));
+
+    assert_eq!(
+        AssemblyError::kernel_proc_not_found(&crate::ProcedureId::from_kernel_name("baz")),
+        assembler.compile("begin syscall.baz end").unwrap_err()
+    );
+
+    // a package whose recorded procedures do not match its kernel module is rejected
+    let mut bytes = package.to_bytes();
+    let len = bytes.len();
+    bytes[len - 1] ^= 1;
+    let tampered = KernelPackage::read_from_bytes(&bytes).unwrap();
+    assert_eq!(
+        AssemblyError::KernelPackageMismatch("foo".to_string()),
+        Assembler::default().with_kernel_package(&tampered).err().unwrap()
+    );
+}
+
+#[test]
+fn kernel_package_compatibility() {
+    let v1 = Version {
+        major: 1,
+        minor: 0,
+        patch: 0,
+    };
+    let v2 = Version {
+        major: 2,
+        minor: 0,
+        patch: 0,
+    };
+    let old_kernel = "export.foo add end export.bar mul end export.baz push.1 drop end";
+    let new_kernel = "export.foo add end export.bar mul mul end export.qux push.2 drop end";
+    let old_assembler = Assembler::default().with_kernel(old_kernel).unwrap();
+    let old = old_assembler.kernel_package(v1).unwrap();
+    let new = Assembler::default()
+        .with_kernel(new_kernel)
+        .unwrap()
+        .kernel_package(v2)
+        .unwrap();
+
+    // syscalls nested in called procedures are checked as well
+    let source = "
+        proc.inner syscall.baz end
+        begin
+            syscall.foo
+            if.true syscall.bar else call.inner end
+        end";
+    let program = old_assembler.compile(source).unwrap();
+
+    let report = old.check_compatibility(&new, &program);
+    assert!(!report.is_compatible());
+    assert_eq!(3, report.num_syscalls());
+    assert_eq!(2, report.issues().len());
+    for issue in [
+        SyscallIncompatibility::ProcedureChanged {
+            name: ProcedureName::try_from("bar").unwrap(),
+            old_mast_root: old.get_proc_root("bar").unwrap(),
+            new_mast_root: new.get_proc_root("bar").unwrap(),
+        },
+        SyscallIncompatibility::ProcedureRemoved {
+            name: ProcedureName::try_from("baz").unwrap(),
+            mast_root: old.get_proc_root("baz").unwrap(),
+        },
+    ] {
+        assert!(report.issues().contains(&issue));
+    }
+
+    // a program which invokes only unchanged procedures is compatible
+    let program = old_assembler.compile("begin syscall.foo end").unwrap();
+    assert!(old.check_compatibility(&new, &program).is_compatible());
+
+    // syscalls to procedures which are not part of the old kernel are reported as unknown
+    let report = new.check_compatibility(&old, &program);
+    assert!(report.is_compatible());
+    let program = Assembler::default()
+        .with_kernel(new_kernel)
+        .unwrap()
+        .compile("begin syscall.qux end")
+        .unwrap();
+    let report = old.check_compatibility(&new, &program);
+    assert_eq!(
+        &[SyscallIncompatibility::UnknownProcedure(new.get_proc_root("qux").unwrap())][..],
+        report.issues()
+    );
+}
+
 // PROGRAMS WITH DYNAMIC CODE BLOCKS
 // ================================================================================================
 
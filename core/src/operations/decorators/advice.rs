@@ -219,6 +219,40 @@ pub enum AdviceInjector {
     ///   Advice stack: [ilog2(n), ...]
     ILog2,
 
+    /// Given a 256-bit value in Montgomery form and a 256-bit prime modulus on the top of the
+    /// stack, computes the multiplicative inverse of the value (also in Montgomery form) and
+    /// pushes the result onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where m[0..8], a[0..8] and b[0..8] are the 32-bit limbs of the modulus, the value and its
+    /// inverse respectively (with limb 0 being the least significant one). The Montgomery radix
+    /// is 2^256. If the value is zero, the pushed result is also zero.
+    U256MontInv,
+
+    /// Given a 256-bit odd modulus and the lower 256 bits of a 512-bit product on the top of the
+    /// stack, computes the quotient used by the Montgomery reduction of the product and pushes it
+    /// onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, p0, p1, p2, p3, p4, p5, p6, p7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, p0, p1, p2, p3, p4, p5, p6, p7, ...]
+    ///   Advice stack: [q7, q6, q5, q4, q3, q2, q1, q0, ...]
+    ///
+    /// Where m[0..8], p[0..8] and q[0..8] are the 32-bit limbs of the modulus, the lower half of
+    /// the product and the quotient respectively (with limb 0 being the least significant one).
+    /// The quotient is q = -p * m^-1 mod 2^256, and thus, p + q * m is divisible by 2^256.
+    U256MontQuot,
+
     // ADVICE MAP INJECTORS
     // --------------------------------------------------------------------------------------------
     /// Reads words from memory at the specified range and inserts them into the advice map under
@@ -310,6 +344,8 @@ impl fmt::Display for AdviceInjector {
             Self::U32Clo => write!(f, "u32clo"),
             Self::U32Cto => write!(f, "u32cto"),
             Self::ILog2 => write!(f, "ilog2"),
+            Self::U256MontInv => write!(f, "u256_mont_inv"),
+            Self::U256MontQuot => write!(f, "u256_mont_quot"),
             Self::MemToMap => write!(f, "mem_to_map"),
             Self::HdwordToMap { domain } => write!(f, "hdword_to_map.{domain}"),
             Self::HpermToMap => write!(f, "hperm_to_map"),
@@ -207,6 +207,34 @@ pub enum Operation {
     /// If either of the elements is greater than or equal to 2^32, execution fails.
     U32xor,
 
+    /// Pops an element off the stack, computes the number of its set bits, and pushes the result
+    /// back onto the stack.
+    ///
+    /// If the element is greater than or equal to 2^32, execution fails.
+    U32popcnt,
+
+    /// Pops two elements off the stack, shifts the second element to the left by the number of
+    /// bits specified by the first element, and splits the result into upper and lower 32-bit
+    /// values. Then pushes these values back onto the stack.
+    ///
+    /// The upper 32-bit value holds the bits shifted out of the lower 32 bits, and thus, the sum of
+    /// the two values is the second element rotated to the left.
+    ///
+    /// If the second element is greater than or equal to 2^32, or if the shift is greater than 32,
+    /// execution fails.
+    U32shl,
+
+    /// Pops two elements off the stack and shifts the second element to the right by the number of
+    /// bits specified by the first element. Then pushes the result, together with the bits shifted
+    /// out of the second element moved into the most significant bits of a 32-bit value, onto the
+    /// stack.
+    ///
+    /// The sum of the two values is the second element rotated to the right.
+    ///
+    /// If the second element is greater than or equal to 2^32, or if the shift is greater than 32,
+    /// execution fails.
+    U32shr,
+
     // ----- stack manipulation -------------------------------------------------------------------
     /// Pushes 0 onto the stack.
     Pad,
@@ -351,6 +379,36 @@ pub enum Operation {
     /// memory address. The remaining 3 elements of the word are not affected.
     MStore,
 
+    /// Pops an element off the stack, interprets it as an element address, and pushes the element
+    /// located at the specified address to the stack.
+    ///
+    /// Element address `a` refers to element `a mod 4` of the word located at word address
+    /// `a / 4`.
+    MLoadE,
+
+    /// Pops an element off the stack, interprets it as an element address, and writes the
+    /// remaining element at the top of the stack into memory at the specified address. The other
+    /// 3 elements of the enclosing word are not affected.
+    ///
+    /// Element address `a` refers to element `a mod 4` of the word located at word address
+    /// `a / 4`.
+    MStoreE,
+
+    /// Lends the memory segment `[s0, s1)` of the current context to the next context called by
+    /// the current context. The called context can read words from the lent segment via the
+    /// `MLOADWB` operation, but cannot modify them.
+    ///
+    /// The stack is not affected by this operation.
+    MLend,
+
+    /// Pops an element off the stack, interprets it as a memory address, and replaces the
+    /// remaining 4 elements at the top of the stack with values located at the specified address
+    /// in the memory segment lent to the current context by its caller.
+    ///
+    /// The execution fails if no memory segment was lent to the current context, or if the address
+    /// is outside of the lent segment.
+    MLoadWB,
+
     /// Loads two words from memory, and replaces the top 8 elements of the stack with them,
     /// element-wise, in stack order.
     ///
@@ -485,7 +543,7 @@ impl Operation {
             Self::SwapW2        => 0b0001_1100,
             Self::SwapW3        => 0b0001_1101,
             Self::SwapDW        => 0b0001_1110,
-            // <empty>          => 0b0001_1111,
+            Self::U32popcnt     => 0b0001_1111,
 
             Self::Assert(_)     => 0b0010_0000,
             Self::Eq            => 0b0010_0001,
@@ -540,12 +598,12 @@ impl Operation {
             Self::Join          => 0b0101_0111,
             Self::Dyn           => 0b0101_1000,
             Self::RCombBase     => 0b0101_1001,
-            // <empty>          => 0b0101_1010,
-            // <empty>          => 0b0101_1011,
-            // <empty>          => 0b0101_1100,
-            // <empty>          => 0b0101_1101,
-            // <empty>          => 0b0101_1110,
-            // <empty>          => 0b0101_1111,
+            Self::MLoadE        => 0b0101_1010,
+            Self::MStoreE       => 0b0101_1011,
+            Self::MLend         => 0b0101_1100,
+            Self::MLoadWB       => 0b0101_1101,
+            Self::U32shl        => 0b0101_1110,
+            Self::U32shr        => 0b0101_1111,
 
             Self::MrUpdate      => 0b0110_0000,
             Self::Push(_)       => 0b0110_0100,
@@ -644,6 +702,9 @@ impl fmt::Display for Operation {
 
             Self::U32and => write!(f, "u32and"),
             Self::U32xor => write!(f, "u32xor"),
+            Self::U32popcnt => write!(f, "u32popcnt"),
+            Self::U32shl => write!(f, "u32shl"),
+            Self::U32shr => write!(f, "u32shr"),
 
             // ----- stack manipulation -----------------------------------------------------------
             Self::Drop => write!(f, "drop"),
@@ -699,6 +760,11 @@ impl fmt::Display for Operation {
             Self::MLoad => write!(f, "mload"),
             Self::MStore => write!(f, "mstore"),
 
+            Self::MLoadE => write!(f, "mloade"),
+            Self::MStoreE => write!(f, "mstoree"),
+            Self::MLend => write!(f, "mlend"),
+            Self::MLoadWB => write!(f, "mloadwb"),
+
             Self::MStream => write!(f, "mstream"),
             Self::Pipe => write!(f, "pipe"),
 
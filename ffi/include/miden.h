@@ -0,0 +1,224 @@
+#ifndef MIDEN_H
+#define MIDEN_H
+
+/* This file is generated by cbindgen; run `make ffi-header` to regenerate it. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Version of the C API; incremented whenever a backward-incompatible change is made to it.
+#define MIDEN_ABI_VERSION 1
+
+// Number of bytes in a serialized program hash.
+#define MIDEN_DIGEST_BYTES 32
+
+// Security level targeted by a generated proof.
+typedef enum MidenSecurityLevel {
+  // 96-bit security level.
+  MIDEN_SECURITY_LEVEL_BITS96 = 96,
+  // 128-bit security level.
+  MIDEN_SECURITY_LEVEL_BITS128 = 128,
+} MidenSecurityLevel;
+
+// Status code returned by all fallible functions of the C API.
+//
+// On any status other than `MIDEN_STATUS_OK`, a description of the error can be retrieved via
+// `miden_last_error_message()`.
+typedef enum MidenStatus {
+  // The operation completed successfully.
+  MIDEN_STATUS_OK = 0,
+  // A required pointer argument was NULL.
+  MIDEN_STATUS_NULL_POINTER = 1,
+  // A string argument was not valid UTF-8.
+  MIDEN_STATUS_INVALID_STRING = 2,
+  // The source code could not be compiled.
+  MIDEN_STATUS_ASSEMBLY = 3,
+  // A serialized argument (e.g., stack inputs or a proof) could not be deserialized.
+  MIDEN_STATUS_DESERIALIZATION = 4,
+  // The program failed to execute, or the proof of its execution could not be generated.
+  MIDEN_STATUS_EXECUTION = 5,
+  // The proof is not valid for the specified program and public inputs.
+  MIDEN_STATUS_VERIFICATION = 6,
+  // The library panicked; this indicates a bug in Miden VM.
+  MIDEN_STATUS_PANIC = 7,
+} MidenStatus;
+
+// Opaque handle to an assembler which compiles Miden assembly source code into programs.
+typedef struct MidenAssembler MidenAssembler;
+
+// Opaque handle to a compiled Miden program.
+typedef struct MidenProgram MidenProgram;
+
+// A byte buffer allocated by the library.
+//
+// Buffers written by the library must be freed via `miden_buffer_free()`.
+typedef struct MidenBuffer {
+  uint8_t *data;
+  size_t len;
+} MidenBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Returns the version of the C API implemented by the library.
+//
+// Callers should compare it against `MIDEN_ABI_VERSION` from the header they were compiled
+// against, to detect being linked against an incompatible version of the library.
+uint32_t miden_abi_version(void);
+
+// Frees the memory of a buffer written by the library, and resets the buffer to be empty. Does
+// nothing if `buffer` is NULL or if the buffer is already empty.
+//
+// # Safety
+// `buffer` must be NULL or point to a buffer which was written by the library or reset by this
+// function.
+void miden_buffer_free(struct MidenBuffer *buffer);
+
+// Executes the program against the provided inputs, and writes the serialized resulting
+// `StackOutputs` into `stack_outputs`.
+//
+// `stack_inputs` and `advice_inputs` must hold serialized `StackInputs` and `AdviceInputs`
+// respectively; either of them can be empty (with a length of 0), in which case empty inputs are
+// used.
+//
+// The buffer must be freed via `miden_buffer_free()`.
+//
+// # Safety
+// `program` must be a valid program handle, the input pointers must point to the specified number
+// of readable bytes, and `stack_outputs` must be a valid pointer.
+enum MidenStatus miden_execute(const struct MidenProgram *program,
+                               const uint8_t *stack_inputs,
+                               size_t stack_inputs_len,
+                               const uint8_t *advice_inputs,
+                               size_t advice_inputs_len,
+                               struct MidenBuffer *stack_outputs);
+
+// Executes the program against the provided inputs and generates a STARK proof of the execution.
+// The serialized resulting `StackOutputs` are written into `stack_outputs`, and the serialized
+// proof is written into `proof`.
+//
+// Inputs are expected in the same format as for `miden_execute()`. If `recursive` is set, the
+// proof is generated using parameters which make it efficient to verify it inside Miden VM.
+//
+// Both buffers must be freed via `miden_buffer_free()`.
+//
+// # Safety
+// `program` must be a valid program handle, the input pointers must point to the specified number
+// of readable bytes, and `stack_outputs` and `proof` must be valid pointers.
+enum MidenStatus miden_prove(const struct MidenProgram *program,
+                             const uint8_t *stack_inputs,
+                             size_t stack_inputs_len,
+                             const uint8_t *advice_inputs,
+                             size_t advice_inputs_len,
+                             enum MidenSecurityLevel security_level,
+                             bool recursive,
+                             struct MidenBuffer *stack_outputs,
+                             struct MidenBuffer *proof);
+
+// Verifies that the program described by the serialized `program_info` was executed correctly
+// against the provided serialized `StackInputs` and `StackOutputs`, and writes the security level
+// of the proof into `security_level`.
+//
+// The proof is expected to be serialized as produced by `miden_prove()` (or, equivalently, as
+// written into proof files by the `miden prove` command). As for `miden_execute()`, empty stack
+// inputs can be specified with a length of 0.
+//
+// # Safety
+// All pointers to bytes must point to the specified number of readable bytes, and
+// `security_level` must be a valid pointer.
+enum MidenStatus miden_verify(const uint8_t *program_info,
+                              size_t program_info_len,
+                              const uint8_t *stack_inputs,
+                              size_t stack_inputs_len,
+                              const uint8_t *stack_outputs,
+                              size_t stack_outputs_len,
+                              const uint8_t *proof,
+                              size_t proof_len,
+                              uint32_t *security_level);
+
+// Creates a new assembler and writes a handle to it into `assembler`.
+//
+// If `with_stdlib` is set, programs compiled by the assembler can use procedures from the Miden
+// standard library. If `kernel_source` is not NULL, it is compiled as the kernel against which
+// all programs are compiled.
+//
+// The assembler must be freed via `miden_assembler_free()`.
+//
+// # Safety
+// `kernel_source` must be NULL or point to a NUL-terminated string, and `assembler` must be a
+// valid pointer.
+enum MidenStatus miden_assembler_new(bool with_stdlib,
+                                     const char *kernel_source,
+                                     struct MidenAssembler **assembler);
+
+// Frees an assembler created via `miden_assembler_new()`. Does nothing if `assembler` is NULL.
+//
+// # Safety
+// `assembler` must be NULL or a handle returned by `miden_assembler_new()` which was not freed
+// yet.
+void miden_assembler_free(struct MidenAssembler *assembler);
+
+// Compiles the provided Miden assembly source code and writes a handle to the resulting program
+// into `program`.
+//
+// The program must be freed via `miden_program_free()`.
+//
+// # Safety
+// `assembler` must be a valid assembler handle, `source` must point to a NUL-terminated string,
+// and `program` must be a valid pointer.
+enum MidenStatus miden_assembler_compile(const struct MidenAssembler *assembler,
+                                         const char *source,
+                                         struct MidenProgram **program);
+
+// Compiles a program from its binary AST (e.g., the contents of a `.masb` file written by the
+// `miden compile` command) and writes a handle to the resulting program into `program`.
+//
+// The program must be freed via `miden_program_free()`.
+//
+// # Safety
+// `assembler` must be a valid assembler handle, `ast` must point to `ast_len` readable bytes, and
+// `program` must be a valid pointer.
+enum MidenStatus miden_assembler_compile_ast(const struct MidenAssembler *assembler,
+                                             const uint8_t *ast,
+                                             size_t ast_len,
+                                             struct MidenProgram **program);
+
+// Frees a program created via `miden_assembler_compile()` or `miden_assembler_compile_ast()`.
+// Does nothing if `program` is NULL.
+//
+// # Safety
+// `program` must be NULL or a program handle which was not freed yet.
+void miden_program_free(struct MidenProgram *program);
+
+// Writes the 32-byte hash of the program into `hash`.
+//
+// # Safety
+// `program` must be a valid program handle, and `hash` must point to 32 writable bytes.
+enum MidenStatus miden_program_hash(const struct MidenProgram *program, uint8_t *hash);
+
+// Writes the serialized program info (the program hash together with its kernel) into
+// `program_info`. Proofs of the execution of the program are verified against this info.
+//
+// The buffer must be freed via `miden_buffer_free()`.
+//
+// # Safety
+// `program` must be a valid program handle, and `program_info` must be a valid pointer.
+enum MidenStatus miden_program_info(const struct MidenProgram *program,
+                                    struct MidenBuffer *program_info);
+
+// Returns the message of the last error which occurred on the calling thread, or NULL if no
+// error occurred yet.
+//
+// The returned string is owned by the library and remains valid until the next call into the
+// library on the same thread; it must not be freed by the caller.
+const char *miden_last_error_message(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* MIDEN_H */
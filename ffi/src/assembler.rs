@@ -0,0 +1,156 @@
+use super::{
+    bytes_arg, ffi_call, str_arg, write_bytes, write_handle, Error, MidenBuffer, MidenStatus,
+    MIDEN_DIGEST_BYTES,
+};
+use assembly::ast::ProgramAst;
+use core::ffi::c_char;
+use stdlib::StdLibrary;
+use vm_core::utils::Serializable;
+
+// ASSEMBLER
+// ================================================================================================
+
+/// Opaque handle to an assembler which compiles Miden assembly source code into programs.
+pub struct MidenAssembler(assembly::Assembler);
+
+/// Creates a new assembler and writes a handle to it into `assembler`.
+///
+/// If `with_stdlib` is set, programs compiled by the assembler can use procedures from the Miden
+/// standard library. If `kernel_source` is not NULL, it is compiled as the kernel against which
+/// all programs are compiled.
+///
+/// The assembler must be freed via `miden_assembler_free()`.
+///
+/// # Safety
+/// `kernel_source` must be NULL or point to a NUL-terminated string, and `assembler` must be a
+/// valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn miden_assembler_new(
+    with_stdlib: bool,
+    kernel_source: *const c_char,
+    assembler: *mut *mut MidenAssembler,
+) -> MidenStatus {
+    ffi_call(|| {
+        let mut inner = assembly::Assembler::default();
+        if with_stdlib {
+            inner = inner.with_library(&StdLibrary::default())?;
+        }
+        if !kernel_source.is_null() {
+            inner = inner.with_kernel(str_arg(kernel_source, "kernel_source")?)?;
+        }
+        write_handle(assembler, "assembler", MidenAssembler(inner))
+    })
+}
+
+/// Frees an assembler created via `miden_assembler_new()`. Does nothing if `assembler` is NULL.
+///
+/// # Safety
+/// `assembler` must be NULL or a handle returned by `miden_assembler_new()` which was not freed
+/// yet.
+#[no_mangle]
+pub unsafe extern "C" fn miden_assembler_free(assembler: *mut MidenAssembler) {
+    if !assembler.is_null() {
+        drop(Box::from_raw(assembler));
+    }
+}
+
+/// Compiles the provided Miden assembly source code and writes a handle to the resulting program
+/// into `program`.
+///
+/// The program must be freed via `miden_program_free()`.
+///
+/// # Safety
+/// `assembler` must be a valid assembler handle, `source` must point to a NUL-terminated string,
+/// and `program` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn miden_assembler_compile(
+    assembler: *const MidenAssembler,
+    source: *const c_char,
+    program: *mut *mut MidenProgram,
+) -> MidenStatus {
+    ffi_call(|| {
+        let assembler = assembler.as_ref().ok_or(Error::NullPointer("assembler"))?;
+        let compiled = assembler.0.compile(str_arg(source, "source")?)?;
+        write_handle(program, "program", MidenProgram(compiled))
+    })
+}
+
+/// Compiles a program from its binary AST (e.g., the contents of a `.masb` file written by the
+/// `miden compile` command) and writes a handle to the resulting program into `program`.
+///
+/// The program must be freed via `miden_program_free()`.
+///
+/// # Safety
+/// `assembler` must be a valid assembler handle, `ast` must point to `ast_len` readable bytes, and
+/// `program` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn miden_assembler_compile_ast(
+    assembler: *const MidenAssembler,
+    ast: *const u8,
+    ast_len: usize,
+    program: *mut *mut MidenProgram,
+) -> MidenStatus {
+    ffi_call(|| {
+        let assembler = assembler.as_ref().ok_or(Error::NullPointer("assembler"))?;
+        let ast = ProgramAst::from_bytes(bytes_arg(ast, ast_len, "ast")?)
+            .map_err(|err| Error::Deserialization("ast", err))?;
+        let compiled = assembler.0.compile_ast(&ast)?;
+        write_handle(program, "program", MidenProgram(compiled))
+    })
+}
+
+// PROGRAM
+// ================================================================================================
+
+/// Opaque handle to a compiled Miden program.
+pub struct MidenProgram(pub(crate) vm_core::Program);
+
+/// Frees a program created via `miden_assembler_compile()` or `miden_assembler_compile_ast()`.
+/// Does nothing if `program` is NULL.
+///
+/// # Safety
+/// `program` must be NULL or a program handle which was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn miden_program_free(program: *mut MidenProgram) {
+    if !program.is_null() {
+        drop(Box::from_raw(program));
+    }
+}
+
+/// Writes the 32-byte hash of the program into `hash`.
+///
+/// # Safety
+/// `program` must be a valid program handle, and `hash` must point to 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_program_hash(
+    program: *const MidenProgram,
+    hash: *mut u8,
+) -> MidenStatus {
+    ffi_call(|| {
+        let program = program.as_ref().ok_or(Error::NullPointer("program"))?;
+        if hash.is_null() {
+            return Err(Error::NullPointer("hash"));
+        }
+        hash.copy_from_nonoverlapping(program.0.hash().as_bytes().as_ptr(), MIDEN_DIGEST_BYTES);
+        Ok(())
+    })
+}
+
+/// Writes the serialized program info (the program hash together with its kernel) into
+/// `program_info`. Proofs of the execution of the program are verified against this info.
+///
+/// The buffer must be freed via `miden_buffer_free()`.
+///
+/// # Safety
+/// `program` must be a valid program handle, and `program_info` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn miden_program_info(
+    program: *const MidenProgram,
+    program_info: *mut MidenBuffer,
+) -> MidenStatus {
+    ffi_call(|| {
+        let program = program.as_ref().ok_or(Error::NullPointer("program"))?;
+        let info = vm_core::ProgramInfo::from(program.0.clone());
+        write_bytes(program_info, "program_info", info.to_bytes())
+    })
+}
@@ -0,0 +1,136 @@
+use assembly::AssemblyError;
+use core::{ffi::c_char, fmt, ptr, str::Utf8Error};
+use processor::{DeserializationError, ExecutionError};
+use std::{any::Any, cell::RefCell, ffi::CString};
+use verifier::VerificationError;
+
+// STATUS
+// ================================================================================================
+
+/// Status code returned by all fallible functions of the C API.
+///
+/// On any status other than `MIDEN_STATUS_OK`, a description of the error can be retrieved via
+/// `miden_last_error_message()`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidenStatus {
+    /// The operation completed successfully.
+    Ok = 0,
+    /// A required pointer argument was NULL.
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8.
+    InvalidString = 2,
+    /// The source code could not be compiled.
+    Assembly = 3,
+    /// A serialized argument (e.g., stack inputs or a proof) could not be deserialized.
+    Deserialization = 4,
+    /// The program failed to execute, or the proof of its execution could not be generated.
+    Execution = 5,
+    /// The proof is not valid for the specified program and public inputs.
+    Verification = 6,
+    /// The library panicked; this indicates a bug in Miden VM.
+    Panic = 7,
+}
+
+// ERROR
+// ================================================================================================
+
+/// An error which can occur while executing a function of the C API.
+#[derive(Debug)]
+pub enum Error {
+    NullPointer(&'static str),
+    InvalidString(&'static str, Utf8Error),
+    Assembly(AssemblyError),
+    Deserialization(&'static str, DeserializationError),
+    Execution(ExecutionError),
+    Verification(VerificationError),
+    Panic(String),
+}
+
+impl Error {
+    /// Returns the status code which identifies the kind of this error across the C API.
+    pub fn status(&self) -> MidenStatus {
+        match self {
+            Self::NullPointer(_) => MidenStatus::NullPointer,
+            Self::InvalidString(..) => MidenStatus::InvalidString,
+            Self::Assembly(_) => MidenStatus::Assembly,
+            Self::Deserialization(..) => MidenStatus::Deserialization,
+            Self::Execution(_) => MidenStatus::Execution,
+            Self::Verification(_) => MidenStatus::Verification,
+            Self::Panic(_) => MidenStatus::Panic,
+        }
+    }
+
+    /// Returns an error describing the provided panic payload.
+    pub(crate) fn from_panic(payload: Box<dyn Any + Send>) -> Self {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            String::from(*message)
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            String::from("unknown panic")
+        };
+        Self::Panic(message)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NullPointer(name) => write!(f, "argument `{name}` must not be NULL"),
+            Self::InvalidString(name, err) => {
+                write!(f, "argument `{name}` is not a valid UTF-8 string: {err}")
+            }
+            Self::Assembly(err) => write!(f, "{err}"),
+            Self::Deserialization(name, err) => {
+                write!(f, "failed to deserialize argument `{name}`: {err}")
+            }
+            Self::Execution(err) => write!(f, "{err}"),
+            Self::Verification(err) => write!(f, "{err}"),
+            Self::Panic(message) => write!(f, "Miden VM panicked: {message}"),
+        }
+    }
+}
+
+impl From<AssemblyError> for Error {
+    fn from(err: AssemblyError) -> Self {
+        Self::Assembly(err)
+    }
+}
+
+impl From<ExecutionError> for Error {
+    fn from(err: ExecutionError) -> Self {
+        Self::Execution(err)
+    }
+}
+
+impl From<VerificationError> for Error {
+    fn from(err: VerificationError) -> Self {
+        Self::Verification(err)
+    }
+}
+
+// LAST ERROR
+// ================================================================================================
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Records the message of the provided error as the last error of the current thread.
+pub(crate) fn set_last_error(err: &Error) {
+    // interior NUL bytes cannot be represented in a C string, so they are replaced
+    let message = err.to_string().replace('\0', "\\0");
+    let message = CString::new(message).expect("message contains no NUL bytes");
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Returns the message of the last error which occurred on the calling thread, or NULL if no
+/// error occurred yet.
+///
+/// The returned string is owned by the library and remains valid until the next call into the
+/// library on the same thread; it must not be freed by the caller.
+#[no_mangle]
+pub extern "C" fn miden_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |message| message.as_ptr()))
+}
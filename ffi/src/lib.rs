@@ -0,0 +1,331 @@
+//! C bindings for Miden VM.
+//!
+//! All functions of the C API follow the same conventions:
+//! - Fallible functions return a [MidenStatus]; on failure, the description of the error can be
+//!   retrieved via [miden_last_error_message()].
+//! - Results are written into out-pointers supplied by the caller.
+//! - Objects allocated by the library (assemblers, programs, and buffers) must be freed by the
+//!   caller via the corresponding `*_free()` function.
+//! - Inputs, outputs, program info, and proofs cross the API in their binary serialization format,
+//!   the same one used by the Rust crates and the `miden` CLI.
+//! - Panics never cross the API; they are reported as [MidenStatus::Panic].
+
+use core::{ptr, slice};
+use processor::{AdviceInputs, DefaultHost, ExecutionOptions, MemAdviceProvider, StackInputs};
+use prover::ProvingOptions;
+use std::{
+    ffi::{c_char, CStr},
+    panic::{self, AssertUnwindSafe},
+};
+use verifier::{ExecutionProof, ProgramInfo, StackOutputs};
+use vm_core::utils::{Deserializable, Serializable};
+
+mod assembler;
+pub use assembler::{
+    miden_assembler_compile, miden_assembler_compile_ast, miden_assembler_free,
+    miden_assembler_new, miden_program_free, miden_program_hash, miden_program_info,
+    MidenAssembler, MidenProgram,
+};
+
+mod errors;
+pub use errors::{miden_last_error_message, Error, MidenStatus};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Version of the C API; incremented whenever a backward-incompatible change is made to it.
+pub const MIDEN_ABI_VERSION: u32 = 1;
+
+/// Number of bytes in a serialized program hash.
+pub const MIDEN_DIGEST_BYTES: usize = 32;
+
+/// Returns the version of the C API implemented by the library.
+///
+/// Callers should compare it against `MIDEN_ABI_VERSION` from the header they were compiled
+/// against, to detect being linked against an incompatible version of the library.
+#[no_mangle]
+pub extern "C" fn miden_abi_version() -> u32 {
+    MIDEN_ABI_VERSION
+}
+
+// BUFFER
+// ================================================================================================
+
+/// A byte buffer allocated by the library.
+///
+/// Buffers written by the library must be freed via `miden_buffer_free()`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct MidenBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl MidenBuffer {
+    /// Moves the provided bytes into a new buffer.
+    pub(crate) fn from_vec(bytes: Vec<u8>) -> Self {
+        let len = bytes.len();
+        let data = Box::into_raw(bytes.into_boxed_slice()) as *mut u8;
+        Self { data, len }
+    }
+}
+
+/// Frees the memory of a buffer written by the library, and resets the buffer to be empty. Does
+/// nothing if `buffer` is NULL or if the buffer is already empty.
+///
+/// # Safety
+/// `buffer` must be NULL or point to a buffer which was written by the library or reset by this
+/// function.
+#[no_mangle]
+pub unsafe extern "C" fn miden_buffer_free(buffer: *mut MidenBuffer) {
+    let Some(buffer) = buffer.as_mut() else {
+        return;
+    };
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)));
+    }
+    buffer.data = ptr::null_mut();
+    buffer.len = 0;
+}
+
+// EXECUTOR
+// ================================================================================================
+
+/// Executes the program against the provided inputs, and writes the serialized resulting
+/// `StackOutputs` into `stack_outputs`.
+///
+/// `stack_inputs` and `advice_inputs` must hold serialized `StackInputs` and `AdviceInputs`
+/// respectively; either of them can be empty (with a length of 0), in which case empty inputs are
+/// used.
+///
+/// The buffer must be freed via `miden_buffer_free()`.
+///
+/// # Safety
+/// `program` must be a valid program handle, the input pointers must point to the specified number
+/// of readable bytes, and `stack_outputs` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn miden_execute(
+    program: *const MidenProgram,
+    stack_inputs: *const u8,
+    stack_inputs_len: usize,
+    advice_inputs: *const u8,
+    advice_inputs_len: usize,
+    stack_outputs: *mut MidenBuffer,
+) -> MidenStatus {
+    ffi_call(|| {
+        let program = program.as_ref().ok_or(Error::NullPointer("program"))?;
+        let stack_inputs = stack_inputs_arg(stack_inputs, stack_inputs_len)?;
+        let host = host_arg(advice_inputs, advice_inputs_len)?;
+
+        let trace =
+            processor::execute(&program.0, stack_inputs, host, ExecutionOptions::default())?;
+        write_bytes(stack_outputs, "stack_outputs", trace.stack_outputs().to_bytes())
+    })
+}
+
+// PROVER
+// ================================================================================================
+
+/// Security level targeted by a generated proof.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidenSecurityLevel {
+    /// 96-bit security level.
+    Bits96 = 96,
+    /// 128-bit security level.
+    Bits128 = 128,
+}
+
+/// Executes the program against the provided inputs and generates a STARK proof of the execution.
+/// The serialized resulting `StackOutputs` are written into `stack_outputs`, and the serialized
+/// proof is written into `proof`.
+///
+/// Inputs are expected in the same format as for `miden_execute()`. If `recursive` is set, the
+/// proof is generated using parameters which make it efficient to verify it inside Miden VM.
+///
+/// Both buffers must be freed via `miden_buffer_free()`.
+///
+/// # Safety
+/// `program` must be a valid program handle, the input pointers must point to the specified number
+/// of readable bytes, and `stack_outputs` and `proof` must be valid pointers.
+#[no_mangle]
+pub unsafe extern "C" fn miden_prove(
+    program: *const MidenProgram,
+    stack_inputs: *const u8,
+    stack_inputs_len: usize,
+    advice_inputs: *const u8,
+    advice_inputs_len: usize,
+    security_level: MidenSecurityLevel,
+    recursive: bool,
+    stack_outputs: *mut MidenBuffer,
+    proof: *mut MidenBuffer,
+) -> MidenStatus {
+    ffi_call(|| {
+        let program = program.as_ref().ok_or(Error::NullPointer("program"))?;
+        let stack_inputs = stack_inputs_arg(stack_inputs, stack_inputs_len)?;
+        let host = host_arg(advice_inputs, advice_inputs_len)?;
+        let options = match security_level {
+            MidenSecurityLevel::Bits96 => ProvingOptions::with_96_bit_security(recursive),
+            MidenSecurityLevel::Bits128 => ProvingOptions::with_128_bit_security(recursive),
+        };
+        // check the out-pointers upfront so that proving is not wasted on a call which fails anyway
+        if stack_outputs.is_null() {
+            return Err(Error::NullPointer("stack_outputs"));
+        } else if proof.is_null() {
+            return Err(Error::NullPointer("proof"));
+        }
+
+        let (outputs, execution_proof) = prover::prove(&program.0, stack_inputs, host, options)?;
+        write_bytes(proof, "proof", execution_proof.to_bytes())?;
+        write_bytes(stack_outputs, "stack_outputs", outputs.to_bytes())
+    })
+}
+
+// VERIFIER
+// ================================================================================================
+
+/// Verifies that the program described by the serialized `program_info` was executed correctly
+/// against the provided serialized `StackInputs` and `StackOutputs`, and writes the security level
+/// of the proof into `security_level`.
+///
+/// The proof is expected to be serialized as produced by `miden_prove()` (or, equivalently, as
+/// written into proof files by the `miden prove` command). As for `miden_execute()`, empty stack
+/// inputs can be specified with a length of 0.
+///
+/// # Safety
+/// All pointers to bytes must point to the specified number of readable bytes, and
+/// `security_level` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn miden_verify(
+    program_info: *const u8,
+    program_info_len: usize,
+    stack_inputs: *const u8,
+    stack_inputs_len: usize,
+    stack_outputs: *const u8,
+    stack_outputs_len: usize,
+    proof: *const u8,
+    proof_len: usize,
+    security_level: *mut u32,
+) -> MidenStatus {
+    ffi_call(|| {
+        let program_info = bytes_arg(program_info, program_info_len, "program_info")?;
+        let program_info = ProgramInfo::read_from_bytes(program_info)
+            .map_err(|err| Error::Deserialization("program_info", err))?;
+        let stack_inputs = stack_inputs_arg(stack_inputs, stack_inputs_len)?;
+        let stack_outputs = bytes_arg(stack_outputs, stack_outputs_len, "stack_outputs")?;
+        let stack_outputs = StackOutputs::read_from_bytes(stack_outputs)
+            .map_err(|err| Error::Deserialization("stack_outputs", err))?;
+        let proof = ExecutionProof::from_bytes(bytes_arg(proof, proof_len, "proof")?)
+            .map_err(|err| Error::Deserialization("proof", err))?;
+
+        let level = verifier::verify(program_info, stack_inputs, stack_outputs, proof)?;
+        write_out(security_level, "security_level", level)
+    })
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Executes the provided closure, converting its error or panic into a status code and recording
+/// the error as the last error of the current thread.
+pub(crate) fn ffi_call<F>(f: F) -> MidenStatus
+where
+    F: FnOnce() -> Result<(), Error>,
+{
+    let result = panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(Error::from_panic(payload)));
+    match result {
+        Ok(()) => MidenStatus::Ok,
+        Err(err) => {
+            errors::set_last_error(&err);
+            err.status()
+        }
+    }
+}
+
+/// Writes the provided value into the out-pointer, returning an error if the pointer is NULL.
+pub(crate) unsafe fn write_out<T>(out: *mut T, name: &'static str, value: T) -> Result<(), Error> {
+    if out.is_null() {
+        return Err(Error::NullPointer(name));
+    }
+    out.write(value);
+    Ok(())
+}
+
+/// Moves the provided bytes into a buffer written into the out-pointer, returning an error if the
+/// pointer is NULL.
+pub(crate) unsafe fn write_bytes(
+    out: *mut MidenBuffer,
+    name: &'static str,
+    bytes: Vec<u8>,
+) -> Result<(), Error> {
+    if out.is_null() {
+        return Err(Error::NullPointer(name));
+    }
+    out.write(MidenBuffer::from_vec(bytes));
+    Ok(())
+}
+
+/// Moves the provided value into a new heap-allocated object, and writes the handle to this object
+/// into the out-pointer, returning an error if the pointer is NULL.
+pub(crate) unsafe fn write_handle<T>(
+    out: *mut *mut T,
+    name: &'static str,
+    value: T,
+) -> Result<(), Error> {
+    if out.is_null() {
+        return Err(Error::NullPointer(name));
+    }
+    out.write(Box::into_raw(Box::new(value)));
+    Ok(())
+}
+
+/// Returns the bytes specified by the pointer and length; a NULL pointer is accepted only for an
+/// empty slice.
+pub(crate) unsafe fn bytes_arg<'a>(
+    data: *const u8,
+    len: usize,
+    name: &'static str,
+) -> Result<&'a [u8], Error> {
+    match (data.is_null(), len) {
+        (true, 0) => Ok(&[]),
+        (true, _) => Err(Error::NullPointer(name)),
+        (false, _) => Ok(slice::from_raw_parts(data, len)),
+    }
+}
+
+/// Returns the string specified by the pointer to a NUL-terminated string.
+pub(crate) unsafe fn str_arg<'a>(
+    data: *const c_char,
+    name: &'static str,
+) -> Result<&'a str, Error> {
+    if data.is_null() {
+        return Err(Error::NullPointer(name));
+    }
+    CStr::from_ptr(data).to_str().map_err(|err| Error::InvalidString(name, err))
+}
+
+/// Deserializes stack inputs from the specified bytes, returning empty inputs if there are none.
+unsafe fn stack_inputs_arg(data: *const u8, len: usize) -> Result<StackInputs, Error> {
+    match bytes_arg(data, len, "stack_inputs")? {
+        [] => Ok(StackInputs::default()),
+        bytes => StackInputs::read_from_bytes(bytes)
+            .map_err(|err| Error::Deserialization("stack_inputs", err)),
+    }
+}
+
+/// Deserializes advice inputs from the specified bytes, and returns a host which provides them.
+unsafe fn host_arg(data: *const u8, len: usize) -> Result<DefaultHost<MemAdviceProvider>, Error> {
+    let advice_inputs = match bytes_arg(data, len, "advice_inputs")? {
+        [] => AdviceInputs::default(),
+        bytes => AdviceInputs::read_from_bytes(bytes)
+            .map_err(|err| Error::Deserialization("advice_inputs", err))?,
+    };
+    Ok(DefaultHost::new(MemAdviceProvider::from(advice_inputs)))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,233 @@
+use super::*;
+use assembly::ast::{AstSerdeOptions, ProgramAst};
+use core::ptr;
+use processor::Felt;
+use std::ffi::CString;
+use vm_core::utils::ByteWriter;
+
+const SOURCE: &str = "
+    use.std::math::u64
+    begin
+        adv_push.2 exec.u64::wrapping_add
+    end";
+
+#[test]
+fn execute_prove_and_verify() {
+    let assembler = new_assembler(true);
+    let program = compile(assembler, SOURCE);
+
+    let stack_inputs = StackInputs::try_from_ints([3, 4]).unwrap().to_bytes();
+    let advice_inputs = AdviceInputs::default().with_stack_values([5, 6]).unwrap().to_bytes();
+
+    // execute the program
+    let mut outputs = empty_buffer();
+    let status = unsafe {
+        miden_execute(
+            program,
+            stack_inputs.as_ptr(),
+            stack_inputs.len(),
+            advice_inputs.as_ptr(),
+            advice_inputs.len(),
+            &mut outputs,
+        )
+    };
+    assert_eq!(MidenStatus::Ok, status);
+    let stack_outputs = StackOutputs::read_from_bytes(buffer_bytes(&outputs)).unwrap();
+    assert_eq!(&[Felt::new(10), Felt::new(8)], &stack_outputs.stack()[..2]);
+
+    // prove the execution
+    let mut proof_outputs = empty_buffer();
+    let mut proof = empty_buffer();
+    let status = unsafe {
+        miden_prove(
+            program,
+            stack_inputs.as_ptr(),
+            stack_inputs.len(),
+            advice_inputs.as_ptr(),
+            advice_inputs.len(),
+            MidenSecurityLevel::Bits96,
+            false,
+            &mut proof_outputs,
+            &mut proof,
+        )
+    };
+    assert_eq!(MidenStatus::Ok, status);
+    assert_eq!(buffer_bytes(&outputs), buffer_bytes(&proof_outputs));
+
+    // verify the proof
+    let mut program_info = empty_buffer();
+    assert_eq!(MidenStatus::Ok, unsafe { miden_program_info(program, &mut program_info) });
+    let mut security_level = 0;
+    let status = verify(&program_info, &stack_inputs, &outputs, &proof, &mut security_level);
+    assert_eq!(MidenStatus::Ok, status);
+    assert_eq!(96, security_level);
+
+    // a proof for different public inputs is rejected
+    let wrong_inputs = StackInputs::try_from_ints([3, 5]).unwrap().to_bytes();
+    let status = verify(&program_info, &wrong_inputs, &outputs, &proof, &mut security_level);
+    assert_eq!(MidenStatus::Verification, status);
+    assert!(!last_error().is_empty());
+
+    unsafe {
+        for buffer in [&mut outputs, &mut proof_outputs, &mut proof, &mut program_info] {
+            miden_buffer_free(buffer);
+            assert!(buffer.data.is_null());
+        }
+        miden_program_free(program);
+        miden_assembler_free(assembler);
+    }
+}
+
+#[test]
+fn compile_binary_ast() {
+    let assembler = new_assembler(false);
+    let source = "begin push.1 push.2 add end";
+    let ast = ProgramAst::parse(source).unwrap().to_bytes(AstSerdeOptions::new(false));
+
+    let mut program = ptr::null_mut();
+    let status =
+        unsafe { miden_assembler_compile_ast(assembler, ast.as_ptr(), ast.len(), &mut program) };
+    assert_eq!(MidenStatus::Ok, status);
+
+    let expected = compile(assembler, source);
+    let mut hash = [0; MIDEN_DIGEST_BYTES];
+    let mut expected_hash = [0; MIDEN_DIGEST_BYTES];
+    unsafe {
+        assert_eq!(MidenStatus::Ok, miden_program_hash(program, hash.as_mut_ptr()));
+        assert_eq!(MidenStatus::Ok, miden_program_hash(expected, expected_hash.as_mut_ptr()));
+    }
+    assert_eq!(expected_hash, hash);
+
+    unsafe {
+        miden_program_free(program);
+        miden_program_free(expected);
+        miden_assembler_free(assembler);
+    }
+}
+
+#[test]
+fn status_codes_and_error_messages() {
+    let assembler = new_assembler(false);
+
+    let source = CString::new("begin push end").unwrap();
+    let mut program = ptr::null_mut();
+    let status = unsafe { miden_assembler_compile(assembler, source.as_ptr(), &mut program) };
+    assert_eq!(MidenStatus::Assembly, status);
+    assert!(program.is_null());
+
+    let status = unsafe { miden_assembler_compile(assembler, ptr::null(), &mut program) };
+    assert_eq!(MidenStatus::NullPointer, status);
+    assert_eq!("argument `source` must not be NULL", last_error());
+
+    let program = compile(assembler, "begin push.1 assertz end");
+    let mut outputs = empty_buffer();
+    let status = unsafe { miden_execute(program, ptr::null(), 0, ptr::null(), 0, &mut outputs) };
+    assert_eq!(MidenStatus::Execution, status);
+    assert!(outputs.data.is_null());
+
+    let mut stack_inputs = StackInputs::try_from_ints([1]).unwrap().to_bytes();
+    stack_inputs.pop();
+    let status = unsafe {
+        miden_execute(
+            program,
+            stack_inputs.as_ptr(),
+            stack_inputs.len(),
+            ptr::null(),
+            0,
+            &mut outputs,
+        )
+    };
+    assert_eq!(MidenStatus::Deserialization, status);
+    assert!(last_error().starts_with("failed to deserialize argument `stack_inputs`"));
+
+    // malformed lengths of the advice inputs result in errors rather than aborted allocations;
+    // the inputs below claim 2^40 values on the advice stack, then 2^40 values under a single key
+    // of the advice map, and then 2^40 nodes in the Merkle store
+    let mut stack_len = Vec::new();
+    stack_len.write_usize(1 << 40);
+    let mut map_len = Vec::new();
+    map_len.write_usize(0);
+    map_len.write_usize(1);
+    map_len.write_bytes(&[0; 32]);
+    map_len.write_usize(1 << 40);
+    let mut store_len = Vec::new();
+    store_len.write_usize(0);
+    store_len.write_usize(0);
+    store_len.write_u64(1 << 40);
+    for advice_inputs in [stack_len, map_len, store_len] {
+        let status = unsafe {
+            miden_execute(
+                program,
+                ptr::null(),
+                0,
+                advice_inputs.as_ptr(),
+                advice_inputs.len(),
+                &mut outputs,
+            )
+        };
+        assert_eq!(MidenStatus::Deserialization, status);
+        assert!(last_error().starts_with("failed to deserialize argument `advice_inputs`"));
+    }
+
+    unsafe {
+        miden_program_free(program);
+        miden_assembler_free(assembler);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn new_assembler(with_stdlib: bool) -> *mut MidenAssembler {
+    let mut assembler = ptr::null_mut();
+    let status = unsafe { miden_assembler_new(with_stdlib, ptr::null(), &mut assembler) };
+    assert_eq!(MidenStatus::Ok, status);
+    assembler
+}
+
+fn compile(assembler: *const MidenAssembler, source: &str) -> *mut MidenProgram {
+    let source = CString::new(source).unwrap();
+    let mut program = ptr::null_mut();
+    let status = unsafe { miden_assembler_compile(assembler, source.as_ptr(), &mut program) };
+    assert_eq!(MidenStatus::Ok, status);
+    program
+}
+
+fn verify(
+    program_info: &MidenBuffer,
+    stack_inputs: &[u8],
+    stack_outputs: &MidenBuffer,
+    proof: &MidenBuffer,
+    security_level: &mut u32,
+) -> MidenStatus {
+    unsafe {
+        miden_verify(
+            program_info.data,
+            program_info.len,
+            stack_inputs.as_ptr(),
+            stack_inputs.len(),
+            stack_outputs.data,
+            stack_outputs.len,
+            proof.data,
+            proof.len,
+            security_level,
+        )
+    }
+}
+
+fn empty_buffer() -> MidenBuffer {
+    MidenBuffer {
+        data: ptr::null_mut(),
+        len: 0,
+    }
+}
+
+fn buffer_bytes(buffer: &MidenBuffer) -> &[u8] {
+    unsafe { slice::from_raw_parts(buffer.data, buffer.len) }
+}
+
+fn last_error() -> String {
+    let message = miden_last_error_message();
+    assert!(!message.is_null());
+    unsafe { CStr::from_ptr(message) }.to_str().unwrap().to_string()
+}
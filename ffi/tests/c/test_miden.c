@@ -0,0 +1,140 @@
+// Tests of the C API of Miden VM, linked against the library built from the `miden-ffi` crate.
+// Run `make test-ffi` to build the library, and to compile and run these tests.
+
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "miden.h"
+
+#define CHECK(cond)                                                                   \
+    do {                                                                              \
+        if (!(cond)) {                                                                \
+            const char *message = miden_last_error_message();                         \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #cond);  \
+            fprintf(stderr, "last error: %s\n", message ? message : "(none)");        \
+            exit(1);                                                                  \
+        }                                                                             \
+    } while (0)
+
+static const char *SOURCE = "begin mul end";
+
+// Serializes a list of at most 127 field elements in the same way as `StackInputs`: the number of
+// elements is encoded into a single byte, followed by the elements in little-endian byte order.
+static size_t serialize_elements(const uint64_t *values, size_t len, uint8_t *out) {
+    out[0] = (uint8_t)(len << 1 | 1);
+    for (size_t i = 0; i < len; i++) {
+        for (size_t j = 0; j < 8; j++) {
+            out[1 + i * 8 + j] = (uint8_t)(values[i] >> (j * 8));
+        }
+    }
+    return 1 + len * 8;
+}
+
+// Reads the value at the top of the stack from serialized `StackOutputs`.
+static uint64_t read_stack_top(const MidenBuffer *stack_outputs) {
+    uint64_t value = 0;
+    for (size_t j = 0; j < 8; j++) {
+        value |= (uint64_t)stack_outputs->data[1 + j] << (j * 8);
+    }
+    return value;
+}
+
+static void test_abi_version(void) {
+    CHECK(miden_abi_version() == MIDEN_ABI_VERSION);
+}
+
+static void test_execute_prove_and_verify(void) {
+    MidenAssembler *assembler = NULL;
+    CHECK(miden_assembler_new(false, NULL, &assembler) == MIDEN_STATUS_OK);
+
+    MidenProgram *program = NULL;
+    CHECK(miden_assembler_compile(assembler, SOURCE, &program) == MIDEN_STATUS_OK);
+
+    uint8_t hash[MIDEN_DIGEST_BYTES] = {0};
+    uint8_t zero[MIDEN_DIGEST_BYTES] = {0};
+    CHECK(miden_program_hash(program, hash) == MIDEN_STATUS_OK);
+    CHECK(memcmp(hash, zero, MIDEN_DIGEST_BYTES) != 0);
+
+    uint64_t inputs[] = {3, 4};
+    uint8_t stack_inputs[17];
+    size_t stack_inputs_len = serialize_elements(inputs, 2, stack_inputs);
+
+    // execute the program
+    MidenBuffer outputs = {0};
+    CHECK(miden_execute(program, stack_inputs, stack_inputs_len, NULL, 0, &outputs) ==
+          MIDEN_STATUS_OK);
+    CHECK(read_stack_top(&outputs) == 12);
+
+    // prove the execution
+    MidenBuffer proof_outputs = {0};
+    MidenBuffer proof = {0};
+    CHECK(miden_prove(program, stack_inputs, stack_inputs_len, NULL, 0,
+                      MIDEN_SECURITY_LEVEL_BITS96, false, &proof_outputs,
+                      &proof) == MIDEN_STATUS_OK);
+    CHECK(proof_outputs.len == outputs.len);
+    CHECK(memcmp(proof_outputs.data, outputs.data, outputs.len) == 0);
+
+    // verify the proof
+    MidenBuffer program_info = {0};
+    CHECK(miden_program_info(program, &program_info) == MIDEN_STATUS_OK);
+    uint32_t security_level = 0;
+    CHECK(miden_verify(program_info.data, program_info.len, stack_inputs, stack_inputs_len,
+                       outputs.data, outputs.len, proof.data, proof.len,
+                       &security_level) == MIDEN_STATUS_OK);
+    CHECK(security_level == 96);
+
+    // a proof for different public inputs is rejected
+    uint64_t wrong_inputs[] = {3, 5};
+    stack_inputs_len = serialize_elements(wrong_inputs, 2, stack_inputs);
+    CHECK(miden_verify(program_info.data, program_info.len, stack_inputs, stack_inputs_len,
+                       outputs.data, outputs.len, proof.data, proof.len,
+                       &security_level) == MIDEN_STATUS_VERIFICATION);
+    CHECK(miden_last_error_message() != NULL);
+
+    miden_buffer_free(&outputs);
+    miden_buffer_free(&proof_outputs);
+    miden_buffer_free(&proof);
+    miden_buffer_free(&program_info);
+    CHECK(proof.data == NULL && proof.len == 0);
+
+    miden_program_free(program);
+    miden_assembler_free(assembler);
+}
+
+static void test_errors(void) {
+    MidenAssembler *assembler = NULL;
+    CHECK(miden_assembler_new(true, NULL, &assembler) == MIDEN_STATUS_OK);
+
+    MidenProgram *program = NULL;
+    CHECK(miden_assembler_compile(assembler, "begin push end", &program) ==
+          MIDEN_STATUS_ASSEMBLY);
+    CHECK(program == NULL);
+    CHECK(miden_last_error_message() != NULL);
+
+    CHECK(miden_assembler_compile(assembler, NULL, &program) == MIDEN_STATUS_NULL_POINTER);
+    CHECK(strcmp(miden_last_error_message(), "argument `source` must not be NULL") == 0);
+
+    CHECK(miden_assembler_compile(assembler, "begin push.1 assertz end", &program) ==
+          MIDEN_STATUS_OK);
+    MidenBuffer outputs = {0};
+    CHECK(miden_execute(program, NULL, 0, NULL, 0, &outputs) == MIDEN_STATUS_EXECUTION);
+    CHECK(outputs.data == NULL);
+
+    uint8_t proof[] = {1, 2, 3};
+    uint32_t security_level = 0;
+    CHECK(miden_verify(NULL, 0, NULL, 0, NULL, 0, proof, sizeof(proof), &security_level) ==
+          MIDEN_STATUS_DESERIALIZATION);
+
+    miden_program_free(program);
+    miden_assembler_free(assembler);
+}
+
+int main(void) {
+    test_abi_version();
+    test_execute_prove_and_verify();
+    test_errors();
+    printf("all tests passed\n");
+    return 0;
+}
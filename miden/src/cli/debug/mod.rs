@@ -1,4 +1,7 @@
-use super::data::{Debug, InputFile, Libraries, ProgramFile};
+use super::{
+    data::{Debug, Libraries, ProgramFile},
+    InputFile,
+};
 use clap::Parser;
 use rustyline::{error::ReadlineError, Config, DefaultEditor, EditMode};
 use std::path::PathBuf;
@@ -0,0 +1,115 @@
+use super::{
+    data::{instrument, Debug, Libraries, ProgramFile},
+    InputFile,
+};
+use clap::Parser;
+use miden_vm::ProvingOptions;
+use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Estimate the cost of proving a miden program")]
+pub struct EstimateCmd {
+    /// Path to .masm assembly file
+    #[clap(short = 'a', long = "assembly", value_parser)]
+    assembly_file: PathBuf,
+
+    /// Number of cycles the program is expected to consume
+    #[clap(short = 'e', long = "exp-cycles", default_value = "64")]
+    expected_cycles: u32,
+
+    /// Path to input file
+    #[clap(short = 'i', long = "input", value_parser)]
+    input_file: Option<PathBuf>,
+
+    /// Paths to .masl library files
+    #[clap(short = 'l', long = "libraries", value_parser)]
+    library_paths: Vec<PathBuf>,
+
+    /// Maximum number of cycles a program is allowed to consume
+    #[clap(short = 'm', long = "max-cycles", default_value = "4294967295")]
+    max_cycles: u32,
+
+    /// Estimate the cost of proofs suitable for recursive verification
+    #[clap(short = 'r', long = "recursive")]
+    recursive: bool,
+
+    /// Security level for execution proofs generated by the VM
+    #[clap(short = 's', long = "security", default_value = "96bits")]
+    security: String,
+}
+
+impl EstimateCmd {
+    pub fn get_proof_options(&self) -> Result<ProvingOptions, ExecutionOptionsError> {
+        let exec_options =
+            ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles, false)?;
+        Ok(match self.security.as_str() {
+            "96bits" => ProvingOptions::with_96_bit_security(self.recursive),
+            "128bits" => ProvingOptions::with_128_bit_security(self.recursive),
+            other => panic!("{} is not a valid security setting", other),
+        }
+        .with_execution_options(exec_options))
+    }
+
+    pub fn execute(&self) -> Result<(), String> {
+        println!("===============================================================================");
+        println!("Estimate proving cost of program: {}", self.assembly_file.display());
+        println!("-------------------------------------------------------------------------------");
+
+        let (program, input_data) = load_data(self)?;
+
+        // fetch the stack and program inputs from the arguments
+        let stack_inputs = input_data.parse_stack_inputs()?;
+        let host = DefaultHost::new(input_data.parse_advice_provider()?);
+
+        let proving_options = self.get_proof_options().map_err(|err| format!("{err}"))?;
+
+        // execute the program and estimate the cost of proving it
+        let estimate = prover::estimate(&program, stack_inputs, host, &proving_options)
+            .map_err(|err| format!("Failed to execute program - {:?}", err))?;
+
+        println!(
+            "VM cycles: {} extended to {} steps.
+├── Main trace columns: {}
+├── Auxiliary trace columns: {}
+├── Main transition constraints: {}
+├── Auxiliary transition constraints: {}
+├── Boundary constraints: {}
+├── Composition polynomial columns: {}
+├── LDE domain size: {}
+└── FRI layers: {}",
+            estimate.num_cycles(),
+            estimate.padded_trace_len(),
+            estimate.main_trace_width(),
+            estimate.aux_trace_width(),
+            estimate.num_main_constraints(),
+            estimate.num_aux_constraints(),
+            estimate.num_assertions(),
+            estimate.num_composition_columns(),
+            estimate.lde_domain_size(),
+            estimate.num_fri_layers(),
+        );
+        println!("Estimated proof size: {} KB", estimate.proof_size() / 1024);
+        println!("Estimated prover memory: {} MB", estimate.prover_memory() / (1024 * 1024));
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+#[instrument(skip_all)]
+fn load_data(params: &EstimateCmd) -> Result<(Program, InputFile), String> {
+    // load libraries from files
+    let libraries = Libraries::new(&params.library_paths)?;
+
+    // load program from file and compile
+    let program =
+        ProgramFile::read(&params.assembly_file)?.compile(&Debug::Off, libraries.libraries)?;
+
+    // load input data from file
+    let input_data = InputFile::read(&params.input_file, &params.assembly_file)?;
+
+    Ok((program, input_data))
+}
@@ -0,0 +1,1037 @@
+use super::data::{event, instrument, Level};
+use miden_vm::{
+    crypto::{
+        InnerNodeInfo, MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, Rpo256, RpoDigest,
+        SimpleSmt,
+    },
+    math::Felt,
+    AdviceDataFile, AdviceInputs, FileAdviceProvider, MemAdviceProvider, StackInputs, Word,
+};
+use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+const SIMPLE_SMT_DEPTH: u8 = u64::BITS as u8;
+
+/// Maximum depth of nested file references in the Merkle store section of an input file.
+const MAX_FILE_REFERENCE_DEPTH: usize = 8;
+
+// INPUT FILE
+// ================================================================================================
+
+/// Input data of a program, read from an input file. It consists of four components:
+/// - operand_stack
+/// - advice_stack
+/// - advice_map
+/// - merkle_store
+///
+/// Input files can be written in JSON or TOML (files with the `.toml` extension are read as
+/// TOML). Files which do not specify a `version` are read in the legacy format, in which all
+/// values are field elements written as decimal numbers, and Merkle tree leaves are hex-encoded
+/// words. Version 1 files can additionally contain typed values (see [InputValue]) and reference
+/// Merkle data stored in other files.
+#[derive(Debug, Clone, Default)]
+pub struct InputFile {
+    /// Values of the initial operand stack; the last value ends up on top of the stack.
+    pub operand_stack: Vec<InputValue>,
+    /// Values of the initial advice stack; the first value ends up on top of the stack.
+    pub advice_stack: Vec<InputValue>,
+    /// Initial advice map.
+    pub advice_map: BTreeMap<RpoDigest, Vec<InputValue>>,
+    /// Merkle data which will be loaded into the initial Merkle store. Data referenced from other
+    /// files is included inline.
+    pub merkle_store: Vec<MerkleData>,
+}
+
+/// Helper methods to interact with the input file
+impl InputFile {
+    /// The latest version of the input file format.
+    pub const CURRENT_VERSION: u64 = 1;
+
+    #[instrument(name = "read_input_file", skip_all)]
+    pub fn read(inputs_path: &Option<PathBuf>, program_path: &Path) -> Result<Self, String> {
+        // if file not specified explicitly and corresponding file with same name as program_path
+        // with '.inputs' extension does't exist, set operand_stack to empty vector
+        if !inputs_path.is_some() && !program_path.with_extension("inputs").exists() {
+            return Ok(Self::default());
+        }
+
+        // If inputs_path has been provided then use this as path. Alternatively we will
+        // replace the program_path extension with `.inputs` and use this as a default.
+        let path = match inputs_path {
+            Some(path) => path.clone(),
+            None => program_path.with_extension("inputs"),
+        };
+
+        Self::read_file(&path, 0)
+    }
+
+    /// Writes this input file into the specified path. The file is written as TOML if the path
+    /// has the `.toml` extension, and as JSON otherwise.
+    #[instrument(name = "write_input_file", fields(path = %path.display()), skip_all)]
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        let contents = if path.extension().is_some_and(|ext| ext == "toml") {
+            toml::to_string_pretty(self).map_err(|err| err.to_string())
+        } else {
+            serde_json::to_string_pretty(self).map_err(|err| err.to_string())
+        }
+        .map_err(|err| format!("Failed to serialize input data - {}", err))?;
+
+        fs::write(path, contents)
+            .map_err(|err| format!("Failed to write input file `{}` - {}", path.display(), err))
+    }
+
+    /// Returns a copy of this input file with its advice data replaced by the specified advice
+    /// inputs. Nodes of the Merkle store are included as they are, without reconstructing the
+    /// trees they belong to; roots of empty subtrees are omitted since every Merkle store contains
+    /// them.
+    pub fn with_advice_inputs(&self, advice_inputs: &AdviceInputs) -> Self {
+        let to_values =
+            |elements: &[Felt]| elements.iter().copied().map(InputValue::Felt).collect();
+        let empty_store: MerkleStore = MerkleStore::default();
+        let nodes = advice_inputs
+            .merkle_store()
+            .inner_nodes()
+            .filter(|node| empty_store.get_node(node.value, NodeIndex::root()).is_err())
+            .map(|node| (node.left.into(), node.right.into()))
+            .collect();
+
+        Self {
+            operand_stack: self.operand_stack.clone(),
+            advice_stack: to_values(advice_inputs.stack()),
+            advice_map: advice_inputs
+                .map()
+                .iter()
+                .map(|(key, values)| (*key, to_values(values)))
+                .collect(),
+            merkle_store: vec![MerkleData::Nodes(nodes)],
+        }
+    }
+
+    /// Reads and parses the input file located at the specified path.
+    fn read_file(path: &Path, depth: usize) -> Result<Self, String> {
+        let source = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to open input file `{}` - {}", path.display(), err))?;
+        Self::parse(&source, path, depth)
+    }
+
+    /// Parses input data from the provided string. The string is parsed as TOML if the specified
+    /// path has the `.toml` extension, and as JSON otherwise.
+    ///
+    /// Merkle data files referenced by the input data are resolved relative to the directory of
+    /// the specified path, and `depth` is the number of file references followed to reach it.
+    fn parse(source: &str, path: &Path, depth: usize) -> Result<Self, String> {
+        let value = if path.extension().is_some_and(|ext| ext == "toml") {
+            let value: toml::Value = toml::from_str(source).map_err(|err| {
+                format!("Failed to deserialize input file `{}` - {}", path.display(), err)
+            })?;
+            serde_json::to_value(value).map_err(|err| err.to_string())?
+        } else {
+            serde_json::from_str(source).map_err(|err| {
+                format!("Failed to deserialize input file `{}` - {}", path.display(), err)
+            })?
+        };
+
+        let base_dir = path.parent().unwrap_or(Path::new(""));
+        InputParser::parse(&value, base_dir, depth).map_err(|err| {
+            format!("Failed to parse input file `{}` - {}: {}", path.display(), err.path, err.msg)
+        })
+    }
+
+    /// Parses input data from the provided JSON value, written in the same format as input files.
+    ///
+    /// Merkle data files referenced by the input data are resolved relative to the specified
+    /// directory.
+    pub fn from_json(value: &Value, base_dir: &Path) -> Result<Self, String> {
+        InputParser::parse(value, base_dir, 0)
+            .map_err(|err| format!("Failed to parse input data - {}: {}", err.path, err.msg))
+    }
+
+    /// Parse advice provider data from the input file.
+    pub fn parse_advice_provider(&self) -> Result<MemAdviceProvider, String> {
+        Ok(MemAdviceProvider::from(self.parse_advice_inputs()?))
+    }
+
+    /// Parse advice provider data from the input file, and back it by the advice data file
+    /// located at the specified path.
+    pub fn parse_file_advice_provider(
+        &self,
+        advice_data: &Path,
+    ) -> Result<FileAdviceProvider, String> {
+        let data = AdviceDataFile::open(advice_data).map_err(|err| {
+            format!("Failed to open advice data file `{}` - {}", advice_data.display(), err)
+        })?;
+        Ok(FileAdviceProvider::new(self.parse_advice_inputs()?, data))
+    }
+
+    /// Parse advice inputs (advice stack, advice map, and Merkle store) from the input file.
+    pub fn parse_advice_inputs(&self) -> Result<AdviceInputs, String> {
+        let stack = self.advice_stack.iter().flat_map(InputValue::to_elements);
+        let map: BTreeMap<RpoDigest, Vec<Felt>> = self
+            .advice_map
+            .iter()
+            .map(|(key, values)| (*key, values.iter().flat_map(InputValue::to_elements).collect()))
+            .collect();
+        let merkle_store = self
+            .parse_merkle_store()
+            .map_err(|e| format!("failed to parse advice provider: {e}"))?;
+
+        Ok(AdviceInputs::default()
+            .with_stack(stack)
+            .with_map(map)
+            .with_merkle_store(merkle_store))
+    }
+
+    /// Parse merkle store data from the input file.
+    fn parse_merkle_store(&self) -> Result<MerkleStore, String> {
+        let mut merkle_store = MerkleStore::default();
+        for data in self.merkle_store.iter() {
+            match data {
+                MerkleData::MerkleTree(leaves) => {
+                    let tree = MerkleTree::new(leaves.clone())
+                        .map_err(|e| format!("failed to parse a Merkle tree: {e}"))?;
+                    merkle_store.extend(tree.inner_nodes());
+                    event!(
+                        Level::TRACE,
+                        "Added Merkle tree with root {} to the Merkle store",
+                        tree.root()
+                    );
+                }
+                MerkleData::SparseMerkleTree(entries) => {
+                    let tree = SimpleSmt::<SIMPLE_SMT_DEPTH>::with_leaves(entries.clone())
+                        .map_err(|e| format!("failed to parse a Sparse Merkle Tree: {e}"))?;
+                    merkle_store.extend(tree.inner_nodes());
+                    event!(
+                        Level::TRACE,
+                        "Added Sparse Merkle tree with root {} to the Merkle store",
+                        tree.root()
+                    );
+                }
+                MerkleData::Nodes(nodes) => {
+                    merkle_store.extend(nodes.iter().map(|(left, right)| {
+                        let (left, right) = (RpoDigest::new(*left), RpoDigest::new(*right));
+                        InnerNodeInfo {
+                            value: Rpo256::merge(&[left, right]),
+                            left,
+                            right,
+                        }
+                    }));
+                }
+                MerkleData::PartialMerkleTree(entries) => {
+                    let entries = entries
+                        .iter()
+                        .map(|((depth, index), leaf)| {
+                            let node_index = NodeIndex::new(*depth, *index).map_err(|e| {
+                                format!(
+                                    "failed to create node index with depth {depth} and index \
+                                    {index} - {e}"
+                                )
+                            })?;
+                            Ok((node_index, RpoDigest::new(*leaf)))
+                        })
+                        .collect::<Result<Vec<_>, String>>()?;
+                    let tree = PartialMerkleTree::with_leaves(entries)
+                        .map_err(|e| format!("failed to parse a Partial Merkle Tree: {e}"))?;
+                    merkle_store.extend(tree.inner_nodes());
+                    event!(
+                        Level::TRACE,
+                        "Added Partial Merkle tree with root {} to the Merkle store",
+                        tree.root()
+                    );
+                }
+            }
+        }
+
+        Ok(merkle_store)
+    }
+
+    /// Parse a `Word` from a hex string.
+    pub fn parse_word(word_hex: &str) -> Result<Word, String> {
+        let word_value = word_hex.strip_prefix("0x").ok_or_else(|| {
+            format!("failed to decode `Word` from hex {word_hex} - missing 0x prefix")
+        })?;
+        let mut word_data = [0u8; 32];
+        hex::decode_to_slice(word_value, &mut word_data)
+            .map_err(|e| format!("failed to decode `Word` from hex {word_hex} - {e}"))?;
+        let mut word = Word::default();
+        for (i, value) in word_data.chunks(8).enumerate() {
+            word[i] = Felt::try_from(value).map_err(|e| {
+                format!("failed to convert `Word` data {word_hex} (element {i}) to Felt - {e}")
+            })?;
+        }
+        Ok(word)
+    }
+
+    /// Parse and return the stack inputs for the program.
+    pub fn parse_stack_inputs(&self) -> Result<StackInputs, String> {
+        let stack_inputs = self.operand_stack.iter().flat_map(InputValue::to_elements).collect();
+        StackInputs::new(stack_inputs).map_err(|e| e.to_string())
+    }
+}
+
+/// Input files are always serialized in the latest version of the format.
+impl Serialize for InputFile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let advice_map: BTreeMap<String, &Vec<InputValue>> = self
+            .advice_map
+            .iter()
+            .map(|(key, values)| (String::from(key), values))
+            .collect();
+
+        let mut map = serializer.serialize_map(Some(5))?;
+        map.serialize_entry("version", &Self::CURRENT_VERSION)?;
+        map.serialize_entry("operand_stack", &self.operand_stack)?;
+        map.serialize_entry("advice_stack", &self.advice_stack)?;
+        map.serialize_entry("advice_map", &advice_map)?;
+        map.serialize_entry("merkle_store", &self.merkle_store)?;
+        map.end()
+    }
+}
+
+// INPUT VALUE
+// ================================================================================================
+
+/// A typed value of an input file, which expands into one or more field elements.
+///
+/// In version 1 input files, values are written as plain numbers (or decimal strings) for field
+/// elements, or as single-entry tables naming the type of the value, e.g. `{"u64": 5}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputValue {
+    /// A single field element (`{"felt": n}` or a plain number).
+    Felt(Felt),
+    /// A 32-bit integer (`{"u32": n}`), expanding into a single field element.
+    U32(u32),
+    /// A 64-bit integer (`{"u64": n}`), expanding into its 32-bit limbs `[lo, hi]`, so that the
+    /// high limb ends up on top of the stack once the value is pushed onto the operand stack.
+    U64(u64),
+    /// A word encoded as a 32 byte hex string (`{"word": "0x..."}`), expanding into its four
+    /// elements.
+    Word(Word),
+    /// A hex-encoded byte string (`{"bytes": "0x..."}`), packed into field elements 4 bytes at a
+    /// time in little-endian order, with the last element padded with zeros.
+    Bytes(Vec<u8>),
+    /// RPO hash of the elements of the listed values (`{"rpo_digest": [...]}`), expanding into
+    /// the four elements of the digest.
+    RpoDigest(Vec<InputValue>),
+}
+
+impl InputValue {
+    /// Returns the field elements this value expands into.
+    pub fn to_elements(&self) -> Vec<Felt> {
+        match self {
+            Self::Felt(value) => vec![*value],
+            Self::U32(value) => vec![Felt::from(*value)],
+            Self::U64(value) => vec![Felt::from(*value as u32), Felt::from((*value >> 32) as u32)],
+            Self::Word(word) => word.to_vec(),
+            Self::Bytes(bytes) => bytes
+                .chunks(4)
+                .map(|chunk| {
+                    let mut limb = [0u8; 4];
+                    limb[..chunk.len()].copy_from_slice(chunk);
+                    Felt::from(u32::from_le_bytes(limb))
+                })
+                .collect(),
+            Self::RpoDigest(values) => {
+                let elements: Vec<Felt> = values.iter().flat_map(Self::to_elements).collect();
+                Rpo256::hash_elements(&elements).as_elements().to_vec()
+            }
+        }
+    }
+}
+
+impl Serialize for InputValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (ty, value) = match self {
+            Self::Felt(value) => return serializer.serialize_str(&value.as_int().to_string()),
+            Self::U32(value) => ("u32", Value::from(*value)),
+            Self::U64(value) => ("u64", Value::from(value.to_string())),
+            Self::Word(word) => ("word", Value::from(word_to_hex(word))),
+            Self::Bytes(bytes) => ("bytes", Value::from(format!("0x{}", hex::encode(bytes)))),
+            Self::RpoDigest(values) => {
+                ("rpo_digest", serde_json::to_value(values).map_err(serde::ser::Error::custom)?)
+            }
+        };
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(ty, &value)?;
+        map.end()
+    }
+}
+
+// MERKLE DATA
+// ================================================================================================
+
+/// Merkle data of an input file. Merkle data can be represented as a Merkle tree, a Sparse Merkle
+/// Tree, a Partial Merkle Tree, or (in version 1) as a list of Merkle store nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub enum MerkleData {
+    /// Leaves of a Merkle tree.
+    MerkleTree(Vec<Word>),
+    /// Leaves of a Sparse Merkle Tree as (index, value) tuples.
+    SparseMerkleTree(Vec<(u64, Word)>),
+    /// Leaves of a Partial Merkle Tree as ((depth, index), value) tuples.
+    PartialMerkleTree(Vec<((u8, u64), Word)>),
+    /// Internal nodes of a Merkle store as (left child, right child) tuples.
+    Nodes(Vec<(Word, Word)>),
+}
+
+/// Merkle data is serialized with leaves as 32 byte hex strings.
+impl Serialize for MerkleData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(serde_derive::Serialize)]
+        #[allow(clippy::enum_variant_names)]
+        enum MerkleDataRepr {
+            #[serde(rename = "merkle_tree")]
+            MerkleTree(Vec<String>),
+            #[serde(rename = "sparse_merkle_tree")]
+            SparseMerkleTree(Vec<(u64, String)>),
+            #[serde(rename = "partial_merkle_tree")]
+            PartialMerkleTree(Vec<((u8, u64), String)>),
+            #[serde(rename = "nodes")]
+            Nodes(Vec<(String, String)>),
+        }
+
+        match self {
+            Self::MerkleTree(leaves) => {
+                MerkleDataRepr::MerkleTree(leaves.iter().map(word_to_hex).collect())
+            }
+            Self::SparseMerkleTree(entries) => MerkleDataRepr::SparseMerkleTree(
+                entries.iter().map(|(index, leaf)| (*index, word_to_hex(leaf))).collect(),
+            ),
+            Self::PartialMerkleTree(entries) => MerkleDataRepr::PartialMerkleTree(
+                entries.iter().map(|(index, leaf)| (*index, word_to_hex(leaf))).collect(),
+            ),
+            Self::Nodes(nodes) => MerkleDataRepr::Nodes(
+                nodes
+                    .iter()
+                    .map(|(left, right)| (word_to_hex(left), word_to_hex(right)))
+                    .collect(),
+            ),
+        }
+        .serialize(serializer)
+    }
+}
+
+// INPUT PARSER
+// ================================================================================================
+
+/// An error encountered while parsing input data, together with the path of the offending value
+/// (e.g., `advice_stack[2].u64`).
+struct ParseError {
+    path: String,
+    msg: String,
+}
+
+impl ParseError {
+    fn new(path: &str, msg: impl Into<String>) -> Self {
+        Self {
+            path: path.to_string(),
+            msg: msg.into(),
+        }
+    }
+}
+
+/// Parser of input data which was deserialized into a JSON value.
+struct InputParser<'a> {
+    version: u64,
+    base_dir: &'a Path,
+    depth: usize,
+}
+
+impl<'a> InputParser<'a> {
+    /// Parses input data from the provided value. `depth` is the number of file references
+    /// followed to reach this value.
+    fn parse(value: &Value, base_dir: &'a Path, depth: usize) -> Result<InputFile, ParseError> {
+        let fields = value
+            .as_object()
+            .ok_or_else(|| ParseError::new("$", "expected a table of input data"))?;
+
+        let version = match fields.get("version") {
+            None => 0,
+            Some(version) => match version.as_u64() {
+                Some(version @ 1..=InputFile::CURRENT_VERSION) => version,
+                _ => {
+                    return Err(ParseError::new(
+                        "version",
+                        format!(
+                            "unsupported input file version {version}; the latest supported \
+                            version is {}",
+                            InputFile::CURRENT_VERSION
+                        ),
+                    ))
+                }
+            },
+        };
+        let parser = Self {
+            version,
+            base_dir,
+            depth,
+        };
+
+        // unknown fields are ignored in the legacy format for backward compatibility
+        if version > 0 {
+            const FIELDS: [&str; 5] =
+                ["version", "operand_stack", "advice_stack", "advice_map", "merkle_store"];
+            if let Some(field) = fields.keys().find(|field| !FIELDS.contains(&field.as_str())) {
+                return Err(ParseError::new(field, "unknown field"));
+            }
+        }
+
+        let mut inputs = InputFile::default();
+        if let Some(values) = fields.get("operand_stack") {
+            inputs.operand_stack = parser.parse_values(values, "operand_stack")?;
+        }
+        if let Some(values) = fields.get("advice_stack") {
+            inputs.advice_stack = parser.parse_values(values, "advice_stack")?;
+        }
+        if let Some(map) = fields.get("advice_map") {
+            inputs.advice_map = parser.parse_advice_map(map, "advice_map")?;
+        }
+        if let Some(data) = fields.get("merkle_store") {
+            inputs.merkle_store = parser.parse_merkle_store(data, "merkle_store")?;
+        }
+
+        Ok(inputs)
+    }
+
+    // VALUES
+    // --------------------------------------------------------------------------------------------
+
+    fn parse_values(&self, values: &Value, path: &str) -> Result<Vec<InputValue>, ParseError> {
+        as_array(values, path)?
+            .iter()
+            .enumerate()
+            .map(|(i, value)| self.parse_value(value, &format!("{path}[{i}]")))
+            .collect()
+    }
+
+    fn parse_value(&self, value: &Value, path: &str) -> Result<InputValue, ParseError> {
+        let (ty, value) = match value {
+            Value::Object(fields) => self.typed_entry(fields, path)?,
+            _ => return parse_felt(value, path).map(InputValue::Felt),
+        };
+
+        let path = &format!("{path}.{ty}");
+        match ty {
+            "felt" => parse_felt(value, path).map(InputValue::Felt),
+            "u32" => {
+                let value = parse_u64(value, path)?;
+                u32::try_from(value)
+                    .map(InputValue::U32)
+                    .map_err(|_| ParseError::new(path, format!("value {value} is not a valid u32")))
+            }
+            "u64" => parse_u64(value, path).map(InputValue::U64),
+            "word" => parse_word(value, path).map(InputValue::Word),
+            "bytes" => {
+                let bytes = as_str(value, path)?
+                    .strip_prefix("0x")
+                    .ok_or_else(|| ParseError::new(path, "expected a 0x-prefixed hex string"))?;
+                hex::decode(bytes)
+                    .map(InputValue::Bytes)
+                    .map_err(|e| ParseError::new(path, format!("invalid hex string: {e}")))
+            }
+            "rpo_digest" => self.parse_values(value, path).map(InputValue::RpoDigest),
+            _ => Err(ParseError::new(
+                path,
+                "unknown value type; expected one of felt, u32, u64, word, bytes, rpo_digest",
+            )),
+        }
+    }
+
+    /// Returns the type name and the value of a typed value, i.e., a single-entry table.
+    fn typed_entry<'v>(
+        &self,
+        fields: &'v Map<String, Value>,
+        path: &str,
+    ) -> Result<(&'v str, &'v Value), ParseError> {
+        if self.version == 0 {
+            return Err(ParseError::new(path, "typed values require input file version 1"));
+        }
+        let mut entries = fields.iter();
+        match (entries.next(), entries.next()) {
+            (Some((ty, value)), None) => Ok((ty.as_str(), value)),
+            _ => Err(ParseError::new(path, "expected a table with a single entry")),
+        }
+    }
+
+    /// Parses a Merkle tree leaf, which is either a hex-encoded word, or (in version 1) a typed
+    /// value which expands into exactly four elements.
+    fn parse_leaf(&self, value: &Value, path: &str) -> Result<Word, ParseError> {
+        if value.is_string() {
+            return parse_word(value, path);
+        }
+        let Value::Object(fields) = value else {
+            return Err(ParseError::new(path, "expected a 32 byte hex string"));
+        };
+
+        self.typed_entry(fields, path)?;
+        let elements = self.parse_value(value, path)?.to_elements();
+        elements.try_into().map_err(|elements: Vec<Felt>| {
+            ParseError::new(
+                path,
+                format!("expected a value of 4 elements, but it has {} elements", elements.len()),
+            )
+        })
+    }
+
+    // ADVICE MAP
+    // --------------------------------------------------------------------------------------------
+
+    fn parse_advice_map(
+        &self,
+        map: &Value,
+        path: &str,
+    ) -> Result<BTreeMap<RpoDigest, Vec<InputValue>>, ParseError> {
+        let entries = map
+            .as_object()
+            .ok_or_else(|| ParseError::new(path, "expected a table of key-value pairs"))?;
+
+        entries
+            .iter()
+            .map(|(key, values)| {
+                let path = format!("{path}.{key}");
+                let key = RpoDigest::try_from(key)
+                    .map_err(|e| ParseError::new(&path, format!("invalid advice map key: {e}")))?;
+                Ok((key, self.parse_values(values, &path)?))
+            })
+            .collect()
+    }
+
+    // MERKLE STORE
+    // --------------------------------------------------------------------------------------------
+
+    fn parse_merkle_store(&self, data: &Value, path: &str) -> Result<Vec<MerkleData>, ParseError> {
+        let mut merkle_store = Vec::new();
+        for (i, entry) in as_array(data, path)?.iter().enumerate() {
+            let path = &format!("{path}[{i}]");
+            let (ty, value) = match entry.as_object() {
+                Some(fields) if fields.len() == 1 => fields.iter().next().expect("one entry"),
+                _ => return Err(ParseError::new(path, "expected a table with a single entry")),
+            };
+
+            let path = &format!("{path}.{ty}");
+            match ty.as_str() {
+                "merkle_tree" => {
+                    let leaves = as_array(value, path)?
+                        .iter()
+                        .enumerate()
+                        .map(|(i, leaf)| self.parse_leaf(leaf, &format!("{path}[{i}]")))
+                        .collect::<Result<_, _>>()?;
+                    merkle_store.push(MerkleData::MerkleTree(leaves));
+                }
+                "sparse_merkle_tree" => {
+                    let entries = as_array(value, path)?
+                        .iter()
+                        .enumerate()
+                        .map(|(i, entry)| {
+                            let path = format!("{path}[{i}]");
+                            let [index, leaf] = as_tuple(entry, &path)?;
+                            let index = parse_u64(index, &format!("{path}[0]"))?;
+                            Ok((index, self.parse_leaf(leaf, &format!("{path}[1]"))?))
+                        })
+                        .collect::<Result<_, _>>()?;
+                    merkle_store.push(MerkleData::SparseMerkleTree(entries));
+                }
+                "partial_merkle_tree" => {
+                    let entries = as_array(value, path)?
+                        .iter()
+                        .enumerate()
+                        .map(|(i, entry)| {
+                            let path = format!("{path}[{i}]");
+                            let [index, leaf] = as_tuple(entry, &path)?;
+                            let index_path = format!("{path}[0]");
+                            let [depth, index] = as_tuple(index, &index_path)?;
+                            let depth_path = format!("{index_path}[0]");
+                            let depth = u8::try_from(parse_u64(depth, &depth_path)?)
+                                .map_err(|_| ParseError::new(&depth_path, "invalid depth"))?;
+                            let index = parse_u64(index, &format!("{index_path}[1]"))?;
+                            Ok(((depth, index), self.parse_leaf(leaf, &format!("{path}[1]"))?))
+                        })
+                        .collect::<Result<_, _>>()?;
+                    merkle_store.push(MerkleData::PartialMerkleTree(entries));
+                }
+                "nodes" if self.version > 0 => {
+                    let nodes = as_array(value, path)?
+                        .iter()
+                        .enumerate()
+                        .map(|(i, node)| {
+                            let path = format!("{path}[{i}]");
+                            let [left, right] = as_tuple(node, &path)?;
+                            let left = self.parse_leaf(left, &format!("{path}[0]"))?;
+                            Ok((left, self.parse_leaf(right, &format!("{path}[1]"))?))
+                        })
+                        .collect::<Result<_, _>>()?;
+                    merkle_store.push(MerkleData::Nodes(nodes));
+                }
+                "file" if self.version > 0 => {
+                    merkle_store.extend(self.parse_merkle_file(value, path)?);
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        path,
+                        "unknown Merkle data type; expected one of merkle_tree, \
+                        sparse_merkle_tree, partial_merkle_tree, nodes, file",
+                    ))
+                }
+            }
+        }
+
+        Ok(merkle_store)
+    }
+
+    /// Reads the Merkle data of the input file referenced by the specified value. The path of the
+    /// referenced file is relative to the directory of the referencing file, and the referenced
+    /// file must contain nothing but Merkle data.
+    fn parse_merkle_file(&self, value: &Value, path: &str) -> Result<Vec<MerkleData>, ParseError> {
+        if self.depth >= MAX_FILE_REFERENCE_DEPTH {
+            return Err(ParseError::new(path, "too many nested file references"));
+        }
+
+        let file_path = self.base_dir.join(as_str(value, path)?);
+        let inputs = InputFile::read_file(&file_path, self.depth + 1)
+            .map_err(|e| ParseError::new(path, e))?;
+        if !inputs.operand_stack.is_empty()
+            || !inputs.advice_stack.is_empty()
+            || !inputs.advice_map.is_empty()
+        {
+            return Err(ParseError::new(
+                path,
+                format!("referenced file `{}` must contain only Merkle data", file_path.display()),
+            ));
+        }
+
+        Ok(inputs.merkle_store)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn as_array<'v>(value: &'v Value, path: &str) -> Result<&'v Vec<Value>, ParseError> {
+    value.as_array().ok_or_else(|| ParseError::new(path, "expected an array"))
+}
+
+fn as_tuple<'v>(value: &'v Value, path: &str) -> Result<[&'v Value; 2], ParseError> {
+    match as_array(value, path)?.as_slice() {
+        [first, second] => Ok([first, second]),
+        _ => Err(ParseError::new(path, "expected an array of 2 values")),
+    }
+}
+
+fn as_str<'v>(value: &'v Value, path: &str) -> Result<&'v str, ParseError> {
+    value.as_str().ok_or_else(|| ParseError::new(path, "expected a string"))
+}
+
+/// Parses an unsigned integer written as a number or as a decimal string.
+fn parse_u64(value: &Value, path: &str) -> Result<u64, ParseError> {
+    match value {
+        Value::Number(number) => number.as_u64(),
+        Value::String(string) => string.parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| ParseError::new(path, format!("expected an unsigned integer, found {value}")))
+}
+
+fn parse_felt(value: &Value, path: &str) -> Result<Felt, ParseError> {
+    let value = parse_u64(value, path)?;
+    Felt::try_from(value).map_err(|e| {
+        ParseError::new(path, format!("value {value} is not a valid field element: {e}"))
+    })
+}
+
+fn parse_word(value: &Value, path: &str) -> Result<Word, ParseError> {
+    InputFile::parse_word(as_str(value, path)?).map_err(|e| ParseError::new(path, e))
+}
+
+/// Encodes a word as a 32 byte hex string in the format accepted by [InputFile::parse_word()].
+fn word_to_hex(word: &Word) -> String {
+    let bytes: Vec<u8> = word.iter().flat_map(|element| element.as_int().to_le_bytes()).collect();
+    format!("0x{}", hex::encode(bytes))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod test {
+    use super::{Felt, InputFile, InputValue, MerkleData, Rpo256};
+    use std::{fs, path::Path};
+
+    fn parse(source: &str) -> Result<InputFile, String> {
+        InputFile::parse(source, Path::new("test.inputs"), 0)
+    }
+
+    #[test]
+    fn test_merkle_data_parsing() {
+        let program_with_pmt = "
+        {
+            \"operand_stack\": [\"1\"],
+            \"merkle_store\": [
+                {
+                    \"partial_merkle_tree\": [
+                        [
+                            [2, 0],
+                            \"0x1400000000000000000000000000000000000000000000000000000000000000\"
+                        ],
+                        [
+                            [2, 1],
+                            \"0x1500000000000000000000000000000000000000000000000000000000000000\"
+                        ],
+                        [
+                            [1, 1],
+                            \"0x0b00000000000000000000000000000000000000000000000000000000000000\"
+                        ]
+                    ]
+                }
+            ]
+        }";
+        let inputs = parse(program_with_pmt).unwrap();
+        assert!(inputs.parse_merkle_store().is_ok());
+
+        let program_with_smt = "
+        {
+            \"operand_stack\": [\"1\"],
+            \"merkle_store\": [
+              {
+                \"sparse_merkle_tree\": [
+                  [
+                    0,
+                    \"0x1400000000000000000000000000000000000000000000000000000000000000\"
+                  ],
+                  [
+                    1,
+                    \"0x1500000000000000000000000000000000000000000000000000000000000000\"
+                  ],
+                  [
+                    3,
+                    \"0x1700000000000000000000000000000000000000000000000000000000000000\"
+                  ]
+                ]
+              }
+            ]
+          }";
+        let inputs = parse(program_with_smt).unwrap();
+        assert!(inputs.parse_merkle_store().is_ok());
+
+        let program_with_merkle_tree = "
+        {
+            \"operand_stack\": [\"1\"],
+            \"merkle_store\": [
+                {
+                    \"merkle_tree\": [
+                        \"0x1400000000000000000000000000000000000000000000000000000000000000\",
+                        \"0x1500000000000000000000000000000000000000000000000000000000000000\",
+                        \"0x1600000000000000000000000000000000000000000000000000000000000000\",
+                        \"0x1700000000000000000000000000000000000000000000000000000000000000\"
+                    ]
+                }
+            ]
+        }";
+        let inputs = parse(program_with_merkle_tree).unwrap();
+        assert!(inputs.parse_merkle_store().is_ok());
+    }
+
+    #[test]
+    fn test_typed_values() {
+        let inputs = parse(
+            r#"{
+                "version": 1,
+                "operand_stack": [1, "2", { "u32": 3 }, { "u64": "4294967301" }],
+                "advice_stack": [
+                    { "felt": 7 },
+                    { "bytes": "0x0102030405" },
+                    { "word": "0x0100000000000000020000000000000003000000000000000400000000000000" },
+                    { "rpo_digest": [1, { "u64": 2 }] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        // u64 values are pushed as [lo, hi]
+        let stack = inputs.parse_stack_inputs().unwrap();
+        let stack = stack.values().iter().map(|v| v.as_int()).collect::<Vec<_>>();
+        assert_eq!(&[1, 5, 3, 2, 1], &stack[..5]);
+
+        let digest = Rpo256::hash_elements(&[Felt::new(1), Felt::new(2), Felt::new(0)]);
+        let mut expected = vec![7, 0x04030201, 5, 1, 2, 3, 4];
+        expected.extend(digest.as_elements().iter().map(|v| v.as_int()));
+        let elements = inputs
+            .advice_stack
+            .iter()
+            .flat_map(InputValue::to_elements)
+            .map(|v| v.as_int())
+            .collect::<Vec<_>>();
+        assert_eq!(expected, elements);
+
+        // serialized input files can be parsed back
+        let serialized = serde_json::to_string(&inputs).unwrap();
+        let parsed = parse(&serialized).unwrap();
+        assert_eq!(inputs.operand_stack, parsed.operand_stack);
+        assert_eq!(inputs.advice_stack, parsed.advice_stack);
+    }
+
+    #[test]
+    fn test_toml_inputs() {
+        let source = r#"
+            version = 1
+            operand_stack = [1, { u64 = 8589934595 }]
+
+            [advice_map]
+            "0x0100000000000000000000000000000000000000000000000000000000000000" = [{ u32 = 9 }]
+
+            [[merkle_store]]
+            merkle_tree = [
+                { rpo_digest = [1] },
+                "0x0200000000000000000000000000000000000000000000000000000000000000",
+            ]
+        "#;
+        let inputs = InputFile::parse(source, Path::new("test.toml"), 0).unwrap();
+        assert_eq!(
+            vec![InputValue::Felt(Felt::new(1)), InputValue::U64(8589934595)],
+            inputs.operand_stack
+        );
+        assert_eq!(1, inputs.advice_map.len());
+        assert!(
+            matches!(&inputs.merkle_store[0], MerkleData::MerkleTree(leaves) if leaves.len() == 2)
+        );
+        assert!(inputs.parse_advice_inputs().is_ok());
+    }
+
+    #[test]
+    fn test_merkle_file_references() {
+        let dir = std::env::temp_dir().join(format!("miden-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("data")).unwrap();
+        fs::write(
+            dir.join("data/tree.toml"),
+            r#"
+                version = 1
+                [[merkle_store]]
+                sparse_merkle_tree = [[3, { rpo_digest = [{ u64 = 1 }] }], [4, { rpo_digest = [1, 2] }]]
+            "#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("data/nested.json"),
+            r#"{ "version": 1, "merkle_store": [{ "file": "tree.toml" }] }"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("inputs.json"),
+            r#"{ "version": 1, "merkle_store": [{ "file": "data/nested.json" }] }"#,
+        )
+        .unwrap();
+
+        let inputs = InputFile::read(&Some(dir.join("inputs.json")), Path::new("")).unwrap();
+        let [MerkleData::SparseMerkleTree(entries)] = &inputs.merkle_store[..] else {
+            panic!("expected a single Sparse Merkle Tree");
+        };
+        assert_eq!(vec![3, 4], entries.iter().map(|(index, _)| *index).collect::<Vec<_>>());
+
+        // referenced files may contain only Merkle data, and cannot reference themselves forever
+        fs::write(dir.join("data/stack.json"), r#"{ "version": 1, "advice_stack": [1] }"#).unwrap();
+        fs::write(
+            dir.join("data/cycle.json"),
+            r#"{ "version": 1, "merkle_store": [{ "file": "cycle.json" }] }"#,
+        )
+        .unwrap();
+        for file in ["stack.json", "cycle.json"] {
+            let source =
+                format!(r#"{{ "version": 1, "merkle_store": [{{ "file": "data/{file}" }}] }}"#);
+            let err = InputFile::parse(&source, &dir.join("inputs.json"), 0).unwrap_err();
+            assert!(err.contains("merkle_store[0].file"), "{err}");
+        }
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_witness_inputs() {
+        let inputs = parse(
+            r#"{
+                "version": 1,
+                "operand_stack": [{ "u32": 1 }],
+                "advice_stack": [2, 3],
+                "advice_map": {
+                    "0x0100000000000000000000000000000000000000000000000000000000000000": [4]
+                },
+                "merkle_store": [{ "merkle_tree": [{ "word": "0x0500000000000000000000000000000000000000000000000000000000000000" }, "0x0600000000000000000000000000000000000000000000000000000000000000"] }]
+            }"#,
+        )
+        .unwrap();
+        let advice_inputs = inputs.parse_advice_inputs().unwrap();
+
+        // Merkle store nodes are written as (left, right) tuples and read back into the same store
+        let witness = inputs.with_advice_inputs(&advice_inputs);
+        assert_eq!(inputs.operand_stack, witness.operand_stack);
+        let [MerkleData::Nodes(nodes)] = &witness.merkle_store[..] else {
+            panic!("expected a single list of nodes");
+        };
+        assert_eq!(1, nodes.len());
+
+        let source = serde_json::to_string(&witness).unwrap();
+        let witness_inputs = parse(&source).unwrap().parse_advice_inputs().unwrap();
+        assert_eq!(advice_inputs.stack(), witness_inputs.stack());
+        assert_eq!(
+            advice_inputs.map().iter().collect::<Vec<_>>(),
+            witness_inputs.map().iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            advice_inputs.merkle_store().inner_nodes().collect::<Vec<_>>(),
+            witness_inputs.merkle_store().inner_nodes().collect::<Vec<_>>()
+        );
+
+        // lists of nodes are not supported by the legacy format
+        let err = parse(r#"{ "merkle_store": [{ "nodes": [] }] }"#).unwrap_err();
+        assert!(err.contains("merkle_store[0].nodes: unknown Merkle data type"));
+    }
+
+    #[test]
+    fn test_error_paths() {
+        let cases = [
+            (r#"{ "version": 2 }"#, "version: unsupported input file version 2"),
+            (r#"{ "version": 1, "stack": [] }"#, "stack: unknown field"),
+            (r#"{ "advice_stack": [{ "u32": 1 }] }"#, "advice_stack[0]: typed values require"),
+            (
+                r#"{ "version": 1, "operand_stack": [1, { "u32": 4294967296 }] }"#,
+                "operand_stack[1].u32: value 4294967296 is not a valid u32",
+            ),
+            (
+                r#"{ "version": 1, "advice_stack": [{ "u65": 1 }] }"#,
+                "advice_stack[0].u65: unknown value type",
+            ),
+            (
+                r#"{ "operand_stack": ["18446744069414584321"] }"#,
+                "operand_stack[0]: value 18446744069414584321 is not a valid field element",
+            ),
+            (
+                r#"{ "version": 1, "advice_map": { "0x01": [1] } }"#,
+                "advice_map.0x01: invalid advice map key",
+            ),
+            (
+                r#"{ "version": 1, "merkle_store": [{ "merkle_tree": ["0x01", { "u64": 1 }] }] }"#,
+                "merkle_store[0].merkle_tree[0]: failed to decode `Word`",
+            ),
+            (
+                r#"{ "version": 1, "merkle_store": [{ "sparse_merkle_tree": [[1, { "u64": 1 }]] }] }"#,
+                "merkle_store[0].sparse_merkle_tree[0][1]: expected a value of 4 elements",
+            ),
+            (
+                r#"{ "merkle_store": [{ "file": "tree.json" }] }"#,
+                "merkle_store[0].file: unknown Merkle data type",
+            ),
+        ];
+
+        for (source, expected) in cases {
+            let err = parse(source).unwrap_err();
+            assert!(err.contains(expected), "expected '{expected}' in '{err}'");
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::data::ProofFile;
+use clap::Parser;
+use miden_vm::ProofReport;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Print the parameters and components of an execution proof")]
+pub struct InspectProofCmd {
+    /// Path to proof file
+    #[clap(short = 'p', long = "proof", value_parser)]
+    proof_file: PathBuf,
+}
+
+impl InspectProofCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        println!("===============================================================================");
+        println!("Inspect proof: {}", self.proof_file.display());
+        println!("-------------------------------------------------------------------------------");
+
+        // load proof from file
+        let proof = ProofFile::read(&Some(self.proof_file.clone()), &self.proof_file)?;
+        let report = proof
+            .inspect()
+            .map_err(|err| format!("Failed to decode proof data - {}", err))?;
+
+        print_options(&report);
+        print_trace(&report);
+        print_commitments(&report);
+        print_sizes(&report);
+        println!(
+            "Security: {} bits (conjectured), {} bits (proven)",
+            report.conjectured_security(),
+            report.proven_security()
+        );
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn print_options(report: &ProofReport) {
+    println!(
+        "Proof options:
+├── Hash function: {:?}
+├── Queries: {} ({} unique)
+├── Blowup factor: {}
+├── Grinding factor: {} bits
+├── Field extension: {:?} (degree {})
+├── FRI folding factor: {}
+└── FRI remainder max degree: {}",
+        report.hash_fn(),
+        report.num_queries(),
+        report.num_unique_queries(),
+        report.blowup_factor(),
+        report.grinding_factor(),
+        report.field_extension(),
+        report.field_extension().degree(),
+        report.fri_folding_factor(),
+        report.fri_remainder_max_degree(),
+    );
+}
+
+fn print_trace(report: &ProofReport) {
+    println!(
+        "Trace:
+├── Length: {}
+├── Main trace columns: {}
+├── Auxiliary trace columns: {:?}
+└── LDE domain size: {}",
+        report.trace_length(),
+        report.main_trace_width(),
+        report.aux_segment_widths(),
+        report.lde_domain_size(),
+    );
+}
+
+fn print_commitments(report: &ProofReport) {
+    println!("Commitments:");
+    for (i, root) in report.trace_roots().iter().enumerate() {
+        println!("├── Trace segment {}: {}", i, hex::encode(root));
+    }
+    println!("├── Constraint evaluations: {}", hex::encode(report.constraint_root()));
+    for (i, root) in report.fri_layer_roots().iter().enumerate() {
+        println!("├── FRI layer {}: {}", i, hex::encode(root));
+    }
+    println!(
+        "└── FRI remainder ({} elements): {}",
+        report.num_fri_remainder_elements(),
+        hex::encode(report.fri_remainder_root())
+    );
+}
+
+fn print_sizes(report: &ProofReport) {
+    let sizes = report.sizes();
+    println!("Proof size: {} bytes", sizes.total());
+    println!("├── Context: {} bytes", sizes.context());
+    println!("├── Commitments: {} bytes", sizes.commitments());
+    for (i, size) in sizes.trace_queries().iter().enumerate() {
+        println!("├── Trace segment {} queries: {} bytes", i, size);
+    }
+    println!("├── Constraint queries: {} bytes", sizes.constraint_queries());
+    println!("├── Out-of-domain frame: {} bytes", sizes.ood_frame());
+    println!(
+        "└── FRI proof ({} layers): {} bytes",
+        report.num_fri_layers(),
+        sizes.fri_proof()
+    );
+}
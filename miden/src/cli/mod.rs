@@ -2,16 +2,25 @@ mod bundle;
 mod compile;
 mod data;
 mod debug;
+mod estimate;
+mod input_file;
+mod inspect_proof;
+mod pack_advice;
 mod prove;
 mod repl;
 mod run;
+mod serve;
 mod verify;
 
 pub use bundle::BundleCmd;
 pub use compile::CompileCmd;
-pub use data::InputFile;
 pub use debug::DebugCmd;
+pub use estimate::EstimateCmd;
+pub use input_file::{InputFile, InputValue};
+pub use inspect_proof::InspectProofCmd;
+pub use pack_advice::PackAdviceCmd;
 pub use prove::ProveCmd;
 pub use repl::ReplCmd;
 pub use run::RunCmd;
+pub use serve::ServeCmd;
 pub use verify::VerifyCmd;
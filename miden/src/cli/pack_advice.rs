@@ -0,0 +1,51 @@
+use clap::Parser;
+use miden_vm::AdviceDataFile;
+use std::path::PathBuf;
+
+use super::InputFile;
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Pack the advice map and Merkle store of an input file into an advice data file")]
+pub struct PackAdviceCmd {
+    /// Path to input file
+    #[clap(short = 'i', long = "input", value_parser)]
+    input_file: PathBuf,
+    /// Path to output file; defaults to the path of the input file with `.madf` extension
+    #[clap(short = 'o', long = "output", value_parser)]
+    output_file: Option<PathBuf>,
+}
+
+impl PackAdviceCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+        println!("Pack advice data");
+        println!("============================================================");
+
+        // load the advice inputs from the input file
+        let input_data = InputFile::read(&Some(self.input_file.clone()), &self.input_file)?;
+        let advice_inputs = input_data.parse_advice_inputs()?;
+
+        // write the advice map and Merkle store into the advice data file; the advice stack is
+        // not included since it is consumed in full by every program
+        let out_path = self
+            .output_file
+            .clone()
+            .unwrap_or_else(|| self.input_file.with_extension(AdviceDataFile::FILE_EXTENSION));
+        AdviceDataFile::write(&out_path, advice_inputs).map_err(|err| {
+            format!("Failed to write advice data file `{}` - {}", out_path.display(), err)
+        })?;
+
+        // report the contents of the file to the user
+        let data = AdviceDataFile::open(&out_path).map_err(|err| {
+            format!("Failed to open advice data file `{}` - {}", out_path.display(), err)
+        })?;
+        println!(
+            "Packed {} advice map entries and {} Merkle store nodes into {}",
+            data.num_map_entries(),
+            data.num_nodes(),
+            out_path.display()
+        );
+
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
-use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofFile};
+use super::{
+    data::{instrument, Debug, Libraries, OutputFile, ProgramFile, ProofFile},
+    InputFile,
+};
 use clap::Parser;
-use miden_vm::ProvingOptions;
-use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program};
+use miden_vm::{ExecutionProof, ProvingOptions, StackOutputs, TraceStorage};
+use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Host, Program, StackInputs};
 
 use std::{path::PathBuf, time::Instant};
 
@@ -51,6 +54,16 @@ pub struct ProveCmd {
     /// Enable tracing to monitor execution of the VM
     #[clap(short = 't', long = "tracing")]
     tracing: bool,
+
+    /// Path to a directory in which the low-degree extension of the execution trace is stored
+    /// during proving, instead of keeping it in memory
+    #[clap(long = "trace-dir", value_parser)]
+    trace_dir: Option<PathBuf>,
+
+    /// Path to an advice data file from which advice map entries and Merkle store nodes are read
+    /// on demand
+    #[clap(long = "advice-data", value_parser)]
+    advice_data: Option<PathBuf>,
 }
 
 impl ProveCmd {
@@ -78,14 +91,18 @@ impl ProveCmd {
 
         // fetch the stack and program inputs from the arguments
         let stack_inputs = input_data.parse_stack_inputs()?;
-        let host = DefaultHost::new(input_data.parse_advice_provider()?);
-
-        let proving_options = self.get_proof_options().map_err(|err| format!("{err}"))?;
 
         // execute program and generate proof
-        let (stack_outputs, proof) =
-            prover::prove(&program, stack_inputs, host, proving_options)
-                .map_err(|err| format!("Failed to prove program - {:?}", err))?;
+        let (stack_outputs, proof) = match &self.advice_data {
+            Some(advice_data) => {
+                let host = DefaultHost::new(input_data.parse_file_advice_provider(advice_data)?);
+                self.prove_program(&program, stack_inputs, host)?
+            }
+            None => {
+                let host = DefaultHost::new(input_data.parse_advice_provider()?);
+                self.prove_program(&program, stack_inputs, host)?
+            }
+        };
 
         println!(
             "Program with hash {} proved in {} ms",
@@ -115,6 +132,30 @@ impl ProveCmd {
     }
 }
 
+impl ProveCmd {
+    /// Executes and proves the program with the specified inputs, storing the low-degree extension
+    /// of the execution trace on disk if a trace directory was specified.
+    fn prove_program<H: Host>(
+        &self,
+        program: &Program,
+        stack_inputs: StackInputs,
+        host: H,
+    ) -> Result<(StackOutputs, ExecutionProof), String> {
+        let proving_options = self.get_proof_options().map_err(|err| format!("{err}"))?;
+
+        match &self.trace_dir {
+            Some(trace_dir) => {
+                let storage = TraceStorage::new(trace_dir).map_err(|err| {
+                    format!("Failed to create trace directory `{}` - {}", trace_dir.display(), err)
+                })?;
+                prover::prove_with_storage(program, stack_inputs, host, proving_options, storage)
+            }
+            None => prover::prove(program, stack_inputs, host, proving_options),
+        }
+        .map_err(|err| format!("Failed to prove program - {:?}", err))
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
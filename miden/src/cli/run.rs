@@ -1,4 +1,7 @@
-use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile};
+use super::{
+    data::{instrument, Debug, Libraries, OutputFile, ProgramFile},
+    InputFile,
+};
 use clap::Parser;
 use processor::{DefaultHost, ExecutionOptions, ExecutionTrace};
 use std::{path::PathBuf, time::Instant};
@@ -37,6 +40,16 @@ pub struct RunCmd {
     /// Enable tracing to monitor execution of the VM
     #[clap(short = 't', long = "tracing")]
     tracing: bool,
+
+    /// Path to an advice data file from which advice map entries and Merkle store nodes are read
+    /// on demand
+    #[clap(long = "advice-data", value_parser)]
+    advice_data: Option<PathBuf>,
+
+    /// Path to an input file into which the witness of the execution is written, i.e., the
+    /// operand stack together with the advice inputs which were accessed by the program
+    #[clap(long = "record-witness", value_parser, conflicts_with = "advice_data")]
+    record_witness: Option<PathBuf>,
 }
 
 impl RunCmd {
@@ -116,13 +129,33 @@ fn run_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), String> {
 
     // fetch the stack and program inputs from the arguments
     let stack_inputs = input_data.parse_stack_inputs()?;
-    let host = DefaultHost::new(input_data.parse_advice_provider()?);
 
     let program_hash: [u8; 32] = program.hash().into();
 
     // execute program and generate outputs
-    let trace = processor::execute(&program, stack_inputs, host, execution_options)
-        .map_err(|err| format!("Failed to generate execution trace = {:?}", err))?;
+    let trace = match (&params.advice_data, &params.record_witness) {
+        (Some(advice_data), _) => {
+            let host = DefaultHost::new(input_data.parse_file_advice_provider(advice_data)?);
+            processor::execute(&program, stack_inputs, host, execution_options)
+        }
+        (None, Some(witness_path)) => {
+            let advice_inputs = input_data.parse_advice_inputs()?;
+            let (trace, witness) =
+                processor::record_witness(&program, stack_inputs, advice_inputs, execution_options)
+                    .map_err(|err| format!("Failed to generate execution trace = {:?}", err))?;
+
+            // the witness is written together with the operand stack, so that the program can be
+            // re-executed using only the witness file
+            input_data.with_advice_inputs(&witness).write(witness_path)?;
+            println!("Recorded the witness of the execution in {}", witness_path.display());
+            Ok(trace)
+        }
+        (None, None) => {
+            let host = DefaultHost::new(input_data.parse_advice_provider()?);
+            processor::execute(&program, stack_inputs, host, execution_options)
+        }
+    }
+    .map_err(|err| format!("Failed to generate execution trace = {:?}", err))?;
 
     Ok((trace, program_hash))
 }
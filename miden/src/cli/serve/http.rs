@@ -0,0 +1,182 @@
+use std::io::{self, BufRead, Read, Write};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum length of the request line and of each header line.
+const MAX_LINE_LEN: u64 = 8 * 1024;
+
+/// Maximum number of headers in a request.
+const MAX_HEADERS: usize = 64;
+
+/// Maximum length of a request body; this leaves plenty of room for hex-encoded proofs.
+const MAX_BODY_LEN: usize = 16 * 1024 * 1024;
+
+// REQUEST
+// ================================================================================================
+
+/// An HTTP request; only the parts of the request which are used by the server are kept.
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    /// Reads a single HTTP/1.1 request from the provided reader.
+    ///
+    /// Only requests with a body of known length are supported (i.e., chunked transfer encoding
+    /// is not). If the request cannot be read, returns the response which should be sent back.
+    pub fn read<R: BufRead>(reader: &mut R) -> Result<Self, Response> {
+        let request_line = read_line(reader)?;
+        let mut parts = request_line.split_whitespace();
+        let (method, path) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(method), Some(path), Some(version)) if version.starts_with("HTTP/1.") => {
+                (method.to_string(), path.to_string())
+            }
+            _ => return Err(Response::error(400, "malformed request line")),
+        };
+
+        let mut content_type = None;
+        let mut content_len = None;
+        for _ in 0..=MAX_HEADERS {
+            let line = read_line(reader)?;
+            if line.is_empty() {
+                let body = read_body(reader, content_len.unwrap_or(0))?;
+                return Ok(Self {
+                    method,
+                    path,
+                    content_type,
+                    body,
+                });
+            }
+
+            let (name, value) =
+                line.split_once(':').ok_or_else(|| Response::error(400, "malformed header"))?;
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                let len = value
+                    .parse::<usize>()
+                    .map_err(|_| Response::error(400, "invalid content length"))?;
+                content_len = Some(len);
+            } else if name.eq_ignore_ascii_case("content-type") {
+                content_type = Some(value.to_ascii_lowercase());
+            } else if name.eq_ignore_ascii_case("transfer-encoding") {
+                return Err(Response::error(501, "transfer encodings are not supported"));
+            }
+        }
+
+        Err(Response::error(431, "too many headers"))
+    }
+
+    /// Returns true if the body of this request is declared to be JSON.
+    pub fn is_json(&self) -> bool {
+        self.content_type
+            .as_deref()
+            .and_then(|content_type| content_type.split(';').next())
+            .is_some_and(|mime_type| mime_type.trim() == "application/json")
+    }
+}
+
+// RESPONSE
+// ================================================================================================
+
+/// An HTTP response with a JSON body, or without a body.
+pub struct Response {
+    status: u16,
+    body: Option<String>,
+}
+
+impl Response {
+    /// Returns a response with status 200 and the specified JSON body.
+    pub fn json(body: String) -> Self {
+        Self {
+            status: 200,
+            body: Some(body),
+        }
+    }
+
+    /// Returns a response with status 204 and no body.
+    pub fn no_content() -> Self {
+        Self {
+            status: 204,
+            body: None,
+        }
+    }
+
+    /// Returns a response with the specified error status and a JSON body describing the error.
+    pub fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            body: Some(serde_json::json!({ "error": message }).to_string()),
+        }
+    }
+
+    /// Writes this response into the provided writer. The connection is always closed after the
+    /// response is sent.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "HTTP/1.1 {} {}\r\nConnection: close\r\n", self.status, self.reason())?;
+        if let Some(body) = &self.body {
+            write!(
+                writer,
+                "Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            )?;
+        } else {
+            write!(writer, "\r\n")?;
+        }
+        writer.flush()
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            204 => "No Content",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            415 => "Unsupported Media Type",
+            431 => "Request Header Fields Too Large",
+            501 => "Not Implemented",
+            503 => "Service Unavailable",
+            _ => "",
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads a single CRLF-terminated line, without the line terminator.
+fn read_line<R: BufRead>(reader: &mut R) -> Result<String, Response> {
+    let mut line = Vec::new();
+    reader
+        .take(MAX_LINE_LEN)
+        .read_until(b'\n', &mut line)
+        .map_err(|_| Response::error(400, "failed to read request"))?;
+    if line.is_empty() {
+        return Err(Response::error(400, "unexpected end of request"));
+    }
+    if line.pop() != Some(b'\n') {
+        return Err(Response::error(431, "request line or header is too long"));
+    }
+    if line.last() == Some(&b'\r') {
+        line.pop();
+    }
+    String::from_utf8(line).map_err(|_| Response::error(400, "request is not valid UTF-8"))
+}
+
+/// Reads a request body of the specified length.
+fn read_body<R: BufRead>(reader: &mut R, len: usize) -> Result<Vec<u8>, Response> {
+    if len > MAX_BODY_LEN {
+        return Err(Response::error(413, "request body is too large"));
+    }
+    let mut body = vec![0; len];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| Response::error(400, "failed to read request body"))?;
+    Ok(body)
+}
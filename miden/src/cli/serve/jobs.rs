@@ -0,0 +1,376 @@
+use miden_vm::{
+    AdviceInputs, DefaultHost, ExecutionProof, MemAdviceProvider, Program, ProvingOptions,
+    StackInputs, StackOutputs,
+};
+use processor::{
+    AdviceExtractor, AdviceInjector, ExecutionError, ExecutionOptions, Host, HostResponse,
+    ProcessState,
+};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex, MutexGuard,
+    },
+};
+use vm_core::DebugOptions;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of finished jobs kept by the queue; once this number is exceeded, the oldest
+/// finished jobs (together with their results) are dropped.
+const MAX_FINISHED_JOBS: usize = 1024;
+
+/// Error with which a job fails when it is interrupted by a cancellation.
+const JOB_CANCELLED: &str = "The job was cancelled";
+
+// JOB REQUEST
+// ================================================================================================
+
+/// Describes whether a job only executes a program or also proves the execution.
+#[derive(Debug, Clone)]
+pub enum JobKind {
+    Execute(ExecutionOptions),
+    Prove(ProvingOptions),
+}
+
+impl JobKind {
+    /// Returns the name of this job kind, which matches the RPC method used to submit the job.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Execute(_) => "execute",
+            Self::Prove(_) => "prove",
+        }
+    }
+}
+
+/// A program together with its inputs, waiting to be executed or proved.
+pub struct JobRequest {
+    pub kind: JobKind,
+    pub program: Program,
+    pub stack_inputs: StackInputs,
+    pub advice_inputs: AdviceInputs,
+}
+
+impl JobRequest {
+    /// Executes or proves the program of this request.
+    ///
+    /// Once the `cancelled` flag is set, the execution is interrupted at the next request the
+    /// program makes to the host, and a job which has already been executed is not proved.
+    fn run(self, cancelled: Arc<AtomicBool>) -> Result<JobOutput, String> {
+        let host = CancellableHost {
+            host: DefaultHost::new(MemAdviceProvider::from(self.advice_inputs)),
+            cancelled: cancelled.clone(),
+        };
+        match self.kind {
+            JobKind::Execute(options) => {
+                let trace = processor::execute(&self.program, self.stack_inputs, host, options)
+                    .map_err(|err| format!("Failed to execute program - {:?}", err))?;
+                Ok(JobOutput::Executed {
+                    stack_outputs: trace.stack_outputs().clone(),
+                    cycles: trace.trace_len_summary().trace_len(),
+                })
+            }
+            JobKind::Prove(options) => {
+                let trace = processor::execute(
+                    &self.program,
+                    self.stack_inputs.clone(),
+                    host,
+                    *options.execution_options(),
+                )
+                .map_err(|err| format!("Failed to execute program - {:?}", err))?;
+                if cancelled.load(Ordering::Relaxed) {
+                    return Err(JOB_CANCELLED.to_string());
+                }
+                let (stack_outputs, proof) = prover::prove_trace(trace, self.stack_inputs, options)
+                    .map_err(|err| format!("Failed to prove program - {:?}", err))?;
+                Ok(JobOutput::Proved {
+                    stack_outputs,
+                    proof,
+                })
+            }
+        }
+    }
+}
+
+// CANCELLABLE HOST
+// ================================================================================================
+
+/// A host which forwards all requests to the inner host until the job it runs is cancelled, and
+/// fails all requests afterwards.
+///
+/// The VM does not check for cancellation on its own, and thus, this is what interrupts the
+/// execution of a running job which gets cancelled.
+struct CancellableHost<H> {
+    host: H,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<H: Host> CancellableHost<H> {
+    fn check_cancelled(&self) -> Result<(), ExecutionError> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(ExecutionError::EventError(JOB_CANCELLED.to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl<H: Host> Host for CancellableHost<H> {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_cancelled()?;
+        self.host.get_advice(process, extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_cancelled()?;
+        self.host.set_advice(process, injector)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_cancelled()?;
+        self.host.on_event(process, event_id)
+    }
+
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_cancelled()?;
+        self.host.on_debug(process, options)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_cancelled()?;
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        self.host.on_assert_failed(process, err_code)
+    }
+}
+
+// JOB STATUS
+// ================================================================================================
+
+/// Results of a successfully completed job.
+pub enum JobOutput {
+    Executed {
+        stack_outputs: StackOutputs,
+        cycles: usize,
+    },
+    Proved {
+        stack_outputs: StackOutputs,
+        proof: ExecutionProof,
+    },
+}
+
+/// Status of a job as reported to clients.
+#[derive(Clone)]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Completed(Arc<JobOutput>),
+    Failed(String),
+    Cancelled,
+}
+
+impl JobStatus {
+    /// Returns the name of this status.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Completed(_) => "completed",
+            Self::Failed(_) => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// Information about a job submitted to the queue.
+#[derive(Clone)]
+pub struct JobInfo {
+    pub id: u64,
+    pub kind: &'static str,
+    pub program_hash: String,
+    pub status: JobStatus,
+}
+
+// JOB QUEUE
+// ================================================================================================
+
+/// Error returned when a job is submitted to a queue which already holds the maximum number of
+/// queued jobs.
+#[derive(Debug)]
+pub struct QueueFull;
+
+/// A queue of execution and proving jobs processed by a fixed number of workers.
+///
+/// Each worker is a thread running [JobQueue::run_worker], and thus the number of workers limits
+/// the number of jobs which are processed concurrently.
+pub struct JobQueue {
+    state: Mutex<QueueState>,
+    job_queued: Condvar,
+    max_queued: usize,
+}
+
+#[derive(Default)]
+struct QueueState {
+    next_id: u64,
+    jobs: BTreeMap<u64, JobInfo>,
+    queued: VecDeque<(u64, JobRequest)>,
+    running: BTreeMap<u64, Arc<AtomicBool>>,
+    finished: VecDeque<u64>,
+}
+
+impl QueueState {
+    /// Records that the specified job finished, dropping the oldest finished jobs if there are
+    /// too many of them.
+    fn finish(&mut self, id: u64) {
+        self.running.remove(&id);
+        self.finished.push_back(id);
+        while self.finished.len() > MAX_FINISHED_JOBS {
+            if let Some(id) = self.finished.pop_front() {
+                self.jobs.remove(&id);
+            }
+        }
+    }
+}
+
+impl JobQueue {
+    /// Returns a new queue which holds at most `max_queued` jobs waiting for a worker.
+    pub fn new(max_queued: usize) -> Self {
+        Self {
+            state: Mutex::new(QueueState::default()),
+            job_queued: Condvar::new(),
+            max_queued,
+        }
+    }
+
+    /// Adds the specified job to the queue and returns its id.
+    pub fn submit(&self, request: JobRequest) -> Result<u64, QueueFull> {
+        let mut state = self.lock();
+        if state.queued.len() >= self.max_queued {
+            return Err(QueueFull);
+        }
+
+        let id = state.next_id;
+        state.next_id += 1;
+        let program_hash: [u8; 32] = request.program.hash().into();
+        let info = JobInfo {
+            id,
+            kind: request.kind.name(),
+            program_hash: hex::encode(program_hash),
+            status: JobStatus::Queued,
+        };
+        state.jobs.insert(id, info);
+        state.queued.push_back((id, request));
+        self.job_queued.notify_one();
+
+        Ok(id)
+    }
+
+    /// Returns information about the specified job, or None if the queue does not know about the
+    /// job.
+    pub fn get(&self, id: u64) -> Option<JobInfo> {
+        self.lock().jobs.get(&id).cloned()
+    }
+
+    /// Cancels the specified job and returns information about it, or None if the queue does not
+    /// know about the job.
+    ///
+    /// A queued job is removed from the queue. A running job is marked as cancelled right away and
+    /// is signaled to stop: its execution is interrupted at the next request the program makes to
+    /// the host, and it is not proved once executed. Whatever the job produces after the
+    /// cancellation is discarded. Cancelling a finished job has no effect.
+    pub fn cancel(&self, id: u64) -> Option<JobInfo> {
+        let mut state = self.lock();
+        let status = state.jobs.get(&id)?.status.clone();
+        match status {
+            JobStatus::Queued => {
+                state.queued.retain(|(queued_id, _)| *queued_id != id);
+                state.finish(id);
+            }
+            JobStatus::Running => {
+                if let Some(cancelled) = state.running.get(&id) {
+                    cancelled.store(true, Ordering::Relaxed);
+                }
+            }
+            _ => return state.jobs.get(&id).cloned(),
+        }
+
+        let info = state.jobs.get_mut(&id)?;
+        info.status = JobStatus::Cancelled;
+        Some(info.clone())
+    }
+
+    /// Processes jobs from the queue one by one; this function never returns.
+    pub fn run_worker(&self) {
+        loop {
+            let (id, request, cancelled) = {
+                let mut state = self.lock();
+                let (id, request) = loop {
+                    match state.queued.pop_front() {
+                        Some(job) => break job,
+                        None => {
+                            state = self.job_queued.wait(state).unwrap_or_else(|e| e.into_inner())
+                        }
+                    }
+                };
+                if let Some(info) = state.jobs.get_mut(&id) {
+                    info.status = JobStatus::Running;
+                }
+                let cancelled = Arc::new(AtomicBool::new(false));
+                state.running.insert(id, cancelled.clone());
+                (id, request, cancelled)
+            };
+
+            // a panic while processing a job should not bring down the worker
+            let status = match panic::catch_unwind(AssertUnwindSafe(|| request.run(cancelled))) {
+                Ok(Ok(output)) => JobStatus::Completed(Arc::new(output)),
+                Ok(Err(err)) => JobStatus::Failed(err),
+                Err(_) => JobStatus::Failed("The job panicked".to_string()),
+            };
+            self.finish(id, status);
+        }
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the final status of the specified job, unless the job was cancelled while running.
+    fn finish(&self, id: u64, status: JobStatus) {
+        let mut state = self.lock();
+        if let Some(info) = state.jobs.get_mut(&id) {
+            if !matches!(info.status, JobStatus::Cancelled) {
+                info.status = status;
+            }
+            state.finish(id);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        // the state is never left inconsistent, so it is safe to use even if a thread panicked
+        // while holding the lock
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,166 @@
+use super::data::Libraries;
+use clap::Parser;
+use std::{
+    io::BufReader,
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+mod http;
+use http::{Request, Response};
+
+mod jobs;
+use jobs::JobQueue;
+
+mod rpc;
+use rpc::RpcServer;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum time the server waits for a client to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+// SERVE COMMAND
+// ================================================================================================
+
+#[derive(Debug, Clone, Parser)]
+#[clap(
+    about = "Serve a local JSON-RPC API for compiling, executing, proving, and verifying programs"
+)]
+pub struct ServeCmd {
+    /// Port on which the server listens on the loopback interface; 0 selects any free port
+    #[clap(short = 'p', long = "port", default_value = "8080")]
+    port: u16,
+
+    /// Maximum number of jobs executed or proved concurrently
+    #[clap(short = 'w', long = "workers", default_value = "1")]
+    workers: usize,
+
+    /// Maximum number of jobs waiting to be executed or proved
+    #[clap(short = 'q', long = "max-queued", default_value = "64")]
+    max_queued: usize,
+
+    /// Maximum number of connections handled concurrently
+    #[clap(short = 'c', long = "max-connections", default_value = "64")]
+    max_connections: usize,
+
+    /// Maximum number of cycles any job is allowed to consume
+    #[clap(short = 'm', long = "max-cycles", default_value = "4294967295")]
+    max_cycles: u32,
+
+    /// Paths to .masl library files which programs can use
+    #[clap(short = 'l', long = "libraries", value_parser)]
+    library_paths: Vec<PathBuf>,
+}
+
+impl ServeCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        if self.workers == 0 {
+            return Err("The number of workers must be greater than zero".to_string());
+        }
+        if self.max_connections == 0 {
+            return Err("The number of connections must be greater than zero".to_string());
+        }
+
+        // load libraries from files
+        let libraries = Libraries::new(&self.library_paths)?;
+
+        // start the workers processing the jobs
+        let queue = Arc::new(JobQueue::new(self.max_queued));
+        for i in 0..self.workers {
+            let queue = queue.clone();
+            thread::Builder::new()
+                .name(format!("worker-{i}"))
+                .spawn(move || queue.run_worker())
+                .map_err(|err| format!("Failed to start worker - {}", err))?;
+        }
+        let server = Arc::new(RpcServer::new(queue, libraries.libraries, self.max_cycles));
+
+        // the server only listens on the loopback interface, so it is not reachable from other
+        // machines
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, self.port))
+            .map_err(|err| format!("Failed to listen on port {} - {}", self.port, err))?;
+        let addr = listener.local_addr().map_err(|err| err.to_string())?;
+        println!("Listening on http://{addr}");
+
+        // each connection is handled by its own thread; connections beyond the limit are rejected
+        // right away, so that clients cannot make the server spawn an unbounded number of threads
+        let connections = Arc::new(AtomicUsize::new(0));
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else { continue };
+            let Some(guard) = ConnectionGuard::acquire(&connections, self.max_connections) else {
+                let _ = Response::error(503, "too many connections").write(&mut &stream);
+                continue;
+            };
+            let server = server.clone();
+            let _ = thread::Builder::new().spawn(move || {
+                handle_connection(&server, stream);
+                drop(guard);
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// CONNECTION GUARD
+// ================================================================================================
+
+/// Counts a connection towards the limit of concurrently handled connections for as long as the
+/// guard is alive.
+struct ConnectionGuard(Arc<AtomicUsize>);
+
+impl ConnectionGuard {
+    /// Returns a guard for a new connection, or None if `max_connections` connections are already
+    /// being handled.
+    fn acquire(connections: &Arc<AtomicUsize>, max_connections: usize) -> Option<Self> {
+        // connections are only acquired by the thread accepting them, so the count cannot grow
+        // between the check and the increment
+        if connections.load(Ordering::Acquire) >= max_connections {
+            return None;
+        }
+        connections.fetch_add(1, Ordering::AcqRel);
+        Some(Self(connections.clone()))
+    }
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads a single request from the provided connection and sends back the response.
+///
+/// JSON-RPC requests are accepted only as POST requests with a JSON content type; in particular,
+/// this prevents web pages from sending requests to the server without the consent of the server.
+fn handle_connection(server: &RpcServer, stream: TcpStream) {
+    if stream.set_read_timeout(Some(READ_TIMEOUT)).is_err() {
+        return;
+    }
+    let mut reader = BufReader::new(&stream);
+    let response = match Request::read(&mut reader) {
+        Ok(request) if request.path != "/" => Response::error(404, "not found"),
+        Ok(request) if request.method != "POST" => Response::error(405, "method not allowed"),
+        Ok(request) if !request.is_json() => {
+            Response::error(415, "expected content type application/json")
+        }
+        Ok(request) => match server.handle(&request.body) {
+            Some(body) => Response::json(body.to_string()),
+            None => Response::no_content(),
+        },
+        Err(response) => response,
+    };
+    // there is nothing to do if the client went away
+    let _ = response.write(&mut &stream);
+}
@@ -0,0 +1,400 @@
+use super::jobs::{JobInfo, JobKind, JobOutput, JobQueue, JobRequest, JobStatus};
+use crate::cli::{
+    data::{OutputFile, ProgramHash},
+    InputFile,
+};
+use assembly::MaslLibrary;
+use miden_vm::{Assembler, ExecutionProof, Kernel, Program, ProgramInfo, ProvingOptions};
+use processor::ExecutionOptions;
+use serde::de::DeserializeOwned;
+use serde_derive::Deserialize;
+use serde_json::{json, Map, Value};
+use std::{path::Path, sync::Arc};
+use stdlib::StdLibrary;
+
+// ERROR CODES
+// ================================================================================================
+
+/// Error codes defined by the JSON-RPC 2.0 specification.
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// Error codes specific to this server.
+const COMPILATION_FAILED: i64 = -32000;
+const JOB_NOT_FOUND: i64 = -32001;
+const QUEUE_FULL: i64 = -32002;
+const VERIFICATION_FAILED: i64 = -32003;
+
+/// Error returned from an RPC method.
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(INVALID_PARAMS, message)
+    }
+}
+
+// RPC SERVER
+// ================================================================================================
+
+/// Handles JSON-RPC 2.0 requests for compiling, executing, proving, and verifying programs.
+///
+/// Compilation and verification are performed while handling the request, while execution and
+/// proving are submitted as jobs to the job queue; the results of these jobs are retrieved by
+/// their ids.
+pub struct RpcServer {
+    queue: Arc<JobQueue>,
+    libraries: Vec<MaslLibrary>,
+    max_cycles: u32,
+}
+
+impl RpcServer {
+    /// Returns a new server which submits jobs into the specified queue. Programs can use the
+    /// standard library and the specified libraries, and no job is allowed to run for more than
+    /// `max_cycles` cycles.
+    pub fn new(queue: Arc<JobQueue>, libraries: Vec<MaslLibrary>, max_cycles: u32) -> Self {
+        Self {
+            queue,
+            libraries,
+            max_cycles,
+        }
+    }
+
+    /// Handles the provided JSON-RPC request body, which contains either a single request or a
+    /// batch of requests, and returns the response body. Returns None if no response should be
+    /// sent, i.e., if all requests are notifications.
+    pub fn handle(&self, body: &[u8]) -> Option<Value> {
+        let request: Value = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(err) => return Some(error_response(Value::Null, PARSE_ERROR, err.to_string())),
+        };
+
+        match request {
+            Value::Array(batch) if batch.is_empty() => {
+                Some(error_response(Value::Null, INVALID_REQUEST, "empty batch"))
+            }
+            Value::Array(batch) => {
+                let responses: Vec<Value> =
+                    batch.into_iter().filter_map(|request| self.handle_request(request)).collect();
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            request => self.handle_request(request),
+        }
+    }
+
+    /// Handles a single JSON-RPC request.
+    fn handle_request(&self, request: Value) -> Option<Value> {
+        let Value::Object(mut request) = request else {
+            return Some(error_response(Value::Null, INVALID_REQUEST, "expected a request object"));
+        };
+        // requests without an id are notifications, which must not be answered
+        let id = request.remove("id");
+        let response_id = id.clone().unwrap_or(Value::Null);
+        if !matches!(response_id, Value::Null | Value::Number(_) | Value::String(_)) {
+            return Some(error_response(Value::Null, INVALID_REQUEST, "invalid request id"));
+        }
+        if request.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+            return Some(error_response(response_id, INVALID_REQUEST, "expected JSON-RPC 2.0"));
+        }
+        let Some(Value::String(method)) = request.remove("method") else {
+            return Some(error_response(response_id, INVALID_REQUEST, "missing method"));
+        };
+        let params = match request.remove("params") {
+            None => Value::Object(Map::new()),
+            Some(params @ Value::Object(_)) => params,
+            Some(_) => {
+                let message = "params must be passed by name";
+                return Some(error_response(response_id, INVALID_PARAMS, message));
+            }
+        };
+
+        let result = match method.as_str() {
+            "compile" => parse_params(params).and_then(|params| self.compile(params)),
+            "execute" => parse_params(params).and_then(|params| self.execute(params)),
+            "prove" => parse_params(params).and_then(|params| self.prove(params)),
+            "get_job" => parse_params(params).and_then(|params| self.get_job(params)),
+            "cancel_job" => parse_params(params).and_then(|params| self.cancel_job(params)),
+            "verify" => parse_params(params).and_then(verify),
+            _ => Err(RpcError::new(METHOD_NOT_FOUND, format!("unknown method `{method}`"))),
+        };
+
+        id.map(|id| match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(err) => error_response(id, err.code, err.message),
+        })
+    }
+
+    // METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles a program and returns its hash.
+    fn compile(&self, params: CompileParams) -> Result<Value, RpcError> {
+        let program = self.compile_program(&params.source)?;
+        Ok(json!({ "program_hash": program_hash(&program) }))
+    }
+
+    /// Submits a job which executes a program, and returns the id of the job.
+    fn execute(&self, params: ExecuteParams) -> Result<Value, RpcError> {
+        let options = self.execution_options(&params.options)?;
+        self.submit(&params.source, params.inputs, JobKind::Execute(options))
+    }
+
+    /// Submits a job which executes and proves a program, and returns the id of the job.
+    fn prove(&self, params: ProveParams) -> Result<Value, RpcError> {
+        let execution_options = self.execution_options(&params.options)?;
+        let proving_options = match params.security.as_str() {
+            "96bits" => ProvingOptions::with_96_bit_security(params.recursive),
+            "128bits" => ProvingOptions::with_128_bit_security(params.recursive),
+            other => {
+                return Err(RpcError::invalid_params(format!(
+                    "{other} is not a valid security setting; expected 96bits or 128bits"
+                )))
+            }
+        };
+        let kind = JobKind::Prove(proving_options.with_execution_options(execution_options));
+        self.submit(&params.source, params.inputs, kind)
+    }
+
+    /// Returns the status of a job, together with its results if the job completed.
+    fn get_job(&self, params: JobParams) -> Result<Value, RpcError> {
+        let info = self.queue.get(params.id).ok_or_else(|| job_not_found(params.id))?;
+        Ok(job_to_json(&info))
+    }
+
+    /// Cancels a job and returns its status.
+    fn cancel_job(&self, params: JobParams) -> Result<Value, RpcError> {
+        let info = self.queue.cancel(params.id).ok_or_else(|| job_not_found(params.id))?;
+        Ok(json!({ "id": info.id, "status": info.status.name() }))
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles the provided source code with the standard library and the libraries of this
+    /// server.
+    fn compile_program(&self, source: &str) -> Result<Program, RpcError> {
+        Assembler::default()
+            .with_library(&StdLibrary::default())
+            .and_then(|assembler| assembler.with_libraries(self.libraries.iter()))
+            .map_err(|err| {
+                RpcError::new(COMPILATION_FAILED, format!("Failed to load libraries - {err}"))
+            })?
+            .compile(source)
+            .map_err(|err| {
+                RpcError::new(COMPILATION_FAILED, format!("Failed to compile program - {err}"))
+            })
+    }
+
+    /// Builds execution options from the provided job options, making sure that the job does not
+    /// exceed the cycle limit of this server.
+    fn execution_options(&self, options: &JobOptions) -> Result<ExecutionOptions, RpcError> {
+        let max_cycles = options.max_cycles.unwrap_or(self.max_cycles);
+        if max_cycles > self.max_cycles {
+            return Err(RpcError::invalid_params(format!(
+                "max_cycles must not exceed the limit of the server ({})",
+                self.max_cycles
+            )));
+        }
+        ExecutionOptions::new(Some(max_cycles), options.expected_cycles, false)
+            .map_err(|err| RpcError::invalid_params(err.to_string()))
+    }
+
+    /// Compiles the program, parses its inputs, and submits a job of the specified kind.
+    fn submit(
+        &self,
+        source: &str,
+        inputs: Option<Value>,
+        kind: JobKind,
+    ) -> Result<Value, RpcError> {
+        let program = self.compile_program(source)?;
+        let inputs = parse_inputs(inputs)?;
+        let request = JobRequest {
+            kind,
+            program,
+            stack_inputs: inputs.parse_stack_inputs().map_err(RpcError::invalid_params)?,
+            advice_inputs: inputs.parse_advice_inputs().map_err(RpcError::invalid_params)?,
+        };
+        let id = self
+            .queue
+            .submit(request)
+            .map_err(|_| RpcError::new(QUEUE_FULL, "the job queue is full"))?;
+        Ok(json!({ "id": id }))
+    }
+}
+
+// PARAMETERS
+// ================================================================================================
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CompileParams {
+    source: String,
+}
+
+/// Limits of the execution of a single job.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JobOptions {
+    max_cycles: Option<u32>,
+    #[serde(default = "default_expected_cycles")]
+    expected_cycles: u32,
+}
+
+impl Default for JobOptions {
+    fn default() -> Self {
+        Self {
+            max_cycles: None,
+            expected_cycles: default_expected_cycles(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ExecuteParams {
+    source: String,
+    inputs: Option<Value>,
+    #[serde(default)]
+    options: JobOptions,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProveParams {
+    source: String,
+    inputs: Option<Value>,
+    #[serde(default)]
+    options: JobOptions,
+    #[serde(default = "default_security")]
+    security: String,
+    #[serde(default)]
+    recursive: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JobParams {
+    id: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct VerifyParams {
+    program_hash: String,
+    inputs: Option<Value>,
+    stack_outputs: OutputFile,
+    proof: String,
+}
+
+fn default_expected_cycles() -> u32 {
+    64
+}
+
+fn default_security() -> String {
+    "96bits".to_string()
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Verifies a proof of execution of a program compiled without a kernel, and returns the security
+/// level of the proof.
+fn verify(params: VerifyParams) -> Result<Value, RpcError> {
+    let program_hash = ProgramHash::read(&params.program_hash).map_err(RpcError::invalid_params)?;
+    let stack_inputs = parse_inputs(params.inputs)?
+        .parse_stack_inputs()
+        .map_err(RpcError::invalid_params)?;
+    let stack_outputs = params.stack_outputs.stack_outputs().map_err(RpcError::invalid_params)?;
+    let proof = hex::decode(&params.proof)
+        .map_err(|err| err.to_string())
+        .and_then(|bytes| ExecutionProof::from_bytes(&bytes).map_err(|err| err.to_string()))
+        .map_err(|err| RpcError::invalid_params(format!("Failed to decode proof - {err}")))?;
+
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+    let security_level = verifier::verify(program_info, stack_inputs, stack_outputs, proof)
+        .map_err(|err| {
+            RpcError::new(VERIFICATION_FAILED, format!("Program failed verification! - {err}"))
+        })?;
+    Ok(json!({ "security_level": security_level }))
+}
+
+/// Deserializes method parameters from the provided JSON object.
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|err| RpcError::invalid_params(err.to_string()))
+}
+
+/// Parses program inputs written in the input file format. Merkle data files referenced by the
+/// inputs are resolved relative to the working directory of the server.
+fn parse_inputs(inputs: Option<Value>) -> Result<InputFile, RpcError> {
+    match inputs {
+        Some(inputs) => {
+            InputFile::from_json(&inputs, Path::new("")).map_err(RpcError::invalid_params)
+        }
+        None => Ok(InputFile::default()),
+    }
+}
+
+/// Returns the hex-encoded hash of the provided program.
+fn program_hash(program: &Program) -> String {
+    let program_hash: [u8; 32] = program.hash().into();
+    hex::encode(program_hash)
+}
+
+/// Converts information about a job into JSON, including the results of a completed job.
+fn job_to_json(info: &JobInfo) -> Value {
+    let mut job = json!({
+        "id": info.id,
+        "kind": info.kind,
+        "program_hash": info.program_hash,
+        "status": info.status.name(),
+    });
+    match &info.status {
+        JobStatus::Completed(output) => {
+            job["result"] = match output.as_ref() {
+                JobOutput::Executed {
+                    stack_outputs,
+                    cycles,
+                } => json!({
+                    "stack_outputs": OutputFile::new(stack_outputs),
+                    "cycles": cycles,
+                }),
+                JobOutput::Proved {
+                    stack_outputs,
+                    proof,
+                } => json!({
+                    "stack_outputs": OutputFile::new(stack_outputs),
+                    "proof": hex::encode(proof.to_bytes()),
+                    "security_level": proof.security_level(),
+                }),
+            }
+        }
+        JobStatus::Failed(err) => job["error"] = Value::String(err.clone()),
+        _ => (),
+    }
+    job
+}
+
+fn job_not_found(id: u64) -> RpcError {
+    RpcError::new(JOB_NOT_FOUND, format!("job {id} not found"))
+}
+
+/// Returns a JSON-RPC error response.
+fn error_response(id: Value, code: i64, message: impl Into<String>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message.into() },
+    })
+}
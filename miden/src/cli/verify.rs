@@ -1,4 +1,7 @@
-use super::data::{InputFile, OutputFile, ProgramHash, ProofFile};
+use super::{
+    data::{OutputFile, ProgramHash, ProofFile},
+    InputFile,
+};
 use clap::Parser;
 use miden_vm::{Kernel, ProgramInfo};
 use std::{path::PathBuf, time::Instant};
@@ -1,20 +1,32 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 
+#[macro_use]
+extern crate alloc;
+
 // EXPORTS
 // ================================================================================================
 
 pub use assembly::{
     ast::{ModuleAst, ProgramAst},
-    Assembler, AssemblyError, ParsingError,
+    Assembler, AssemblyError, MemoryMode, ParsingError,
 };
 pub use processor::{
-    crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, DefaultHost,
-    ExecutionError, ExecutionTrace, Host, Kernel, MemAdviceProvider, Operation, Program,
-    ProgramInfo, StackInputs, VmState, VmStateIterator, ZERO,
+    crypto, execute, execute_iter, record_witness, utils, AdviceInputs, AdviceProvider, AsmOpInfo,
+    DefaultHost, ExecutionError, ExecutionSession, ExecutionTrace, Host, Kernel, MemAdviceProvider,
+    MemoryAccess, MemoryAccessKind, MemoryAccessReport, MemoryConventionViolation, MemoryFootprint,
+    Operation, Program, ProgramInfo, RecAdviceProvider, StackInputs, VmState, VmStateIterator,
+    ZERO,
 };
+#[cfg(feature = "std")]
+pub use processor::{AdviceDataFile, FileAdviceProvider};
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, ProvingOptions,
-    StackOutputs, StarkProof, Word,
+    estimate, math, prove, prove_trace, Digest, ExecutionProof, FieldExtension, HashFunction,
+    InputError, ProofEstimate, ProofReport, ProofSizes, ProvingOptions, StackOutputs, StarkProof,
+    Word,
 };
+#[cfg(feature = "std")]
+pub use prover::{prove_with_storage, TraceStorage};
 pub use verifier::{verify, VerificationError};
+
+pub mod recursion;
@@ -27,9 +27,13 @@ pub enum Actions {
     Compile(cli::CompileCmd),
     Bundle(cli::BundleCmd),
     Debug(cli::DebugCmd),
+    Estimate(cli::EstimateCmd),
     Example(examples::ExampleOptions),
+    InspectProof(cli::InspectProofCmd),
+    PackAdvice(cli::PackAdviceCmd),
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
+    Serve(cli::ServeCmd),
     Verify(cli::VerifyCmd),
     #[cfg(feature = "std")]
     Repl(cli::ReplCmd),
@@ -43,9 +47,13 @@ impl Cli {
             Actions::Compile(compile) => compile.execute(),
             Actions::Bundle(compile) => compile.execute(),
             Actions::Debug(debug) => debug.execute(),
+            Actions::Estimate(estimate) => estimate.execute(),
             Actions::Example(example) => example.execute(),
+            Actions::InspectProof(inspect_proof) => inspect_proof.execute(),
+            Actions::PackAdvice(pack_advice) => pack_advice.execute(),
             Actions::Prove(prove) => prove.execute(),
             Actions::Run(run) => run.execute(),
+            Actions::Serve(serve) => serve.execute(),
             Actions::Verify(verify) => verify.execute(),
             #[cfg(feature = "std")]
             Actions::Repl(repl) => repl.execute(),
@@ -0,0 +1,231 @@
+use super::{channel::VerifierChannel, QuadFelt, RecursionError};
+use crate::{ExecutionProof, HashFunction, ProgramInfo, StackInputs, StackOutputs};
+use air::{ProcessorAir, PublicInputs};
+use processor::{
+    crypto::{MerkleStore, RandomCoin, Rpo256, RpoRandomCoin},
+    AdviceInputs,
+};
+use verifier::VerifierError;
+use vm_core::{Felt, FieldElement, StarkField, ToElements};
+use winter_prover::{math::fft, proof::StarkProof, Air, AuxTraceRandElements, FieldExtension};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Blowup factor assumed by the recursive verifier.
+const BLOWUP_FACTOR: usize = 8;
+
+/// FRI folding factor assumed by the recursive verifier.
+const FRI_FOLDING_FACTOR: usize = 4;
+
+/// Maximum degree of the FRI remainder polynomial assumed by the recursive verifier.
+const FRI_REMAINDER_MAX_DEGREE: usize = 7;
+
+/// Minimum number of queries supported by the recursive verifier.
+const MIN_NUM_QUERIES: usize = 8;
+
+/// Number of stack inputs and outputs of a program whose execution can be verified recursively.
+const NUM_STACK_ITEMS: usize = 16;
+
+// ADVICE GENERATION
+// ================================================================================================
+
+/// Returns the stack and advice inputs required by `std::crypto::stark::verifier::verify` to
+/// verify the specified proof of execution of the program described by `program_info` against the
+/// specified stack inputs and outputs.
+///
+/// The returned stack inputs are `[log(trace_length), num_queries, log(blowup), grinding]`, with
+/// `log(trace_length)` at the top of the stack. The returned advice inputs contain the data sent
+/// by the prover in the order in which it is requested by the verifier, as well as the Merkle
+/// authentication paths for all queried values.
+///
+/// # Errors
+/// Returns an error if:
+/// - The proof was generated using options which are not supported by the recursive verifier,
+///   i.e., options other than the ones produced by [crate::ProvingOptions::with_96_bit_security]
+///   and similar recursive parameter sets (RPO hash function, quadratic field extension, blowup
+///   factor 8, FRI folding factor 4, and FRI remainder of degree at most 7).
+/// - The program was compiled with a kernel.
+/// - The number of stack inputs or stack outputs is not exactly 16.
+/// - The proof is malformed.
+pub fn generate_advice_inputs(
+    proof: ExecutionProof,
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+) -> Result<(StackInputs, AdviceInputs), RecursionError> {
+    if !program_info.kernel().is_empty() {
+        return Err(RecursionError::UnsupportedKernel);
+    }
+    if stack_inputs.values().len() != NUM_STACK_ITEMS {
+        return Err(RecursionError::InvalidNumStackInputs(stack_inputs.values().len()));
+    }
+    if stack_outputs.stack().len() != NUM_STACK_ITEMS {
+        return Err(RecursionError::InvalidNumStackOutputs(stack_outputs.stack().len()));
+    }
+
+    let (hash_fn, proof) = proof.into_parts();
+    if hash_fn != HashFunction::Rpo256 {
+        return Err(RecursionError::UnsupportedHashFunction(hash_fn));
+    }
+    check_proof_options(&proof)?;
+
+    let options = proof.options();
+    let verifier_inputs = [
+        options.grinding_factor() as u64,
+        options.blowup_factor().ilog2() as u64,
+        options.num_queries() as u64,
+        proof.get_trace_info().length().ilog2() as u64,
+    ];
+    let verifier_stack_inputs =
+        StackInputs::try_from_ints(verifier_inputs).expect("verifier inputs are valid");
+
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    let advice_inputs =
+        generate_verifier_advice(proof, pub_inputs).map_err(RecursionError::InvalidProof)?;
+
+    Ok((verifier_stack_inputs, advice_inputs))
+}
+
+/// Returns an error if the parameters of the specified proof are not supported by the recursive
+/// verifier.
+fn check_proof_options(proof: &StarkProof) -> Result<(), RecursionError> {
+    let options = proof.options();
+    if options.field_extension() != FieldExtension::Quadratic {
+        return Err(RecursionError::UnsupportedProofOptions(
+            "only the quadratic field extension is supported",
+        ));
+    }
+    if options.blowup_factor() != BLOWUP_FACTOR {
+        return Err(RecursionError::UnsupportedProofOptions("only blowup factor 8 is supported"));
+    }
+    if options.num_queries() < MIN_NUM_QUERIES {
+        return Err(RecursionError::UnsupportedProofOptions(
+            "the number of queries must be at least 8",
+        ));
+    }
+    if options.grinding_factor() >= u32::BITS {
+        return Err(RecursionError::UnsupportedProofOptions(
+            "the grinding factor must be smaller than 32",
+        ));
+    }
+    let fri_options = options.to_fri_options();
+    if fri_options.folding_factor() != FRI_FOLDING_FACTOR {
+        return Err(RecursionError::UnsupportedProofOptions(
+            "only FRI folding factor 4 is supported",
+        ));
+    }
+    if fri_options.remainder_max_degree() != FRI_REMAINDER_MAX_DEGREE {
+        return Err(RecursionError::UnsupportedProofOptions(
+            "only FRI remainder polynomials of degree at most 7 are supported",
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the advice inputs required by the recursive verifier to verify the specified STARK
+/// proof against the specified public inputs.
+///
+/// This mirrors the logic of the native STARK verifier: the public coin is replayed in order to
+/// derive the query positions needed to unbatch the Merkle proofs contained in the proof.
+fn generate_verifier_advice(
+    proof: StarkProof,
+    pub_inputs: PublicInputs,
+) -> Result<AdviceInputs, VerifierError> {
+    let blowup_factor = proof.options().blowup_factor();
+    let pub_inputs_elements = pub_inputs.to_elements();
+
+    let mut tape = pub_inputs_elements.clone();
+
+    // build a seed for the public coin; the initial seed is the hash of the proof context and the
+    // public inputs, but as the protocol progresses, the coin will be reseeded with the info
+    // received from the prover
+    let mut public_coin_seed = proof.context.to_elements();
+    public_coin_seed.extend_from_slice(&pub_inputs_elements);
+    let mut public_coin = RpoRandomCoin::new(Rpo256::hash_elements(&public_coin_seed).into());
+
+    let air = ProcessorAir::new(proof.get_trace_info(), pub_inputs, proof.options().clone());
+    let mut channel = VerifierChannel::new(&air, proof)?;
+
+    // 1 ----- trace commitments ------------------------------------------------------------------
+    let trace_commitments = channel.read_trace_commitments().to_vec();
+    public_coin.reseed(trace_commitments[0]);
+    let mut aux_trace_rand_elements = AuxTraceRandElements::<QuadFelt>::new();
+    for (i, commitment) in trace_commitments.iter().enumerate().skip(1) {
+        let rand_elements = air
+            .get_aux_trace_segment_random_elements(i - 1, &mut public_coin)
+            .map_err(|_| VerifierError::RandomCoinError)?;
+        aux_trace_rand_elements.add_segment_elements(rand_elements);
+        public_coin.reseed(*commitment);
+    }
+    tape.extend(trace_commitments.iter().flat_map(|digest| digest.as_elements().to_vec()));
+
+    air.get_constraint_composition_coefficients::<QuadFelt, RpoRandomCoin>(&mut public_coin)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+
+    // 2 ----- constraint commitment --------------------------------------------------------------
+    let constraint_commitment = channel.read_constraint_commitment();
+    public_coin.reseed(constraint_commitment);
+    tape.extend_from_slice(constraint_commitment.as_elements());
+
+    // 3 ----- out-of-domain evaluations ----------------------------------------------------------
+    let ood_trace_frame = channel.read_ood_trace_frame();
+    public_coin.reseed(Rpo256::hash_elements(ood_trace_frame));
+    tape.extend_from_slice(QuadFelt::slice_as_base_elements(ood_trace_frame));
+
+    let ood_constraint_evaluations = channel.read_ood_constraint_evaluations();
+    public_coin.reseed(Rpo256::hash_elements(ood_constraint_evaluations));
+    tape.extend_from_slice(QuadFelt::slice_as_base_elements(ood_constraint_evaluations));
+
+    // 4 ----- FRI commitments and remainder ------------------------------------------------------
+    air.get_deep_composition_coefficients::<QuadFelt, RpoRandomCoin>(&mut public_coin)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+
+    let fri_commitments = channel.read_fri_layer_commitments().to_vec();
+    for commitment in fri_commitments.iter() {
+        public_coin.reseed(*commitment);
+        let _alpha: QuadFelt = public_coin.draw().map_err(|_| VerifierError::RandomCoinError)?;
+    }
+    tape.extend(fri_commitments.iter().flat_map(|digest| digest.as_elements().to_vec()));
+
+    // the verifier expects both the coefficients of the remainder polynomial and its evaluations
+    // over the remainder domain
+    let remainder = channel.read_fri_remainder();
+    let twiddles = fft::get_twiddles(remainder.len());
+    let remainder_evaluations =
+        fft::evaluate_poly_with_offset(remainder, &twiddles, Felt::GENERATOR, blowup_factor);
+    tape.extend_from_slice(QuadFelt::slice_as_base_elements(remainder));
+    tape.extend_from_slice(QuadFelt::slice_as_base_elements(&remainder_evaluations));
+
+    // 5 ----- queries ----------------------------------------------------------------------------
+    let pow_nonce = channel.read_pow_nonce();
+    tape.push(Felt::new(pow_nonce));
+
+    let mut query_positions = public_coin
+        .draw_integers(air.options().num_queries(), air.lde_domain_size(), pow_nonce)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+    query_positions.sort_unstable();
+    query_positions.dedup();
+
+    let (mut partial_trees, mut advice_map) =
+        channel.read_queried_trace_states(&query_positions)?;
+    let (constraint_tree, mut constraint_advice_map) =
+        channel.read_constraint_evaluations(&query_positions)?;
+    let (fri_trees, mut fri_advice_map) =
+        channel.read_fri_layer_queries::<4>(&query_positions, air.lde_domain_size())?;
+
+    partial_trees.push(constraint_tree);
+    partial_trees.extend(fri_trees);
+    advice_map.append(&mut constraint_advice_map);
+    advice_map.append(&mut fri_advice_map);
+
+    let mut store = MerkleStore::new();
+    for tree in partial_trees.iter() {
+        store.extend(tree.inner_nodes());
+    }
+
+    Ok(AdviceInputs::default()
+        .with_stack(tape)
+        .with_map(advice_map)
+        .with_merkle_store(store))
+}
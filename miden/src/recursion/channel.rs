@@ -0,0 +1,381 @@
+use super::QuadFelt;
+use air::ProcessorAir;
+use alloc::{string::ToString, vec::Vec};
+use processor::crypto::{MerklePath, PartialMerkleTree, Rpo256, RpoDigest};
+use verifier::VerifierError;
+use vm_core::{utils::group_slice_elements, Felt, FieldElement, StarkField};
+use winter_fri::folding::fold_positions;
+use winter_prover::{
+    crypto::BatchMerkleProof,
+    proof::{Queries, StarkProof, Table},
+    Air,
+};
+
+/// Advice map entries mapping Merkle tree leaves to the values they commit to.
+type AdviceMapEntries = Vec<(RpoDigest, Vec<Felt>)>;
+
+// VERIFIER CHANNEL
+// ================================================================================================
+
+/// A view into a [StarkProof] structured to simulate an "interactive" channel between the
+/// recursive verifier and the prover.
+///
+/// The channel parses the proof into its components in the same way the native verifier does, and
+/// additionally converts batched Merkle proofs into the partial Merkle trees and advice map
+/// entries needed by the recursive verifier to authenticate queried values.
+pub struct VerifierChannel {
+    // trace queries
+    trace_roots: Vec<RpoDigest>,
+    trace_queries: Option<TraceQueries>,
+    // constraint queries
+    constraint_root: RpoDigest,
+    constraint_queries: Option<ConstraintQueries>,
+    // FRI proof
+    fri_roots: Vec<RpoDigest>,
+    fri_layer_proofs: Vec<BatchMerkleProof<Rpo256>>,
+    fri_layer_queries: Vec<Vec<QuadFelt>>,
+    fri_remainder: Vec<QuadFelt>,
+    // out-of-domain frame
+    ood_trace_frame: Vec<QuadFelt>,
+    ood_constraint_evaluations: Vec<QuadFelt>,
+    // query proof-of-work
+    pow_nonce: u64,
+}
+
+impl VerifierChannel {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates and returns a new [VerifierChannel] initialized from the specified `proof`.
+    pub fn new(air: &ProcessorAir, proof: StarkProof) -> Result<Self, VerifierError> {
+        let StarkProof {
+            context,
+            commitments,
+            trace_queries,
+            constraint_queries,
+            ood_frame,
+            fri_proof,
+            pow_nonce,
+            num_unique_queries,
+        } = proof;
+
+        // make sure AIR and proof base fields are the same
+        if Felt::get_modulus_le_bytes() != context.field_modulus_bytes() {
+            return Err(VerifierError::InconsistentBaseField);
+        }
+
+        let num_trace_segments = air.trace_layout().num_segments();
+        let main_trace_width = air.trace_layout().main_trace_width();
+        let aux_trace_width = air.trace_layout().aux_trace_width();
+        let lde_domain_size = air.lde_domain_size();
+        let fri_options = air.options().to_fri_options();
+        let constraint_frame_width = air.context().num_constraint_composition_columns();
+
+        // --- parse commitments ------------------------------------------------------------------
+        let (trace_roots, constraint_root, fri_roots) = commitments
+            .parse::<Rpo256>(num_trace_segments, fri_options.num_fri_layers(lde_domain_size))
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+        // --- parse trace and constraint queries -------------------------------------------------
+        let trace_queries = TraceQueries::new(trace_queries, air, num_unique_queries as usize)?;
+        let constraint_queries =
+            ConstraintQueries::new(constraint_queries, air, num_unique_queries as usize)?;
+
+        // --- parse FRI proofs -------------------------------------------------------------------
+        let fri_remainder = fri_proof
+            .parse_remainder()
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+        let (fri_layer_queries, fri_layer_proofs) = fri_proof
+            .parse_layers::<Rpo256, QuadFelt>(lde_domain_size, fri_options.folding_factor())
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+        // --- parse out-of-domain evaluation frame -----------------------------------------------
+        let (ood_trace_frame, ood_constraint_evaluations) = ood_frame
+            .parse(main_trace_width, aux_trace_width, constraint_frame_width)
+            .map_err(|err| VerifierError::ProofDeserializationError(err.to_string()))?;
+
+        Ok(VerifierChannel {
+            trace_roots,
+            trace_queries: Some(trace_queries),
+            constraint_root,
+            constraint_queries: Some(constraint_queries),
+            fri_roots,
+            fri_layer_proofs,
+            fri_layer_queries,
+            fri_remainder,
+            ood_trace_frame,
+            ood_constraint_evaluations,
+            pow_nonce,
+        })
+    }
+
+    // DATA READERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns execution trace commitments sent by the prover.
+    pub fn read_trace_commitments(&self) -> &[RpoDigest] {
+        &self.trace_roots
+    }
+
+    /// Returns constraint evaluation commitment sent by the prover.
+    pub fn read_constraint_commitment(&self) -> RpoDigest {
+        self.constraint_root
+    }
+
+    /// Returns trace polynomial evaluations at out-of-domain points z and z * g, with current and
+    /// next rows interleaved column by column.
+    pub fn read_ood_trace_frame(&self) -> &[QuadFelt] {
+        &self.ood_trace_frame
+    }
+
+    /// Returns evaluations of composition polynomial columns at the out-of-domain point z.
+    pub fn read_ood_constraint_evaluations(&self) -> &[QuadFelt] {
+        &self.ood_constraint_evaluations
+    }
+
+    /// Returns FRI layer commitments sent by the prover.
+    pub fn read_fri_layer_commitments(&self) -> &[RpoDigest] {
+        &self.fri_roots
+    }
+
+    /// Returns the coefficients of the FRI remainder polynomial.
+    pub fn read_fri_remainder(&self) -> &[QuadFelt] {
+        &self.fri_remainder
+    }
+
+    /// Returns query proof-of-work nonce sent by the prover.
+    pub fn read_pow_nonce(&self) -> u64 {
+        self.pow_nonce
+    }
+
+    /// Returns the partial Merkle trees and advice map entries needed to authenticate the queried
+    /// main and auxiliary trace rows at the specified positions of the LDE domain.
+    pub fn read_queried_trace_states(
+        &mut self,
+        positions: &[usize],
+    ) -> Result<(Vec<PartialMerkleTree>, AdviceMapEntries), VerifierError> {
+        let queries = self.trace_queries.take().expect("already read");
+        let mut proofs = queries.query_proofs.into_iter();
+
+        let main_rows = queries.main_states.rows().map(|row| row.to_vec()).collect();
+        let (main_tree, mut advice_map) = unbatch_to_partial_mt(
+            positions,
+            main_rows,
+            proofs.next().expect("missing main trace query proof"),
+        )?;
+
+        let aux_rows = queries
+            .aux_states
+            .rows()
+            .map(|row| QuadFelt::slice_as_base_elements(row).to_vec())
+            .collect();
+        let (aux_tree, mut aux_advice_map) = unbatch_to_partial_mt(
+            positions,
+            aux_rows,
+            proofs.next().expect("missing auxiliary trace query proof"),
+        )?;
+
+        advice_map.append(&mut aux_advice_map);
+        Ok((vec![main_tree, aux_tree], advice_map))
+    }
+
+    /// Returns the partial Merkle tree and advice map entries needed to authenticate the queried
+    /// constraint evaluations at the specified positions of the LDE domain.
+    pub fn read_constraint_evaluations(
+        &mut self,
+        positions: &[usize],
+    ) -> Result<(PartialMerkleTree, AdviceMapEntries), VerifierError> {
+        let queries = self.constraint_queries.take().expect("already read");
+        let rows = queries
+            .evaluations
+            .rows()
+            .map(|row| QuadFelt::slice_as_base_elements(row).to_vec())
+            .collect();
+
+        unbatch_to_partial_mt(positions, rows, queries.query_proofs)
+    }
+
+    /// Returns the partial Merkle trees and advice map entries needed to authenticate the queried
+    /// evaluations of all FRI layers.
+    ///
+    /// `positions` are the query positions in the LDE domain of size `domain_size`; the positions
+    /// are folded by `N` for each subsequent layer.
+    pub fn read_fri_layer_queries<const N: usize>(
+        &mut self,
+        positions: &[usize],
+        domain_size: usize,
+    ) -> Result<(Vec<PartialMerkleTree>, AdviceMapEntries), VerifierError> {
+        let mut current_domain_size = domain_size;
+        let mut positions = positions.to_vec();
+
+        let mut partial_trees = Vec::new();
+        let mut advice_map = Vec::new();
+        let layers = self.fri_layer_proofs.drain(..).zip(self.fri_layer_queries.drain(..));
+        for (layer_proof, layer_queries) in layers {
+            let folded_positions = fold_positions(&positions, current_domain_size, N);
+
+            let paths = layer_proof.into_paths(&folded_positions).map_err(|err| {
+                VerifierError::ProofDeserializationError(format!(
+                    "FRI layer query deserialization failed: {err}"
+                ))
+            })?;
+            let layer_values = group_slice_elements::<QuadFelt, N>(&layer_queries);
+            if layer_values.len() != paths.len() {
+                return Err(VerifierError::ProofDeserializationError(
+                    "FRI layer query deserialization failed: inconsistent number of queries"
+                        .to_string(),
+                ));
+            }
+
+            let mut leaves = Vec::with_capacity(paths.len());
+            for ((position, mut path), values) in
+                folded_positions.iter().zip(paths).zip(layer_values)
+            {
+                let leaf = path.remove(0);
+                leaves.push((*position as u64, leaf, MerklePath::from(path)));
+
+                advice_map.push((leaf, QuadFelt::slice_as_base_elements(values).to_vec()));
+            }
+
+            let tree = PartialMerkleTree::with_paths(leaves).map_err(|err| {
+                VerifierError::ProofDeserializationError(format!(
+                    "invalid FRI layer authentication paths: {err}"
+                ))
+            })?;
+            partial_trees.push(tree);
+
+            positions = folded_positions;
+            current_domain_size /= N;
+        }
+
+        Ok((partial_trees, advice_map))
+    }
+}
+
+// TRACE QUERIES
+// ================================================================================================
+
+/// Container of trace query data, including:
+/// * Queried states for the main and the auxiliary trace segments.
+/// * Merkle authentication paths for all queries.
+struct TraceQueries {
+    query_proofs: Vec<BatchMerkleProof<Rpo256>>,
+    main_states: Table<Felt>,
+    aux_states: Table<QuadFelt>,
+}
+
+impl TraceQueries {
+    /// Parses the provided trace queries into trace states in the specified field and
+    /// corresponding Merkle authentication paths.
+    pub fn new(
+        mut queries: Vec<Queries>,
+        air: &ProcessorAir,
+        num_queries: usize,
+    ) -> Result<Self, VerifierError> {
+        if queries.len() != air.trace_layout().num_segments() {
+            return Err(VerifierError::ProofDeserializationError(format!(
+                "expected {} trace segment queries, but received {}",
+                air.trace_layout().num_segments(),
+                queries.len()
+            )));
+        }
+
+        // parse main trace segment queries; parsing also validates that hashes of each table row
+        // form the leaves of Merkle authentication paths in the proofs
+        let main_segment_width = air.trace_layout().main_trace_width();
+        let (main_query_proof, main_states) = queries
+            .remove(0)
+            .parse::<Rpo256, Felt>(air.lde_domain_size(), num_queries, main_segment_width)
+            .map_err(|err| {
+                VerifierError::ProofDeserializationError(format!(
+                    "main trace segment query deserialization failed: {err}"
+                ))
+            })?;
+
+        // parse auxiliary trace segment queries and merge resulting tables into a single table
+        let mut query_proofs = vec![main_query_proof];
+        let mut aux_states = Vec::new();
+        for (i, segment_queries) in queries.into_iter().enumerate() {
+            let segment_width = air.trace_layout().get_aux_segment_width(i);
+            let (segment_query_proof, segment_states) = segment_queries
+                .parse::<Rpo256, QuadFelt>(air.lde_domain_size(), num_queries, segment_width)
+                .map_err(|err| {
+                    VerifierError::ProofDeserializationError(format!(
+                        "auxiliary trace segment query deserialization failed: {err}"
+                    ))
+                })?;
+
+            query_proofs.push(segment_query_proof);
+            aux_states.push(segment_states);
+        }
+
+        Ok(Self {
+            query_proofs,
+            main_states,
+            aux_states: Table::merge(aux_states),
+        })
+    }
+}
+
+// CONSTRAINT QUERIES
+// ================================================================================================
+
+/// Container of constraint evaluation query data, including:
+/// * Queried constraint evaluation values.
+/// * Merkle authentication paths for all queries.
+struct ConstraintQueries {
+    query_proofs: BatchMerkleProof<Rpo256>,
+    evaluations: Table<QuadFelt>,
+}
+
+impl ConstraintQueries {
+    /// Parses the provided constraint queries into evaluations in the specified field and
+    /// corresponding Merkle authentication paths.
+    pub fn new(
+        queries: Queries,
+        air: &ProcessorAir,
+        num_queries: usize,
+    ) -> Result<Self, VerifierError> {
+        let (query_proofs, evaluations) = queries
+            .parse::<Rpo256, QuadFelt>(air.lde_domain_size(), num_queries, air.ce_blowup_factor())
+            .map_err(|err| {
+                VerifierError::ProofDeserializationError(format!(
+                    "constraint evaluation query deserialization failed: {err}"
+                ))
+            })?;
+
+        Ok(Self {
+            query_proofs,
+            evaluations,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts a batched Merkle proof for the rows queried at the specified positions into a partial
+/// Merkle tree, and returns it together with advice map entries mapping each leaf to the row it
+/// commits to.
+fn unbatch_to_partial_mt(
+    positions: &[usize],
+    rows: Vec<Vec<Felt>>,
+    proof: BatchMerkleProof<Rpo256>,
+) -> Result<(PartialMerkleTree, AdviceMapEntries), VerifierError> {
+    let paths = proof.into_paths(positions).map_err(|err| {
+        VerifierError::ProofDeserializationError(format!("invalid batch Merkle proof: {err}"))
+    })?;
+
+    let mut leaves = Vec::with_capacity(paths.len());
+    let mut advice_map = Vec::with_capacity(paths.len());
+    for ((position, mut path), row) in positions.iter().zip(paths).zip(rows) {
+        let leaf = path.remove(0);
+        leaves.push((*position as u64, leaf, MerklePath::from(path)));
+        advice_map.push((leaf, row));
+    }
+
+    let tree = PartialMerkleTree::with_paths(leaves).map_err(|err| {
+        VerifierError::ProofDeserializationError(format!("invalid authentication paths: {err}"))
+    })?;
+    Ok((tree, advice_map))
+}
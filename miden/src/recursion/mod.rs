@@ -0,0 +1,55 @@
+//! Recursive verification of Miden VM execution proofs.
+//!
+//! Execution proofs can be verified inside the VM using the STARK verifier from the standard
+//! library (`std::crypto::stark::verifier`). The inputs needed to verify a single proof using the
+//! recursive verifier can be generated using [generate_advice_inputs()].
+
+use crate::HashFunction;
+use core::fmt;
+use processor::QuadExtension;
+use verifier::VerifierError;
+use vm_core::Felt;
+
+mod advice;
+pub use advice::generate_advice_inputs;
+
+mod channel;
+
+type QuadFelt = QuadExtension<Felt>;
+
+// RECURSION ERROR
+// ================================================================================================
+
+/// Errors which can occur when generating inputs for the recursive STARK verifier.
+#[derive(Debug)]
+pub enum RecursionError {
+    UnsupportedHashFunction(HashFunction),
+    UnsupportedProofOptions(&'static str),
+    UnsupportedKernel,
+    InvalidNumStackInputs(usize),
+    InvalidNumStackOutputs(usize),
+    InvalidProof(VerifierError),
+}
+
+impl fmt::Display for RecursionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use RecursionError::*;
+        match self {
+            UnsupportedHashFunction(hash_fn) => {
+                write!(f, "proofs generated using {hash_fn:?} hash function are not supported")
+            }
+            UnsupportedProofOptions(reason) => write!(f, "unsupported proof options: {reason}"),
+            UnsupportedKernel => write!(f, "programs with a kernel are not supported"),
+            InvalidNumStackInputs(num) => {
+                write!(f, "expected exactly 16 stack inputs, but {num} were provided")
+            }
+            InvalidNumStackOutputs(num) => {
+                write!(f, "expected exactly 16 stack outputs, but {num} were provided")
+            }
+            InvalidProof(err) => write!(f, "invalid proof: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecursionError {}
@@ -1,8 +1,14 @@
-use assembly::{Assembler, Library, MaslLibrary};
-use miden_vm::{math::Felt, DefaultHost, StackInputs, Word};
-use processor::ContextId;
+use crate::cli::{InputFile, InputValue};
+use assembly::{
+    ast::{ModuleAst, ProcedureAst, ProgramAst},
+    Assembler, Library, MaslLibrary, ParsingError,
+};
+use miden_vm::{
+    math::Felt, AdviceInputs, DefaultHost, ExecutionSession, Kernel, MemAdviceProvider,
+    StackInputs, Word,
+};
 use rustyline::{error::ReadlineError, DefaultEditor};
-use std::{collections::BTreeSet, path::PathBuf};
+use std::{collections::BTreeSet, fs, path::PathBuf};
 use stdlib::StdLibrary;
 
 /// This work is in continuation to the amazing work done by team `Scribe`
@@ -17,6 +23,9 @@ use stdlib::StdLibrary;
 /// thing to note is that all the REPL native commands start with an `!` to differentiate them from
 /// regular assembly instructions.
 ///
+/// The REPL keeps the state of the VM (the stack, the memory of the root context, and the advice
+/// provider) between inputs, and every input executes only its own instructions against this state.
+///
 /// Miden Instructions
 /// All Miden instructions mentioned in the
 /// [Miden Assembly section](https://0xpolygonmiden.github.io/miden-vm/user_docs/assembly/main.html)
@@ -63,11 +72,12 @@ use stdlib::StdLibrary;
 /// 3072 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
 ///
 /// `!undo`
-/// The `!undo` command reverts to the previous state of the stack and memory by dropping off the
-/// last executed assembly instruction from the program. One could use `!undo` as often as they want
-/// to restore the state of a stack and memory $n$ instructions ago (provided there are $n$ instructions
-/// in the program). The `!undo` command will result in an error if no remaining instructions are left in
-///  the miden program.
+/// The `!undo` command reverts the stack, memory, and advice provider to the state preceding the
+/// last executed input. The REPL takes a snapshot of the VM state before executing each input, and
+/// `!undo` restores the latest snapshot without re-executing any of the previous inputs. One could
+/// use `!undo` as often as they want to restore the state $n$ inputs ago (provided there are $n$
+/// executed inputs), up to the last 64 inputs. The `!undo` command will result in an error if no
+/// executed inputs are left.
 /// >> push.1 push.2 push.3
 /// >> push.4
 /// >> !stack
@@ -122,14 +132,34 @@ use stdlib::StdLibrary;
 /// If the `addr` has not been initialized:
 /// >> !mem[87]
 /// Memory at address 87 is empty
+///
+/// `proc.<name> ... end`
+/// A procedure can be defined by entering its definition in a single line. Entering a definition
+/// of a procedure with the same name as an existing procedure replaces the existing procedure for
+/// all subsequent inputs. The `!procs` command prints out all defined procedures.
+/// >> proc.double dup add end
+/// Defined procedure double
+/// >> push.21 exec.double
+/// 42 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+///
+/// `!advice`
+/// The `!advice` command prints out the current advice stack and advice map, as well as the Merkle
+/// data loaded so far, in the JSON format of input files. The `!advice <path>` command loads the
+/// advice data of the specified input file: its advice stack is placed below the current advice
+/// stack, and the advice map and the Merkle store are extended with the data from the file.
+///
+/// `!load <path>`
+/// The `!load` command adds imports and procedures of the specified MASM file to the REPL, and
+/// executes the body of its program (if any) as a single input. If an input file with the same name
+/// and the `.inputs` extension exists, its advice data is loaded and the values of its operand
+/// stack are pushed onto the stack before the body is executed.
+///
+/// `!save <path>`
+/// The `!save` command saves all inputs which have not been undone as a program, and all loaded
+/// advice data as an input file with the same name and the `.inputs` extension.
 
 /// Initiates the Miden Repl tool.
 pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
-    let mut program_lines: Vec<String> = Vec::new();
-
-    // set of user imported modules
-    let mut imported_modules: BTreeSet<String> = BTreeSet::new();
-
     // load libraries from files
     let mut provided_libraries = Vec::new();
     for path in library_paths {
@@ -142,70 +172,34 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
         provided_libraries.push(MaslLibrary::from(StdLibrary::default()));
     }
 
+    let assembler = Assembler::default()
+        .with_libraries(provided_libraries.iter())
+        .map_err(|e| format!("Failed to load libraries: {e}"))
+        .unwrap();
+
+    let mut state = ReplState::new();
+
     println!("========================== Miden REPL ============================");
     println!();
     // prints out all the available commands in the Miden Repl tool.
     print_instructions();
 
-    // flag to determine if the stack should be printed or not post the execution of the
-    // last command.
-    let mut should_print_stack = false;
-
-    // state of the entire memory at the latest clock cycle.
-    let mut memory: Vec<(u64, Word)> = Vec::new();
-
     // initializing readline.
     let mut rl = DefaultEditor::new().expect("Readline couldn't be initialized");
     loop {
-        let mut program = String::new();
-        for module in imported_modules.iter() {
-            program.push_str(module);
-            program.push('\n');
-        }
-        program.push_str(&format!(
-            "\nbegin\n{}\nend",
-            program_lines
-                .iter()
-                .map(|l| format!("    {}", l))
-                .collect::<Vec<_>>()
-                .join("\n")
-        ));
-
-        let result = execute(program.clone(), &provided_libraries);
-
-        if !program_lines.is_empty() {
-            match result {
-                Ok((mem, stack_state)) => {
-                    if should_print_stack {
-                        print_stack(stack_state);
-                    }
-                    memory = mem;
-                }
-                Err(e) => {
-                    println!("Error running program: {:?}", e);
-                    program_lines.pop();
-                }
-            }
-        } else if should_print_stack {
-            println!("{}", str::repeat("0 ", 16));
-        }
-
         match rl.readline(">> ") {
             Ok(line) => {
                 if line == "!program" {
-                    println!("{}", program);
-                    should_print_stack = false;
+                    println!("{}", state.build_program(&state.program_lines()));
                 } else if line == "!help" {
                     // prints out all the available commands in the Miden Repl tool.
                     print_instructions();
-                    should_print_stack = false;
                 } else if line == "!mem" {
-                    should_print_stack = false;
-                    if memory.is_empty() {
+                    if state.session.memory().is_empty() {
                         println!("The memory has not been initialized yet");
                         continue;
                     }
-                    for (addr, mem) in &memory {
+                    for (addr, mem) in state.session.memory() {
                         // prints out the address and memory value at that address.
                         print_mem_address(*addr, mem);
                     }
@@ -213,49 +207,58 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
                     // if user wants to see the state of a particular address in a memory, the input should be atleast
                     // of length 5.
 
-                    // flag to determine if the memory at an address has been initialized or not
-                    let mut mem_at_addr_present = false;
-
                     // extracts the address from user input.
                     match read_mem_address(&line) {
-                        Ok(addr) => {
-                            for (i, memory_value) in &memory {
-                                if *i == addr {
-                                    // prints the address and memory value at that address.
-                                    print_mem_address(addr, memory_value);
-                                    // sets the flag to true as the address has been initialized.
-                                    mem_at_addr_present = true;
-                                    break;
-                                }
-                            }
-                            // in case the flag has not been initialized.
-                            if !mem_at_addr_present {
-                                println!("Memory at address {} is empty", addr);
-                            }
-                        }
+                        Ok(addr) => match state.session.get_mem_value(addr) {
+                            // prints the address and memory value at that address.
+                            Some(memory_value) => print_mem_address(addr, &memory_value),
+                            None => println!("Memory at address {} is empty", addr),
+                        },
                         Err(msg) => println!("{}", msg),
                     }
-
-                    should_print_stack = false;
                 } else if line == "!undo" {
-                    match program_lines.pop() {
-                        Some(last_line) => {
+                    // restores the state preceding the last executed input from a snapshot
+                    match state.undo() {
+                        Some(HistoryEntry::Instructions(last_line, _)) => {
                             println!("Undoing {}", last_line);
-                            should_print_stack = true;
-                        }
-                        None => {
-                            println!("There's no previously executed command");
-                            should_print_stack = false;
+                            print_stack(state.session.stack());
                         }
-                    };
+                        Some(HistoryEntry::Advice(_)) => println!("Undoing advice data loading"),
+                        None if !state.history.is_empty() => println!(
+                            "Only the last {} inputs can be undone",
+                            ExecutionSession::<DefaultHost<MemAdviceProvider>>::DEFAULT_MAX_SNAPSHOTS
+                        ),
+                        None => println!("There's no previously executed command"),
+                    }
                 } else if line == "!stack" {
-                    should_print_stack = true;
+                    print_stack(state.session.stack());
+                } else if line == "!procs" {
+                    if state.procedures.is_empty() {
+                        println!("No procedures have been defined yet");
+                    }
+                    for (_, source) in state.procedures.iter() {
+                        println!("{source}");
+                    }
                 } else if line.starts_with("!use") {
-                    handle_use_command(line, &provided_libraries, &mut imported_modules);
+                    handle_use_command(line, &provided_libraries, &mut state.imported_modules);
+                } else if line.starts_with("!advice") {
+                    handle_advice_command(&line, &mut state);
+                } else if line.starts_with("!load") {
+                    handle_load_command(&line, &assembler, &mut state);
+                } else if line.starts_with("!save") {
+                    handle_save_command(&line, &state);
+                } else if line.starts_with("proc.") {
+                    rl.add_history_entry(line.clone()).expect("Failed to add a history entry");
+                    match state.define_procedure(&assembler, line) {
+                        Ok(name) => println!("Defined procedure {name}"),
+                        Err(e) => println!("Error defining procedure: {:?}", e),
+                    }
                 } else {
                     rl.add_history_entry(line.clone()).expect("Failed to add a history entry");
-                    program_lines.push(line.clone());
-                    should_print_stack = true;
+                    match state.execute(&assembler, line) {
+                        Ok(stack_state) => print_stack(stack_state),
+                        Err(e) => println!("Error running program: {:?}", e),
+                    }
                 }
             }
             Err(ReadlineError::Interrupted) => {
@@ -276,42 +279,168 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
         .expect("Couldn't dump the program into the history file");
 }
 
-/// HELPER METHODS
-/// --------------------------------------------------------------------------------------------
+// REPL STATE
+// ================================================================================================
 
-/// Compiles and executes a compiled Miden program, returning the stack, memory and any Miden errors.
-/// The program is passed in as a String, passed to the Miden Assembler, and then passed into the Miden
-/// Processor to be executed.
-fn execute(
-    program: String,
-    provided_libraries: &[MaslLibrary],
-) -> Result<(Vec<(u64, Word)>, Vec<Felt>), String> {
-    // compile program
-    let mut assembler = Assembler::default();
-
-    assembler = assembler
-        .with_libraries(provided_libraries.iter())
-        .map_err(|err| format!("{err}"))?;
+/// An input which changed the state of the REPL session, and which can be reverted via `!undo`.
+enum HistoryEntry {
+    /// Instructions executed against the session, along with the procedures defined at the time
+    /// of the execution.
+    Instructions(String, Vec<(String, String)>),
+    /// Advice data loaded into the session.
+    Advice(InputFile),
+}
+
+/// State of the REPL: the execution session along with the inputs which produced it, and the
+/// imports and procedures available to subsequent inputs.
+struct ReplState {
+    /// Execution session which keeps the state of the stack, memory and advice provider between
+    /// the inputs, so that every input executes only its own instructions.
+    session: ExecutionSession<DefaultHost<MemAdviceProvider>>,
+    /// Inputs which have been executed successfully and have not been undone; every entry
+    /// corresponds to a snapshot of the session.
+    history: Vec<HistoryEntry>,
+    /// Source of the procedures defined in the REPL, in the order of their definition.
+    procedures: Vec<(String, String)>,
+    /// Set of user imported modules.
+    imported_modules: BTreeSet<String>,
+}
+
+impl ReplState {
+    fn new() -> Self {
+        Self {
+            session: ExecutionSession::new(
+                Kernel::default(),
+                StackInputs::default(),
+                DefaultHost::default(),
+            ),
+            history: Vec::new(),
+            procedures: Vec::new(),
+            imported_modules: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the instructions executed so far.
+    fn program_lines(&self) -> Vec<String> {
+        self.history
+            .iter()
+            .filter_map(|entry| match entry {
+                HistoryEntry::Instructions(line, _) => Some(line.clone()),
+                HistoryEntry::Advice(_) => None,
+            })
+            .collect()
+    }
+
+    /// Builds the source of a Miden program with the current imports and procedures, and the
+    /// specified body lines.
+    fn build_program(&self, lines: &[String]) -> String {
+        let mut program = String::new();
+        for module in self.imported_modules.iter() {
+            program.push_str(module);
+            program.push('\n');
+        }
+        for (_, source) in self.procedures.iter() {
+            program.push('\n');
+            program.push_str(source);
+            program.push('\n');
+        }
+        program.push_str(&format!(
+            "\nbegin\n{}\nend",
+            lines
+                .iter()
+                .flat_map(|l| l.lines())
+                .map(|l| format!("    {}", l))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ));
+        program
+    }
+
+    /// Compiles the specified instructions and executes them in the session, returning the
+    /// resulting stack state or any Miden errors. On error, the state of the session is not
+    /// changed.
+    fn execute(&mut self, assembler: &Assembler, line: String) -> Result<&[Felt], String> {
+        let program = self.build_program(core::slice::from_ref(&line));
+        let program = assembler.compile(program).map_err(|err| format!("{err}"))?;
+        self.session.execute(&program).map_err(|err| format!("{err}"))?;
+        self.history.push(HistoryEntry::Instructions(line, self.procedures.clone()));
+        Ok(self.session.stack())
+    }
+
+    /// Defines a new procedure or replaces the procedure with the same name, and returns the name
+    /// of the procedure.
+    ///
+    /// The procedure is compiled together with all other procedures, and is not defined if the
+    /// compilation fails. Redefining a procedure affects only the subsequent inputs.
+    fn define_procedure(
+        &mut self,
+        assembler: &Assembler,
+        source: String,
+    ) -> Result<String, String> {
+        let name = parse_procedure_name(&source)?;
+
+        let mut procedures = self.procedures.clone();
+        match procedures.iter_mut().find(|(n, _)| n == &name) {
+            Some(proc) => proc.1 = source,
+            None => procedures.push((name.clone(), source)),
+        }
+
+        let procedures = core::mem::replace(&mut self.procedures, procedures);
+        let program = self.build_program(&["push.0 drop".to_string()]);
+        if let Err(err) = assembler.compile(program) {
+            self.procedures = procedures;
+            return Err(format!("{err}"));
+        }
+
+        Ok(name)
+    }
+
+    /// Loads the advice data of the input file into the session. The advice stack of the input
+    /// file is placed below the current advice stack, while the advice map and the Merkle store
+    /// are extended with the advice map and the Merkle data of the input file.
+    fn load_advice(&mut self, inputs: InputFile) -> Result<(), String> {
+        let new_advice = inputs.parse_advice_inputs()?;
 
-    let program = assembler.compile(program).map_err(|err| format!("{err}"))?;
+        let (mut stack, map, store) = self.session.host().clone().into_inner().into_parts();
+        stack.reverse();
+        let mut advice =
+            AdviceInputs::default().with_stack(stack).with_map(map).with_merkle_store(store);
+        advice.extend(new_advice);
 
-    let stack_inputs = StackInputs::default();
-    let host = DefaultHost::default();
+        self.session.set_host(DefaultHost::new(MemAdviceProvider::from(advice)));
+        self.history.push(HistoryEntry::Advice(inputs));
+        Ok(())
+    }
 
-    let state_iter = processor::execute_iter(&program, stack_inputs, host);
-    let (system, _, stack, chiplets, err) = state_iter.into_parts();
-    if let Some(err) = err {
-        return Err(format!("{err}"));
+    /// Reverts the last input which changed the state of the session, and returns it.
+    fn undo(&mut self) -> Option<HistoryEntry> {
+        if self.session.undo() {
+            self.history.pop()
+        } else {
+            None
+        }
     }
 
-    // loads the memory at the latest clock cycle.
-    let mem_state = chiplets.get_mem_state_at(ContextId::root(), system.clk());
-    // loads the stack along with the overflow values at the latest clock cycle.
-    let stack_state = stack.get_state_at(system.clk());
+    /// Returns the advice data loaded into the session so far, merged into a single input file.
+    ///
+    /// The operand stack of the returned input file is empty.
+    fn loaded_advice(&self) -> InputFile {
+        let mut advice = InputFile::default();
+        for entry in self.history.iter() {
+            if let HistoryEntry::Advice(inputs) = entry {
+                advice.advice_stack.extend(inputs.advice_stack.iter().cloned());
+                advice.advice_map.extend(inputs.advice_map.clone());
+                advice.merkle_store.extend(inputs.merkle_store.iter().cloned());
+            }
+        }
 
-    Ok((mem_state, stack_state))
+        advice
+    }
 }
 
+/// HELPER METHODS
+/// --------------------------------------------------------------------------------------------
+
 /// Parses the address in integer form from "!mem[addr]" command, otherwise throws an error.
 fn read_mem_address(mem_str: &str) -> Result<u64, String> {
     // the first five characters is "!mem[" and the digit character should start from 6th
@@ -357,6 +486,217 @@ fn handle_use_command(
     }
 }
 
+/// Parses the name of a procedure from its definition (e.g., `proc.foo.2 ... end`).
+fn parse_procedure_name(source: &str) -> Result<String, String> {
+    let header = source.split_whitespace().next().unwrap_or_default();
+    match header.split('.').nth(1) {
+        Some(name) if !name.is_empty() => Ok(name.to_string()),
+        _ => Err(format!("malformed procedure definition '{header}'")),
+    }
+}
+
+/// Parses `!advice` command. Prints out the current advice data, or loads advice data from the
+/// specified input file if a path was provided.
+fn handle_advice_command(line: &str, state: &mut ReplState) {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+    match tokens.len() {
+        1 => {
+            // the current advice stack and map are read from the advice provider, while the
+            // Merkle store is described by the Merkle data loaded so far
+            let (stack, map, _) = state.session.host().clone().into_inner().into_parts();
+            let advice = InputFile {
+                operand_stack: Vec::new(),
+                advice_stack: stack.iter().rev().copied().map(InputValue::Felt).collect(),
+                advice_map: map
+                    .into_iter()
+                    .map(|(k, v)| (k, v.into_iter().map(InputValue::Felt).collect()))
+                    .collect(),
+                merkle_store: state.loaded_advice().merkle_store,
+            };
+            println!("{}", format_advice(&advice));
+        }
+        2 => {
+            let path = PathBuf::from(tokens[1]);
+            match InputFile::read(&Some(path.clone()), &path)
+                .and_then(|inputs| state.load_advice(inputs))
+            {
+                Ok(()) => println!("Loaded advice data from {}", path.display()),
+                Err(e) => println!("Error loading advice data: {e}"),
+            }
+        }
+        _ => println!("malformed instruction '!advice': too many parameters provided"),
+    }
+}
+
+/// Parses `!load` command. Sources the specified MASM file: its imports and procedures are added to
+/// the REPL, and the body of the program (if any) is executed. If an input file with the same
+/// name and the `.inputs` extension exists, its advice data is loaded and its operand stack is
+/// pushed onto the stack before the body of the program is executed.
+fn handle_load_command(line: &str, assembler: &Assembler, state: &mut ReplState) {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if tokens.len() != 2 {
+        println!("malformed instruction '!load': expected a path to a MASM file");
+        return;
+    }
+
+    let path = PathBuf::from(tokens[1]);
+    let source = match fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(e) => {
+            println!("Error reading {}: {e}", path.display());
+            return;
+        }
+    };
+
+    let (imports, procedures, body) = match split_masm_source(&source) {
+        Ok(parts) => parts,
+        Err(err) => {
+            println!("Error parsing {}: {err}", path.display());
+            return;
+        }
+    };
+
+    state.imported_modules.extend(imports);
+    for (name, proc) in procedures {
+        if let Err(e) = state.define_procedure(assembler, proc) {
+            println!("Error defining procedure {name}: {e}");
+            return;
+        }
+        println!("Defined procedure {name}");
+    }
+
+    let inputs = match InputFile::read(&None, &path) {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            println!("Error loading inputs: {e}");
+            return;
+        }
+    };
+    let has_advice = !inputs.advice_stack.is_empty()
+        || !inputs.advice_map.is_empty()
+        || !inputs.merkle_store.is_empty();
+    if has_advice {
+        if let Err(e) = state.load_advice(inputs.clone()) {
+            println!("Error loading advice data: {e}");
+            return;
+        }
+        println!("Loaded advice data from {}", path.with_extension("inputs").display());
+    }
+
+    // values of the operand stack are pushed so that the last value ends up on top of the stack
+    let mut instructions = inputs
+        .operand_stack
+        .iter()
+        .flat_map(InputValue::to_elements)
+        .map(|v| format!("push.{v}"))
+        .collect::<Vec<_>>();
+    instructions.extend(body);
+    if instructions.is_empty() {
+        return;
+    }
+    match state.execute(assembler, instructions.join("\n")) {
+        Ok(stack_state) => print_stack(stack_state),
+        Err(e) => println!("Error running program: {:?}", e),
+    }
+}
+
+/// Parses `!save` command. Saves the inputs executed so far as a program to the specified file,
+/// and the advice data loaded so far into an input file with the same name and the `.inputs`
+/// extension.
+fn handle_save_command(line: &str, state: &ReplState) {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if tokens.len() != 2 {
+        println!("malformed instruction '!save': expected a path to a MASM file");
+        return;
+    }
+
+    let lines = state.program_lines();
+    if lines.is_empty() {
+        println!("There's no previously executed command");
+        return;
+    }
+
+    // the saved program uses the latest definitions of procedures
+    let redefined = state.history.iter().any(|entry| {
+        matches!(entry, HistoryEntry::Instructions(_, procedures) if procedures != &state.procedures)
+    });
+    if redefined {
+        println!("Warning: procedures were redefined during the session; the saved program uses their latest definitions");
+    }
+
+    let path = PathBuf::from(tokens[1]);
+    let inputs_path = path.with_extension("inputs");
+    let result = fs::write(&path, state.build_program(&lines))
+        .and_then(|_| fs::write(&inputs_path, format_advice(&state.loaded_advice())));
+    match result {
+        Ok(()) => println!("Saved the session to {} and {}", path.display(), inputs_path.display()),
+        Err(e) => println!("Error saving the session: {e}"),
+    }
+}
+
+/// Splits MASM source into import statements, procedure definitions (as (name, source) tuples),
+/// and the lines of the program body.
+///
+/// The source is parsed as a program first; sources without a program body are parsed as library
+/// modules. Exported procedures are converted into local procedures, while procedure re-exports
+/// and documentation comments are skipped.
+///
+/// # Errors
+/// Returns the error of parsing the source as a program if it can be parsed neither as a program
+/// nor as a module.
+#[allow(clippy::type_complexity)]
+fn split_masm_source(
+    source: &str,
+) -> Result<(Vec<String>, Vec<(String, String)>, Vec<String>), ParsingError> {
+    let (procedures, body, import_info) = match ProgramAst::parse(source) {
+        Ok(program) => {
+            let import_info = program.import_info().clone();
+            let (procedures, body) = program.into_parts();
+            (procedures, body, import_info)
+        }
+        Err(err) => match ModuleAst::parse(source) {
+            Ok(module) => (module.procs().to_vec(), Vec::new(), module.import_info().clone()),
+            Err(_) => return Err(err),
+        },
+    };
+
+    let procedures = procedures
+        .into_iter()
+        .map(|proc| ProcedureAst {
+            is_export: false,
+            docs: None,
+            ..proc
+        })
+        .collect::<Vec<_>>();
+    let names = procedures.iter().map(|proc| proc.name.to_string()).collect::<Vec<_>>();
+    let program = ProgramAst::new(body, procedures)?.with_import_info(import_info);
+
+    let imports = program
+        .import_info()
+        .import_paths()
+        .into_iter()
+        .map(|path| format!("use.{path}"))
+        .collect();
+    let procedures = names
+        .into_iter()
+        .zip(program.format_procedures())
+        .map(|(name, proc)| (name, proc.trim_end().to_string()))
+        .collect();
+    let body = program.format_body().lines().map(|line| line.trim().to_string()).collect();
+
+    Ok((imports, procedures, body))
+}
+
+/// Formats advice data as JSON in the shape of an input file, omitting the operand stack.
+fn format_advice(advice: &InputFile) -> String {
+    let mut value = serde_json::to_value(advice).expect("failed to serialize advice data");
+    if let Some(fields) = value.as_object_mut() {
+        fields.remove("operand_stack");
+    }
+    serde_json::to_string_pretty(&value).expect("failed to serialize advice data")
+}
+
 /// Prints out all the available command present in the Miden Repl tool.
 fn print_instructions() {
     println!("Available commands:");
@@ -364,16 +704,22 @@ fn print_instructions() {
     println!("!stack: display the complete state of the stack");
     println!("!mem: display the state of the entire memory");
     println!("!mem[i]: display the state of the memory at address i");
-    println!("!undo: remove the last instruction");
+    println!("!undo: revert the last executed input");
     println!("!use: display a list of modules available for import");
     println!("!use <full_module_name>: import the specified module");
     println!("!program: display the program");
+    println!("proc.<name> ... end: define or redefine a procedure");
+    println!("!procs: display the defined procedures");
+    println!("!advice: display the advice data");
+    println!("!advice <inputs_file>: load advice data from an input file");
+    println!("!load <masm_file>: load a MASM file and its input file, and execute its program");
+    println!("!save <masm_file>: save the session as a program and an input file");
     println!("!help: print out all the available commands");
     println!();
 }
 
 /// Returns the state of the stack along with its overflown part in a string format.
-fn print_stack(stack: Vec<Felt>) {
+fn print_stack(stack: &[Felt]) {
     // converts the stack which is a vector of felt into string and prints it.
     println!("{}", stack.iter().map(|f| format!("{}", f)).collect::<Vec<_>>().join(" "),)
 }
@@ -384,3 +730,78 @@ fn print_mem_address(addr: u64, mem: &Word) {
     let mem_int = mem.iter().map(|&x| x.as_int()).collect::<Vec<_>>();
     println!("{} {:?}", addr, mem_int)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        split_masm_source, Assembler, Felt, HistoryEntry, InputFile, InputValue, ReplState,
+    };
+
+    #[test]
+    fn repl_procedures_and_advice() {
+        let assembler = Assembler::default();
+        let mut state = ReplState::new();
+
+        state
+            .define_procedure(&assembler, "proc.foo push.2 mul end".to_string())
+            .unwrap();
+        assert_eq!(
+            42,
+            state.execute(&assembler, "push.21 exec.foo".to_string()).unwrap()[0].as_int()
+        );
+
+        // procedures which fail to compile are not defined, and redefinitions replace procedures
+        assert!(state.define_procedure(&assembler, "proc.bar exec.baz end".to_string()).is_err());
+        state
+            .define_procedure(&assembler, "proc.foo push.3 mul end".to_string())
+            .unwrap();
+        assert_eq!(1, state.procedures.len());
+        assert_eq!(126, state.execute(&assembler, "exec.foo".to_string()).unwrap()[0].as_int());
+
+        // advice data is placed below the current advice stack
+        let inputs = |stack: &[u64]| InputFile {
+            advice_stack: stack.iter().map(|v| InputValue::Felt(Felt::new(*v))).collect(),
+            ..Default::default()
+        };
+        state.load_advice(inputs(&[7])).unwrap();
+        state.load_advice(inputs(&[8, 9])).unwrap();
+        assert_eq!(7, state.execute(&assembler, "adv_push.1".to_string()).unwrap()[0].as_int());
+        assert_eq!(inputs(&[7, 8, 9]).advice_stack, state.loaded_advice().advice_stack);
+
+        // undo reverts executed instructions and loaded advice data
+        assert!(matches!(state.undo(), Some(HistoryEntry::Instructions(..))));
+        assert!(matches!(state.undo(), Some(HistoryEntry::Advice(_))));
+        assert_eq!(7, state.execute(&assembler, "adv_push.1".to_string()).unwrap()[0].as_int());
+        assert_eq!(vec!["push.21 exec.foo", "exec.foo", "adv_push.1"], state.program_lines());
+    }
+
+    #[test]
+    fn repl_split_masm_source() {
+        let source = "use.std::math::u64 proc.foo.1 loc_store.0 end proc.bar if.true add end end
+            begin exec.foo if.true push.1 end end";
+        let (imports, procedures, body) = split_masm_source(source).unwrap();
+
+        assert_eq!(vec!["use.std::math::u64"], imports);
+        let names = procedures.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+        assert_eq!(vec!["foo", "bar"], names);
+        assert!(procedures[1].1.starts_with("proc.bar.0\n"));
+        assert!(procedures[1].1.ends_with("\nend"));
+        assert_eq!(vec!["exec.foo", "if.true", "push.1", "end"], body);
+
+        // exported procedures of modules are converted into local procedures
+        let (_, procedures, body) = split_masm_source("export.baz push.1 end").unwrap();
+        assert!(procedures[0].1.starts_with("proc.baz.0\n"));
+        assert!(body.is_empty());
+
+        // procedure boundaries do not depend on the layout of the source
+        let source =
+            "#! docs\nexport.foo\n  if.true\n    push.1\n  end\n  end\nproc.bar exec.foo end";
+        let (_, procedures, _) = split_masm_source(source).unwrap();
+        assert_eq!("proc.foo.0\n    if.true\n        push.1\n    end\nend", procedures[0].1);
+        assert_eq!("proc.bar.0\n    exec.foo\nend", procedures[1].1);
+
+        // malformed sources are reported as errors
+        assert!(split_masm_source("proc.foo push.1").is_err());
+        assert!(split_masm_source("begin push.1").is_err());
+    }
+}
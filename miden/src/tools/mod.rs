@@ -2,7 +2,7 @@ use super::{cli::InputFile, ProgramError};
 use clap::Parser;
 use core::fmt;
 use miden_vm::{Assembler, DefaultHost, Host, Operation, StackInputs};
-use processor::{AsmOpInfo, TraceLenSummary};
+use processor::{AsmOpInfo, MemoryAccessReport, TraceLenSummary};
 use std::{fs, path::PathBuf};
 use stdlib::StdLibrary;
 
@@ -72,6 +72,8 @@ pub struct ExecutionDetails {
     asm_op_stats: Vec<AsmOpStats>,
     /// Information about VM components trace lengths.
     trace_len_summary: TraceLenSummary,
+    /// Report on the memory accesses made by the program, see [MemoryAccessReport].
+    memory_access_report: Option<MemoryAccessReport>,
 }
 
 impl ExecutionDetails {
@@ -91,6 +93,12 @@ impl ExecutionDetails {
         self.trace_len_summary
     }
 
+    /// Returns [MemoryAccessReport] that contains uninitialized memory reads, memory convention
+    /// violations and memory footprints of all execution contexts.
+    pub fn memory_access_report(&self) -> Option<&MemoryAccessReport> {
+        self.memory_access_report.as_ref()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -132,6 +140,11 @@ impl ExecutionDetails {
     pub fn set_trace_len_summary(&mut self, extended_cycles_info: &TraceLenSummary) {
         self.trace_len_summary = *extended_cycles_info;
     }
+
+    /// Sets the report on the memory accesses made by the program.
+    pub fn set_memory_access_report(&mut self, memory_access_report: Option<MemoryAccessReport>) {
+        self.memory_access_report = memory_access_report;
+    }
 }
 
 impl fmt::Display for ExecutionDetails {
@@ -197,6 +210,10 @@ impl fmt::Display for ExecutionDetails {
 
         writeln!(f, "\nTotal number of NOOPs executed: {}", total_noops)?;
 
+        if let Some(memory_access_report) = self.memory_access_report() {
+            writeln!(f, "\n{memory_access_report}")?;
+        }
+
         Ok(())
     }
 }
@@ -220,6 +237,7 @@ where
 
     let vm_state_iterator = processor::execute_iter(&program, stack_inputs, host);
     execution_details.set_trace_len_summary(vm_state_iterator.trace_len_summary());
+    execution_details.set_memory_access_report(vm_state_iterator.memory_access_report());
 
     for state in vm_state_iterator {
         let vm_state = state.map_err(ProgramError::ExecutionError)?;
@@ -291,7 +309,7 @@ impl AsmOpStats {
 #[cfg(test)]
 mod tests {
     use super::{AsmOpStats, ExecutionDetails, StackInputs};
-    use processor::{ChipletsLengths, DefaultHost, TraceLenSummary};
+    use processor::{ChipletsLengths, ContextId, DefaultHost, TraceLenSummary};
 
     #[test]
     fn analyze_test() {
@@ -299,8 +317,14 @@ mod tests {
             "proc.foo.1 loc_store.0 end begin mem_storew.1 dropw push.17 push.1 movdn.2 exec.foo end";
         let stack_inputs = StackInputs::default();
         let host = DefaultHost::default();
-        let execution_details =
+        let mut execution_details =
             super::analyze(source, stack_inputs, host).expect("analyze_test: Unexpected Error");
+
+        // the program writes to memory before reading from it and accesses only allocated locals
+        let memory_access_report = execution_details.memory_access_report.take().unwrap();
+        assert!(memory_access_report.is_clean());
+        assert_eq!(2, memory_access_report.footprints()[&ContextId::root()].num_addresses());
+
         let expected_details = ExecutionDetails {
             total_noops: 2,
             asm_op_stats: vec![
@@ -315,6 +339,7 @@ mod tests {
                 39,
                 ChipletsLengths::from_parts(8, 0, 2, 0),
             ),
+            memory_access_report: None,
         };
         assert_eq!(execution_details, expected_details);
     }
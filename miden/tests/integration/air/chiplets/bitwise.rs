@@ -27,6 +27,16 @@ fn bitwise_xor() {
     build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
 }
 
+#[test]
+fn bitwise_shift() {
+    // Test the extreme shift amounts 0 and 32 as well as shifts in between.
+    let asm_op = "push.3 u32shl push.5 u32shr push.32 u32rotl push.0 u32rotr push.7 u32rotl \
+        push.32 u32shr";
+    let pub_inputs = vec![3735928559];
+
+    build_op_test!(&asm_op, &pub_inputs).prove_and_verify(pub_inputs, false);
+}
+
 #[test]
 fn all_operations() {
     let source = "begin u32and push.0 u32or push.0 u32xor end";
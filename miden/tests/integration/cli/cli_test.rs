@@ -35,3 +35,49 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn cli_inspect_proof() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let dir = std::env::temp_dir().join(format!("miden-cli-inspect-proof-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let proof_file = dir.join("fib.proof");
+
+    bin_under_test
+        .command()
+        .arg("prove")
+        .arg("-a")
+        .arg("./examples/fib/fib.masm")
+        .arg("-p")
+        .arg(&proof_file)
+        .arg("-o")
+        .arg(dir.join("fib.outputs"))
+        .unwrap()
+        .assert()
+        .success();
+
+    let output = bin_under_test
+        .command()
+        .arg("inspect-proof")
+        .arg("-p")
+        .arg(&proof_file)
+        .unwrap();
+    let proof_size = std::fs::metadata(&proof_file)?.len();
+    std::fs::remove_dir_all(&dir)?;
+
+    output
+        .assert()
+        .stdout(predicate::str::contains(format!("Proof size: {proof_size} bytes")))
+        .stdout(predicate::str::contains("Hash function: Blake3_192"))
+        .stdout(predicate::str::contains("FRI layer 0:"))
+        .stdout(predicate::str::contains("Security: 96 bits (conjectured)"));
+
+    Ok(())
+}
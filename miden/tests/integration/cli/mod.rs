@@ -1 +1,2 @@
 mod cli_test;
+mod serve;
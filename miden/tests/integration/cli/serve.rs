@@ -0,0 +1,277 @@
+use serde_json::{json, Value};
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    process::{Child, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+// SERVER
+// ================================================================================================
+
+/// A `miden serve` process listening on a local port; the process is killed when dropped.
+struct Server {
+    process: Child,
+    addr: String,
+}
+
+impl Server {
+    /// Starts the server with the specified arguments and waits until it listens for requests.
+    fn start(args: &[&str]) -> Self {
+        let bin_under_test = escargot::CargoBuild::new()
+            .bin("miden")
+            .features("executable")
+            .current_release()
+            .current_target()
+            .run()
+            .unwrap();
+
+        let mut process = bin_under_test
+            .command()
+            .arg("serve")
+            .args(["--port", "0"])
+            .args(args)
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        let mut line = String::new();
+        BufReader::new(process.stdout.take().unwrap()).read_line(&mut line).unwrap();
+        let addr = line
+            .trim()
+            .strip_prefix("Listening on http://")
+            .unwrap_or_else(|| panic!("unexpected server output: {line}"))
+            .to_string();
+
+        Self { process, addr }
+    }
+
+    /// Sends an HTTP POST request with the specified content type and body, and returns the
+    /// status code and the body of the response.
+    fn post(&self, content_type: &str, body: &str) -> (u16, String) {
+        self.try_post(content_type, body).unwrap()
+    }
+
+    /// Same as [Server::post], but returns an error if the connection fails, e.g., because the
+    /// server rejected the connection and reset it before the response was read.
+    fn try_post(&self, content_type: &str, body: &str) -> io::Result<(u16, String)> {
+        let mut stream = TcpStream::connect(&self.addr)?;
+        write!(
+            stream,
+            "POST / HTTP/1.1\r\nHost: {}\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n{}",
+            self.addr,
+            content_type,
+            body.len(),
+            body
+        )?;
+
+        // the server closes the connection after sending the response
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        Ok((status, body.to_string()))
+    }
+
+    /// Calls the specified JSON-RPC method and returns the response.
+    fn call(&self, method: &str, params: Value) -> Value {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let (status, body) = self.post("application/json", &request.to_string());
+        assert_eq!(status, 200);
+        serde_json::from_str(&body).unwrap()
+    }
+
+    /// Calls the specified JSON-RPC method and returns its result, panicking on errors.
+    fn result(&self, method: &str, params: Value) -> Value {
+        let response = self.call(method, params);
+        assert!(response["error"].is_null(), "unexpected error: {response}");
+        response["result"].clone()
+    }
+
+    /// Calls the specified JSON-RPC method and returns the code of the error it failed with.
+    fn error_code(&self, method: &str, params: Value) -> i64 {
+        let response = self.call(method, params);
+        response["error"]["code"]
+            .as_i64()
+            .unwrap_or_else(|| panic!("expected an error: {response}"))
+    }
+
+    /// Submits a job using the specified method and returns the job id.
+    fn submit(&self, method: &str, params: Value) -> u64 {
+        self.result(method, params)["id"].as_u64().unwrap()
+    }
+
+    /// Waits until the specified job reaches one of the specified statuses, and returns the job.
+    fn wait_for(&self, id: u64, statuses: &[&str]) -> Value {
+        let start = Instant::now();
+        loop {
+            let job = self.result("get_job", json!({ "id": id }));
+            if statuses.contains(&job["status"].as_str().unwrap()) {
+                return job;
+            }
+            assert!(start.elapsed() < Duration::from_secs(120), "job timed out: {job}");
+            thread::sleep(Duration::from_millis(20));
+        }
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+/// A program which never terminates on its own.
+const INFINITE_LOOP: &str = "begin push.1 while.true push.1 end end";
+
+/// A program which never terminates on its own and makes a request to the host in every
+/// iteration.
+const INFINITE_ADVICE_LOOP: &str =
+    "begin push.1.1.1.1 push.1 while.true adv.push_u64div push.1 end end";
+
+#[test]
+fn serve_execute_prove_and_verify() {
+    let server = Server::start(&[]);
+    let source = "begin push.3 add end";
+    let inputs = json!({ "operand_stack": ["2"] });
+
+    // compile
+    let program_hash =
+        server.result("compile", json!({ "source": source }))["program_hash"].clone();
+    assert_eq!(program_hash.as_str().unwrap().len(), 64);
+
+    // execute
+    let id = server.submit("execute", json!({ "source": source, "inputs": inputs }));
+    let job = server.wait_for(id, &["completed", "failed"]);
+    assert_eq!(job["status"], "completed", "{job}");
+    assert_eq!(job["kind"], "execute");
+    assert_eq!(job["program_hash"], program_hash);
+    assert_eq!(job["result"]["stack_outputs"]["stack"][0], "5");
+    assert!(job["result"]["cycles"].as_u64().unwrap() > 0);
+
+    // prove and retrieve the proof by job id
+    let id = server.submit("prove", json!({ "source": source, "inputs": inputs }));
+    let job = server.wait_for(id, &["completed", "failed"]);
+    assert_eq!(job["status"], "completed", "{job}");
+    let result = &job["result"];
+    assert_eq!(result["stack_outputs"]["stack"][0], "5");
+    assert_eq!(result["security_level"], 96);
+
+    // verify
+    let mut params = json!({
+        "program_hash": program_hash,
+        "inputs": inputs,
+        "stack_outputs": result["stack_outputs"],
+        "proof": result["proof"],
+    });
+    assert_eq!(server.result("verify", params.clone())["security_level"], 96);
+
+    // proofs are rejected against wrong outputs
+    params["stack_outputs"]["stack"][0] = json!("6");
+    assert_eq!(server.error_code("verify", params), -32003);
+}
+
+#[test]
+fn serve_job_limits_and_cancellation() {
+    let server = Server::start(&["--workers", "1", "--max-queued", "1", "--max-cycles", "1048576"]);
+
+    // per-job limits cannot exceed the limit of the server
+    let options = json!({ "max_cycles": 1 << 21 });
+    let params = json!({ "source": INFINITE_LOOP, "options": options });
+    assert_eq!(server.error_code("execute", params), -32602);
+
+    // jobs exceeding their cycle limit fail
+    let options = json!({ "max_cycles": 1 << 12 });
+    let id = server.submit("execute", json!({ "source": INFINITE_LOOP, "options": options }));
+    let job = server.wait_for(id, &["completed", "failed"]);
+    assert_eq!(job["status"], "failed");
+    assert!(job["error"].as_str().unwrap().contains("CycleLimitExceeded"), "{job}");
+
+    // occupy the only worker with a long-running job; the next job waits in the queue, and the
+    // queue does not accept any more jobs
+    let running = server.submit("execute", json!({ "source": INFINITE_LOOP }));
+    server.wait_for(running, &["running"]);
+    let queued = server.submit("execute", json!({ "source": INFINITE_LOOP }));
+    assert_eq!(server.result("get_job", json!({ "id": queued }))["status"], "queued");
+    assert_eq!(server.error_code("execute", json!({ "source": INFINITE_LOOP })), -32002);
+
+    // cancelling jobs
+    let cancelled = server.result("cancel_job", json!({ "id": queued }));
+    assert_eq!(cancelled["status"], "cancelled");
+    let cancelled = server.result("cancel_job", json!({ "id": running }));
+    assert_eq!(cancelled["status"], "cancelled");
+
+    // the results of a cancelled job are discarded, and the worker picks up new jobs
+    let id = server.submit("execute", json!({ "source": "begin push.1 end" }));
+    assert_eq!(server.wait_for(id, &["completed"])["status"], "completed");
+    assert_eq!(server.result("get_job", json!({ "id": running }))["status"], "cancelled");
+    assert!(server.result("get_job", json!({ "id": running }))["result"].is_null());
+    assert_eq!(server.error_code("get_job", json!({ "id": 1000 })), -32001);
+}
+
+#[test]
+fn serve_cancellation_interrupts_running_jobs() {
+    // without a cycle limit, the jobs below would occupy the only worker for hours
+    let server = Server::start(&["--workers", "1"]);
+
+    for method in ["execute", "prove"] {
+        let running = server.submit(method, json!({ "source": INFINITE_ADVICE_LOOP }));
+        server.wait_for(running, &["running"]);
+        let cancelled = server.result("cancel_job", json!({ "id": running }));
+        assert_eq!(cancelled["status"], "cancelled");
+
+        // the cancelled job is interrupted, and thus the worker picks up new jobs
+        let id = server.submit("execute", json!({ "source": "begin push.1 end" }));
+        assert_eq!(server.wait_for(id, &["completed"])["status"], "completed");
+        assert_eq!(server.result("get_job", json!({ "id": running }))["status"], "cancelled");
+    }
+}
+
+#[test]
+fn serve_connection_limit() {
+    let server = Server::start(&["--max-connections", "1"]);
+
+    // an idle connection occupies the only connection slot until it is closed
+    let idle = TcpStream::connect(&server.addr).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    // further connections are rejected without reading their requests
+    let mut response = String::new();
+    TcpStream::connect(&server.addr).unwrap().read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 503"), "{response}");
+
+    // once the connection is closed, new connections are handled again; until the server notices
+    // that, requests may also be reset before their rejection is read
+    drop(idle);
+    let start = Instant::now();
+    while !matches!(server.try_post("application/json", "{}"), Ok((200, _))) {
+        assert!(start.elapsed() < Duration::from_secs(10), "connection slot was not released");
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn serve_protocol_errors() {
+    let server = Server::start(&[]);
+
+    assert_eq!(server.error_code("compile", json!({ "source": "begin push end" })), -32000);
+    assert_eq!(server.error_code("compile", json!({ "src": "begin push.1 end" })), -32602);
+    assert_eq!(server.error_code("unknown", json!({})), -32601);
+
+    let (status, body) = server.post("application/json", "{");
+    assert_eq!(status, 200);
+    assert_eq!(serde_json::from_str::<Value>(&body).unwrap()["error"]["code"], -32700);
+
+    // notifications are not answered
+    let request = json!({ "jsonrpc": "2.0", "method": "compile", "params": { "source": "" } });
+    assert_eq!(server.post("application/json", &request.to_string()).0, 204);
+
+    // requests which are not declared to be JSON are rejected
+    let request = json!({ "jsonrpc": "2.0", "id": 1, "method": "compile", "params": {} });
+    assert_eq!(server.post("text/plain", &request.to_string()).0, 415);
+}
@@ -0,0 +1,46 @@
+use miden_vm::{
+    estimate, prove, Assembler, DefaultHost, MemAdviceProvider, ProvingOptions, StackInputs,
+};
+
+// PROOF ESTIMATE TESTS
+// ================================================================================================
+
+const SOURCE: &str = "begin repeat.100 push.1 add push.2 mul u32split drop end end";
+
+#[test]
+fn estimate_trace_dimensions() {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let options = ProvingOptions::default();
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let estimate = estimate(&program, StackInputs::default(), host, &options).unwrap();
+
+    assert_eq!(estimate.padded_trace_len(), estimate.trace_len_summary().padded_trace_len());
+    assert!(estimate.num_cycles() > 500);
+    assert!(estimate.main_trace_width() > estimate.aux_trace_width());
+    assert!(estimate.aux_trace_width() > 0);
+    assert_eq!(estimate.lde_domain_size(), estimate.padded_trace_len() * 8);
+    assert!(estimate.num_main_constraints() > 0);
+    assert!(estimate.num_aux_constraints() > 0);
+    assert!(estimate.prover_memory() > estimate.lde_domain_size());
+}
+
+#[test]
+fn estimate_proof_size() {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    for options in [
+        ProvingOptions::with_96_bit_security(false),
+        ProvingOptions::with_128_bit_security(false),
+    ] {
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        let estimate = estimate(&program, StackInputs::default(), host, &options).unwrap();
+
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        let (_, proof) = prove(&program, StackInputs::default(), host, options).unwrap();
+        let proof_size = proof.to_bytes().len();
+
+        // the estimate is an upper bound which should be within a small factor of the actual
+        // proof size
+        assert!(estimate.proof_size() >= proof_size);
+        assert!(estimate.proof_size() < 2 * proof_size);
+    }
+}
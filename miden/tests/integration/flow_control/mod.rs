@@ -2,7 +2,7 @@ use assembly::{Assembler, AssemblyContext, LibraryPath};
 use miden_vm::ModuleAst;
 use processor::ExecutionError;
 use stdlib::StdLibrary;
-use test_utils::{build_test, AdviceInputs, StackInputs, Test, TestError};
+use test_utils::{build_test, AdviceInputs, MemoryMode, StackInputs, Test, TestError};
 
 // SIMPLE FLOW CONTROL TESTS
 // ================================================================================================
@@ -208,6 +208,7 @@ fn simple_syscall() {
         advice_inputs: AdviceInputs::default(),
         in_debug_mode: false,
         libraries: Vec::default(),
+        memory_mode: MemoryMode::default(),
     };
     test.expect_stack(&[3]);
 
@@ -265,6 +266,7 @@ fn simple_dyn_exec() {
         advice_inputs: AdviceInputs::default(),
         in_debug_mode: false,
         libraries: Vec::default(),
+        memory_mode: MemoryMode::default(),
     };
 
     test.expect_stack(&[6]);
@@ -371,6 +373,7 @@ fn simple_dyncall() {
         advice_inputs: AdviceInputs::default(),
         in_debug_mode: false,
         libraries: Vec::default(),
+        memory_mode: MemoryMode::default(),
     };
 
     test.expect_stack(&[6]);
@@ -2,9 +2,16 @@ use test_utils::build_test;
 
 mod air;
 mod cli;
+mod estimate;
 mod exec_iters;
 mod flow_control;
+mod memory_report;
 mod operations;
+mod proof_report;
+mod recursion;
+mod storage;
+mod trace;
+mod witness;
 
 // TESTS
 // ================================================================================================
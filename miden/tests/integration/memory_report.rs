@@ -0,0 +1,124 @@
+use processor::{
+    ContextId, DefaultHost, ExecutionOptions, MemoryAccessKind, MemoryConventionViolation, FMP_MIN,
+};
+use test_utils::{build_debug_test, build_test, StackInputs};
+
+// MEMORY ACCESS REPORT TESTS
+// ================================================================================================
+
+#[test]
+fn uninitialized_reads() {
+    let source = "
+    begin
+        mem_load.5 drop
+        push.1 mem_store.6
+        mem_load.6 drop
+        mem_load.5 drop
+    end";
+
+    let test = build_debug_test!(source);
+    let report = test.execute_iter().memory_access_report().unwrap();
+
+    // only the first read of address 5 is reported; address 6 was written before it was read
+    let reads = report.uninitialized_reads();
+    assert_eq!(1, reads.len());
+    assert_eq!(ContextId::root(), reads[0].ctx);
+    assert_eq!(5, reads[0].addr);
+    assert_eq!(MemoryAccessKind::Read, reads[0].kind);
+
+    assert!(report.convention_violations().is_empty());
+    assert!(!report.is_clean());
+
+    let footprint = report.footprints()[&ContextId::root()];
+    assert_eq!(3, footprint.num_reads());
+    assert_eq!(1, footprint.num_writes());
+    assert_eq!(2, footprint.num_addresses());
+    assert_eq!(0, footprint.num_local_addresses());
+    assert_eq!(Some((5, 6)), footprint.addr_range());
+}
+
+#[test]
+fn unallocated_locals() {
+    // the local of `foo` is located at FMP_MIN + 1, and is accessed via an absolute address after
+    // `foo` returns
+    let source = format!(
+        "
+    proc.foo.1
+        loc_store.0
+    end
+
+    begin
+        push.1 exec.foo
+        mem_load.{} drop
+    end",
+        FMP_MIN + 1
+    );
+
+    let test = build_debug_test!(&source);
+    let report = test.execute_iter().memory_access_report().unwrap();
+    assert!(report.uninitialized_reads().is_empty());
+
+    let violations = report.convention_violations();
+    assert_eq!(1, violations.len());
+    let MemoryConventionViolation::UnallocatedLocal { access, fmp } = violations[0];
+    assert_eq!(FMP_MIN, fmp);
+    assert_eq!(FMP_MIN + 1, access.addr as u64);
+    assert_eq!(MemoryAccessKind::Read, access.kind);
+
+    let footprint = report.footprints()[&ContextId::root()];
+    assert_eq!(1, footprint.num_addresses());
+    assert_eq!(1, footprint.num_local_addresses());
+}
+
+#[test]
+fn context_footprints() {
+    let source = "
+    proc.foo
+        push.1 mem_store.0
+        push.2 mem_store.1
+    end
+
+    begin
+        push.3 mem_store.0
+        call.foo
+    end";
+
+    let test = build_debug_test!(source);
+    let report = test.execute_iter().memory_access_report().unwrap();
+    assert!(report.is_clean());
+
+    let footprints = report.footprints();
+    assert_eq!(2, footprints.len());
+
+    let root = footprints[&ContextId::root()];
+    assert_eq!((0, 1, 1), (root.num_reads(), root.num_writes(), root.num_addresses()));
+
+    let (_, callee) = footprints.iter().find(|(&ctx, _)| ctx != ContextId::root()).unwrap();
+    assert_eq!((0, 2, 2), (callee.num_reads(), callee.num_writes(), callee.num_addresses()));
+}
+
+#[test]
+fn memory_tracing_option() {
+    let test = build_test!("begin mem_load.1 drop end");
+    let program = test.compile().unwrap();
+
+    // memory accesses are not recorded by default
+    let trace = processor::execute(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+    assert!(trace.memory_access_report().is_none());
+
+    let trace = processor::execute(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default().with_memory_tracing(),
+    )
+    .unwrap();
+    let report = trace.memory_access_report().unwrap();
+    assert_eq!(1, report.uninitialized_reads().len());
+}
@@ -122,6 +122,67 @@ fn advice_push_u64div_conditional_execution() {
     test.expect_stack(&[0, 0, 0, 0, 0, 4, 0, 8]);
 }
 
+#[test]
+fn advice_push_u256montinv() {
+    // push the inverse of a onto the advice stack and then move it onto the operand stack.
+    let source = "begin adv.push_u256montinv dropw dropw dropw dropw adv_push.8 end";
+
+    // use the largest 32-bit prime as the modulus so that the expected result could be computed
+    // using native arithmetic
+    let m: u64 = 4294967291;
+    let a = rand_value::<u64>() % m;
+
+    // in Montgomery form with radix R = 2^256, the inverse of a is R^2 * a^-1 mod m
+    let r = pow_mod(2, 256, m);
+    let expected = r * r % m * pow_mod(a, m - 2, m) % m;
+
+    let test = build_test!(source, &[0, 0, 0, 0, 0, 0, 0, a, 0, 0, 0, 0, 0, 0, 0, m]);
+    test.expect_stack(&[expected, 0, 0, 0, 0, 0, 0, 0]);
+
+    // the inverse of zero is zero
+    let test = build_test!(source, &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, m]);
+    test.expect_stack(&[0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // the modulus must be odd
+    let test = build_test!(source, &[0, 0, 0, 0, 0, 0, 0, a, 0, 0, 0, 0, 0, 0, 0, m + 1]);
+    assert!(test.execute().is_err());
+}
+
+#[test]
+fn advice_push_u256montquot() {
+    // push the quotient onto the advice stack and then move it onto the operand stack.
+    let source = "begin adv.push_u256montquot dropw dropw dropw dropw adv_push.8 end";
+
+    // use the secp256k1 base field prime as the modulus and a random value as the lower half of
+    // the product
+    let m: [u64; 8] = [
+        4294966319, 4294967294, 4294967295, 4294967295, 4294967295, 4294967295, 4294967295,
+        4294967295,
+    ];
+    let p: [u64; 8] = core::array::from_fn(|_| rand_value::<u32>() as u64);
+
+    let mut inputs = p.iter().rev().chain(m.iter().rev()).copied().collect::<Vec<_>>();
+    let test = build_test!(source, &inputs);
+    let q = test.get_last_stack_state().map(|value| value.as_int());
+
+    // p + q * m must be divisible by 2^256
+    let mut result = p;
+    for i in 0..8 {
+        let mut carry = 0;
+        for j in 0..8 - i {
+            let sum = result[i + j] + q[i] * m[j] + carry;
+            result[i + j] = sum as u32 as u64;
+            carry = sum >> 32;
+        }
+    }
+    assert_eq!(result, [0; 8]);
+
+    // the modulus must be odd
+    inputs[15] -= 1;
+    let test = build_test!(source, &inputs);
+    assert!(test.execute().is_err());
+}
+
 #[test]
 fn advice_insert_mem() {
     let source = "begin
@@ -329,3 +390,18 @@ fn advice_insert_hdword() {
     let test = build_test!(source, &stack_inputs);
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn pow_mod(base: u64, exp: u64, m: u64) -> u64 {
+    let (base, m) = (base as u128, m as u128);
+    let mut result = 1u128;
+    for i in (0..64).rev() {
+        result = result * result % m;
+        if (exp >> i) & 1 == 1 {
+            result = result * base % m;
+        }
+    }
+    result as u64
+}
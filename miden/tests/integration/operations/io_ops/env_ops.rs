@@ -1,6 +1,6 @@
 use processor::FMP_MIN;
 use test_utils::{
-    build_op_test, build_test, AdviceInputs, StackInputs, Test, Word, STACK_TOP_SIZE,
+    build_op_test, build_test, AdviceInputs, MemoryMode, StackInputs, Test, Word, STACK_TOP_SIZE,
 };
 use vm_core::{code_blocks::CodeBlock, Operation};
 
@@ -150,6 +150,7 @@ fn caller() {
         advice_inputs: AdviceInputs::default(),
         in_debug_mode: false,
         libraries: Vec::default(),
+        memory_mode: MemoryMode::default(),
     };
     // top 4 elements should be overwritten with the hash of `bar` procedure, but the 5th
     // element should remain untouched
@@ -1,4 +1,7 @@
-use super::{apply_permutation, build_op_test, build_test, Felt, ToElements};
+use super::{
+    apply_permutation, build_op_test, build_test, ContextId, ExecutionError, Felt, MemoryMode,
+    TestError, ToElements,
+};
 
 // LOADING SINGLE ELEMENT ONTO THE STACK (MLOAD)
 // ================================================================================================
@@ -40,6 +43,223 @@ fn mem_store() {
     test.expect_stack_and_memory(&[3, 2, 1], addr, &[4, 0, 0, 0]);
 }
 
+// LOADING A SINGLE ELEMENT BY ELEMENT ADDRESS (MLOADE)
+// ================================================================================================
+
+#[test]
+fn mem_loade() {
+    // --- read from uninitialized memory - address provided via the stack ------------------------
+    let test = build_op_test!("mem_loade", &[6]);
+    test.expect_stack(&[0]);
+
+    // --- read from uninitialized memory - address provided as a parameter -----------------------
+    let test = build_op_test!("mem_loade.6", &[1, 2, 3, 4]);
+    test.expect_stack(&[0, 4, 3, 2, 1]);
+
+    // --- read each element of a word written to word address 1 (element addresses 4 - 7) --------
+    let source = "
+        begin
+            push.1 mem_storew dropw
+            mem_loade.4 mem_loade.5 push.6 mem_loade push.7 mem_loade
+        end";
+
+    let test = build_test!(source, &[1, 2, 3, 4]);
+    test.expect_stack(&[4, 3, 2, 1]);
+    test.prove_and_verify(vec![1, 2, 3, 4], false);
+}
+
+// SAVING A SINGLE ELEMENT BY ELEMENT ADDRESS (MSTOREE)
+// ================================================================================================
+
+#[test]
+fn mem_storee() {
+    let asm_op = "mem_storee";
+    // element address 6 is the third element of the word at word address 1
+    let addr = 6_u32;
+
+    // --- address provided via the stack ---------------------------------------------------------
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, addr as u64]);
+    test.expect_stack_and_memory(&[3, 2, 1], 1, &[0, 0, 4, 0]);
+
+    // --- address provided as a parameter --------------------------------------------------------
+    let asm_op = format!("{asm_op}.{addr}");
+    let test = build_op_test!(&asm_op, &[1, 2, 3, 4]);
+    test.expect_stack_and_memory(&[3, 2, 1], 1, &[0, 0, 4, 0]);
+
+    // --- the other elements of the word are not affected ----------------------------------------
+    let source = "
+        begin
+            push.1 mem_storew dropw
+            mem_storee.5 mem_storee.7
+        end";
+
+    let test = build_test!(source, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    test.expect_stack_and_memory(&[2, 1], 1, &[5, 4, 7, 3]);
+    test.prove_and_verify(vec![1, 2, 3, 4, 5, 6, 7, 8], false);
+}
+
+// ELEMENT MEMORY MODE
+// ================================================================================================
+
+#[test]
+fn mem_load_store_element_mode() {
+    // in element memory mode, mem_load and mem_store take element addresses; mem_loadw and
+    // mem_storew still take word addresses.
+    let source = "
+        begin
+            push.1 mem_storew dropw
+            mem_store.6
+            mem_load.4 mem_load.5 mem_load.6 mem_load.7
+        end";
+
+    let mut test = build_test!(source, &[1, 2, 3, 4, 5]);
+    test.memory_mode = MemoryMode::Element;
+    test.expect_stack_and_memory(&[5, 1, 3, 2], 1, &[2, 3, 1, 5]);
+
+    // in the default word memory mode, the same program accesses the first elements of words.
+    let test = build_test!(source, &[1, 2, 3, 4, 5]);
+    test.expect_stack_and_memory(&[0, 1, 0, 0], 6, &[1, 0, 0, 0]);
+}
+
+// BORROWED MEMORY
+// ================================================================================================
+
+#[test]
+fn mem_loadw_borrowed() {
+    let source = "
+        proc.foo
+            # the lent words are not visible through the memory of the callee
+            padw mem_loadw.2 dropw
+
+            padw mem_loadw_borrowed.2
+            padw push.3 mem_loadw_borrowed
+            swapdw dropw dropw
+        end
+
+        begin
+            push.1.2.3.4 mem_storew.2 dropw
+            push.5.6.7.8 mem_storew.3 dropw
+            push.4.2 mem_lend
+            call.foo
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[8, 7, 6, 5, 4, 3, 2, 1]);
+    test.prove_and_verify(vec![], false);
+}
+
+#[test]
+fn mem_loadw_borrowed_fails() {
+    // --- address outside of the lent segment ---------------------------------------------------
+    let source = "
+        proc.foo
+            padw mem_loadw_borrowed.4 dropw
+        end
+
+        begin
+            push.4.2 mem_lend
+            call.foo
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_error(TestError::ExecutionError(
+        ExecutionError::BorrowedMemoryAddressOutOfBounds {
+            addr: 4,
+            start_addr: 2,
+            end_addr: 4,
+        },
+    ));
+
+    // --- no segment was lent to the current context --------------------------------------------
+    let test = build_test!("begin padw mem_loadw_borrowed.0 dropw end", &[]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::BorrowedMemoryNotAvailable(
+        ContextId::root(),
+    )));
+
+    // --- the segment was revoked by lending an empty segment ------------------------------------
+    let source = "
+        proc.foo
+            padw mem_loadw_borrowed.2 dropw
+        end
+
+        begin
+            push.4.2 mem_lend
+            call.foo
+            push.0.0 mem_lend
+            call.foo
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_error(TestError::ExecutionError(
+        ExecutionError::BorrowedMemoryAddressOutOfBounds {
+            addr: 2,
+            start_addr: 0,
+            end_addr: 0,
+        },
+    ));
+
+    // --- invalid segment bounds ----------------------------------------------------------------
+    let test = build_test!("begin push.2.4 mem_lend end", &[]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::InvalidMemoryRange {
+        start_addr: 4,
+        end_addr: 2,
+    }));
+
+    // --- the segment contains the addresses of the lending records -----------------------------
+    let test = build_test!("begin push.4294967295.2 mem_lend end", &[]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::InvalidMemoryRange {
+        start_addr: 2,
+        end_addr: 4294967295,
+    }));
+}
+
+#[test]
+fn mem_loadw_borrowed_multiple_calls() {
+    // the lent segment stays available to all contexts called by the lender, but it is not passed
+    // on to the contexts called by the borrowers
+    let source = "
+        proc.bar
+            push.9.9.9.9 mem_storew.2 dropw
+        end
+
+        proc.foo
+            padw mem_loadw_borrowed.2
+            call.bar
+            swapw dropw
+        end
+
+        begin
+            push.1.2.3.4 mem_storew.2 dropw
+            push.3.2 mem_lend
+            call.foo dropw
+            call.foo
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[4, 3, 2, 1]);
+    test.prove_and_verify(vec![], false);
+
+    let source = "
+        proc.bar
+            padw mem_loadw_borrowed.2 dropw
+        end
+
+        proc.foo
+            call.bar
+        end
+
+        begin
+            push.3.2 mem_lend
+            call.foo
+        end";
+
+    let test = build_test!(source, &[]);
+    // the context of `bar` is created at clock cycle 10
+    test.expect_error(TestError::ExecutionError(ExecutionError::BorrowedMemoryNotAvailable(
+        ContextId::from(11),
+    )));
+}
+
 // LOADING A WORD FROM MEMORY (MLOADW)
 // ================================================================================================
 
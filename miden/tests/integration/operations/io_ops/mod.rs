@@ -1,4 +1,5 @@
-use test_utils::{build_op_test, build_test, Felt, TestError, ToElements};
+use processor::{ContextId, ExecutionError};
+use test_utils::{build_op_test, build_test, Felt, MemoryMode, TestError, ToElements};
 use vm_core::chiplets::hasher::apply_permutation;
 
 mod adv_ops;
@@ -216,9 +216,24 @@ fn u32shl() {
     let test = build_op_test!(asm_op, &[a as u64, b as u64]);
     test.expect_stack(&[a.wrapping_shl(b) as u64]);
 
-    // --- test out of bounds input (should not fail) --------------------------------------------
+    // --- test b = 32 ----------------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let test = build_op_test!(asm_op, &[a as u64, 32]);
+    test.expect_stack(&[0]);
+}
+
+#[test]
+fn u32shl_fail() {
+    let asm_op = "u32shl";
+
     let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
-    assert!(test.execute().is_ok());
+    test.expect_error(TestError::ExecutionError(ExecutionError::NotU32Value(
+        Felt::new(U32_BOUND),
+        ZERO,
+    )));
+
+    let test = build_op_test!(asm_op, &[1, 33]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::InvalidShiftAmount(Felt::new(33))));
 }
 
 #[test]
@@ -292,9 +307,24 @@ fn u32shr() {
     let test = build_op_test!(asm_op, &[a as u64, b as u64]);
     test.expect_stack(&[a.wrapping_shr(b) as u64]);
 
-    // --- test out of bounds inputs (should not fail) --------------------------------------------
+    // --- test b = 32 ----------------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let test = build_op_test!(asm_op, &[a as u64, 32]);
+    test.expect_stack(&[0]);
+}
+
+#[test]
+fn u32shr_fail() {
+    let asm_op = "u32shr";
+
     let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
-    assert!(test.execute().is_ok());
+    test.expect_error(TestError::ExecutionError(ExecutionError::NotU32Value(
+        Felt::new(U32_BOUND),
+        ZERO,
+    )));
+
+    let test = build_op_test!(asm_op, &[1, 33]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::InvalidShiftAmount(Felt::new(33))));
 }
 
 #[test]
@@ -379,9 +409,24 @@ fn u32rotl() {
     let test = build_op_test!(asm_op, &[a as u64, b as u64]);
     test.expect_stack(&[a.rotate_left(b) as u64]);
 
-    // --- test out of bounds inputs (should not fail) --------------------------------------------
+    // --- test b = 32 ----------------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let test = build_op_test!(asm_op, &[a as u64, 32]);
+    test.expect_stack(&[a as u64]);
+}
+
+#[test]
+fn u32rotl_fail() {
+    let asm_op = "u32rotl";
+
     let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
-    assert!(test.execute().is_ok());
+    test.expect_error(TestError::ExecutionError(ExecutionError::NotU32Value(
+        Felt::new(U32_BOUND),
+        ZERO,
+    )));
+
+    let test = build_op_test!(asm_op, &[1, 33]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::InvalidShiftAmount(Felt::new(33))));
 }
 
 #[test]
@@ -427,9 +472,24 @@ fn u32rotr() {
     let test = build_op_test!(asm_op, &[a as u64, b as u64]);
     test.expect_stack(&[a.rotate_right(b) as u64]);
 
-    // --- test out of bounds inputs (should not fail) --------------------------------------------
+    // --- test b = 32 ----------------------------------------------------------------------------
+    let a = rand_value::<u32>();
+    let test = build_op_test!(asm_op, &[a as u64, 32]);
+    test.expect_stack(&[a as u64]);
+}
+
+#[test]
+fn u32rotr_fail() {
+    let asm_op = "u32rotr";
+
     let test = build_op_test!(asm_op, &[U32_BOUND, 1]);
-    assert!(test.execute().is_ok());
+    test.expect_error(TestError::ExecutionError(ExecutionError::NotU32Value(
+        Felt::new(U32_BOUND),
+        ZERO,
+    )));
+
+    let test = build_op_test!(asm_op, &[1, 33]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::InvalidShiftAmount(Felt::new(33))));
 }
 
 #[test]
@@ -548,6 +608,16 @@ proptest! {
         test.prop_expect_stack(&[c as u64])?;
     }
 
+    #[test]
+    fn u32shr_proptest(a in any::<u32>(), b in 0_u32..32) {
+        let asm_opcode = "u32shr";
+
+        // should execute right shift
+        let c = a.wrapping_shr(b);
+        let test = build_op_test!(asm_opcode, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[c as u64])?;
+    }
+
     #[test]
     fn u32rotl_proptest(a in any::<u32>(), b in 0_u32..32) {
         let asm_opcode = "u32rotl";
@@ -0,0 +1,82 @@
+use miden_vm::{
+    estimate, prove, Assembler, DefaultHost, ExecutionProof, FieldExtension, HashFunction,
+    MemAdviceProvider, ProvingOptions, StackInputs,
+};
+
+// PROOF REPORT TESTS
+// ================================================================================================
+
+const SOURCE: &str = "begin repeat.100 push.1 add push.2 mul u32split drop end end";
+
+#[test]
+fn inspect_proof() {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    for (options, hash_fn, digest_len, field_extension) in [
+        (
+            ProvingOptions::with_96_bit_security(false),
+            HashFunction::Blake3_192,
+            24,
+            FieldExtension::Quadratic,
+        ),
+        (
+            ProvingOptions::with_128_bit_security(false),
+            HashFunction::Blake3_256,
+            32,
+            FieldExtension::Cubic,
+        ),
+        (
+            ProvingOptions::with_96_bit_security(true),
+            HashFunction::Rpo256,
+            32,
+            FieldExtension::Quadratic,
+        ),
+    ] {
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        let estimate = estimate(&program, StackInputs::default(), host, &options).unwrap();
+
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        let (_, proof) = prove(&program, StackInputs::default(), host, options).unwrap();
+        let report = proof.inspect().unwrap();
+
+        // options and trace dimensions
+        assert_eq!(report.hash_fn(), hash_fn);
+        assert_eq!(report.field_extension(), field_extension);
+        assert!(report.num_unique_queries() <= report.num_queries());
+        assert_eq!(report.trace_length(), estimate.padded_trace_len());
+        assert_eq!(report.main_trace_width(), estimate.main_trace_width());
+        assert_eq!(report.aux_segment_widths().iter().sum::<usize>(), estimate.aux_trace_width());
+        assert_eq!(report.lde_domain_size(), estimate.lde_domain_size());
+        assert_eq!(report.lde_domain_size(), report.trace_length() * report.blowup_factor());
+
+        // commitments
+        assert_eq!(report.num_fri_layers(), estimate.num_fri_layers());
+        assert_eq!(report.fri_layer_roots().len(), report.num_fri_layers());
+        assert_eq!(report.trace_roots().len(), 2);
+        for root in report.trace_roots().iter().chain(report.fri_layer_roots()) {
+            assert_eq!(root.len(), digest_len);
+        }
+        assert_eq!(report.constraint_root().len(), digest_len);
+        assert_eq!(report.fri_remainder_root().len(), digest_len);
+        assert!(report.num_fri_remainder_elements() <= report.fri_remainder_max_degree() + 1);
+
+        // the sizes of the components add up to the size of the proof, together with the hash
+        // function identifier, the number of unique queries, and the proof-of-work nonce
+        let sizes = report.sizes();
+        let components = sizes.context()
+            + sizes.commitments()
+            + sizes.trace_queries().iter().sum::<usize>()
+            + sizes.constraint_queries()
+            + sizes.ood_frame()
+            + sizes.fri_proof();
+        assert_eq!(sizes.total(), proof.to_bytes().len());
+        assert_eq!(sizes.total(), components + 2 + 8);
+
+        // security
+        assert_eq!(report.conjectured_security(), proof.security_level());
+        assert!(report.proven_security() < report.conjectured_security());
+
+        // deserialized proofs are described in the same way
+        let proof = ExecutionProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert_eq!(proof.inspect().unwrap(), report);
+    }
+}
@@ -0,0 +1,115 @@
+use miden_vm::{
+    execute, prove,
+    recursion::{generate_advice_inputs, RecursionError},
+    AdviceInputs, Assembler, DefaultHost, ExecutionProof, FieldExtension, HashFunction,
+    MemAdviceProvider, ProgramInfo, ProvingOptions, StackInputs, StackOutputs,
+};
+use processor::ExecutionOptions;
+use stdlib::StdLibrary;
+
+const FIB_SOURCE: &str = "begin repeat.32 swap dup.1 add end end";
+
+/// A statement proven by an execution proof.
+#[derive(Clone)]
+struct Statement {
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+}
+
+impl Statement {
+    fn generate_advice_inputs(
+        self,
+        proof: ExecutionProof,
+    ) -> Result<(StackInputs, AdviceInputs), RecursionError> {
+        generate_advice_inputs(proof, self.program_info, self.stack_inputs, self.stack_outputs)
+    }
+}
+
+/// Proves execution of a small Fibonacci program against the specified initial values and returns
+/// the proof together with the statement it proves.
+fn prove_fib(a: u64, b: u64, options: ProvingOptions) -> (Statement, ExecutionProof) {
+    let program = Assembler::default().compile(FIB_SOURCE).unwrap();
+    let mut inputs = vec![0_u64; 16];
+    inputs[14] = a;
+    inputs[15] = b;
+    let stack_inputs = StackInputs::try_from_ints(inputs).unwrap();
+
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let (stack_outputs, proof) = prove(&program, stack_inputs.clone(), host, options).unwrap();
+
+    let statement = Statement {
+        program_info: ProgramInfo::from(program),
+        stack_inputs,
+        stack_outputs,
+    };
+    (statement, proof)
+}
+
+/// Executes the recursive verifier from the standard library against the specified inputs.
+fn run_verifier(statement: Statement, proof: ExecutionProof) -> Result<(), String> {
+    let (stack_inputs, advice_inputs) =
+        statement.generate_advice_inputs(proof).map_err(|err| err.to_string())?;
+
+    let program = Assembler::default()
+        .with_library(&StdLibrary::default())
+        .unwrap()
+        .compile("use.std::crypto::stark::verifier begin exec.verifier::verify end")
+        .unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    execute(&program, stack_inputs, host, ExecutionOptions::default())
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+// ADVICE INPUT GENERATION TESTS
+// ================================================================================================
+
+#[test]
+fn generate_advice_inputs_for_recursive_verifier() {
+    let (statement, proof) = prove_fib(0, 1, ProvingOptions::with_96_bit_security(true));
+    let log_trace_len = proof.stark_proof().get_trace_info().length().ilog2() as u64;
+
+    let (stack_inputs, _) = statement.clone().generate_advice_inputs(proof.clone()).unwrap();
+    let expected = StackInputs::try_from_ints([16, 3, 27, log_trace_len]).unwrap();
+    assert_eq!(stack_inputs.values(), expected.values());
+
+    assert!(run_verifier(statement.clone(), proof.clone()).is_ok());
+
+    // the verifier must reject the proof if the claimed outputs are different
+    let mut outputs = statement.stack_outputs.stack().to_vec();
+    outputs[0] += miden_vm::math::Felt::new(1);
+    let statement = Statement {
+        stack_outputs: StackOutputs::new(outputs, Vec::new()).unwrap(),
+        ..statement
+    };
+    assert!(run_verifier(statement, proof).is_err());
+}
+
+#[test]
+fn generate_advice_inputs_rejects_incompatible_proofs() {
+    let generate =
+        |(statement, proof): (Statement, ExecutionProof)| statement.generate_advice_inputs(proof);
+
+    let proof = prove_fib(0, 1, ProvingOptions::with_96_bit_security(false));
+    assert!(matches!(generate(proof), Err(RecursionError::UnsupportedHashFunction(_))));
+
+    let options = ProvingOptions::new(27, 8, 16, FieldExtension::Cubic, 4, 7, HashFunction::Rpo256);
+    let proof = prove_fib(0, 1, options);
+    assert!(matches!(generate(proof), Err(RecursionError::UnsupportedProofOptions(_))));
+
+    let options =
+        ProvingOptions::new(27, 8, 16, FieldExtension::Quadratic, 8, 7, HashFunction::Rpo256);
+    let proof = prove_fib(0, 1, options);
+    assert!(matches!(generate(proof), Err(RecursionError::UnsupportedProofOptions(_))));
+
+    let (statement, proof) = prove_fib(0, 1, ProvingOptions::with_96_bit_security(true));
+    let statement = Statement {
+        stack_inputs: StackInputs::try_from_ints([0, 1]).unwrap(),
+        ..statement
+    };
+    assert!(matches!(
+        generate((statement, proof)),
+        Err(RecursionError::InvalidNumStackInputs(2))
+    ));
+}
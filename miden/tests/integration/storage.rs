@@ -0,0 +1,54 @@
+use miden_vm::{
+    prove, prove_with_storage, verify, Assembler, DefaultHost, ExecutionError, MemAdviceProvider,
+    ProgramInfo, ProvingOptions, StackInputs, TraceStorage,
+};
+
+// PROVING WITH TRACE STORAGE TESTS
+// ================================================================================================
+
+const SOURCE: &str = "begin repeat.100 push.1 add push.2 mul u32split drop end end";
+
+#[test]
+fn prove_with_storage_matches_prove() {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let dir = std::env::temp_dir().join("miden-prove-with-storage-test");
+
+    for options in [
+        ProvingOptions::with_96_bit_security(false),
+        ProvingOptions::with_128_bit_security(false),
+        ProvingOptions::with_96_bit_security(true),
+    ] {
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        let (expected_outputs, expected_proof) =
+            prove(&program, StackInputs::default(), host, options.clone()).unwrap();
+
+        let host = DefaultHost::new(MemAdviceProvider::default());
+        let storage = TraceStorage::new(&dir).unwrap();
+        let (outputs, proof) =
+            prove_with_storage(&program, StackInputs::default(), host, options, storage).unwrap();
+
+        assert_eq!(expected_outputs, outputs);
+        assert_eq!(expected_proof.to_bytes(), proof.to_bytes());
+
+        let program_info = ProgramInfo::from(program.clone());
+        assert!(verify(program_info, StackInputs::default(), outputs, proof).is_ok());
+    }
+
+    // all trace files are removed once the proofs are generated
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+}
+
+#[test]
+fn prove_with_storage_fails_on_storage_errors() {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let dir = std::env::temp_dir().join("miden-prove-with-storage-error-test");
+
+    // the directory of the storage disappears before the proof is generated
+    let storage = TraceStorage::new(&dir).unwrap();
+    std::fs::remove_dir(&dir).unwrap();
+
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let options = ProvingOptions::with_96_bit_security(false);
+    let result = prove_with_storage(&program, StackInputs::default(), host, options, storage);
+    assert!(matches!(result, Err(ExecutionError::TraceStorageFailed(_))), "{result:?}");
+}
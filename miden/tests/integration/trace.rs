@@ -0,0 +1,41 @@
+use miden_vm::{
+    execute, prove, prove_trace, utils::Deserializable, utils::Serializable, verify, Assembler,
+    DefaultHost, ExecutionTrace, MemAdviceProvider, ProgramInfo, ProvingOptions, StackInputs,
+};
+use processor::ExecutionOptions;
+
+// OFFLINE PROVING TESTS
+// ================================================================================================
+
+const SOURCE: &str = "
+    begin
+        repeat.10
+            push.1 add u32split drop dup.5 push.2 mul u32assert2 swap.2
+        end
+        push.1000 mem_store.0 mem_load.0 add
+    end";
+
+#[test]
+fn prove_deserialized_trace() {
+    let program = Assembler::default().compile(SOURCE).unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1, 2, 3, 4]).unwrap();
+    let options = ProvingOptions::default();
+
+    // execute the program and serialize the resulting trace
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let trace = execute(&program, stack_inputs.clone(), host, ExecutionOptions::default()).unwrap();
+    let bytes = trace.to_bytes();
+
+    // prove the deserialized trace; the proof must be the same as the one generated directly
+    let trace = ExecutionTrace::read_from_bytes(&bytes).unwrap();
+    let (outputs, proof) = prove_trace(trace, stack_inputs.clone(), options.clone()).unwrap();
+
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let (expected_outputs, expected_proof) =
+        prove(&program, stack_inputs.clone(), host, options).unwrap();
+    assert_eq!(expected_outputs, outputs);
+    assert_eq!(expected_proof.to_bytes(), proof.to_bytes());
+
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, stack_inputs, outputs, proof).is_ok());
+}
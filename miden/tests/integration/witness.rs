@@ -0,0 +1,91 @@
+use miden_vm::{
+    crypto::{MerkleStore, MerkleTree, NodeIndex, Rpo256},
+    execute,
+    math::Felt,
+    prove, record_witness, verify, AdviceInputs, Assembler, DefaultHost, MemAdviceProvider,
+    ProgramInfo, ProvingOptions, StackInputs, Word,
+};
+use processor::ExecutionOptions;
+use std::collections::BTreeMap;
+
+// WITNESS RECORDING TESTS
+// ================================================================================================
+
+#[test]
+fn record_witness_keeps_accessed_inputs() {
+    let tree = MerkleTree::new(leaves(0..8)).unwrap();
+    let unused_tree = MerkleTree::new(leaves(8..12)).unwrap();
+    let mut store = MerkleStore::default();
+    store.extend(tree.inner_nodes());
+    store.extend(unused_tree.inner_nodes());
+
+    let key = Rpo256::hash_elements(&[Felt::new(1)]);
+    let unused_key = Rpo256::hash_elements(&[Felt::new(2)]);
+    let advice_inputs = AdviceInputs::default()
+        .with_stack_values([1, 2, 3])
+        .unwrap()
+        .with_map([(key, vec![Felt::new(5), Felt::new(6)]), (unused_key, vec![Felt::new(7)])])
+        .with_merkle_store(store);
+
+    // the program consumes one element of the advice stack, one advice map entry, and one leaf of
+    // the first tree
+    let source = format!(
+        "begin
+            adv_push.1
+            push.{key} adv.push_mapval dropw adv_push.2
+            push.{root} push.5.3 mtree_get
+        end",
+        key = String::from(&key),
+        root = String::from(&tree.root()),
+    );
+    let program = Assembler::default().compile(source).unwrap();
+
+    let (trace, witness) = record_witness(
+        &program,
+        StackInputs::default(),
+        advice_inputs.clone(),
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+
+    let expected_trace = execute(
+        &program,
+        StackInputs::default(),
+        DefaultHost::new(MemAdviceProvider::from(advice_inputs)),
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(expected_trace.stack_outputs(), trace.stack_outputs());
+
+    // the witness contains only the accessed inputs
+    assert_eq!(&[Felt::new(1)], witness.stack());
+    assert_eq!(Some(&[Felt::new(5), Felt::new(6)][..]), witness.mapped_values(&key));
+    assert_eq!(None, witness.mapped_values(&unused_key));
+    let witness_store = witness.merkle_store();
+    // only the nodes along the path to the leaf are included in addition to the empty subtree
+    // roots which every Merkle store contains
+    let num_empty_nodes = MerkleStore::<BTreeMap<_, _>>::default().num_internal_nodes();
+    assert_eq!(num_empty_nodes + 3, witness_store.num_internal_nodes());
+    assert_eq!(
+        tree.get_node(NodeIndex::new(3, 5).unwrap()).unwrap(),
+        witness_store.get_node(tree.root(), NodeIndex::new(3, 5).unwrap()).unwrap()
+    );
+    assert!(witness_store.get_node(unused_tree.root(), NodeIndex::root()).is_err());
+
+    // the execution can be proven using only the witness
+    let host = DefaultHost::new(MemAdviceProvider::from(witness));
+    let (outputs, proof) =
+        prove(&program, StackInputs::default(), host, ProvingOptions::default()).unwrap();
+    assert_eq!(trace.stack_outputs(), &outputs);
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, StackInputs::default(), outputs, proof).is_ok());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn leaves(range: core::ops::Range<u64>) -> Vec<Word> {
+    range
+        .map(|i| [Felt::new(i), Felt::new(0), Felt::new(0), Felt::new(0)])
+        .collect()
+}
@@ -1,4 +1,8 @@
 use super::{super::trace::AuxColumnBuilder, Felt, FieldElement};
+use crate::{
+    system::{LEND_CALLEE_ADDR, LEND_SEGMENT_ADDR},
+    utils::join,
+};
 
 use alloc::vec::Vec;
 use miden_air::trace::{
@@ -31,10 +35,17 @@ const RESPAN: u8 = Operation::Respan.op_code();
 const END: u8 = Operation::End.op_code();
 const AND: u8 = Operation::U32and.op_code();
 const XOR: u8 = Operation::U32xor.op_code();
+const POPCNT: u8 = Operation::U32popcnt.op_code();
+const SHL: u8 = Operation::U32shl.op_code();
+const SHR: u8 = Operation::U32shr.op_code();
 const MLOADW: u8 = Operation::MLoadW.op_code();
 const MSTOREW: u8 = Operation::MStoreW.op_code();
 const MLOAD: u8 = Operation::MLoad.op_code();
 const MSTORE: u8 = Operation::MStore.op_code();
+const MLOADE: u8 = Operation::MLoadE.op_code();
+const MSTOREE: u8 = Operation::MStoreE.op_code();
+const MLEND: u8 = Operation::MLend.op_code();
+const MLOADWB: u8 = Operation::MLoadWB.op_code();
 const MSTREAM: u8 = Operation::MStream.op_code();
 const RCOMBBASE: u8 = Operation::RCombBase.op_code();
 const HPERM: u8 = Operation::HPerm.op_code();
@@ -53,9 +64,11 @@ impl AuxTraceBuilder {
     // COLUMN TRACE CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
-    /// Builds and returns the Chiplets's auxiliary trace columns. Currently this consists of
-    /// a single bus column `b_chip` describing chiplet lookups requested by the stack and
-    /// provided by chiplets in the Chiplets module.
+    /// Builds and returns the Chiplets's auxiliary trace columns. This consists of the chiplets
+    /// virtual table column `t_chip`, the bus column `b_chip` describing chiplet lookups requested
+    /// by the stack and provided by chiplets in the Chiplets module, and the kernel ROM bus column
+    /// `b_krom` describing kernel procedure calls requested by the decoder and provided by the
+    /// kernel ROM chiplet.
     pub fn build_aux_columns<E: FieldElement<BaseField = Felt>>(
         &self,
         main_trace: &MainTrace,
@@ -63,9 +76,17 @@ impl AuxTraceBuilder {
     ) -> Vec<Vec<E>> {
         let v_table_col_builder = ChipletsVTableColBuilder::default();
         let bus_col_builder = BusColumnBuilder::default();
-        let t_chip = v_table_col_builder.build_aux_column(main_trace, rand_elements);
-        let b_chip = bus_col_builder.build_aux_column(main_trace, rand_elements);
-        vec![t_chip, b_chip]
+        let kernel_rom_bus_col_builder = KernelRomBusColumnBuilder::default();
+        let ((t_chip, b_chip), b_krom) = join(
+            || {
+                join(
+                    || v_table_col_builder.build_aux_column(main_trace, rand_elements),
+                    || bus_col_builder.build_aux_column(main_trace, rand_elements),
+                )
+            },
+            || kernel_rom_bus_col_builder.build_aux_column(main_trace, rand_elements),
+        );
+        vec![t_chip, b_chip, b_krom]
     }
 }
 
@@ -86,19 +107,26 @@ impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for BusColumnBuilder
         let op_code = op_code_felt.as_int() as u8;
 
         match op_code {
-            JOIN | SPLIT | LOOP | DYN | CALL => {
+            JOIN | SPLIT | LOOP | DYN | SYSCALL => {
                 build_control_block_request(main_trace, op_code_felt, alphas, row)
             }
-            SYSCALL => build_syscall_block_request(main_trace, op_code_felt, alphas, row),
+            CALL => build_call_request(main_trace, op_code_felt, alphas, row),
             SPAN => build_span_block_request(main_trace, alphas, row),
             RESPAN => build_respan_block_request(main_trace, alphas, row),
             END => build_end_block_request(main_trace, alphas, row),
             AND => build_bitwise_request(main_trace, ZERO, alphas, row),
             XOR => build_bitwise_request(main_trace, ONE, alphas, row),
+            POPCNT => build_bitwise_popcnt_request(main_trace, alphas, row),
+            SHL => build_bitwise_mask_request(main_trace, true, alphas, row),
+            SHR => build_bitwise_mask_request(main_trace, false, alphas, row),
             MLOADW => build_mem_request_word(main_trace, MEMORY_READ_LABEL, alphas, row),
             MSTOREW => build_mem_request_word(main_trace, MEMORY_WRITE_LABEL, alphas, row),
             MLOAD => build_mem_request_element(main_trace, MEMORY_READ_LABEL, alphas, row),
             MSTORE => build_mem_request_element(main_trace, MEMORY_WRITE_LABEL, alphas, row),
+            MLOADE => build_mem_request_element_addr(main_trace, MEMORY_READ_LABEL, alphas, row),
+            MSTOREE => build_mem_request_element_addr(main_trace, MEMORY_WRITE_LABEL, alphas, row),
+            MLEND => build_mem_request_lend(main_trace, alphas, row),
+            MLOADWB => build_mem_request_borrowed_word(main_trace, alphas, row),
             MSTREAM => build_mstream_request(main_trace, alphas, row),
             RCOMBBASE => build_rcomb_base_request(main_trace, alphas, row),
             HPERM => build_hperm_request(main_trace, alphas, row),
@@ -117,28 +145,49 @@ impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for BusColumnBuilder
         let selector1 = main_trace.chiplet_selector_1(row);
         let selector2 = main_trace.chiplet_selector_2(row);
         let selector3 = main_trace.chiplet_selector_3(row);
-        let selector4 = main_trace.chiplet_selector_4(row);
 
         if selector0 == ZERO {
             build_hasher_chiplet_responses(main_trace, row, alphas, selector1, selector2, selector3)
         } else if selector1 == ZERO {
             debug_assert_eq!(selector0, ONE);
-            build_bitwise_chiplet_responses(main_trace, row, selector2, alphas)
+            build_bitwise_chiplet_responses(main_trace, row, selector2, selector3, alphas)
         } else if selector2 == ZERO {
             debug_assert_eq!(selector0, ONE);
             debug_assert_eq!(selector1, ONE);
             build_memory_chiplet_responses(main_trace, row, selector3, alphas)
-        } else if selector3 == ZERO {
+        } else {
+            // the kernel ROM chiplet provides its responses via the kernel ROM bus.
             debug_assert_eq!(selector0, ONE);
             debug_assert_eq!(selector1, ONE);
             debug_assert_eq!(selector2, ONE);
+            E::ONE
+        }
+    }
+}
+
+// KERNEL ROM BUS TRACE BUILDER
+// ================================================================================================
+
+/// Describes how to construct the execution trace of the kernel ROM bus auxiliary trace column.
+#[derive(Default)]
+pub struct KernelRomBusColumnBuilder {}
+
+impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for KernelRomBusColumnBuilder {
+    /// Constructs the kernel procedure requests made by the decoder at row i.
+    fn get_requests_at(&self, main_trace: &MainTrace, alphas: &[E], row: usize) -> E {
+        if main_trace.get_op_code(row).as_int() as u8 == SYSCALL {
+            build_syscall_kernel_request(main_trace, alphas, row)
+        } else {
+            E::ONE
+        }
+    }
+
+    /// Constructs the kernel procedure responses provided by the kernel ROM chiplet at row i.
+    fn get_responses_at(&self, main_trace: &MainTrace, alphas: &[E], row: usize) -> E {
+        if main_trace.is_kernel_row(row) {
+            let selector4 = main_trace.chiplet_selector_4(row);
             build_kernel_chiplet_responses(main_trace, row, selector4, alphas)
-                * build_kernel_procedure_table_responses(main_trace, row, alphas)
         } else {
-            debug_assert_eq!(selector0, ONE);
-            debug_assert_eq!(selector1, ONE);
-            debug_assert_eq!(selector2, ONE);
-            debug_assert_eq!(selector3, ONE);
             E::ONE
         }
     }
@@ -243,13 +292,19 @@ where
 }
 
 /// Constructs the inclusions to the kernel procedure table.
+///
+/// Each procedure is included in the last row of its section of the kernel ROM trace, i.e., when
+/// the procedure index changes in the next row or the next row is not a part of the kernel ROM.
 fn chiplets_kernel_table_include<E>(main_trace: &MainTrace, alphas: &[E], row: usize) -> E
 where
     E: FieldElement<BaseField = Felt>,
 {
-    if main_trace.is_kernel_row(row) && main_trace.is_addr_change(row) {
+    if main_trace.is_kernel_row(row)
+        && (!main_trace.is_kernel_row(row + 1)
+            || main_trace.chiplet_kernel_addr(row) != main_trace.chiplet_kernel_addr(row + 1))
+    {
         alphas[0]
-            + alphas[1].mul_base(main_trace.addr(row))
+            + alphas[1].mul_base(main_trace.chiplet_kernel_addr(row))
             + alphas[2].mul_base(main_trace.chiplet_kernel_root_0(row))
             + alphas[3].mul_base(main_trace.chiplet_kernel_root_1(row))
             + alphas[4].mul_base(main_trace.chiplet_kernel_root_2(row))
@@ -282,25 +337,39 @@ fn build_control_block_request<E: FieldElement<BaseField = Felt>>(
     header + build_value(&alphas[8..16], &state) + alphas[5].mul_base(op_code_felt)
 }
 
-/// Builds requests made to kernel ROM chiplet when initializing a syscall block.
-fn build_syscall_block_request<E: FieldElement<BaseField = Felt>>(
+/// Builds requests made at the start of a CALL block. In addition to the request made to the
+/// hasher chiplet, the ID of the new context is written into the memory of the calling context
+/// at address LEND_CALLEE_ADDR.
+fn build_call_request<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
     op_code_felt: Felt,
     alphas: &[E],
     row: usize,
 ) -> E {
-    let factor1 = build_control_block_request(main_trace, op_code_felt, alphas, row);
+    let control_block_request = build_control_block_request(main_trace, op_code_felt, alphas, row);
 
+    let callee = [main_trace.ctx(row + 1), ZERO, ZERO, ZERO];
+    let addr = Felt::from(LEND_CALLEE_ADDR);
+
+    control_block_request
+        * compute_memory_request(main_trace, MEMORY_WRITE_LABEL, alphas, row, addr, callee)
+}
+
+/// Builds requests made to kernel ROM chiplet when initializing a syscall block.
+fn build_syscall_kernel_request<E: FieldElement<BaseField = Felt>>(
+    main_trace: &MainTrace,
+    alphas: &[E],
+    row: usize,
+) -> E {
     let op_label = KERNEL_PROC_LABEL;
     let state = main_trace.decoder_hasher_state(row);
-    let factor2 = alphas[0]
+
+    alphas[0]
         + alphas[1].mul_base(op_label)
         + alphas[2].mul_base(state[0])
         + alphas[3].mul_base(state[1])
         + alphas[4].mul_base(state[2])
-        + alphas[5].mul_base(state[3]);
-
-    factor1 * factor2
+        + alphas[5].mul_base(state[3])
 }
 
 /// Builds requests made to the hasher chiplet at the start of a span block.
@@ -386,6 +455,45 @@ fn build_bitwise_request<E: FieldElement<BaseField = Felt>>(
         + alphas[4].mul_base(z)
 }
 
+/// Builds requests made to the bitwise chiplet for the computation of a `POPCNT` operation. The
+/// `b` input of the operation is always set to 0.
+fn build_bitwise_popcnt_request<E: FieldElement<BaseField = Felt>>(
+    main_trace: &MainTrace,
+    alphas: &[E],
+    row: usize,
+) -> E {
+    let op_label = get_op_label(ONE, ZERO, ZERO, ONE);
+    let a = main_trace.stack_element(0, row);
+    let z = main_trace.stack_element(0, row + 1);
+
+    alphas[0] + alphas[1].mul_base(op_label) + alphas[2].mul_base(a) + alphas[4].mul_base(z)
+}
+
+/// Builds requests made to the bitwise chiplet for the computation of a `MASK` operation on behalf
+/// of the `U32SHL` and `U32SHR` operations. The mask `b` is set to the power of two in the second
+/// helper register minus one, the result `z` is the value in the first helper register, and the
+/// mask length is `32 - s` for `U32SHL` and `s` for `U32SHR`, where `s` is the shift amount.
+fn build_bitwise_mask_request<E: FieldElement<BaseField = Felt>>(
+    main_trace: &MainTrace,
+    is_shl: bool,
+    alphas: &[E],
+    row: usize,
+) -> E {
+    let op_label = get_op_label(ONE, ZERO, ONE, ONE);
+    let shift = main_trace.stack_element(0, row);
+    let a = main_trace.stack_element(1, row);
+    let b = main_trace.helper_register(1, row) - ONE;
+    let z = main_trace.helper_register(0, row);
+    let mask_len = if is_shl { Felt::from(32_u8) - shift } else { shift };
+
+    alphas[0]
+        + alphas[1].mul_base(op_label)
+        + alphas[2].mul_base(a)
+        + alphas[3].mul_base(b)
+        + alphas[4].mul_base(z)
+        + alphas[5].mul_base(mask_len)
+}
+
 /// Builds `MLOAD` and `MSTORE` requests made to the memory chiplet.
 fn build_mem_request_element<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
@@ -404,6 +512,56 @@ fn build_mem_request_element<E: FieldElement<BaseField = Felt>>(
     compute_memory_request(main_trace, op_label, alphas, row, addr, word)
 }
 
+/// Builds `MLOADE` and `MSTOREE` requests made to the memory and bitwise chiplets.
+///
+/// The element address at the top of the stack is split into the word address and the element
+/// index using the index bits in helper registers 4 and 5. The word is read from the first 4
+/// helper registers. For `MSTOREE`, the request combines a read of this word with a write of the
+/// word in which the addressed element is replaced with the stored value; both accesses happen in
+/// the same clock cycle. This ties the untouched elements to the values stored in memory.
+///
+/// The request also includes a bitwise AND of the word address with itself. The bitwise chiplet
+/// accepts only 32-bit operands, and thus, this ensures that the word address is a u32 value and
+/// that the index bits are the lower bits of the element address.
+fn build_mem_request_element_addr<E: FieldElement<BaseField = Felt>>(
+    main_trace: &MainTrace,
+    op_label: u8,
+    alphas: &[E],
+    row: usize,
+) -> E {
+    let b0 = main_trace.helper_register(4, row);
+    let b1 = main_trace.helper_register(5, row);
+    let elem_addr = main_trace.stack_element(0, row);
+    let addr = (elem_addr - b0 - b1.double()) * Felt::new(4).inv();
+
+    let old_word = [
+        main_trace.helper_register(0, row),
+        main_trace.helper_register(1, row),
+        main_trace.helper_register(2, row),
+        main_trace.helper_register(3, row),
+    ];
+    let bitwise_label = get_op_label(ONE, ZERO, ZERO, ZERO);
+    let range_check_request = alphas[0]
+        + alphas[1].mul_base(bitwise_label)
+        + alphas[2].mul_base(addr)
+        + alphas[3].mul_base(addr)
+        + alphas[4].mul_base(addr);
+
+    let read_request =
+        compute_memory_request(main_trace, MEMORY_READ_LABEL, alphas, row, addr, old_word);
+    if op_label == MEMORY_READ_LABEL {
+        return read_request * range_check_request;
+    }
+
+    let mut new_word = old_word;
+    let idx = (b0.as_int() + 2 * b1.as_int()) as usize;
+    new_word[idx] = main_trace.stack_element(0, row + 1);
+
+    read_request
+        * compute_memory_request(main_trace, MEMORY_WRITE_LABEL, alphas, row, addr, new_word)
+        * range_check_request
+}
+
 /// Builds `MLOADW` and `MSTOREW` requests made to the memory chiplet.
 fn build_mem_request_word<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
@@ -422,6 +580,85 @@ fn build_mem_request_word<E: FieldElement<BaseField = Felt>>(
     compute_memory_request(main_trace, op_label, alphas, row, addr, word)
 }
 
+/// Builds `MLEND` requests made to the memory chiplet. The lent segment is written into the
+/// memory of the current context at address LEND_SEGMENT_ADDR.
+fn build_mem_request_lend<E: FieldElement<BaseField = Felt>>(
+    main_trace: &MainTrace,
+    alphas: &[E],
+    row: usize,
+) -> E {
+    let segment = [main_trace.stack_element(0, row), main_trace.stack_element(1, row), ONE, ZERO];
+    let addr = Felt::from(LEND_SEGMENT_ADDR);
+
+    compute_memory_request(main_trace, MEMORY_WRITE_LABEL, alphas, row, addr, segment)
+}
+
+/// Builds `MLOADWB` requests made to the memory and bitwise chiplets.
+///
+/// All memory requests are made against the memory of the lending context, whose ID is located in
+/// the first helper register. The request combines the following:
+/// - A read of the ID of the last context called by the lender, which must be the current
+///   context.
+/// - A read of the segment lent by the lender, whose start and end addresses are located in the
+///   second and the third helper registers.
+/// - A read of the word at the address located at the top of the stack.
+/// - A bitwise AND of (addr - start) and (end - 1 - addr), whose result is located in the fourth
+///   helper register. The bitwise chiplet accepts only 32-bit operands, and thus, this ensures
+///   that the address is located in the lent segment.
+fn build_mem_request_borrowed_word<E: FieldElement<BaseField = Felt>>(
+    main_trace: &MainTrace,
+    alphas: &[E],
+    row: usize,
+) -> E {
+    let word = [
+        main_trace.stack_element(3, row + 1),
+        main_trace.stack_element(2, row + 1),
+        main_trace.stack_element(1, row + 1),
+        main_trace.stack_element(0, row + 1),
+    ];
+    let lender = main_trace.helper_register(0, row);
+    let start_addr = main_trace.helper_register(1, row);
+    let end_addr = main_trace.helper_register(2, row);
+    let bounds_check = main_trace.helper_register(3, row);
+    let addr = main_trace.stack_element(0, row);
+    let op_label = MEMORY_READ_LABEL;
+
+    let callee = [main_trace.ctx(row), ZERO, ZERO, ZERO];
+    let segment = [start_addr, end_addr, ONE, ZERO];
+    let callee_addr = Felt::from(LEND_CALLEE_ADDR);
+    let segment_addr = Felt::from(LEND_SEGMENT_ADDR);
+
+    let callee_request = compute_memory_request_in_ctx(
+        main_trace,
+        op_label,
+        alphas,
+        row,
+        lender,
+        callee_addr,
+        callee,
+    );
+    let segment_request = compute_memory_request_in_ctx(
+        main_trace,
+        op_label,
+        alphas,
+        row,
+        lender,
+        segment_addr,
+        segment,
+    );
+    let word_request =
+        compute_memory_request_in_ctx(main_trace, op_label, alphas, row, lender, addr, word);
+
+    let bitwise_label = get_op_label(ONE, ZERO, ZERO, ZERO);
+    let bitwise_request = alphas[0]
+        + alphas[1].mul_base(bitwise_label)
+        + alphas[2].mul_base(addr - start_addr)
+        + alphas[3].mul_base(end_addr - ONE - addr)
+        + alphas[4].mul_base(bounds_check);
+
+    callee_request * segment_request * word_request * bitwise_request
+}
+
 /// Builds `MSTREAM` requests made to the memory chiplet.
 fn build_mstream_request<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
@@ -827,23 +1064,26 @@ fn build_bitwise_chiplet_responses<E>(
     main_trace: &MainTrace,
     row: usize,
     is_xor: Felt,
+    is_popcnt: Felt,
     alphas: &[E],
 ) -> E
 where
     E: FieldElement<BaseField = Felt>,
 {
     if row % BITWISE_OP_CYCLE_LEN == BITWISE_OP_CYCLE_LEN - 1 {
-        let op_label = get_op_label(ONE, ZERO, is_xor, ZERO);
+        let op_label = get_op_label(ONE, ZERO, is_xor, is_popcnt);
 
         let a = main_trace.chiplet_bitwise_a(row);
         let b = main_trace.chiplet_bitwise_b(row);
         let z = main_trace.chiplet_bitwise_z(row);
+        let mask_len = main_trace.chiplet_bitwise_mask_len(row);
 
         alphas[0]
             + alphas[1].mul_base(op_label)
             + alphas[2].mul_base(a)
             + alphas[3].mul_base(b)
             + alphas[4].mul_base(z)
+            + alphas[5].mul_base(mask_len)
     } else {
         E::ONE
     }
@@ -907,29 +1147,6 @@ where
     v.mul_base(kernel_chiplet_selector) + E::from(ONE - kernel_chiplet_selector)
 }
 
-/// Builds the response from the kernel procedure table at `row`.
-fn build_kernel_procedure_table_responses<E>(main_trace: &MainTrace, row: usize, alphas: &[E]) -> E
-where
-    E: FieldElement<BaseField = Felt>,
-{
-    let addr = main_trace.chiplet_kernel_addr(row);
-    let addr_nxt = main_trace.chiplet_kernel_addr(row + 1);
-    let addr_delta = addr_nxt - addr;
-    let root0 = main_trace.chiplet_kernel_root_0(row);
-    let root1 = main_trace.chiplet_kernel_root_1(row);
-    let root2 = main_trace.chiplet_kernel_root_2(row);
-    let root3 = main_trace.chiplet_kernel_root_3(row);
-
-    let v = alphas[0]
-        + alphas[1].mul_base(addr)
-        + alphas[2].mul_base(root0)
-        + alphas[3].mul_base(root1)
-        + alphas[4].mul_base(root2)
-        + alphas[5].mul_base(root3);
-
-    v.mul_base(addr_delta) + E::from(ONE - addr_delta)
-}
-
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -975,6 +1192,20 @@ fn compute_memory_request<E: FieldElement<BaseField = Felt>>(
     value: Word,
 ) -> E {
     let ctx = main_trace.ctx(row);
+    compute_memory_request_in_ctx(main_trace, op_label, alphas, row, ctx, addr, value)
+}
+
+/// Computes a memory read or write request at `row` given randomness `alphas`, memory context
+/// `ctx`, memory address `addr` and value `value`.
+fn compute_memory_request_in_ctx<E: FieldElement<BaseField = Felt>>(
+    main_trace: &MainTrace,
+    op_label: u8,
+    alphas: &[E],
+    row: usize,
+    ctx: Felt,
+    addr: Felt,
+    value: Word,
+) -> E {
     let clk = main_trace.clk(row);
 
     alphas[0]
@@ -1,8 +1,9 @@
 use super::{utils::get_trace_len, ExecutionError, Felt, TraceFragment, ZERO};
 use alloc::vec::Vec;
 use miden_air::trace::chiplets::bitwise::{
-    A_COL_IDX, A_COL_RANGE, BITWISE_AND, BITWISE_XOR, B_COL_IDX, B_COL_RANGE, OUTPUT_COL_IDX,
-    PREV_OUTPUT_COL_IDX, TRACE_WIDTH,
+    Selectors, A_COL_IDX, A_COL_RANGE, BITWISE_AND, BITWISE_MASK, BITWISE_POPCNT, BITWISE_XOR,
+    B_COL_IDX, B_COL_RANGE, MASK_LEN_COL_IDX, NUM_SELECTORS, OUTPUT_COL_IDX, PREV_OUTPUT_COL_IDX,
+    TRACE_WIDTH,
 };
 
 #[cfg(test)]
@@ -17,21 +18,22 @@ const INIT_TRACE_CAPACITY: usize = 128;
 // BITWISE
 // ================================================================================================
 
-/// Helper for the VM that computes AND and XOR bitwise operations on 32-bit values.
+/// Helper for the VM that computes AND, XOR, POPCNT, and MASK bitwise operations on 32-bit values.
 /// It also builds an execution trace of these operations.
 ///
-/// ## Bitwise operation execution trace (AND and XOR)
-/// The execution trace for each operation consists of 8 rows and 14 columns. At a high level,
+/// ## Bitwise operation execution trace (AND, XOR, POPCNT, and MASK)
+/// The execution trace for each operation consists of 8 rows and 15 columns. At a high level,
 /// we break input values into 4-bit limbs, apply the bitwise operation to these limbs at every
 /// row starting with the most significant limb, and accumulate the result in the result column.
 ///
 /// The layout of the table is illustrated below.
 ///
-///    s     a     b      a0     a1     a2     a3     b0     b1     b2     b3    zp     z
-/// ├─────┴─────┴─────┴───────┴──────┴──────┴──────┴──────┴──────┴──────┴──────┴─────┴─────┤
+///    s0    s1    a     b      a0     a1     a2     a3     b0     b1     b2     b3    zp     z     w
+/// ├─────┴─────┴─────┴─────┴───────┴──────┴──────┴──────┴──────┴──────┴──────┴──────┴─────┴─────┴─────┤
 ///
 /// In the above, the meaning of the columns is as follows:
-/// - Selector column s is used to specify the bitwise operator for each row.
+/// - Selector columns s0 and s1 are used to specify the bitwise operator for each row: [0, 0]
+///   for AND, [1, 0] for XOR, [0, 1] for POPCNT, and [1, 1] for MASK.
 /// - Columns `a` and `b` contain accumulated 4-bit limbs of input values. Specifically, at the
 ///   first row, the values of columns `a` and `b` are set to the most significant 4-bit limb
 ///   of each input value. With all subsequent rows, the next most significant limb is appended
@@ -46,6 +48,16 @@ const INIT_TRACE_CAPACITY: usize = 128;
 ///   significant 4-bit limbs of the input values. With every subsequent row, the next most
 ///   significant 4-bit limb of the result is appended to it. Thus, by the 8th row, column `z`
 ///   contains the full result of the bitwise operation.
+/// - Column `w` contains the accumulated number of set bits of `b` for the MASK operation. For all
+///   other operations, it is set to 0.
+///
+/// For the POPCNT operation, the input value `b` is set to 0, and with every row, the number of
+/// set bits in the 4-bit limb of `a` is added to the value in column `z` instead. Thus, by the
+/// 8th row, column `z` contains the number of set bits in `a`.
+///
+/// For the MASK operation, the input value `b` is a mask of `k` least significant ones, and the
+/// output is computed in the same way as for the AND operation. Thus, by the 8th row, column `z`
+/// contains `a` modulo 2^k and column `w` contains `k`.
 pub struct Bitwise {
     trace: [Vec<Felt>; TRACE_WIDTH],
 }
@@ -145,6 +157,73 @@ impl Bitwise {
         Ok(Felt::new(result))
     }
 
+    /// Computes the number of set bits of `a` and returns the result. We assume that `a` is a
+    /// 32-bit value. If that's not the case, the result of the computation is undefined.
+    ///
+    /// This also adds 8 rows to the internal execution trace table required for computing the
+    /// operation.
+    pub fn u32popcnt(&mut self, a: Felt) -> Result<Felt, ExecutionError> {
+        let a = assert_u32(a)?.as_int();
+        let mut result = 0u64;
+
+        // append 8 rows to the trace, each row counting the set bits in 4 bit limbs starting with
+        // the most significant limb.
+        for bit_offset in (0..32).step_by(4).rev() {
+            // append the previous row's result to the column for previous output values
+            self.trace[PREV_OUTPUT_COL_IDX].push(Felt::new(result));
+            // shift a so that the next 4-bit limb is in the least significant position
+            let a = a >> bit_offset;
+
+            // add a new row to the trace table and populate it with binary decomposition of the 4
+            // least significant bits of a; b is not used by the operation and is set to 0.
+            self.add_bitwise_trace_row(BITWISE_POPCNT, a, 0);
+
+            // add the number of set bits among the 4 least significant bits of a to the result
+            // accumulator, and save the current result into the output column in the trace.
+            result += (a & 0xf).count_ones() as u64;
+            self.trace[OUTPUT_COL_IDX].push(Felt::new(result));
+        }
+
+        Ok(Felt::new(result))
+    }
+
+    /// Computes the `k` least significant bits of `a` (i.e., `a` modulo 2^k) and returns the
+    /// result. We assume that `a` is a 32-bit value and that `k` is at most 32. If that's not the
+    /// case, the result of the computation is undefined.
+    ///
+    /// This also adds 8 rows to the internal execution trace table required for computing the
+    /// operation.
+    pub fn u32mask(&mut self, a: Felt, k: u32) -> Result<Felt, ExecutionError> {
+        debug_assert!(k <= 32, "mask length must be at most 32");
+        let a = assert_u32(a)?.as_int();
+        let b = (1u64 << k) - 1;
+        let mut result = 0u64;
+
+        // append 8 rows to the trace, each row computing bitwise AND of a with the mask in 4 bit
+        // limbs starting with the most significant limb.
+        for bit_offset in (0..32).step_by(4).rev() {
+            // append the previous row's result to the column for previous output values
+            self.trace[PREV_OUTPUT_COL_IDX].push(Felt::new(result));
+            // shift a and b so that the next 4-bit limb is in the least significant position
+            let a = a >> bit_offset;
+            let b = b >> bit_offset;
+
+            // add a new row to the trace table and populate it with binary decomposition of the 4
+            // least significant bits of a and b.
+            self.add_bitwise_trace_row(BITWISE_MASK, a, b);
+
+            // compute bitwise AND of the 4 least significant bits of a and b
+            let result_4_bit = (a & b) & 0xf;
+
+            // append the 4 bit result to the result accumulator, and save the current result into
+            // the output column in the trace.
+            result = (result << 4) | result_4_bit;
+            self.trace[OUTPUT_COL_IDX].push(Felt::new(result));
+        }
+
+        Ok(Felt::new(result))
+    }
+
     // EXECUTION TRACE GENERATION
     // --------------------------------------------------------------------------------------------
 
@@ -155,25 +234,26 @@ impl Bitwise {
         debug_assert_eq!(TRACE_WIDTH, trace.width(), "inconsistent trace widths");
 
         // copy trace into the fragment column-by-column
-        // TODO: this can be parallelized to copy columns in multiple threads
-        for (out_column, column) in trace.columns().zip(self.trace) {
-            out_column.copy_from_slice(&column);
-        }
+        trace.copy_columns(&self.trace);
     }
 
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
-    /// Appends a new row to the trace table and populates the first 14 columns of trace as follows:
-    /// - Column 0 is set to the selector value for the bitwise operation being executed.
-    /// - Column 1 is set to the current value of `a`.
-    /// - Column 2 is set to the current value of `b`.
-    /// - Columns 3 to 6 are set to the 4 least-significant bits of `a`.
-    /// - Columns 7 to 10 are set to the 4 least-significant bits of `b`.
-    /// - Columns 11 and 12 are left for the output value and that of the previous row, which are
+    /// Appends a new row to the trace table and populates the following columns of trace:
+    /// - Columns 0 and 1 are set to the selector values for the bitwise operation being executed.
+    /// - Column 2 is set to the current value of `a`.
+    /// - Column 3 is set to the current value of `b`.
+    /// - Columns 4 to 7 are set to the 4 least-significant bits of `a`.
+    /// - Columns 8 to 11 are set to the 4 least-significant bits of `b`.
+    /// - Columns 12 and 13 are left for the output value and that of the previous row, which are
     ///   set elsewhere.
-    fn add_bitwise_trace_row(&mut self, selector: Felt, a: u64, b: u64) {
-        self.trace[0].push(selector);
+    /// - Column 14 is set to the number of set bits in the current value of `b` for the MASK
+    ///   operation, and to 0 for all other operations.
+    fn add_bitwise_trace_row(&mut self, selectors: Selectors, a: u64, b: u64) {
+        for (column, selector) in self.trace.iter_mut().take(NUM_SELECTORS).zip(selectors) {
+            column.push(selector);
+        }
         self.trace[A_COL_IDX].push(Felt::new(a));
         self.trace[B_COL_IDX].push(Felt::new(b));
 
@@ -186,6 +266,9 @@ impl Bitwise {
         self.trace[B_COL_RANGE.start + 1].push(Felt::new((b >> 1) & 1));
         self.trace[B_COL_RANGE.start + 2].push(Felt::new((b >> 2) & 1));
         self.trace[B_COL_RANGE.start + 3].push(Felt::new((b >> 3) & 1));
+
+        let mask_len = if selectors == BITWISE_MASK { b.count_ones() } else { 0 };
+        self.trace[MASK_LEN_COL_IDX].push(Felt::from(mask_len));
     }
 }
 
@@ -1,8 +1,8 @@
 use super::{Bitwise, Felt, TraceFragment};
 use alloc::vec::Vec;
 use miden_air::trace::chiplets::bitwise::{
-    A_COL_IDX, A_COL_RANGE, BITWISE_AND, BITWISE_XOR, B_COL_IDX, B_COL_RANGE, OP_CYCLE_LEN,
-    OUTPUT_COL_IDX, PREV_OUTPUT_COL_IDX, TRACE_WIDTH,
+    A_COL_IDX, A_COL_RANGE, BITWISE_AND, BITWISE_MASK, BITWISE_POPCNT, BITWISE_XOR, B_COL_IDX,
+    B_COL_RANGE, MASK_LEN_COL_IDX, OP_CYCLE_LEN, OUTPUT_COL_IDX, PREV_OUTPUT_COL_IDX, TRACE_WIDTH,
 };
 use test_utils::rand::rand_value;
 use vm_core::ZERO;
@@ -28,7 +28,7 @@ fn bitwise_and() {
 
     // make sure the selector values specify bitwise AND at each step in the trace
     for row in 0..OP_CYCLE_LEN {
-        assert_eq!(trace[0][row], BITWISE_AND);
+        assert_eq!([trace[0][row], trace[1][row]], BITWISE_AND);
     }
 
     // make sure result and result from the trace are the same
@@ -71,7 +71,7 @@ fn bitwise_xor() {
 
     // make sure the selector values specify bitwise XOR at each step in the trace
     for row in 0..OP_CYCLE_LEN {
-        assert_eq!(trace[0][row], BITWISE_XOR);
+        assert_eq!([trace[0][row], trace[1][row]], BITWISE_XOR);
     }
 
     // make sure result and result from the trace are the same
@@ -99,6 +99,98 @@ fn bitwise_xor() {
     }
 }
 
+#[test]
+fn bitwise_popcnt() {
+    let mut bitwise = Bitwise::new();
+
+    let a = rand_u32();
+
+    let result = bitwise.u32popcnt(a).unwrap();
+    assert_eq!(a.as_int().count_ones() as u64, result.as_int());
+
+    // --- check generated trace ----------------------------------------------
+    let trace = build_trace(bitwise, OP_CYCLE_LEN);
+
+    // make sure the selector values specify bitwise POPCNT at each step in the trace
+    for row in 0..OP_CYCLE_LEN {
+        assert_eq!([trace[0][row], trace[1][row]], BITWISE_POPCNT);
+    }
+
+    // make sure result and result from the trace are the same
+    assert_eq!(result, trace[OUTPUT_COL_IDX][OP_CYCLE_LEN - 1]);
+
+    // make sure value a was decomposed correctly and b is zero
+    check_decomposition(&trace, 0, a.as_int(), 0);
+
+    // make sure the result was accumulated correctly
+    let mut prev_result = ZERO;
+
+    for i in 0..OP_CYCLE_LEN {
+        let count = trace[A_COL_RANGE.start][i]
+            + trace[A_COL_RANGE.start + 1][i]
+            + trace[A_COL_RANGE.start + 2][i]
+            + trace[A_COL_RANGE.start + 3][i];
+        let result = prev_result + count;
+
+        assert_eq!(prev_result, trace[PREV_OUTPUT_COL_IDX][i]);
+        assert_eq!(result, trace[OUTPUT_COL_IDX][i]);
+
+        prev_result = result;
+    }
+}
+
+#[test]
+fn bitwise_mask() {
+    let mut bitwise = Bitwise::new();
+
+    let a = rand_u32();
+    let k = (rand_value::<u64>() % 33) as u32;
+    let mask = (1u64 << k) - 1;
+
+    let result = bitwise.u32mask(a, k).unwrap();
+    assert_eq!(a.as_int() & mask, result.as_int());
+
+    // --- check generated trace ----------------------------------------------
+    let trace = build_trace(bitwise, OP_CYCLE_LEN);
+
+    // make sure the selector values specify bitwise MASK at each step in the trace
+    for row in 0..OP_CYCLE_LEN {
+        assert_eq!([trace[0][row], trace[1][row]], BITWISE_MASK);
+    }
+
+    // make sure result and result from the trace are the same
+    assert_eq!(result, trace[OUTPUT_COL_IDX][OP_CYCLE_LEN - 1]);
+
+    // make sure value a and the mask were decomposed correctly
+    check_decomposition(&trace, 0, a.as_int(), mask);
+
+    // make sure the result was re-composed correctly and the mask length was accumulated
+    let mut prev_result = ZERO;
+    let mut mask_len = ZERO;
+
+    for i in 0..OP_CYCLE_LEN {
+        let c0 = binary_and(trace[A_COL_RANGE.start][i], trace[B_COL_RANGE.start][i]);
+        let c1 = binary_and(trace[A_COL_RANGE.start + 1][i], trace[B_COL_RANGE.start + 1][i]);
+        let c2 = binary_and(trace[A_COL_RANGE.start + 2][i], trace[B_COL_RANGE.start + 2][i]);
+        let c3 = binary_and(trace[A_COL_RANGE.start + 3][i], trace[B_COL_RANGE.start + 3][i]);
+
+        let result_4_bit = c0 + Felt::new(2) * c1 + Felt::new(4) * c2 + Felt::new(8) * c3;
+        let result = prev_result * Felt::new(16) + result_4_bit;
+
+        mask_len += trace[B_COL_RANGE.start][i]
+            + trace[B_COL_RANGE.start + 1][i]
+            + trace[B_COL_RANGE.start + 2][i]
+            + trace[B_COL_RANGE.start + 3][i];
+
+        assert_eq!(prev_result, trace[PREV_OUTPUT_COL_IDX][i]);
+        assert_eq!(result, trace[OUTPUT_COL_IDX][i]);
+        assert_eq!(mask_len, trace[MASK_LEN_COL_IDX][i]);
+
+        prev_result = result;
+    }
+    assert_eq!(Felt::from(k), mask_len);
+}
+
 #[test]
 fn bitwise_multiple() {
     let mut bitwise = Bitwise::new();
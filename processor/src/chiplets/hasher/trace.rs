@@ -139,9 +139,6 @@ impl HasherTrace {
         columns.push(self.node_index);
 
         // copy trace into the fragment column-by-column
-        // TODO: this can be parallelized to copy columns in multiple threads
-        for (out_column, column) in trace.columns().zip(columns) {
-            out_column.copy_from_slice(&column);
-        }
+        trace.copy_columns(&columns);
     }
 }
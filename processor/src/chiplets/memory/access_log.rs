@@ -0,0 +1,51 @@
+use super::Word;
+use crate::system::ContextId;
+use core::fmt;
+
+// MEMORY ACCESS
+// ================================================================================================
+
+/// Type of a memory access made by the VM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MemoryAccessKind {
+    Read,
+    Write,
+}
+
+impl fmt::Display for MemoryAccessKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read => write!(f, "read"),
+            Self::Write => write!(f, "write"),
+        }
+    }
+}
+
+/// A single memory access made by the VM.
+///
+/// Memory accesses are recorded by the [Memory](super::Memory) chiplet only when memory tracing
+/// is enabled via [ExecutionOptions::with_memory_tracing()](crate::ExecutionOptions).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryAccess {
+    /// Context in which the access was made.
+    pub ctx: ContextId,
+    /// Word address of the accessed memory location.
+    pub addr: u32,
+    /// Clock cycle at which the access was made.
+    pub clk: u32,
+    /// Type of the access.
+    pub kind: MemoryAccessKind,
+    /// Value read from memory for reads, or value written into memory for writes.
+    pub value: Word,
+}
+
+impl fmt::Display for MemoryAccess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.value.map(|v| v.as_int());
+        write!(
+            f,
+            "clk={}, ctx={}, {} addr={}, value={value:?}",
+            self.clk, self.ctx, self.kind, self.addr
+        )
+    }
+}
@@ -8,6 +8,9 @@ use miden_air::trace::chiplets::memory::{
     ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, V_COL_RANGE,
 };
 
+mod access_log;
+pub use access_log::{MemoryAccess, MemoryAccessKind};
+
 mod segment;
 use segment::MemorySegmentTrace;
 
@@ -66,11 +69,17 @@ const INIT_MEM_VALUE: Word = EMPTY_WORD;
 ///   - When the context remains the same but the address changes, these columns contain
 ///     (`new_addr` - `old-addr`).
 ///   - When both the context and the address remain the same, these columns contain
-///     (`new_clk` - `old_clk` - 1).
+///     (`new_clk` - `old_clk` - 1), or (`new_clk` - `old_clk`) when a write follows a read. This
+///     allows a word to be read and then written in the same clock cycle.
 /// - `d_inv` contains the inverse of the delta between two consecutive context IDs, addresses, or
 ///   clock cycles computed as described above.
 ///
 /// For the first row of the trace, values in `d0`, `d1`, and `d_inv` are set to zeros.
+///
+/// ## Access log
+/// In addition to the execution trace, the memory can record a log of all memory accesses in the
+/// order in which they were made. Recording is disabled by default, and can be enabled via
+/// [Memory::enable_access_log()].
 #[derive(Default)]
 pub struct Memory {
     /// Memory segment traces sorted by their execution context ID.
@@ -79,9 +88,23 @@ pub struct Memory {
     /// Total number of entries in the trace (across all contexts); tracked separately so that we
     /// don't have to sum up lengths of all address trace vectors for all contexts all the time.
     num_trace_rows: usize,
+
+    /// Log of all memory accesses in the order in which they were made; this is None unless
+    /// recording of memory accesses was enabled.
+    access_log: Option<Vec<MemoryAccess>>,
 }
 
 impl Memory {
+    // CONFIGURATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Enables recording of all subsequent memory accesses into the access log.
+    pub fn enable_access_log(&mut self) {
+        if self.access_log.is_none() {
+            self.access_log = Some(Vec::new());
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -110,6 +133,12 @@ impl Memory {
         self.get_value(ctx, addr).unwrap_or(INIT_MEM_VALUE)
     }
 
+    /// Returns all memory accesses made so far in the order in which they were made, or None if
+    /// recording of memory accesses has not been enabled.
+    pub fn access_log(&self) -> Option<&[MemoryAccess]> {
+        self.access_log.as_deref()
+    }
+
     /// Returns the entire memory state for the specified execution context at the specified cycle.
     /// The state is returned as a vector of (address, value) tuples, and includes addresses which
     /// have been accessed at least once.
@@ -133,13 +162,63 @@ impl Memory {
     /// returned. This effectively implies that memory is initialized to ZERO.
     pub fn read(&mut self, ctx: ContextId, addr: u32, clk: u32) -> Word {
         self.num_trace_rows += 1;
-        self.trace.entry(ctx).or_default().read(addr, Felt::from(clk))
+        let value = self.trace.entry(ctx).or_default().read(addr, Felt::from(clk));
+        self.record_access(ctx, addr, clk, MemoryAccessKind::Read, value);
+        value
     }
 
     /// Writes the provided word at the specified context/address.
     pub fn write(&mut self, ctx: ContextId, addr: u32, clk: u32, value: Word) {
         self.num_trace_rows += 1;
         self.trace.entry(ctx).or_default().write(addr, Felt::from(clk), value);
+        self.record_access(ctx, addr, clk, MemoryAccessKind::Write, value);
+    }
+
+    /// Replaces the element with the specified index of the word located at the specified
+    /// context/address, and returns the word previously stored at that address.
+    ///
+    /// The previous word is read in the same clock cycle in which the new word is written, and
+    /// thus, two rows are added to the memory trace. Only the write is recorded in the access log.
+    ///
+    /// # Panics
+    /// Panics if `idx` is greater than 3.
+    pub fn update_element(
+        &mut self,
+        ctx: ContextId,
+        addr: u32,
+        idx: usize,
+        clk: u32,
+        value: Felt,
+    ) -> Word {
+        self.num_trace_rows += 2;
+        let segment = self.trace.entry(ctx).or_default();
+        let old_word = segment.read(addr, Felt::from(clk));
+        let mut new_word = old_word;
+        new_word[idx] = value;
+        segment.write(addr, Felt::from(clk), new_word);
+        self.record_access(ctx, addr, clk, MemoryAccessKind::Write, new_word);
+
+        old_word
+    }
+
+    /// Appends the specified access to the access log if recording of memory accesses is enabled.
+    fn record_access(
+        &mut self,
+        ctx: ContextId,
+        addr: u32,
+        clk: u32,
+        kind: MemoryAccessKind,
+        value: Word,
+    ) {
+        if let Some(access_log) = self.access_log.as_mut() {
+            access_log.push(MemoryAccess {
+                ctx,
+                addr,
+                clk,
+                kind,
+                value,
+            });
+        }
     }
 
     // EXECUTION TRACE GENERATION
@@ -152,9 +231,10 @@ impl Memory {
         // trace; we also adjust the clock cycle so that delta value for the first row would end
         // up being ZERO. if the trace is empty, return without any further processing.
         let (mut prev_ctx, mut prev_addr, mut prev_clk) = match self.get_first_row_info() {
-            Some((ctx, addr, clk)) => (ctx, addr, clk.as_int() - 1),
+            Some((ctx, addr, clk)) => (ctx, addr, clk - ONE),
             None => return,
         };
+        let mut prev_is_read = false;
 
         // op range check index
         let mut row = memory_start_row as u32;
@@ -164,15 +244,17 @@ impl Memory {
                 // when we start a new address, we set the previous value to all zeros. the effect of
                 // this is that memory is always initialized to zero.
                 for memory_access in addr_trace {
-                    let clk = memory_access.clk().as_int();
+                    let clk = memory_access.clk();
 
                     // compute delta as difference between context IDs, addresses, or clock cycles
                     let delta = if prev_ctx != ctx {
                         (u32::from(ctx) - u32::from(prev_ctx)).into()
                     } else if prev_addr != addr {
                         (addr - prev_addr) as u64
+                    } else if prev_is_read && !memory_access.is_read() {
+                        (clk - prev_clk).as_int()
                     } else {
-                        clk - prev_clk - 1
+                        (clk - prev_clk - ONE).as_int()
                     };
 
                     let (delta_hi, delta_lo) = split_u32_into_u16(delta);
@@ -182,6 +264,7 @@ impl Memory {
                     prev_ctx = ctx;
                     prev_addr = addr;
                     prev_clk = clk;
+                    prev_is_read = memory_access.is_read();
                     row += 1;
                 }
             }
@@ -199,6 +282,7 @@ impl Memory {
             Some((ctx, addr, clk)) => (Felt::from(ctx), Felt::from(addr), clk - ONE),
             None => return,
         };
+        let mut prev_is_read = false;
 
         // iterate through addresses in ascending order, and write trace row for each memory access
         // into the trace. we expect the trace to be 14 columns wide.
@@ -229,6 +313,9 @@ impl Memory {
                         ctx - prev_ctx
                     } else if prev_addr != felt_addr {
                         felt_addr - prev_addr
+                    } else if prev_is_read && !memory_access.is_read() {
+                        // a write can follow a read of the same address in the same clock cycle
+                        clk - prev_clk
                     } else {
                         clk - prev_clk - ONE
                     };
@@ -243,6 +330,7 @@ impl Memory {
                     prev_ctx = ctx;
                     prev_addr = felt_addr;
                     prev_clk = clk;
+                    prev_is_read = memory_access.is_read();
                     row += 1;
                 }
             }
@@ -46,16 +46,12 @@ impl MemorySegmentTrace {
         let search_clk = (clk - 1) as u64;
 
         for (&addr, addr_trace) in self.0.iter() {
-            match addr_trace.binary_search_by(|access| access.clk().as_int().cmp(&search_clk)) {
-                Ok(i) => result.push((addr.into(), addr_trace[i].value())),
-                Err(i) => {
-                    // Binary search finds the index of the data with the specified clock cycle.
-                    // Decrement the index to get the trace from the previously accessed clock
-                    // cycle to insert into the results.
-                    if i > 0 {
-                        result.push((addr.into(), addr_trace[i - 1].value()));
-                    }
-                }
+            // an address can be accessed more than once in the same cycle (e.g., a read followed
+            // by a write), and thus, we look for the last access made no later than the search
+            // cycle.
+            let i = addr_trace.partition_point(|access| access.clk().as_int() <= search_clk);
+            if i > 0 {
+                result.push((addr.into(), addr_trace[i - 1].value()));
             }
         }
 
@@ -155,6 +151,11 @@ impl MemorySegmentAccess {
         self.clk
     }
 
+    /// Returns true if this memory access is a read.
+    pub(super) fn is_read(&self) -> bool {
+        self.op != MemoryOperation::Write
+    }
+
     /// Returns the selector values matching the operation used in this memory access.
     pub(super) fn op_selectors(&self) -> Selectors {
         match self.op {
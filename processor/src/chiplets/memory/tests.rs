@@ -1,6 +1,6 @@
 use super::{
-    super::ZERO, Felt, FieldElement, Memory, TraceFragment, ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX,
-    D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, EMPTY_WORD, ONE, V_COL_RANGE,
+    super::ZERO, Felt, FieldElement, Memory, MemoryAccessKind, TraceFragment, ADDR_COL_IDX,
+    CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, EMPTY_WORD, ONE, V_COL_RANGE,
 };
 use crate::ContextId;
 use alloc::vec::Vec;
@@ -196,6 +196,79 @@ fn mem_write_read() {
     verify_memory_access(&trace, 8, MEMORY_COPY_READ, &memory_access, prev_row);
 }
 
+#[test]
+fn mem_update_element() {
+    let mut mem = Memory::default();
+    mem.enable_access_log();
+
+    // write [1, 2, 3, 4] into address 3; clk = 1
+    let addr3 = 3;
+    let value = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+    mem.write(ContextId::root(), addr3, 1, value);
+
+    // replace the third element of the word at address 3 with 7; clk = 2
+    let old_value = mem.update_element(ContextId::root(), addr3, 2, 2, Felt::new(7));
+    assert_eq!(value, old_value);
+    assert_eq!(3, mem.trace_len());
+
+    let new_value = [ONE, Felt::new(2), Felt::new(7), Felt::new(4)];
+    assert_eq!(Some(new_value), mem.get_value(ContextId::root(), addr3));
+    assert_eq!(vec![(addr3 as u64, new_value)], mem.get_state_at(ContextId::root(), 3));
+
+    // only the write is recorded in the access log
+    let log = mem.access_log().unwrap();
+    assert_eq!(2, log.len());
+    assert_eq!(MemoryAccessKind::Write, log[1].kind);
+    assert_eq!(new_value, log[1].value);
+
+    // the word is read and then written in the same clock cycle
+    let trace = build_trace(mem, 3);
+
+    let memory_access = MemoryAccess::new(ContextId::root(), addr3, 1, value);
+    let prev_row =
+        verify_memory_access(&trace, 0, MEMORY_WRITE, &memory_access, [ZERO; MEMORY_TRACE_WIDTH]);
+
+    let memory_access = MemoryAccess::new(ContextId::root(), addr3, 2, value);
+    let prev_row = verify_memory_access(&trace, 1, MEMORY_COPY_READ, &memory_access, prev_row);
+
+    let memory_access = MemoryAccess::new(ContextId::root(), addr3, 2, new_value);
+    verify_memory_access(&trace, 2, MEMORY_WRITE, &memory_access, prev_row);
+}
+
+#[test]
+fn mem_access_log() {
+    let mut mem = Memory::default();
+
+    // accesses are not recorded by default
+    mem.write(ContextId::root(), 0, 1, [ONE, ZERO, ZERO, ZERO]);
+    assert!(mem.access_log().is_none());
+
+    // once enabled, all subsequent accesses are recorded in the order in which they were made
+    mem.enable_access_log();
+    let value = [ZERO, ONE, ZERO, ZERO];
+    mem.write(3.into(), 2, 4, value);
+    mem.read(ContextId::root(), 0, 5);
+    mem.read(3.into(), 1, 6);
+
+    let log = mem.access_log().unwrap();
+    assert_eq!(3, log.len());
+
+    assert_eq!((ContextId::from(3), 2, 4), (log[0].ctx, log[0].addr, log[0].clk));
+    assert_eq!(MemoryAccessKind::Write, log[0].kind);
+    assert_eq!(value, log[0].value);
+
+    assert_eq!((ContextId::root(), 0, 5), (log[1].ctx, log[1].addr, log[1].clk));
+    assert_eq!(MemoryAccessKind::Read, log[1].kind);
+    assert_eq!([ONE, ZERO, ZERO, ZERO], log[1].value);
+
+    assert_eq!((ContextId::from(3), 1, 6), (log[2].ctx, log[2].addr, log[2].clk));
+    assert_eq!(MemoryAccessKind::Read, log[2].kind);
+    assert_eq!(EMPTY_WORD, log[2].value);
+
+    // recording accesses does not affect the trace
+    assert_eq!(4, mem.trace_len());
+}
+
 #[test]
 fn mem_multi_context() {
     let mut mem = Memory::default();
@@ -361,6 +434,8 @@ fn build_trace_row(
             row[CTX_COL_IDX] - prev_row[CTX_COL_IDX]
         } else if row[ADDR_COL_IDX] != prev_row[ADDR_COL_IDX] {
             row[ADDR_COL_IDX] - prev_row[ADDR_COL_IDX]
+        } else if prev_row[0] == ONE && row[0] == ZERO {
+            row[CLK_COL_IDX] - prev_row[CLK_COL_IDX]
         } else {
             row[CLK_COL_IDX] - prev_row[CLK_COL_IDX] - ONE
         };
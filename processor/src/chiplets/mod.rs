@@ -1,4 +1,4 @@
-use crate::system::ContextId;
+use crate::{system::ContextId, utils::join};
 
 use super::{
     crypto::MerklePath, utils, ChipletsTrace, ExecutionError, Felt, FieldElement, RangeChecker,
@@ -18,6 +18,7 @@ use hasher::Hasher;
 
 mod memory;
 use memory::Memory;
+pub use memory::{MemoryAccess, MemoryAccessKind};
 
 mod kernel_rom;
 use kernel_rom::KernelRom;
@@ -281,6 +282,24 @@ impl Chiplets {
         Ok(result)
     }
 
+    /// Requests the number of set bits of `a` from the Bitwise chiplet and returns the result.
+    /// We assume that `a` is a 32-bit value. If that's not the case, the result of the
+    /// computation is undefined.
+    pub fn u32popcnt(&mut self, a: Felt) -> Result<Felt, ExecutionError> {
+        let result = self.bitwise.u32popcnt(a)?;
+
+        Ok(result)
+    }
+
+    /// Requests the `k` least significant bits of `a` (i.e., `a` modulo 2^k) from the Bitwise
+    /// chiplet and returns the result. We assume that `a` is a 32-bit value and that `k` is at
+    /// most 32. If that's not the case, the result of the computation is undefined.
+    pub fn u32mask(&mut self, a: Felt, k: u32) -> Result<Felt, ExecutionError> {
+        let result = self.bitwise.u32mask(a, k)?;
+
+        Ok(result)
+    }
+
     // MEMORY CHIPLET ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -321,6 +340,22 @@ impl Chiplets {
         old_word
     }
 
+    /// Reads the word located at the specified context/address, and writes the provided element
+    /// into the slot with the specified index of this word in the same clock cycle, leaving the
+    /// remaining 3 elements unchanged. Returns the word previously stored at that address.
+    ///
+    /// # Panics
+    /// Panics if `idx` is greater than 3.
+    pub fn update_mem_element(
+        &mut self,
+        ctx: ContextId,
+        addr: u32,
+        idx: usize,
+        value: Felt,
+    ) -> Word {
+        self.memory.update_element(ctx, addr, idx, self.clk, value)
+    }
+
     /// Writes the two provided words to two consecutive addresses in memory in the specified
     /// context, starting at the specified address.
     pub fn write_mem_double(&mut self, ctx: ContextId, addr: u32, words: [Word; 2]) {
@@ -346,6 +381,17 @@ impl Chiplets {
         self.memory.get_state_at(ctx, clk)
     }
 
+    /// Returns all memory accesses made so far in the order in which they were made, or None if
+    /// recording of memory accesses has not been enabled.
+    pub fn get_mem_access_log(&self) -> Option<&[MemoryAccess]> {
+        self.memory.access_log()
+    }
+
+    /// Enables recording of all subsequent memory accesses.
+    pub fn enable_mem_access_log(&mut self) {
+        self.memory.enable_access_log();
+    }
+
     /// Returns current size of the memory (in words) across all execution contexts.
     #[cfg(test)]
     pub fn get_mem_size(&self) -> usize {
@@ -446,12 +492,12 @@ impl Chiplets {
         // so they can be filled with the chiplet traces
         for (column_num, column) in trace.iter_mut().enumerate().skip(1) {
             match column_num {
-                1 | 15..=17 => {
-                    // columns 1 and 15 - 17 are relevant only for the hasher
+                1 | 17 => {
+                    // columns 1 and 17 are relevant only for the hasher
                     hasher_fragment.push_column_slice(column, hasher.trace_len());
                 }
-                2 => {
-                    // column 2 is relevant to the hasher and to bitwise chiplet
+                2 | 15..=16 => {
+                    // columns 2, 15, and 16 are relevant to the hasher and to bitwise chiplet
                     let rest = hasher_fragment.push_column_slice(column, hasher.trace_len());
                     bitwise_fragment.push_column_slice(rest, bitwise.trace_len());
                 }
@@ -472,12 +518,22 @@ impl Chiplets {
             }
         }
 
-        // fill the fragments with the execution trace from each chiplet
-        // TODO: this can be parallelized to fill the traces in multiple threads
-        hasher.fill_trace(&mut hasher_fragment);
-        bitwise.fill_trace(&mut bitwise_fragment);
-        memory.fill_trace(&mut memory_fragment);
-        kernel_rom.fill_trace(&mut kernel_rom_fragment);
+        // fill the fragments with the execution trace from each chiplet; the fragments cover
+        // disjoint regions of the trace, and thus can be filled in parallel
+        join(
+            || {
+                join(
+                    || hasher.fill_trace(&mut hasher_fragment),
+                    || bitwise.fill_trace(&mut bitwise_fragment),
+                )
+            },
+            || {
+                join(
+                    || memory.fill_trace(&mut memory_fragment),
+                    || kernel_rom.fill_trace(&mut kernel_rom_fragment),
+                )
+            },
+        );
     }
 }
 
@@ -169,7 +169,7 @@ fn validate_bitwise_trace(trace: &ChipletsTrace, start: usize, end: usize) {
         assert_eq!(ZERO, trace[1][row]);
 
         // the expected start of the bitwise trace should hold the expected bitwise op selectors
-        assert_eq!(BITWISE_XOR, trace[2][row]);
+        assert_eq!(BITWISE_XOR, [trace[2][row], trace[3][row]]);
 
         // the final columns should be padded
         for column in trace.iter().skip(BITWISE_TRACE_WIDTH + NUM_BITWISE_SELECTORS) {
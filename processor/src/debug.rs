@@ -1,7 +1,10 @@
 use crate::{
-    range::RangeChecker, system::ContextId, Chiplets, ChipletsLengths, Decoder, ExecutionError,
-    Felt, Host, Process, Stack, System, TraceLenSummary,
+    range::RangeChecker,
+    system::{ContextId, LEND_SEGMENT_ADDR},
+    Chiplets, ChipletsLengths, Decoder, ExecutionError, Felt, Host, MemoryAccess, MemoryAccessKind,
+    Process, Stack, System, TraceLenSummary, FMP_MIN,
 };
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt;
@@ -180,6 +183,14 @@ impl VmStateIterator {
         &self.trace_len_summary
     }
 
+    /// Returns a report on all memory accesses made during execution, or None if memory accesses
+    /// were not recorded.
+    pub fn memory_access_report(&self) -> Option<MemoryAccessReport> {
+        self.chiplets
+            .get_mem_access_log()
+            .map(|accesses| MemoryAccessReport::new(accesses, &self.system))
+    }
+
     /// Returns an instance of [TraceLenSummary] based on provided data.
     fn build_trace_len_summary(
         system: &System,
@@ -243,6 +254,206 @@ impl Iterator for VmStateIterator {
     }
 }
 
+// MEMORY ACCESS REPORT
+// ================================================================================================
+
+/// Analysis of the memory accesses made while executing a program.
+///
+/// The report is built from the log of memory accesses recorded when memory tracing is enabled
+/// (see [ExecutionOptions::with_memory_tracing()](crate::ExecutionOptions)), and contains:
+/// - Reads of memory locations which have not been written to previously in the same context.
+///   Such reads return zeros (or "garbage" for procedure locals) and often indicate a bug. Only
+///   the first such read of each location is reported.
+/// - Accesses which do not follow the memory conventions of the VM, see
+///   [MemoryConventionViolation].
+/// - The memory footprint of each execution context.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryAccessReport {
+    uninitialized_reads: Vec<MemoryAccess>,
+    convention_violations: Vec<MemoryConventionViolation>,
+    footprints: BTreeMap<ContextId, MemoryFootprint>,
+}
+
+impl MemoryAccessReport {
+    /// Returns a new report for the specified memory accesses. The accesses are expected to be
+    /// in the order in which they were made.
+    pub(crate) fn new(accesses: &[MemoryAccess], system: &System) -> Self {
+        let mut report = Self::default();
+        let mut written = BTreeSet::new();
+        let mut reported = BTreeSet::new();
+        let mut addresses = BTreeMap::<ContextId, BTreeSet<u32>>::new();
+
+        for access in accesses {
+            // the lending records are maintained by the VM rather than by the program
+            if access.addr >= LEND_SEGMENT_ADDR {
+                continue;
+            }
+
+            let location = (access.ctx, access.addr);
+            match access.kind {
+                MemoryAccessKind::Read => {
+                    if !written.contains(&location) && reported.insert(location) {
+                        report.uninitialized_reads.push(*access);
+                    }
+                }
+                MemoryAccessKind::Write => {
+                    written.insert(location);
+                }
+            }
+
+            // procedure locals are allocated in the region (FMP_MIN, fmp]; any other access to
+            // the locals region touches memory which does not belong to any allocated local
+            let addr = access.addr as u64;
+            let fmp = system.get_fmp_at(access.clk).as_int();
+            if addr >= FMP_MIN && (addr == FMP_MIN || addr > fmp) {
+                report.convention_violations.push(MemoryConventionViolation::UnallocatedLocal {
+                    access: *access,
+                    fmp,
+                });
+            }
+
+            report.footprints.entry(access.ctx).or_default().record(access);
+            addresses.entry(access.ctx).or_default().insert(access.addr);
+        }
+
+        for (ctx, addresses) in addresses {
+            let footprint = report.footprints.get_mut(&ctx).expect("missing footprint");
+            footprint.num_addresses = addresses.len();
+            footprint.num_local_addresses =
+                addresses.iter().filter(|&&addr| addr as u64 >= FMP_MIN).count();
+        }
+
+        report
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the first read of every memory location which was read before it was written to.
+    pub fn uninitialized_reads(&self) -> &[MemoryAccess] {
+        &self.uninitialized_reads
+    }
+
+    /// Returns all accesses which do not follow the memory conventions of the VM.
+    pub fn convention_violations(&self) -> &[MemoryConventionViolation] {
+        &self.convention_violations
+    }
+
+    /// Returns the memory footprint of every execution context which accessed memory.
+    pub fn footprints(&self) -> &BTreeMap<ContextId, MemoryFootprint> {
+        &self.footprints
+    }
+
+    /// Returns true if no uninitialized reads and no convention violations were detected.
+    pub fn is_clean(&self) -> bool {
+        self.uninitialized_reads.is_empty() && self.convention_violations.is_empty()
+    }
+}
+
+impl fmt::Display for MemoryAccessReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Memory footprint:")?;
+        for (ctx, footprint) in self.footprints.iter() {
+            writeln!(f, "├── ctx={ctx}: {footprint}")?;
+        }
+
+        writeln!(f, "Reads of uninitialized memory: {}", self.uninitialized_reads.len())?;
+        for access in self.uninitialized_reads.iter() {
+            writeln!(f, "├── {access}")?;
+        }
+
+        writeln!(f, "Memory convention violations: {}", self.convention_violations.len())?;
+        for violation in self.convention_violations.iter() {
+            writeln!(f, "├── {violation}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A memory access which does not follow the memory conventions of the VM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryConventionViolation {
+    /// Memory in the procedure locals region (at or above [FMP_MIN]) was accessed at an address
+    /// which is outside of the region `(FMP_MIN, fmp]` allocated to procedure locals. Such an
+    /// address does not belong to the locals of any procedure on the call stack, and is typically
+    /// either global memory placed in the locals region or a local of a procedure which has
+    /// already returned.
+    UnallocatedLocal { access: MemoryAccess, fmp: u64 },
+}
+
+impl fmt::Display for MemoryConventionViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnallocatedLocal { access, fmp } => {
+                write!(f, "unallocated local access (fmp={fmp}): {access}")
+            }
+        }
+    }
+}
+
+/// Summary of the memory accessed by a single execution context.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryFootprint {
+    num_reads: usize,
+    num_writes: usize,
+    num_addresses: usize,
+    num_local_addresses: usize,
+    min_addr: Option<u32>,
+    max_addr: Option<u32>,
+}
+
+impl MemoryFootprint {
+    /// Returns the number of memory reads made by the context.
+    pub fn num_reads(&self) -> usize {
+        self.num_reads
+    }
+
+    /// Returns the number of memory writes made by the context.
+    pub fn num_writes(&self) -> usize {
+        self.num_writes
+    }
+
+    /// Returns the number of distinct memory addresses accessed by the context.
+    pub fn num_addresses(&self) -> usize {
+        self.num_addresses
+    }
+
+    /// Returns the number of distinct addresses in the procedure locals region (at or above
+    /// [FMP_MIN]) accessed by the context.
+    pub fn num_local_addresses(&self) -> usize {
+        self.num_local_addresses
+    }
+
+    /// Returns the lowest and the highest memory addresses accessed by the context.
+    pub fn addr_range(&self) -> Option<(u32, u32)> {
+        self.min_addr.zip(self.max_addr)
+    }
+
+    fn record(&mut self, access: &MemoryAccess) {
+        match access.kind {
+            MemoryAccessKind::Read => self.num_reads += 1,
+            MemoryAccessKind::Write => self.num_writes += 1,
+        }
+        self.min_addr = Some(self.min_addr.map_or(access.addr, |addr| addr.min(access.addr)));
+        self.max_addr = Some(self.max_addr.map_or(access.addr, |addr| addr.max(access.addr)));
+    }
+}
+
+impl fmt::Display for MemoryFootprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} reads, {} writes, {} addresses ({} in locals region)",
+            self.num_reads, self.num_writes, self.num_addresses, self.num_local_addresses
+        )?;
+        if let Some((min_addr, max_addr)) = self.addr_range() {
+            write!(f, ", range=[{min_addr}, {max_addr}]")?;
+        }
+        Ok(())
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 fn word_to_ints(word: &Word) -> [u64; 4] {
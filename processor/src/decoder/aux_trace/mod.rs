@@ -1,5 +1,5 @@
 use super::{Felt, ONE, ZERO};
-use crate::trace::AuxColumnBuilder;
+use crate::{trace::AuxColumnBuilder, utils::join};
 use alloc::vec::Vec;
 use miden_air::trace::main_trace::MainTrace;
 use vm_core::{FieldElement, Operation};
@@ -49,9 +49,15 @@ impl AuxTraceBuilder {
         let block_hash_column_builder = BlockHashTableColumnBuilder::default();
         let op_group_table_column_builder = OpGroupTableColumnBuilder::default();
 
-        let p1 = block_stack_column_builder.build_aux_column(main_trace, rand_elements);
-        let p2 = block_hash_column_builder.build_aux_column(main_trace, rand_elements);
-        let p3 = op_group_table_column_builder.build_aux_column(main_trace, rand_elements);
+        let (p1, (p2, p3)) = join(
+            || block_stack_column_builder.build_aux_column(main_trace, rand_elements),
+            || {
+                join(
+                    || block_hash_column_builder.build_aux_column(main_trace, rand_elements),
+                    || op_group_table_column_builder.build_aux_column(main_trace, rand_elements),
+                )
+            },
+        );
 
         vec![p1, p2, p3]
     }
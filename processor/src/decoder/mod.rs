@@ -2,6 +2,7 @@ use super::{
     Call, Dyn, ExecutionError, Felt, Host, Join, Loop, OpBatch, Operation, Process, Span, Split,
     Word, EMPTY_WORD, MIN_TRACE_LEN, ONE, OP_BATCH_SIZE, ZERO,
 };
+use crate::system::LEND_CALLEE_ADDR;
 use alloc::vec::Vec;
 use miden_air::trace::{
     chiplets::hasher::DIGEST_LEN,
@@ -187,6 +188,12 @@ where
             self.system.start_syscall();
             self.decoder.start_syscall(fn_hash, addr, ctx_info);
         } else {
+            // record the new context as the last context called by the current context; this
+            // lets the new context read memory lent by the current context via MLOADWB
+            let callee_ctx = Felt::from(self.system.clk() + 1);
+            let callee = [callee_ctx, ZERO, ZERO, ZERO];
+            self.chiplets.write_mem(self.system.ctx(), LEND_CALLEE_ADDR, callee);
+
             self.system.start_call(fn_hash);
             self.decoder.start_call(fn_hash, addr, ctx_info);
         }
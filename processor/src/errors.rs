@@ -1,7 +1,7 @@
 use super::{
     crypto::MerkleError,
-    system::{FMP_MAX, FMP_MIN},
-    CodeBlock, Digest, Felt, QuadFelt, Word,
+    system::{ContextId, FMP_MAX, FMP_MIN},
+    CodeBlock, Digest, Felt, Operation, QuadFelt, StackInputs, Word,
 };
 use alloc::string::String;
 use core::fmt::{Display, Formatter};
@@ -16,9 +16,15 @@ use std::error::Error;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ExecutionError {
+    AdviceDataReadFailed(String),
     AdviceMapKeyNotFound(Word),
     AdviceStackReadFailed(u32),
-    CallerNotInSyscall,
+    BorrowedMemoryAddressOutOfBounds {
+        addr: u32,
+        start_addr: u32,
+        end_addr: u32,
+    },
+    BorrowedMemoryNotAvailable(ContextId),
     CodeBlockNotFound(Digest),
     CycleLimitExceeded(u32),
     DivideByZero(u32),
@@ -38,6 +44,8 @@ pub enum ExecutionError {
         start_addr: u64,
         end_addr: u64,
     },
+    InvalidModulus(u32),
+    InvalidShiftAmount(Felt),
     InvalidStackDepthOnReturn(usize),
     InvalidStackWordOffset(usize),
     InvalidTreeDepth {
@@ -59,11 +67,14 @@ pub enum ExecutionError {
     MerkleStoreMergeFailed(MerkleError),
     MerkleStoreUpdateFailed(MerkleError),
     NotBinaryValue(Felt),
+    NotInSyscall(Operation),
     NotU32Value(Felt, Felt),
     ProverError(ProverError),
     SmtNodeNotFound(Word),
     SmtNodePreImageNotValid(Word, usize),
+    StackDepthExceeded(usize),
     SyscallTargetNotInKernel(Digest),
+    TraceStorageFailed(String),
     UnexecutableCodeBlock(CodeBlock),
 }
 
@@ -72,13 +83,24 @@ impl Display for ExecutionError {
         use ExecutionError::*;
 
         match self {
+            AdviceDataReadFailed(err) => write!(f, "Failed to read advice data: {err}"),
             AdviceMapKeyNotFound(key) => {
                 let hex = to_hex(Felt::elements_as_bytes(key))?;
                 write!(f, "Value for key {hex} not present in the advice map")
             }
             AdviceStackReadFailed(step) => write!(f, "Advice stack read failed at step {step}"),
-            CallerNotInSyscall => {
-                write!(f, "Instruction `caller` used outside of kernel context")
+            BorrowedMemoryAddressOutOfBounds {
+                addr,
+                start_addr,
+                end_addr,
+            } => {
+                write!(
+                    f,
+                    "Borrowed memory address {addr} is outside of the lent segment [{start_addr}, {end_addr})"
+                )
+            }
+            BorrowedMemoryNotAvailable(ctx) => {
+                write!(f, "No memory segment was lent to context {ctx}")
             }
             CodeBlockNotFound(digest) => {
                 let hex = to_hex(&digest.as_bytes())?;
@@ -132,6 +154,15 @@ impl Display for ExecutionError {
             } => {
                 write!(f, "Memory range start address cannot exceed end address, but was ({start_addr}, {end_addr})")
             }
+            InvalidModulus(clk) => {
+                write!(
+                    f,
+                    "Modulus must be odd and greater than 2, but was not at clock cycle {clk}"
+                )
+            }
+            InvalidShiftAmount(value) => {
+                write!(f, "Shift amount must be at most 32, but was {value}")
+            }
             InvalidStackDepthOnReturn(depth) => {
                 write!(f, "When returning from a call, stack depth must be {STACK_TOP_SIZE}, but was {depth}")
             }
@@ -171,6 +202,9 @@ impl Display for ExecutionError {
             NotBinaryValue(v) => {
                 write!(f, "An operation expected a binary value, but received {v}")
             }
+            NotInSyscall(op) => {
+                write!(f, "Operation {op} can be executed only inside of a SYSCALL block")
+            }
             NotU32Value(v, err_code) => {
                 write!(
                     f,
@@ -185,11 +219,17 @@ impl Display for ExecutionError {
                 let node_hex = to_hex(Felt::elements_as_bytes(node))?;
                 write!(f, "Invalid pre-image for node {node_hex}. Expected pre-image length to be a multiple of 8, but was {preimage_len}")
             }
+            StackDepthExceeded(depth) => write!(
+                f,
+                "Stack depth {depth} exceeds the maximum number of stack inputs ({})",
+                StackInputs::MAX_LEN
+            ),
             ProverError(error) => write!(f, "Proof generation failed: {error}"),
             SyscallTargetNotInKernel(proc) => {
                 let hex = to_hex(&proc.as_bytes())?;
                 write!(f, "Syscall failed: procedure with root {hex} was not found in the kernel")
             }
+            TraceStorageFailed(err) => write!(f, "Failed to access the trace storage: {err}"),
             UnexecutableCodeBlock(block) => {
                 write!(f, "Execution reached unexecutable code block {block:?}")
             }
@@ -0,0 +1,690 @@
+use super::{
+    injectors, AdviceInputs, AdviceProvider, AdviceSource, ExecutionError, Felt, InnerNodeInfo,
+    MerklePath, MerkleStore, NodeIndex, RpoDigest, Word,
+};
+use crate::ProcessState;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::ToString,
+    vec,
+    vec::Vec,
+};
+use core::cell::{Cell, OnceCell, RefCell};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+use vm_core::{
+    utils::{Deserializable, SliceReader},
+    SignatureKind,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Magic bytes at the start of every advice data file.
+const MAGIC: &[u8; 4] = b"MADF";
+
+/// Version of the advice data file format.
+const VERSION: u8 = 1;
+
+/// Size of the file header: magic bytes, version, and the number of map entries and nodes.
+const HEADER_SIZE: u64 = 4 + 1 + 8 + 8;
+
+/// Size of an advice map index record: key, offset of the values, and the number of values.
+const MAP_RECORD_SIZE: u64 = 32 + 8 + 4;
+
+/// Size of a Merkle node record: node digest, and digests of its left and right children.
+const NODE_RECORD_SIZE: u64 = 32 * 3;
+
+// ADVICE DATA FILE
+// ================================================================================================
+
+/// A read-only file of advice map entries and Merkle store nodes, keyed by their digests.
+///
+/// The file consists of a header, an index of advice map entries sorted by key, a list of Merkle
+/// nodes sorted by node digest, and the values of the advice map entries. When a file is opened,
+/// only the index of the advice map is read into memory. Values of advice map entries are read
+/// from the file the first time they are accessed, and Merkle nodes are looked up via a binary
+/// search over the node list on every access.
+///
+/// Advice data files are created via [AdviceDataFile::write()].
+#[derive(Debug)]
+pub struct AdviceDataFile {
+    file: RefCell<File>,
+    map_index: BTreeMap<RpoDigest, MapEntry>,
+    num_nodes: u64,
+    nodes_offset: u64,
+    values_offset: u64,
+    num_loaded_nodes: Cell<usize>,
+}
+
+/// An entry of the advice map index; values are loaded on first access.
+#[derive(Debug)]
+struct MapEntry {
+    offset: u64,
+    len: u32,
+    values: OnceCell<Vec<Felt>>,
+}
+
+impl AdviceDataFile {
+    /// File extension for advice data files.
+    pub const FILE_EXTENSION: &'static str = "madf";
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Opens the advice data file located at the specified path.
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be read, or if it is not a valid advice data file.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+
+        let mut header = [0u8; HEADER_SIZE as usize];
+        file.read_exact(&mut header)?;
+        if &header[..4] != MAGIC {
+            return Err(invalid_data("not an advice data file"));
+        }
+        if header[4] != VERSION {
+            return Err(invalid_data(format!("unsupported advice data version {}", header[4])));
+        }
+        let num_entries = u64::from_le_bytes(header[5..13].try_into().expect("8 bytes"));
+        let num_nodes = u64::from_le_bytes(header[13..21].try_into().expect("8 bytes"));
+
+        // make sure the advice map index and the Merkle nodes declared in the header fit into the
+        // file before allocating memory for the index
+        let index_len = num_entries
+            .checked_mul(MAP_RECORD_SIZE)
+            .ok_or_else(|| invalid_data("too many advice map entries"))?;
+        let nodes_offset = HEADER_SIZE
+            .checked_add(index_len)
+            .ok_or_else(|| invalid_data("too many advice map entries"))?;
+        let values_offset = num_nodes
+            .checked_mul(NODE_RECORD_SIZE)
+            .and_then(|nodes_len| nodes_offset.checked_add(nodes_len))
+            .ok_or_else(|| invalid_data("too many Merkle nodes"))?;
+        let num_values = (file.metadata()?.len())
+            .checked_sub(values_offset)
+            .ok_or_else(|| invalid_data("file is truncated"))?
+            / 8;
+
+        // read the advice map index and make sure all values are within the file
+        let mut index = vec![0u8; usize::try_from(index_len).map_err(invalid_data)?];
+        file.read_exact(&mut index)?;
+
+        let mut map_index = BTreeMap::new();
+        for record in index.chunks(MAP_RECORD_SIZE as usize) {
+            let key = read_digest(&record[..32])?;
+            let offset = u64::from_le_bytes(record[32..40].try_into().expect("8 bytes"));
+            let len = u32::from_le_bytes(record[40..].try_into().expect("4 bytes"));
+            if offset.checked_add(len as u64).map_or(true, |end| end > num_values) {
+                return Err(invalid_data(format!("values of advice map key {key} are truncated")));
+            }
+
+            let entry = MapEntry {
+                offset,
+                len,
+                values: OnceCell::new(),
+            };
+            if map_index.insert(key, entry).is_some() {
+                return Err(invalid_data(format!("duplicate advice map key {key}")));
+            }
+        }
+
+        Ok(Self {
+            file: RefCell::new(file),
+            map_index,
+            num_nodes,
+            nodes_offset,
+            values_offset,
+            num_loaded_nodes: Cell::new(0),
+        })
+    }
+
+    /// Writes the advice map and the Merkle store of the specified advice inputs into a new
+    /// advice data file at the specified path. The advice stack of the inputs is not written.
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be written.
+    pub fn write(path: impl AsRef<Path>, inputs: AdviceInputs) -> io::Result<()> {
+        let (_, map, store) = inputs.into_parts();
+        let map: Vec<(RpoDigest, Vec<Felt>)> = map.into_iter().collect();
+        let mut nodes: Vec<InnerNodeInfo> = store.inner_nodes().collect();
+        nodes.sort_by_key(|node| node.value);
+
+        let mut file = BufWriter::new(File::create(path)?);
+        file.write_all(MAGIC)?;
+        file.write_all(&[VERSION])?;
+        file.write_all(&(map.len() as u64).to_le_bytes())?;
+        file.write_all(&(nodes.len() as u64).to_le_bytes())?;
+
+        // entries of the advice map are sorted by key because they come from a BTreeMap
+        let mut offset = 0u64;
+        for (key, values) in map.iter() {
+            let len = u32::try_from(values.len()).map_err(invalid_data)?;
+            file.write_all(&key.as_bytes())?;
+            file.write_all(&offset.to_le_bytes())?;
+            file.write_all(&len.to_le_bytes())?;
+            offset += len as u64;
+        }
+
+        for node in nodes.iter() {
+            file.write_all(&node.value.as_bytes())?;
+            file.write_all(&node.left.as_bytes())?;
+            file.write_all(&node.right.as_bytes())?;
+        }
+
+        for value in map.iter().flat_map(|(_, values)| values.iter()) {
+            file.write_all(&value.as_int().to_le_bytes())?;
+        }
+
+        file.flush()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of advice map entries in this file.
+    pub fn num_map_entries(&self) -> usize {
+        self.map_index.len()
+    }
+
+    /// Returns the number of Merkle nodes in this file.
+    pub fn num_nodes(&self) -> u64 {
+        self.num_nodes
+    }
+
+    /// Returns the number of advice map entries whose values have been read from the file.
+    pub fn num_loaded_map_entries(&self) -> usize {
+        self.map_index.values().filter(|entry| entry.values.get().is_some()).count()
+    }
+
+    /// Returns the number of Merkle nodes which have been read from the file.
+    pub fn num_loaded_nodes(&self) -> usize {
+        self.num_loaded_nodes.get()
+    }
+
+    /// Returns the values associated with the specified key in the advice map, or None if the key
+    /// is not present in the file.
+    ///
+    /// Values are read from the file the first time they are accessed.
+    ///
+    /// # Errors
+    /// Returns an error if the values could not be read from the file.
+    pub fn get_map_values(&self, key: &RpoDigest) -> io::Result<Option<&[Felt]>> {
+        let entry = match self.map_index.get(key) {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+
+        if entry.values.get().is_none() {
+            let mut bytes = vec![0u8; entry.len as usize * 8];
+            self.read_at(self.values_offset + entry.offset * 8, &mut bytes)?;
+            let mut reader = SliceReader::new(&bytes);
+            let values = (0..entry.len)
+                .map(|_| Felt::read_from(&mut reader))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(invalid_data)?;
+            let _ = entry.values.set(values);
+        }
+
+        Ok(entry.values.get().map(Vec::as_slice))
+    }
+
+    /// Returns the left and right children of the Merkle node with the specified digest, or None
+    /// if the node is not present in the file.
+    ///
+    /// # Errors
+    /// Returns an error if the node could not be read from the file.
+    pub fn get_node(&self, node: &RpoDigest) -> io::Result<Option<(RpoDigest, RpoDigest)>> {
+        let mut record = [0u8; NODE_RECORD_SIZE as usize];
+        let (mut lo, mut hi) = (0, self.num_nodes);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            self.read_at(self.nodes_offset + mid * NODE_RECORD_SIZE, &mut record)?;
+            match read_digest(&record[..32])?.cmp(node) {
+                core::cmp::Ordering::Less => lo = mid + 1,
+                core::cmp::Ordering::Greater => hi = mid,
+                core::cmp::Ordering::Equal => {
+                    self.num_loaded_nodes.set(self.num_loaded_nodes.get() + 1);
+                    return Ok(Some((read_digest(&record[32..64])?, read_digest(&record[64..])?)));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> io::Result<()> {
+        let mut file = self.file.borrow_mut();
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(buf)
+    }
+}
+
+// FILE ADVICE PROVIDER
+// ================================================================================================
+
+/// An [AdviceProvider] which reads advice map entries and Merkle store nodes lazily from an
+/// [AdviceDataFile].
+///
+/// The advice stack, as well as the advice map entries and Merkle nodes provided via
+/// [AdviceInputs] or inserted during execution, are kept in memory. Advice map entries which are
+/// not in memory are looked up in the advice data file, and Merkle nodes are read from the file
+/// when a path through them is accessed. Only the accessed data is loaded into memory.
+#[derive(Debug)]
+pub struct FileAdviceProvider {
+    stack: Vec<Felt>,
+    map: BTreeMap<RpoDigest, Vec<Felt>>,
+    store: RefCell<MerkleStore>,
+    data: AdviceDataFile,
+}
+
+impl FileAdviceProvider {
+    /// Returns a new [FileAdviceProvider] instantiated from the specified in-memory inputs and
+    /// advice data file. Advice map entries of the inputs take precedence over the entries of the
+    /// file.
+    pub fn new(inputs: AdviceInputs, data: AdviceDataFile) -> Self {
+        let (mut stack, map, store) = inputs.into_parts();
+        stack.reverse();
+        Self {
+            stack,
+            map: map.into_iter().collect(),
+            store: RefCell::new(store),
+            data,
+        }
+    }
+
+    /// Returns the advice data file backing this provider.
+    pub fn data(&self) -> &AdviceDataFile {
+        &self.data
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Loads the nodes on the path from the specified root to the node at the specified index
+    /// into the in-memory Merkle store. Loading stops at the first node which is not present in
+    /// the advice data file.
+    fn load_path(&self, root: Word, index: NodeIndex) -> Result<(), ExecutionError> {
+        let mut store = self.store.borrow_mut();
+        let mut node = RpoDigest::from(root);
+        for i in (0..index.depth()).rev() {
+            let (left, right) = match get_children(&store, node) {
+                Some(children) => children,
+                None => match self.data.get_node(&node).map_err(read_failed)? {
+                    Some((left, right)) => {
+                        store.extend([InnerNodeInfo {
+                            value: node,
+                            left,
+                            right,
+                        }]);
+                        (left, right)
+                    }
+                    None => break,
+                },
+            };
+            node = if (index.value() >> i) & 1 == 0 { left } else { right };
+        }
+
+        Ok(())
+    }
+
+    /// Loads all nodes of the trees with the specified roots into the in-memory Merkle store.
+    fn load_subtrees(&self, roots: &[RpoDigest]) -> Result<(), ExecutionError> {
+        let mut store = self.store.borrow_mut();
+        let mut visited = BTreeSet::new();
+        let mut nodes = roots.to_vec();
+        while let Some(node) = nodes.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            if let Some((left, right)) = get_children(&store, node) {
+                nodes.extend([left, right]);
+            } else if let Some((left, right)) = self.data.get_node(&node).map_err(read_failed)? {
+                store.extend([InnerNodeInfo {
+                    value: node,
+                    left,
+                    right,
+                }]);
+                nodes.extend([left, right]);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl AdviceProvider for FileAdviceProvider {
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    fn pop_stack<S: ProcessState>(&mut self, process: &S) -> Result<Felt, ExecutionError> {
+        self.stack.pop().ok_or(ExecutionError::AdviceStackReadFailed(process.clk()))
+    }
+
+    fn pop_stack_word<S: ProcessState>(&mut self, process: &S) -> Result<Word, ExecutionError> {
+        if self.stack.len() < 4 {
+            return Err(ExecutionError::AdviceStackReadFailed(process.clk()));
+        }
+
+        let idx = self.stack.len() - 4;
+        let result =
+            [self.stack[idx + 3], self.stack[idx + 2], self.stack[idx + 1], self.stack[idx]];
+
+        self.stack.truncate(idx);
+
+        Ok(result)
+    }
+
+    fn pop_stack_dword<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<[Word; 2], ExecutionError> {
+        let word0 = self.pop_stack_word(process)?;
+        let word1 = self.pop_stack_word(process)?;
+
+        Ok([word0, word1])
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        match source {
+            AdviceSource::Value(value) => {
+                self.stack.push(value);
+            }
+            AdviceSource::Word(word) => {
+                self.stack.extend(word.iter().rev());
+            }
+            AdviceSource::Map { key, include_len } => {
+                let values = get_map_values(&self.map, &self.data, &key.into())?
+                    .ok_or(ExecutionError::AdviceMapKeyNotFound(key))?;
+
+                self.stack.extend(values.iter().rev());
+                if include_len {
+                    self.stack
+                        .push(Felt::try_from(values.len() as u64).expect("value length too big"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_signature(
+        &self,
+        kind: SignatureKind,
+        pub_key: Word,
+        msg: Word,
+    ) -> Result<Vec<Felt>, ExecutionError> {
+        let pk_sk = get_map_values(&self.map, &self.data, &pub_key.into())?
+            .ok_or(ExecutionError::AdviceMapKeyNotFound(pub_key))?;
+
+        match kind {
+            SignatureKind::RpoFalcon512 => injectors::dsa::falcon_sign(pk_sk, msg),
+        }
+    }
+
+    // ADVICE MAP
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the values associated with the specified key, reading them from the advice data
+    /// file if needed. Values which could not be read from the file are reported as missing.
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]> {
+        get_map_values(&self.map, &self.data, key).ok().flatten()
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.map.insert(key.into(), values);
+        Ok(())
+    }
+
+    // MERKLE STORE
+    // --------------------------------------------------------------------------------------------
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        let index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        self.load_path(root, index)?;
+        self.store
+            .borrow()
+            .get_node(root.into(), index)
+            .map(|v| v.into())
+            .map_err(ExecutionError::MerkleStoreLookupFailed)
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        let index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        self.load_path(root, index)?;
+        self.store
+            .borrow()
+            .get_path(root.into(), index)
+            .map(|value| value.path)
+            .map_err(ExecutionError::MerkleStoreLookupFailed)
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        let tree_depth = u8::try_from(tree_depth.as_int())
+            .map_err(|_| ExecutionError::InvalidTreeDepth { depth: *tree_depth })?;
+        if let Ok(node_index) = NodeIndex::new(tree_depth, index.as_int()) {
+            self.load_path(root, node_index)?;
+        }
+        self.store
+            .borrow()
+            .get_leaf_depth(root.into(), tree_depth, index.as_int())
+            .map_err(ExecutionError::MerkleStoreLookupFailed)
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<(MerklePath, Word), ExecutionError> {
+        let node_index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        self.load_path(root, node_index)?;
+        self.store
+            .get_mut()
+            .set_node(root.into(), node_index, value.into())
+            .map(|root| (root.path, root.root.into()))
+            .map_err(ExecutionError::MerkleStoreUpdateFailed)
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        self.store
+            .get_mut()
+            .merge_roots(lhs.into(), rhs.into())
+            .map(|v| v.into())
+            .map_err(ExecutionError::MerkleStoreMergeFailed)
+    }
+
+    /// Returns a subset of the Merkle store containing the trees with the specified roots.
+    ///
+    /// All nodes of these trees are loaded from the advice data file. Trees which could not be
+    /// read from the file are not included in the returned store.
+    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+    where
+        I: Iterator<Item = R>,
+        R: core::borrow::Borrow<RpoDigest>,
+    {
+        let roots: Vec<RpoDigest> = roots.map(|root| *root.borrow()).collect();
+        let _ = self.load_subtrees(&roots);
+        self.store.borrow().subset(roots.iter())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the values associated with the specified key in the in-memory advice map, or in the
+/// advice data file if the key is not present in memory.
+fn get_map_values<'a>(
+    map: &'a BTreeMap<RpoDigest, Vec<Felt>>,
+    data: &'a AdviceDataFile,
+    key: &RpoDigest,
+) -> Result<Option<&'a [Felt]>, ExecutionError> {
+    match map.get(key) {
+        Some(values) => Ok(Some(values)),
+        None => data.get_map_values(key).map_err(read_failed),
+    }
+}
+
+/// Returns the children of the specified node, or None if the node is not present in the store.
+fn get_children(store: &MerkleStore, node: RpoDigest) -> Option<(RpoDigest, RpoDigest)> {
+    let left = store.get_node(node, NodeIndex::root().left_child()).ok()?;
+    let right = store.get_node(node, NodeIndex::root().right_child()).ok()?;
+    Some((left, right))
+}
+
+fn read_digest(bytes: &[u8]) -> io::Result<RpoDigest> {
+    RpoDigest::read_from_bytes(bytes).map_err(invalid_data)
+}
+
+fn invalid_data<E: ToString>(err: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err.to_string())
+}
+
+fn read_failed(err: io::Error) -> ExecutionError {
+    ExecutionError::AdviceDataReadFailed(err.to_string())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AdviceDataFile, AdviceInputs, AdviceProvider, FileAdviceProvider, RpoDigest};
+    use crate::{AdviceSource, Felt, MemAdviceProvider, ZERO};
+    use alloc::{vec, vec::Vec};
+    use vm_core::crypto::merkle::{MerkleStore, MerkleTree};
+
+    #[test]
+    fn file_advice_provider() {
+        let leaves: Vec<_> = (0..64u64).map(|i| [Felt::new(i), ZERO, ZERO, ZERO]).collect();
+        let tree = MerkleTree::new(leaves).unwrap();
+        let map: Vec<_> = (0..16u64)
+            .map(|i| ([Felt::new(i), ZERO, ZERO, ZERO], vec![Felt::new(i); 3]))
+            .collect();
+        let inputs = AdviceInputs::default()
+            .with_map(map.iter().map(|(key, values)| (RpoDigest::from(*key), values.clone())))
+            .with_merkle_store(MerkleStore::from(&tree));
+
+        let path = std::env::temp_dir().join(format!("advice-{}.madf", std::process::id()));
+        AdviceDataFile::write(&path, inputs.clone()).unwrap();
+
+        let data = AdviceDataFile::open(&path).unwrap();
+        assert_eq!(16, data.num_map_entries());
+        let mut provider = FileAdviceProvider::new(AdviceInputs::default(), data);
+        let mut expected = MemAdviceProvider::from(inputs);
+
+        // only the accessed map entries and the nodes on the accessed paths are loaded
+        let (key, _) = map[5];
+        provider
+            .push_stack(AdviceSource::Map {
+                key,
+                include_len: true,
+            })
+            .unwrap();
+        expected
+            .push_stack(AdviceSource::Map {
+                key,
+                include_len: true,
+            })
+            .unwrap();
+        assert_eq!(1, provider.data().num_loaded_map_entries());
+        assert_eq!(
+            expected.get_mapped_values(&key.into()),
+            provider.get_mapped_values(&key.into())
+        );
+        assert!(provider.get_mapped_values(&[Felt::new(100); 4].into()).is_none());
+
+        let root = tree.root().into();
+        let (depth, index) = (Felt::new(6), Felt::new(37));
+        assert_eq!(
+            expected.get_merkle_path(root, &depth, &index).unwrap(),
+            provider.get_merkle_path(root, &depth, &index).unwrap()
+        );
+        assert_eq!(6, provider.data().num_loaded_nodes());
+        assert_eq!(
+            expected.get_tree_node(root, &Felt::new(3), &Felt::new(1)).unwrap(),
+            provider.get_tree_node(root, &Felt::new(3), &Felt::new(1)).unwrap()
+        );
+        assert_eq!(8, provider.data().num_loaded_nodes());
+
+        // updates are applied to the in-memory store
+        let value = [Felt::new(100); 4];
+        assert_eq!(
+            expected.update_merkle_node(root, &depth, &Felt::new(2), value).unwrap(),
+            provider.update_merkle_node(root, &depth, &Felt::new(2), value).unwrap()
+        );
+        assert_eq!(
+            expected.get_store_subset([tree.root()].iter()).inner_nodes().count(),
+            provider.get_store_subset([tree.root()].iter()).inner_nodes().count()
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn invalid_advice_data_file() {
+        let path = std::env::temp_dir().join(format!("invalid-{}.madf", std::process::id()));
+        AdviceDataFile::write(
+            &path,
+            AdviceInputs::default().with_map([(RpoDigest::default(), vec![Felt::new(1); 8])]),
+        )
+        .unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..bytes.len() - 8]).unwrap();
+        assert!(AdviceDataFile::open(&path).is_err());
+        std::fs::write(&path, b"not an advice data file").unwrap();
+        assert!(AdviceDataFile::open(&path).is_err());
+
+        // headers declaring more map entries or Merkle nodes than the file holds are rejected
+        // before memory is allocated for the index
+        for (range, count) in [(5..13, 1u64 << 40), (13..21, 1u64 << 40), (5..13, u64::MAX)] {
+            let mut invalid_bytes = bytes.clone();
+            invalid_bytes[range].copy_from_slice(&count.to_le_bytes());
+            std::fs::write(&path, &invalid_bytes).unwrap();
+            let err = AdviceDataFile::open(&path).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -393,6 +393,87 @@ pub(crate) fn push_ilog2<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Given a 256-bit value in Montgomery form and a 256-bit prime modulus on the top of the
+/// stack, computes the multiplicative inverse of the value (also in Montgomery form) and pushes
+/// the result onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, a0, a1, a2, a3, a4, a5, a6, a7, ...]
+///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+///
+/// Where m[0..8], a[0..8] and b[0..8] are the 32-bit limbs of the modulus, the value and its
+/// inverse respectively (with limb 0 being the least significant one). The Montgomery radix is
+/// 2^256. If the value is zero, the pushed result is also zero.
+///
+/// The inverse is computed as a^(m - 2) via Fermat's little theorem, and thus, the result is
+/// correct only if the modulus is prime.
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the limbs is not a u32 value.
+/// - The modulus is even or smaller than 3.
+pub(crate) fn push_u256_mont_inv_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let modulus = get_u256_from_stack(process, 0)?;
+    let value = get_u256_from_stack(process, 8)?;
+
+    if modulus[0] & 1 == 0 || (modulus[0] < 3 && modulus[1..].iter().all(|&limb| limb == 0)) {
+        return Err(ExecutionError::InvalidModulus(process.clk()));
+    }
+
+    let result = u256_mont_inv(&value, &modulus);
+    for limb in result {
+        advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
+/// Given a 256-bit odd modulus and the lower 256 bits of a 512-bit product on the top of the
+/// stack, computes the quotient used by the Montgomery reduction of the product and pushes it
+/// onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, p0, p1, p2, p3, p4, p5, p6, p7, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, p0, p1, p2, p3, p4, p5, p6, p7, ...]
+///   Advice stack: [q7, q6, q5, q4, q3, q2, q1, q0, ...]
+///
+/// Where m[0..8], p[0..8] and q[0..8] are the 32-bit limbs of the modulus, the lower half of the
+/// product and the quotient respectively (with limb 0 being the least significant one). The
+/// quotient is q = -p * m^-1 mod 2^256, and thus, p + q * m is divisible by 2^256.
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the limbs is not a u32 value.
+/// - The modulus is even.
+pub(crate) fn push_u256_mont_quot_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let modulus = get_u256_from_stack(process, 0)?;
+    let product = get_u256_from_stack(process, 8)?;
+
+    if modulus[0] & 1 == 0 {
+        return Err(ExecutionError::InvalidModulus(process.clk()));
+    }
+
+    let result = u256_mont_quot(&product, &modulus);
+    for limb in result {
+        advice_provider.push_stack(AdviceSource::Value(Felt::from(limb)))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -418,3 +499,167 @@ fn push_transformed_stack_top<S: ProcessState, A: AdviceProvider>(
     advice_provider.push_stack(AdviceSource::Value(transformed_stack_top))?;
     Ok(HostResponse::None)
 }
+
+/// Reads a 256-bit value as eight 32-bit limbs from the stack starting at the specified position,
+/// with the least significant limb being the closest to the top of the stack.
+fn get_u256_from_stack<S: ProcessState>(
+    process: &S,
+    start: usize,
+) -> Result<[u32; 8], ExecutionError> {
+    let mut result = [0u32; 8];
+    for (i, limb) in result.iter_mut().enumerate() {
+        let value = process.get_stack_item(start + i);
+        *limb = value
+            .as_int()
+            .try_into()
+            .map_err(|_| ExecutionError::NotU32Value(value, ZERO))?;
+    }
+    Ok(result)
+}
+
+/// Computes the multiplicative inverse of a 256-bit value in Montgomery form (with radix 2^256)
+/// modulo the specified odd prime modulus. The result is also in Montgomery form.
+///
+/// The inverse is computed by raising the value to the power of m - 2 in the Montgomery domain.
+fn u256_mont_inv(value: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    // compute -m^{-1} mod 2^32 using Newton's iteration; each iteration doubles the number of
+    // correct bits, starting from 3 correct bits for an odd modulus
+    let mut m_inv = modulus[0];
+    for _ in 0..4 {
+        m_inv = m_inv.wrapping_mul(2u32.wrapping_sub(modulus[0].wrapping_mul(m_inv)));
+    }
+    let m_inv = m_inv.wrapping_neg();
+
+    // compute the exponent m - 2; since the modulus is odd and greater than 2, this never
+    // underflows
+    let mut exp = *modulus;
+    u256_sub_assign(&mut exp, &[2, 0, 0, 0, 0, 0, 0, 0]);
+
+    // left-to-right square-and-multiply, starting from the most significant set bit of the
+    // exponent; since the exponent is at least 1, this also handles the zero value correctly
+    let base = u256_reduce(value, modulus);
+    let mut bits = u256_bits(&exp);
+    bits.by_ref().find(|&bit| bit);
+    let mut result = base;
+    for bit in bits {
+        result = u256_mont_mul(&result, &result, modulus, m_inv);
+        if bit {
+            result = u256_mont_mul(&result, &base, modulus, m_inv);
+        }
+    }
+    result
+}
+
+/// Computes -p * m^{-1} mod 2^256 for an odd modulus m.
+fn u256_mont_quot(product: &[u32; 8], modulus: &[u32; 8]) -> [u32; 8] {
+    // compute m^{-1} mod 2^256 using Newton's iteration; each iteration doubles the number of
+    // correct bits, starting from 3 correct bits for an odd modulus
+    let mut m_inv = *modulus;
+    for _ in 0..7 {
+        let mut correction = [2, 0, 0, 0, 0, 0, 0, 0];
+        u256_sub_assign(&mut correction, &u256_wrapping_mul(modulus, &m_inv));
+        m_inv = u256_wrapping_mul(&m_inv, &correction);
+    }
+
+    let mut result = [0u32; 8];
+    u256_sub_assign(&mut result, &u256_wrapping_mul(product, &m_inv));
+    result
+}
+
+/// Computes a * b mod 2^256.
+fn u256_wrapping_mul(a: &[u32; 8], b: &[u32; 8]) -> [u32; 8] {
+    let mut result = [0u32; 8];
+    for (i, &a_i) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for j in 0..8 - i {
+            let sum = result[i + j] as u64 + a_i as u64 * b[j] as u64 + carry;
+            result[i + j] = sum as u32;
+            carry = sum >> 32;
+        }
+    }
+    result
+}
+
+/// Computes a * b * 2^-256 mod m using the CIOS variant of the Montgomery multiplication, where
+/// `m_inv` is -m^{-1} mod 2^32. Both inputs must be smaller than m, and the result is fully
+/// reduced modulo m.
+fn u256_mont_mul(a: &[u32; 8], b: &[u32; 8], m: &[u32; 8], m_inv: u32) -> [u32; 8] {
+    let mut t = [0u32; 10];
+    for &b_i in b.iter() {
+        // t = t + a * b_i
+        let mut carry = 0u64;
+        for j in 0..8 {
+            let sum = t[j] as u64 + a[j] as u64 * b_i as u64 + carry;
+            t[j] = sum as u32;
+            carry = sum >> 32;
+        }
+        let sum = t[8] as u64 + carry;
+        t[8] = sum as u32;
+        t[9] = (sum >> 32) as u32;
+
+        // t = (t + q * m) / 2^32, where q is chosen such that the lowest limb becomes zero
+        let q = t[0].wrapping_mul(m_inv);
+        let mut carry = (t[0] as u64 + q as u64 * m[0] as u64) >> 32;
+        for j in 1..8 {
+            let sum = t[j] as u64 + q as u64 * m[j] as u64 + carry;
+            t[j - 1] = sum as u32;
+            carry = sum >> 32;
+        }
+        let sum = t[8] as u64 + carry;
+        t[7] = sum as u32;
+        t[8] = t[9] + (sum >> 32) as u32;
+    }
+
+    // the result is smaller than 2m, so at most one subtraction is needed
+    let mut result = [0u32; 8];
+    result.copy_from_slice(&t[..8]);
+    if t[8] != 0 || !u256_lt(&result, m) {
+        u256_sub_assign(&mut result, m);
+    }
+    result
+}
+
+/// Reduces a 256-bit value modulo m by shifting the bits of the value into the remainder one at a
+/// time.
+fn u256_reduce(value: &[u32; 8], m: &[u32; 8]) -> [u32; 8] {
+    let mut result = [0u32; 8];
+    for bit in u256_bits(value) {
+        // result = 2 * result + bit; since result < m, this value is smaller than 2m and thus, at
+        // most one subtraction is needed (the carry out of the top limb is accounted for by the
+        // wrapping subtraction)
+        let mut carry = bit as u32;
+        for limb in result.iter_mut() {
+            let next_carry = *limb >> 31;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+        if carry != 0 || !u256_lt(&result, m) {
+            u256_sub_assign(&mut result, m);
+        }
+    }
+    result
+}
+
+/// Returns an iterator over the bits of a 256-bit value, starting from the most significant one.
+fn u256_bits(value: &[u32; 8]) -> impl Iterator<Item = bool> + '_ {
+    value
+        .iter()
+        .rev()
+        .flat_map(|limb| (0..32).rev().map(move |i| (limb >> i) & 1 == 1))
+}
+
+/// Returns true if a < b.
+fn u256_lt(a: &[u32; 8], b: &[u32; 8]) -> bool {
+    a.iter().rev().lt(b.iter().rev())
+}
+
+/// Computes a = a - b mod 2^256.
+fn u256_sub_assign(a: &mut [u32; 8], b: &[u32; 8]) {
+    let mut borrow = false;
+    for (a_j, &b_j) in a.iter_mut().zip(b.iter()) {
+        let (diff, overflow_1) = a_j.overflowing_sub(b_j);
+        let (diff, overflow_2) = diff.overflowing_sub(borrow as u32);
+        *a_j = diff;
+        borrow = overflow_1 || overflow_2;
+    }
+}
@@ -1,6 +1,11 @@
 use super::{AdviceMap, Felt, InnerNodeInfo, InputError, MerkleStore};
+use crate::utils::read_many_bounded;
 use alloc::vec::Vec;
-use vm_core::crypto::hash::RpoDigest;
+use vm_core::{
+    crypto::{hash::RpoDigest, merkle::StoreNode},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    FieldElement,
+};
 
 // ADVICE INPUTS
 // ================================================================================================
@@ -114,6 +119,11 @@ impl AdviceInputs {
         self.map.get(key)
     }
 
+    /// Returns the underlying [AdviceMap].
+    pub const fn map(&self) -> &AdviceMap {
+        &self.map
+    }
+
     /// Returns the underlying [MerkleStore].
     pub const fn merkle_store(&self) -> &MerkleStore {
         &self.store
@@ -130,6 +140,37 @@ impl AdviceInputs {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.stack.len());
+        target.write_many(&self.stack);
+        self.map.write_into(target);
+        self.store.write_into(target);
+    }
+}
+
+impl Deserializable for AdviceInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack_len = source.read_usize()?;
+        let stack = read_many_bounded(source, stack_len, Felt::ELEMENT_BYTES)?;
+        let map = AdviceMap::read_from(source)?;
+
+        // the nodes of the Merkle store are read here rather than via MerkleStore::read_from(), so
+        // that a malformed number of nodes cannot cause a large allocation; each node consists of
+        // its digest and the digests of its children, each digest taking 4 field elements
+        let num_nodes =
+            usize::try_from(source.read_u64()?).map_err(|_| DeserializationError::UnexpectedEOF)?;
+        let nodes: Vec<(RpoDigest, StoreNode)> =
+            read_many_bounded(source, num_nodes, 3 * 4 * Felt::ELEMENT_BYTES)?;
+        let store = nodes.into_iter().collect();
+
+        Ok(Self { stack, map, store })
+    }
+}
+
 // INTERNALS
 // ================================================================================================
 
@@ -1,8 +1,13 @@
 use super::Felt;
+use crate::utils::read_many_bounded;
 use alloc::collections::btree_map::IntoIter;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
-use vm_core::crypto::hash::RpoDigest;
+use vm_core::{
+    crypto::hash::RpoDigest,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    FieldElement,
+};
 
 // ADVICE MAP
 // ================================================================================================
@@ -35,6 +40,11 @@ impl AdviceMap {
     pub fn remove(&mut self, key: RpoDigest) -> Option<Vec<Felt>> {
         self.0.remove(&key)
     }
+
+    /// Returns an iterator over the key-value pairs of the advice map, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&RpoDigest, &[Felt])> {
+        self.0.iter().map(|(key, values)| (key, values.as_slice()))
+    }
 }
 
 impl From<BTreeMap<RpoDigest, Vec<Felt>>> for AdviceMap {
@@ -57,3 +67,31 @@ impl Extend<(RpoDigest, Vec<Felt>)> for AdviceMap {
         self.0.extend(iter)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.0.len());
+        for (key, values) in self.0.iter() {
+            key.write_into(target);
+            target.write_usize(values.len());
+            target.write_many(values);
+        }
+    }
+}
+
+impl Deserializable for AdviceMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut map = BTreeMap::new();
+        let count = source.read_usize()?;
+        for _ in 0..count {
+            let key = RpoDigest::read_from(source)?;
+            let num_values = source.read_usize()?;
+            let values = read_many_bounded(source, num_values, Felt::ELEMENT_BYTES)?;
+            map.insert(key, values);
+        }
+        Ok(Self(map))
+    }
+}
@@ -21,6 +21,11 @@ mod injectors;
 mod providers;
 pub use providers::{MemAdviceProvider, RecAdviceProvider};
 
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "std")]
+pub use file::{AdviceDataFile, FileAdviceProvider};
+
 mod source;
 pub use source::AdviceSource;
 
@@ -74,6 +79,8 @@ pub trait AdviceProvider: Sized {
             AdviceInjector::U32Clo => self.push_leading_ones(process),
             AdviceInjector::U32Cto => self.push_trailing_ones(process),
             AdviceInjector::ILog2 => self.push_ilog2(process),
+            AdviceInjector::U256MontInv => self.push_u256_mont_inv_result(process),
+            AdviceInjector::U256MontQuot => self.push_u256_mont_quot_result(process),
 
             AdviceInjector::MemToMap => self.insert_mem_values_into_adv_map(process),
             AdviceInjector::HdwordToMap { domain } => {
@@ -445,6 +452,60 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::push_ilog2(self, process)
     }
 
+    /// Given a 256-bit value in Montgomery form and a 256-bit prime modulus on the top of the
+    /// stack, computes the multiplicative inverse of the value (also in Montgomery form) and
+    /// pushes the result onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, a0, a1, a2, a3, a4, a5, a6, a7, ...]
+    ///   Advice stack: [b7, b6, b5, b4, b3, b2, b1, b0, ...]
+    ///
+    /// Where m[0..8], a[0..8] and b[0..8] are the 32-bit limbs of the modulus, the value and its
+    /// inverse respectively (with limb 0 being the least significant one). The Montgomery radix
+    /// is 2^256. If the value is zero, the pushed result is also zero.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the limbs is not a u32 value.
+    /// - The modulus is even or smaller than 3.
+    fn push_u256_mont_inv_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_u256_mont_inv_result(self, process)
+    }
+
+    /// Given a 256-bit odd modulus and the lower 256 bits of a 512-bit product on the top of the
+    /// stack, computes the quotient used by the Montgomery reduction of the product and pushes
+    /// it onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, p0, p1, p2, p3, p4, p5, p6, p7, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [m0, m1, m2, m3, m4, m5, m6, m7, p0, p1, p2, p3, p4, p5, p6, p7, ...]
+    ///   Advice stack: [q7, q6, q5, q4, q3, q2, q1, q0, ...]
+    ///
+    /// Where m[0..8], p[0..8] and q[0..8] are the 32-bit limbs of the modulus, the lower half of
+    /// the product and the quotient respectively (with limb 0 being the least significant one).
+    /// The quotient is q = -p * m^-1 mod 2^256, and thus, p + q * m is divisible by 2^256.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the limbs is not a u32 value.
+    /// - The modulus is even.
+    fn push_u256_mont_quot_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_u256_mont_quot_result(self, process)
+    }
+
     // DEFAULT MERKLE STORE INJECTORS
     // --------------------------------------------------------------------------------------------
 
@@ -349,15 +349,20 @@ impl MemAdviceProvider {
 pub struct RecAdviceProvider {
     provider: BaseAdviceProvider<RecordingAdviceMap, RecordingMerkleMap>,
     init_stack: Vec<Felt>,
+    /// The smallest depth the advice stack has reached; elements of the initial stack which are
+    /// below this depth have never been consumed.
+    min_stack_len: usize,
 }
 
 impl From<AdviceInputs> for RecAdviceProvider {
     fn from(inputs: AdviceInputs) -> Self {
         let init_stack = inputs.stack().to_vec();
+        let min_stack_len = init_stack.len();
         let provider = inputs.into();
         Self {
             provider,
             init_stack,
+            min_stack_len,
         }
     }
 }
@@ -392,15 +397,21 @@ impl RecAdviceProvider {
 #[rustfmt::skip]
 impl AdviceProvider for RecAdviceProvider {
     fn pop_stack<S: ProcessState>(&mut self, process: &S) -> Result<Felt, ExecutionError> {
-        self.provider.pop_stack(process)
+        let value = self.provider.pop_stack(process)?;
+        self.record_stack_len();
+        Ok(value)
     }
 
     fn pop_stack_word<S: ProcessState>(&mut self, process: &S) -> Result<Word, ExecutionError> {
-        self.provider.pop_stack_word(process)
+        let word = self.provider.pop_stack_word(process)?;
+        self.record_stack_len();
+        Ok(word)
     }
 
     fn pop_stack_dword<S: ProcessState>(&mut self, process: &S) -> Result<[Word; 2], ExecutionError> {
-        self.provider.pop_stack_dword(process)
+        let words = self.provider.pop_stack_dword(process)?;
+        self.record_stack_len();
+        Ok(words)
     }
 
     fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
@@ -456,15 +467,18 @@ impl RecAdviceProvider {
     ///
     /// The [AdviceInputs] can be used to re-execute the program. The returned [AdviceInputs]
     /// instance will contain only the non-deterministic inputs which were requested during program
-    /// execution.
+    /// execution: the elements of the initial advice stack which were consumed, the advice map
+    /// entries which were read, and the Merkle store nodes which were visited.
     ///
     /// The Vec<Felt>, SimpleAdviceMap, MerkleStore represent the stack, map, and Merkle store of
     /// the advice provider at the time of finalization.
     pub fn finalize(self) -> (AdviceInputs, Vec<Felt>, SimpleAdviceMap, MerkleStore) {
         let Self {
             provider,
-            init_stack,
+            mut init_stack,
+            min_stack_len,
         } = self;
+        init_stack.truncate(init_stack.len() - min_stack_len);
         let BaseAdviceProvider { stack, map, store } = provider;
 
         let (map, map_proof) = map.finalize();
@@ -477,4 +491,13 @@ impl RecAdviceProvider {
 
         (proof, stack, map, store.into())
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Updates the smallest depth reached by the advice stack after an element was removed from
+    /// it.
+    fn record_stack_len(&mut self) {
+        self.min_stack_len = self.min_stack_len.min(self.provider.stack.len());
+    }
 }
@@ -44,6 +44,8 @@ mod range;
 use range::RangeChecker;
 
 mod host;
+#[cfg(feature = "std")]
+pub use host::advice::{AdviceDataFile, FileAdviceProvider};
 pub use host::{
     advice::{
         AdviceExtractor, AdviceInputs, AdviceMap, AdviceProvider, AdviceSource, MemAdviceProvider,
@@ -54,18 +56,25 @@ pub use host::{
 
 mod chiplets;
 use chiplets::Chiplets;
+pub use chiplets::{MemoryAccess, MemoryAccessKind};
 
 mod trace;
 use trace::TraceFragment;
-pub use trace::{ChipletsLengths, ExecutionTrace, TraceLenSummary};
+pub use trace::{ChipletsLengths, DetachedExecutionTrace, ExecutionTrace, TraceLenSummary};
 
 mod errors;
 pub use errors::{ExecutionError, Ext2InttError};
 
 pub mod utils;
 
+mod session;
+pub use session::ExecutionSession;
+
 mod debug;
-pub use debug::{AsmOpInfo, VmState, VmStateIterator};
+pub use debug::{
+    AsmOpInfo, MemoryAccessReport, MemoryConventionViolation, MemoryFootprint, VmState,
+    VmStateIterator,
+};
 
 // RE-EXPORTS
 // ================================================================================================
@@ -79,8 +88,8 @@ pub mod crypto {
     pub use vm_core::crypto::{
         hash::{Blake3_192, Blake3_256, ElementHasher, Hasher, Rpo256, RpoDigest},
         merkle::{
-            MerkleError, MerklePath, MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree,
-            SimpleSmt,
+            InnerNodeInfo, MerkleError, MerklePath, MerkleStore, MerkleTree, NodeIndex,
+            PartialMerkleTree, SimpleSmt,
         },
         random::{RandomCoin, RpoRandomCoin, WinterRandomCoin},
     };
@@ -135,6 +144,27 @@ where
     Ok(trace)
 }
 
+/// Executes the provided program against the provided inputs, and returns the execution trace
+/// together with the witness of the execution.
+///
+/// The witness contains only the advice inputs which were accessed during execution: the consumed
+/// elements of the advice stack, the advice map entries which were read, and the subset of the
+/// Merkle store which was visited. Executing the program against the witness produces the same
+/// execution trace, and thus, the witness can be used instead of the full advice inputs to prove
+/// the execution.
+#[tracing::instrument("record_witness", skip_all)]
+pub fn record_witness(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    options: ExecutionOptions,
+) -> Result<(ExecutionTrace, AdviceInputs), ExecutionError> {
+    let mut host = DefaultHost::new(RecAdviceProvider::from(advice_inputs));
+    let trace = execute(program, stack_inputs, &mut host, options)?;
+    let (witness, ..) = host.into_inner().finalize();
+    Ok((trace, witness))
+}
+
 /// Returns an iterator which allows callers to step through the execution and inspect VM state at
 /// each execution step.
 pub fn execute_iter<H>(program: &Program, stack_inputs: StackInputs, host: H) -> VmStateIterator
@@ -188,13 +218,16 @@ where
     }
 
     /// Creates a new process with provided inputs and debug options enabled.
+    ///
+    /// In debug mode, `trace` instructions are handled by the host and all memory accesses are
+    /// recorded.
     pub fn new_debug(kernel: Kernel, stack_inputs: StackInputs, host: H) -> Self {
         Self::initialize(
             kernel,
             stack_inputs,
             host,
             true,
-            ExecutionOptions::default().with_tracing(),
+            ExecutionOptions::default().with_tracing().with_memory_tracing(),
         )
     }
 
@@ -205,12 +238,17 @@ where
         in_debug_mode: bool,
         execution_options: ExecutionOptions,
     ) -> Self {
+        let mut chiplets = Chiplets::new(kernel);
+        if execution_options.enable_memory_tracing() {
+            chiplets.enable_mem_access_log();
+        }
+
         Self {
             system: System::new(execution_options.expected_cycles() as usize),
             decoder: Decoder::new(in_debug_mode),
             stack: Stack::new(&stack, execution_options.expected_cycles() as usize, in_debug_mode),
             range: RangeChecker::new(),
-            chiplets: Chiplets::new(kernel),
+            chiplets,
             host: RefCell::new(host),
             max_cycles: execution_options.max_cycles(),
             enable_tracing: execution_options.enable_tracing(),
@@ -528,6 +566,14 @@ where
         self.chiplets.kernel()
     }
 
+    /// Returns a report on all memory accesses made so far, or None if memory tracing was not
+    /// enabled for this process.
+    pub fn memory_access_report(&self) -> Option<MemoryAccessReport> {
+        self.chiplets
+            .get_mem_access_log()
+            .map(|accesses| MemoryAccessReport::new(accesses, &self.system))
+    }
+
     pub fn into_parts(self) -> (System, Decoder, Stack, RangeChecker, Chiplets, H) {
         (
             self.system,
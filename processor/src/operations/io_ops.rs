@@ -1,5 +1,8 @@
 use super::{ExecutionError, Felt, Host, Operation, Process};
-use crate::Word;
+use crate::{
+    system::{LEND_CALLEE_ADDR, LEND_SEGMENT_ADDR},
+    Word, ONE, ZERO,
+};
 
 // INPUT / OUTPUT OPERATIONS
 // ================================================================================================
@@ -177,6 +180,183 @@ where
         Ok(())
     }
 
+    /// Loads the element located at the specified element address onto the stack.
+    ///
+    /// The operation works as follows:
+    /// - The element address is popped off the stack. Element address `a` refers to element
+    ///   `a mod 4` of the word located at word address `a / 4`.
+    /// - The word containing the element is retrieved from memory. The memory is always
+    ///   initialized to ZEROs, and thus, if the specified address has never been written to,
+    ///   a ZERO element is returned.
+    /// - The addressed element of the word is pushed to the top of the stack.
+    ///
+    /// The first 4 helper registers are filled with the elements of the retrieved word (in memory
+    /// order), and helper registers 4 and 5 contain the lower and upper bits of the element index.
+    /// The word address is range-checked via the bitwise chiplet.
+    pub(super) fn op_mloade(&mut self) -> Result<(), ExecutionError> {
+        // get the element address from the stack and read the enclosing word from memory
+        let ctx = self.system.ctx();
+        let (addr, idx) = Self::split_element_address(self.stack.get(0))?;
+        let word = self.chiplets.read_mem(ctx, addr);
+        self.range_check_word_address(addr)?;
+
+        // update the stack state
+        self.stack.set(0, word[idx]);
+        self.stack.copy_state(1);
+
+        // write the word and the element index bits to the helpers so they're available for
+        // constraint evaluation
+        let helpers = Self::get_element_op_helpers(word, idx);
+        self.decoder.set_user_op_helpers(Operation::MLoadE, &helpers);
+
+        Ok(())
+    }
+
+    /// Stores an element from the stack at the specified element address.
+    ///
+    /// The operation works as follows:
+    /// - The element address is popped off the stack. Element address `a` refers to element
+    ///   `a mod 4` of the word located at word address `a / 4`.
+    /// - The word is read from memory, and the top stack element is saved into the addressed
+    ///   element of that word in the same clock cycle. The remaining 3 elements of the word are not
+    ///   affected. The element is not removed from the stack.
+    ///
+    /// Thus, the net result of the operation is that the stack is shifted left by one item.
+    ///
+    /// The first 4 helper registers are filled with the elements of the word which was previously
+    /// stored in memory (in memory order), and helper registers 4 and 5 contain the lower and
+    /// upper bits of the element index. The word address is range-checked via the bitwise chiplet.
+    pub(super) fn op_mstoree(&mut self) -> Result<(), ExecutionError> {
+        // get the element address and the value from the stack
+        let ctx = self.system.ctx();
+        let (addr, idx) = Self::split_element_address(self.stack.get(0))?;
+        let value = self.stack.get(1);
+
+        // read the previous word and write the value into it in the same clock cycle
+        let old_word = self.chiplets.update_mem_element(ctx, addr, idx, value);
+        self.range_check_word_address(addr)?;
+
+        // write the previous word and the element index bits to the helpers so they're available
+        // for constraint evaluation
+        let helpers = Self::get_element_op_helpers(old_word, idx);
+        self.decoder.set_user_op_helpers(Operation::MStoreE, &helpers);
+
+        // update the stack state
+        self.stack.shift_left(1);
+
+        Ok(())
+    }
+
+    /// Lends the memory segment [start_addr, end_addr) of the current context to the contexts
+    /// created by a CALL from the current context.
+    ///
+    /// The operation works as follows:
+    /// - The start and the end word addresses of the segment are read from the top two stack
+    ///   elements (start address at the top).
+    /// - The word [start_addr, end_addr, 1, 0] is written into the memory of the current context
+    ///   at address LEND_SEGMENT_ADDR. While a context called from the current context is
+    ///   executing, it can read words of the segment via the MLOADWB operation. A segment lent by
+    ///   a later MLEND replaces the previous one.
+    ///
+    /// The stack is not affected by this operation.
+    ///
+    /// # Errors
+    /// Returns an error if either of the addresses is greater than u32::MAX, if the start address
+    /// is greater than the end address, or if the segment contains the addresses reserved for the
+    /// lending records.
+    pub(super) fn op_mlend(&mut self) -> Result<(), ExecutionError> {
+        let start_addr = Self::get_valid_address(self.stack.get(0))?;
+        let end_addr = Self::get_valid_address(self.stack.get(1))?;
+        if start_addr > end_addr || end_addr > LEND_SEGMENT_ADDR {
+            return Err(ExecutionError::InvalidMemoryRange {
+                start_addr: start_addr as u64,
+                end_addr: end_addr as u64,
+            });
+        }
+
+        let ctx = self.system.ctx();
+        let segment = [Felt::from(start_addr), Felt::from(end_addr), ONE, ZERO];
+        self.chiplets.write_mem(ctx, LEND_SEGMENT_ADDR, segment);
+        self.stack.copy_state(0);
+
+        Ok(())
+    }
+
+    /// Loads a word (4 elements) from the memory segment lent to the current context onto the
+    /// stack.
+    ///
+    /// The operation works as follows:
+    /// - The memory address is popped off the stack. The address is a word address in the memory
+    ///   of the context which called the current context (the lender).
+    /// - The lending records are read from the memory of the lender: the ID of the last context
+    ///   called by the lender is read from address LEND_CALLEE_ADDR, and the lent segment is read
+    ///   from address LEND_SEGMENT_ADDR.
+    /// - A word is retrieved from the memory of the lender at the specified address.
+    /// - The top four elements of the stack are overwritten with values retrieved from memory.
+    ///
+    /// Thus, the net result of the operation is that the stack is shifted left by one item.
+    ///
+    /// The helper registers are set as follows:
+    /// - The first helper register contains the ID of the lender.
+    /// - The second and the third helper registers contain the start and the end addresses of the
+    ///   lent segment.
+    /// - The fourth helper register contains the bitwise AND of (addr - start_addr) and
+    ///   (end_addr - 1 - addr). This value is requested from the bitwise chiplet which checks that
+    ///   both operands are 32-bit values, and thus, that the address is within the segment.
+    ///
+    /// # Errors
+    /// Returns an error if no memory segment was lent to the current context, or if the address
+    /// is located outside of the lent segment.
+    pub(super) fn op_mloadwb(&mut self) -> Result<(), ExecutionError> {
+        let ctx = self.system.ctx();
+        let lender = self
+            .system
+            .caller_ctx()
+            .ok_or(ExecutionError::BorrowedMemoryNotAvailable(ctx))?;
+        let addr = Self::get_valid_address(self.stack.get(0))?;
+
+        // read the lending records from the memory of the lender; the current context can borrow
+        // memory only if it is the last context called by the lender and the lender has lent a
+        // segment
+        let callee = self.chiplets.read_mem(lender, LEND_CALLEE_ADDR);
+        let segment = self.chiplets.read_mem(lender, LEND_SEGMENT_ADDR);
+        let (start_addr, end_addr) = (segment[0].as_int(), segment[1].as_int());
+        if callee != [Felt::from(ctx), ZERO, ZERO, ZERO]
+            || segment[2..] != [ONE, ZERO]
+            || start_addr > u32::MAX as u64
+            || end_addr > u32::MAX as u64
+        {
+            return Err(ExecutionError::BorrowedMemoryNotAvailable(ctx));
+        }
+        if !(start_addr..end_addr).contains(&(addr as u64)) {
+            return Err(ExecutionError::BorrowedMemoryAddressOutOfBounds {
+                addr,
+                start_addr: start_addr as u32,
+                end_addr: end_addr as u32,
+            });
+        }
+
+        // read the word from the memory of the lender
+        let word = self.chiplets.read_mem(lender, addr);
+
+        // range-check the distances from the address to the bounds of the segment
+        let addr = Felt::from(addr);
+        let bounds_check = self.chiplets.u32and(addr - segment[0], segment[1] - ONE - addr)?;
+
+        self.decoder.set_user_op_helpers(
+            Operation::MLoadWB,
+            &[lender.into(), segment[0], segment[1], bounds_check],
+        );
+
+        // reverse the order of the memory word & update the stack state
+        for (i, &value) in word.iter().rev().enumerate() {
+            self.stack.set(i, value);
+        }
+        self.stack.shift_left(5);
+
+        Ok(())
+    }
+
     /// Moves 8 elements from the advice stack to the memory, via the operand stack.
     ///
     /// The operation works as follows:
@@ -263,6 +443,36 @@ where
         }
         Ok(addr as u32)
     }
+
+    /// Checks that the element address is a valid u32 value, and splits it into the address of
+    /// the word containing the element and the index of the element within that word.
+    fn split_element_address(addr: Felt) -> Result<(u32, usize), ExecutionError> {
+        let addr = Self::get_valid_address(addr)?;
+        Ok((addr / 4, (addr % 4) as usize))
+    }
+
+    /// Range-checks the word address accessed by the MLOADE and MSTOREE operations via a bitwise
+    /// AND of the address with itself. The bitwise chiplet accepts only 32-bit operands, and thus,
+    /// this ensures that the element index bits in the helper registers are the lower bits of the
+    /// element address.
+    fn range_check_word_address(&mut self, addr: u32) -> Result<(), ExecutionError> {
+        let addr = Felt::from(addr);
+        self.chiplets.u32and(addr, addr)?;
+        Ok(())
+    }
+
+    /// Returns the values of the user op helper registers for the MLOADE and MSTOREE operations:
+    /// the elements of the accessed word followed by the lower and upper bits of the element index.
+    fn get_element_op_helpers(word: Word, idx: usize) -> [Felt; 6] {
+        [
+            word[0],
+            word[1],
+            word[2],
+            word[3],
+            Felt::from((idx & 1) as u32),
+            Felt::from((idx >> 1) as u32),
+        ]
+    }
 }
 
 // TESTS
@@ -272,7 +482,7 @@ where
 mod tests {
     use super::{
         super::{super::AdviceProvider, Operation, STACK_TOP_SIZE},
-        Felt, Host, Process,
+        ExecutionError, Felt, Host, Process,
     };
     use crate::{AdviceSource, ContextId};
     use vm_core::{utils::ToElements, Word, ONE, ZERO};
@@ -501,6 +711,108 @@ mod tests {
         assert!(process.execute_op(Operation::MStore).is_ok());
     }
 
+    #[test]
+    fn op_mloade() {
+        let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
+        assert_eq!(0, process.chiplets.get_mem_size());
+
+        // push a word onto the stack and save it at word address 2 (element addresses 8 - 11)
+        let word = [1, 3, 5, 7].to_elements().try_into().unwrap();
+        store_value(&mut process, 2, word);
+
+        // push the element address of the third element onto the stack and load the element
+        process.execute_op(Operation::Push(Felt::new(10))).unwrap();
+        process.execute_op(Operation::MLoadE).unwrap();
+
+        let expected_stack = build_expected_stack(&[5, 7, 5, 3, 1]);
+        assert_eq!(expected_stack, process.stack.trace_state());
+
+        // check memory state
+        assert_eq!(1, process.chiplets.get_mem_size());
+        assert_eq!(word, process.chiplets.get_mem_value(ContextId::root(), 2).unwrap());
+
+        // --- calling MLOADE with address greater than u32::MAX leads to an error ----------------
+        process.execute_op(Operation::Push(Felt::new(u64::MAX / 2))).unwrap();
+        assert!(process.execute_op(Operation::MLoadE).is_err());
+
+        // --- calling MLOADE with a stack of minimum depth is ok ----------------
+        let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
+        assert!(process.execute_op(Operation::MLoadE).is_ok());
+    }
+
+    #[test]
+    fn op_mstoree() {
+        let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
+        assert_eq!(0, process.chiplets.get_mem_size());
+
+        // push the word onto the stack and save it at word address 2 (element addresses 8 - 11)
+        let word_2 = [1, 3, 5, 7].to_elements().try_into().unwrap();
+        store_value(&mut process, 2, word_2);
+
+        // save a new element at element address 11, which is the last element of word 2
+        let element = Felt::new(12);
+        process.execute_op(Operation::Push(element)).unwrap();
+        process.execute_op(Operation::Push(Felt::new(11))).unwrap();
+        process.execute_op(Operation::MStoreE).unwrap();
+
+        // check stack state
+        let expected_stack = build_expected_stack(&[12, 7, 5, 3, 1]);
+        assert_eq!(expected_stack, process.stack.trace_state());
+
+        // check memory state to make sure the other 3 elements were not affected
+        let mem_2 = [Felt::new(1), Felt::new(3), Felt::new(5), element];
+        assert_eq!(1, process.chiplets.get_mem_size());
+        assert_eq!(mem_2, process.chiplets.get_mem_value(ContextId::root(), 2).unwrap());
+
+        // save an element at element address 1 in uninitialized memory
+        process.execute_op(Operation::Push(Felt::new(1))).unwrap();
+        process.execute_op(Operation::MStoreE).unwrap();
+
+        let mem_0 = [ZERO, element, ZERO, ZERO];
+        assert_eq!(2, process.chiplets.get_mem_size());
+        assert_eq!(mem_0, process.chiplets.get_mem_value(ContextId::root(), 0).unwrap());
+
+        // --- calling MSTOREE with address greater than u32::MAX leads to an error ---------------
+        process.execute_op(Operation::Push(Felt::new(u64::MAX / 2))).unwrap();
+        assert!(process.execute_op(Operation::MStoreE).is_err());
+
+        // --- calling MSTOREE with a stack of minimum depth is ok ----------------
+        let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
+        assert!(process.execute_op(Operation::MStoreE).is_ok());
+    }
+
+    #[test]
+    fn op_mlend() {
+        let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
+
+        // lending a segment does not affect the stack
+        process.execute_op(Operation::Push(Felt::new(4))).unwrap();
+        process.execute_op(Operation::Push(Felt::new(2))).unwrap();
+        process.execute_op(Operation::MLend).unwrap();
+        let expected_stack = build_expected_stack(&[2, 4]);
+        assert_eq!(expected_stack, process.stack.trace_state());
+
+        // --- calling MLEND with start address greater than end address leads to an error -------
+        process.execute_op(Operation::Swap).unwrap();
+        assert_eq!(
+            Err(ExecutionError::InvalidMemoryRange {
+                start_addr: 4,
+                end_addr: 2
+            }),
+            process.execute_op(Operation::MLend)
+        );
+    }
+
+    #[test]
+    fn op_mloadwb() {
+        // --- calling MLOADWB in a context which did not borrow memory leads to an error ---------
+        let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
+        assert_eq!(
+            Err(ExecutionError::BorrowedMemoryNotAvailable(ContextId::root())),
+            process.execute_op(Operation::MLoadWB)
+        );
+    }
+
     #[test]
     fn op_pipe() {
         let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
@@ -85,6 +85,9 @@ where
 
             Operation::U32and => self.op_u32and()?,
             Operation::U32xor => self.op_u32xor()?,
+            Operation::U32popcnt => self.op_u32popcnt()?,
+            Operation::U32shl => self.op_u32shl()?,
+            Operation::U32shr => self.op_u32shr()?,
             Operation::U32assert2(err_code) => self.op_u32assert2(err_code)?,
 
             // ----- stack manipulation -----------------------------------------------------------
@@ -140,6 +143,10 @@ where
 
             Operation::MLoad => self.op_mload()?,
             Operation::MStore => self.op_mstore()?,
+            Operation::MLoadE => self.op_mloade()?,
+            Operation::MStoreE => self.op_mstoree()?,
+            Operation::MLend => self.op_mlend()?,
+            Operation::MLoadWB => self.op_mloadwb()?,
 
             Operation::MStream => self.op_mstream()?,
             Operation::Pipe => self.op_pipe()?,
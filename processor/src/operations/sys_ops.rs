@@ -3,7 +3,7 @@ use super::{
         system::{FMP_MAX, FMP_MIN},
         ONE,
     },
-    ExecutionError, Felt, Host, Process,
+    ExecutionError, Felt, Host, Operation, Process,
 };
 
 // SYSTEM OPERATIONS
@@ -81,7 +81,7 @@ where
     /// Returns an error if the VM is not currently executing a SYSCALL block.
     pub(super) fn op_caller(&mut self) -> Result<(), ExecutionError> {
         if !self.system.in_syscall() {
-            return Err(ExecutionError::CallerNotInSyscall);
+            return Err(ExecutionError::NotInSyscall(Operation::Caller));
         }
 
         let fn_hash = self.system.fn_hash();
@@ -193,6 +193,81 @@ where
         Ok(())
     }
 
+    /// Pops an element off the stack, computes the number of its set bits, and pushes the result
+    /// back onto the stack.
+    pub(super) fn op_u32popcnt(&mut self) -> Result<(), ExecutionError> {
+        let a = self.stack.get(0);
+        let result = self.chiplets.u32popcnt(a)?;
+
+        self.stack.set(0, result);
+        self.stack.copy_state(1);
+
+        Ok(())
+    }
+
+    /// Pops two elements off the stack, shifts the second element to the left by the number of
+    /// bits specified by the first element, and pushes the result back onto the stack as two
+    /// 32-bit limbs: the bits shifted out of the lower 32 bits are pushed onto the top of the
+    /// stack, and the lower 32 bits of the result are pushed below them.
+    ///
+    /// The `32 - b` least significant bits of the shifted value and 2^(32 - b) are saved into the
+    /// first two helper registers; these values are checked by the bitwise chiplet.
+    ///
+    /// # Errors
+    /// Returns an error if the shift amount is greater than 32 or if the shifted value is not a
+    /// 32-bit value.
+    pub(super) fn op_u32shl(&mut self) -> Result<(), ExecutionError> {
+        let b = self.stack.get(0);
+        let a = self.stack.get(1);
+
+        let shift = get_shift_amount(b)?;
+        let num_low_bits = 32 - shift;
+        let low_bits = self.chiplets.u32mask(a, num_low_bits)?;
+
+        let hi = a.as_int() >> num_low_bits;
+        let lo = low_bits.as_int() << shift;
+
+        let pow2 = Felt::new(1 << num_low_bits);
+        self.decoder.set_user_op_helpers(Operation::U32shl, &[low_bits, pow2]);
+
+        self.stack.set(0, Felt::new(hi));
+        self.stack.set(1, Felt::new(lo));
+        self.stack.copy_state(2);
+
+        Ok(())
+    }
+
+    /// Pops two elements off the stack, shifts the second element to the right by the number of
+    /// bits specified by the first element, and pushes the result back onto the stack as two
+    /// 32-bit limbs: the bits shifted out of the value, moved into the most significant bits, are
+    /// pushed onto the top of the stack, and the shifted value is pushed below them.
+    ///
+    /// The `b` least significant bits of the shifted value and 2^b are saved into the first two
+    /// helper registers; these values are checked by the bitwise chiplet.
+    ///
+    /// # Errors
+    /// Returns an error if the shift amount is greater than 32 or if the shifted value is not a
+    /// 32-bit value.
+    pub(super) fn op_u32shr(&mut self) -> Result<(), ExecutionError> {
+        let b = self.stack.get(0);
+        let a = self.stack.get(1);
+
+        let shift = get_shift_amount(b)?;
+        let low_bits = self.chiplets.u32mask(a, shift)?;
+
+        let spill = low_bits.as_int() << (32 - shift);
+        let quotient = a.as_int() >> shift;
+
+        let pow2 = Felt::new(1 << shift);
+        self.decoder.set_user_op_helpers(Operation::U32shr, &[low_bits, pow2]);
+
+        self.stack.set(0, Felt::new(spill));
+        self.stack.set(1, Felt::new(quotient));
+        self.stack.copy_state(2);
+
+        Ok(())
+    }
+
     /// Adds 16-bit range checks to the RangeChecker for the high and low 16-bit limbs of two field
     /// elements which are assumed to have 32-bit integer values. This results in 4 range checks.
     ///
@@ -227,6 +302,21 @@ where
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the shift amount specified by `value`.
+///
+/// # Errors
+/// Returns an error if the shift amount is greater than 32.
+fn get_shift_amount(value: Felt) -> Result<u32, ExecutionError> {
+    let shift = value.as_int();
+    if shift > 32 {
+        return Err(ExecutionError::InvalidShiftAmount(value));
+    }
+    Ok(shift as u32)
+}
+
 // TESTS
 // ================================================================================================
 
@@ -445,6 +535,92 @@ mod tests {
         assert!(process.execute_op(Operation::U32xor).is_ok());
     }
 
+    #[test]
+    fn op_u32popcnt() {
+        let (a, b, c, d) = get_rand_values();
+        let stack = StackInputs::try_from_ints([d as u64, c as u64, b as u64, a as u64]).unwrap();
+        let mut process = Process::new_dummy_with_decoder_helpers(stack);
+
+        process.execute_op(Operation::U32popcnt).unwrap();
+        let expected = build_expected(&[a.count_ones(), b, c, d]);
+        assert_eq!(expected, process.stack.trace_state());
+
+        // --- test with minimum stack depth ----------------------------------
+        let mut process = Process::new_dummy_with_decoder_helpers_and_empty_stack();
+        assert!(process.execute_op(Operation::U32popcnt).is_ok());
+
+        // --- test that non-u32 values are rejected --------------------------
+        let stack = StackInputs::try_from_ints([u32::MAX as u64 + 1]).unwrap();
+        let mut process = Process::new_dummy_with_decoder_helpers(stack);
+        assert!(process.execute_op(Operation::U32popcnt).is_err());
+    }
+
+    #[test]
+    fn op_u32shl() {
+        let (a, _, c, d) = get_rand_values();
+        for b in [0, 1, rand_value::<u64>() as u32 % 33, 31, 32] {
+            let stack =
+                StackInputs::try_from_ints([d as u64, c as u64, a as u64, b as u64]).unwrap();
+            let mut process = Process::new_dummy_with_decoder_helpers(stack);
+
+            process.execute_op(Operation::U32shl).unwrap();
+            let shifted = (a as u64) << b;
+            let expected = build_expected(&[(shifted >> 32) as u32, shifted as u32, c, d]);
+            assert_eq!(expected, process.stack.trace_state());
+
+            // the helper registers hold the low bits of `a` and the power of two they are
+            // shifted by
+            let low_bits = (a as u64 % (1 << (32 - b))) as u32;
+            let mut expected_helpers = [ZERO; NUM_USER_OP_HELPERS];
+            expected_helpers[0] = Felt::from(low_bits);
+            expected_helpers[1] = Felt::new(1 << (32 - b));
+            assert_eq!(expected_helpers, process.decoder.get_user_op_helpers());
+        }
+
+        // --- test that shift amounts greater than 32 are rejected -----------
+        let stack = StackInputs::try_from_ints([1, 33]).unwrap();
+        let mut process = Process::new_dummy_with_decoder_helpers(stack);
+        assert!(process.execute_op(Operation::U32shl).is_err());
+
+        // --- test that non-u32 values are rejected --------------------------
+        let stack = StackInputs::try_from_ints([u32::MAX as u64 + 1, 1]).unwrap();
+        let mut process = Process::new_dummy_with_decoder_helpers(stack);
+        assert!(process.execute_op(Operation::U32shl).is_err());
+    }
+
+    #[test]
+    fn op_u32shr() {
+        let (a, _, c, d) = get_rand_values();
+        for b in [0, 1, rand_value::<u64>() as u32 % 33, 31, 32] {
+            let stack =
+                StackInputs::try_from_ints([d as u64, c as u64, a as u64, b as u64]).unwrap();
+            let mut process = Process::new_dummy_with_decoder_helpers(stack);
+
+            process.execute_op(Operation::U32shr).unwrap();
+            let shifted = ((a as u64) << 32) >> b;
+            let expected = build_expected(&[shifted as u32, (shifted >> 32) as u32, c, d]);
+            assert_eq!(expected, process.stack.trace_state());
+
+            // the helper registers hold the low bits of `a` and the power of two they are
+            // shifted by
+            let low_bits = (a as u64 % (1 << b)) as u32;
+            let mut expected_helpers = [ZERO; NUM_USER_OP_HELPERS];
+            expected_helpers[0] = Felt::from(low_bits);
+            expected_helpers[1] = Felt::new(1 << b);
+            assert_eq!(expected_helpers, process.decoder.get_user_op_helpers());
+        }
+
+        // --- test that shift amounts greater than 32 are rejected -----------
+        let stack = StackInputs::try_from_ints([1, 33]).unwrap();
+        let mut process = Process::new_dummy_with_decoder_helpers(stack);
+        assert!(process.execute_op(Operation::U32shr).is_err());
+
+        // --- test that non-u32 values are rejected --------------------------
+        let stack = StackInputs::try_from_ints([u32::MAX as u64 + 1, 1]).unwrap();
+        let mut process = Process::new_dummy_with_decoder_helpers(stack);
+        assert!(process.execute_op(Operation::U32shr).is_err());
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -1,8 +1,10 @@
 use super::{uninit_vector, Felt, FieldElement, NUM_RAND_ROWS};
+use crate::utils::read_vec_bounded;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use miden_air::trace::main_trace::MainTrace;
 use miden_air::trace::range::{M_COL_IDX, V_COL_IDX};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 // AUXILIARY TRACE BUILDER
 // ================================================================================================
@@ -140,6 +142,35 @@ impl AuxTraceBuilder {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AuxTraceBuilder {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.lookup_values.write_into(target);
+        self.cycle_lookups.write_into(target);
+        target.write_usize(self.values_start);
+    }
+}
+
+impl Deserializable for AuxTraceBuilder {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // each lookup value takes 2 bytes
+        let lookup_values = read_vec_bounded(source, 2)?;
+
+        // the map is read entry by entry so that its length does not cause an up-front allocation
+        let num_cycles = source.read_usize()?;
+        let mut cycle_lookups = BTreeMap::new();
+        for _ in 0..num_cycles {
+            let clk = source.read_u32()?;
+            cycle_lookups.insert(clk, read_vec_bounded(source, 2)?);
+        }
+
+        let values_start = source.read_usize()?;
+        Ok(Self::new(lookup_values, cycle_lookups, values_start))
+    }
+}
+
 /// Runs batch inversion on all range check lookup values and returns a map which maps each value
 /// to the divisor used for including it in the LogUp lookup. In other words, the map contains
 /// mappings of x to 1/(alpha - x).
@@ -0,0 +1,339 @@
+use super::{
+    ContextId, ExecutionError, ExecutionOptions, Felt, Host, Kernel, Process, ProcessState,
+    Program, StackInputs, Word, ZERO,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use core::mem;
+use vm_core::stack::STACK_TOP_SIZE;
+
+// EXECUTION SESSION
+// ================================================================================================
+
+/// An execution session which runs programs one after another against a persistent VM state.
+///
+/// Every program executed in a session starts from the state left by the previously executed
+/// program: the stack (including its overflow part), the memory of the root context, and the
+/// host (and thus, the advice provider). Only the instructions of the new program are executed,
+/// and the cost of executing a program does not depend on the number of programs executed
+/// before it.
+///
+/// Before a program is executed, the session takes a snapshot of its state. The state preceding
+/// the last executed program can be restored via [ExecutionSession::undo()]. Since every snapshot
+/// holds a copy of the host, only the most recent snapshots are retained; their number is limited
+/// to [ExecutionSession::DEFAULT_MAX_SNAPSHOTS] by default, and can be changed via
+/// [ExecutionSession::with_max_snapshots()]. If a program fails, the state of the session remains
+/// unchanged.
+///
+/// Execution traces are not built for programs executed in a session, and thus, these programs
+/// cannot be proven.
+pub struct ExecutionSession<H>
+where
+    H: Host + Clone,
+{
+    kernel: Kernel,
+    state: SessionState<H>,
+    snapshots: VecDeque<SessionState<H>>,
+    max_snapshots: usize,
+}
+
+/// State of the VM which is carried over between programs executed in a session.
+#[derive(Clone)]
+struct SessionState<H> {
+    /// Stack state with the top of the stack at index 0.
+    stack: Vec<Felt>,
+    /// Memory of the root context as a list of (address, value) tuples sorted by address.
+    memory: Vec<(u64, Word)>,
+    host: H,
+}
+
+impl<H> ExecutionSession<H>
+where
+    H: Host + Clone,
+{
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Default maximum number of snapshots retained by a session.
+    pub const DEFAULT_MAX_SNAPSHOTS: usize = 64;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new session with the specified kernel, initial stack inputs, and host.
+    ///
+    /// Memory of a new session is empty.
+    pub fn new(kernel: Kernel, stack_inputs: StackInputs, host: H) -> Self {
+        let mut stack = stack_inputs.values().to_vec();
+        stack.resize(stack.len().max(STACK_TOP_SIZE), ZERO);
+
+        Self {
+            kernel,
+            state: SessionState {
+                stack,
+                memory: Vec::new(),
+                host,
+            },
+            snapshots: VecDeque::new(),
+            max_snapshots: Self::DEFAULT_MAX_SNAPSHOTS,
+        }
+    }
+
+    /// Sets the maximum number of snapshots retained by this session; once the limit is reached,
+    /// the oldest snapshot is discarded whenever a new one is taken.
+    ///
+    /// If `max_snapshots` is 0, no snapshots are taken, and executed programs cannot be undone.
+    pub fn with_max_snapshots(mut self, max_snapshots: usize) -> Self {
+        self.max_snapshots = max_snapshots;
+        self.snapshots.truncate(max_snapshots);
+        self
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the provided program against the current state of this session, and returns the
+    /// resulting stack state.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The execution of the program fails.
+    /// - The depth of the stack left by the program exceeds [StackInputs::MAX_LEN], and thus, the
+    ///   stack cannot be carried over to the next program.
+    ///
+    /// In these cases, the state of the session is not changed.
+    pub fn execute(&mut self, program: &Program) -> Result<&[Felt], ExecutionError> {
+        let stack_inputs = StackInputs::new(self.state.stack.iter().rev().copied().collect())
+            .map_err(|_| ExecutionError::StackDepthExceeded(self.state.stack.len()))?;
+
+        let mut process = Process::initialize(
+            self.kernel.clone(),
+            stack_inputs,
+            self.state.host.clone(),
+            true,
+            ExecutionOptions::default().with_tracing(),
+        );
+
+        // restore the memory of the root context; this is done before the first cycle and thus,
+        // the restored values are visible to the first instruction of the program
+        for (addr, value) in self.state.memory.iter() {
+            process.chiplets.write_mem(ContextId::root(), *addr as u32, *value);
+        }
+
+        process.execute(program)?;
+
+        let stack = process.get_stack_state();
+        if stack.len() > StackInputs::MAX_LEN {
+            return Err(ExecutionError::StackDepthExceeded(stack.len()));
+        }
+
+        let new_state = SessionState {
+            stack,
+            memory: process.get_mem_state(ContextId::root()),
+            host: process.host.into_inner(),
+        };
+        self.replace_state(new_state);
+
+        Ok(&self.state.stack)
+    }
+
+    /// Restores the state of this session to the state preceding the last executed program.
+    ///
+    /// Returns false if no programs have been executed in this session (or if all of them have
+    /// already been undone).
+    ///
+    /// Only the most recent snapshots are retained, and thus, programs executed before them cannot
+    /// be undone; see [ExecutionSession::with_max_snapshots()].
+    pub fn undo(&mut self) -> bool {
+        match self.snapshots.pop_back() {
+            Some(state) => {
+                self.state = state;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces the host of this session (e.g., to provide additional advice data to subsequently
+    /// executed programs).
+    ///
+    /// A snapshot of the current state is taken before the host is replaced, and thus, the
+    /// replacement can be reverted via [ExecutionSession::undo()].
+    pub fn set_host(&mut self, host: H) {
+        let new_state = SessionState {
+            stack: self.state.stack.clone(),
+            memory: self.state.memory.clone(),
+            host,
+        };
+        self.replace_state(new_state);
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Replaces the state of this session with the specified one, and retains the replaced state
+    /// as the most recent snapshot.
+    fn replace_state(&mut self, new_state: SessionState<H>) {
+        let snapshot = mem::replace(&mut self.state, new_state);
+        if self.max_snapshots == 0 {
+            return;
+        }
+        if self.snapshots.len() == self.max_snapshots {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(snapshot);
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the current stack state with the top of the stack at index 0.
+    ///
+    /// The returned slice contains at least 16 elements.
+    pub fn stack(&self) -> &[Felt] {
+        &self.state.stack
+    }
+
+    /// Returns the current memory state of the root context as a list of (address, value) tuples
+    /// sorted by address.
+    ///
+    /// Only addresses which have been accessed at least once are included.
+    pub fn memory(&self) -> &[(u64, Word)] {
+        &self.state.memory
+    }
+
+    /// Returns the word located at the specified address of the root context, or None if the
+    /// address has not been accessed yet.
+    pub fn get_mem_value(&self, addr: u64) -> Option<Word> {
+        self.state
+            .memory
+            .binary_search_by_key(&addr, |(a, _)| *a)
+            .ok()
+            .map(|idx| self.state.memory[idx].1)
+    }
+
+    /// Returns a reference to the current host of this session.
+    pub fn host(&self) -> &H {
+        &self.state.host
+    }
+
+    /// Returns the number of snapshots which can be restored via [ExecutionSession::undo()].
+    pub fn num_snapshots(&self) -> usize {
+        self.snapshots.len()
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{super::DefaultHost, ExecutionSession, Felt, Kernel, StackInputs};
+    use crate::{AdviceInputs, ExecutionError, MemAdviceProvider};
+    use alloc::vec::Vec;
+    use miden_assembly::Assembler;
+
+    fn build_session(
+        stack: &[u64],
+        advice_stack: &[u64],
+    ) -> ExecutionSession<DefaultHost<MemAdviceProvider>> {
+        let stack_inputs = StackInputs::try_from_ints(stack.iter().copied()).unwrap();
+        let advice_inputs =
+            AdviceInputs::default().with_stack_values(advice_stack.iter().copied()).unwrap();
+        let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+        ExecutionSession::new(Kernel::default(), stack_inputs, host)
+    }
+
+    fn run(
+        session: &mut ExecutionSession<DefaultHost<MemAdviceProvider>>,
+        source: &str,
+    ) -> Result<Vec<u64>, ExecutionError> {
+        let program = Assembler::default().compile(source).unwrap();
+        session
+            .execute(&program)
+            .map(|stack| stack.iter().map(|v| v.as_int()).collect())
+    }
+
+    #[test]
+    fn session_preserves_state() {
+        let mut session = build_session(&[1, 2], &[7, 8]);
+        assert_eq!(&[2, 1], &run(&mut session, "begin push.3 drop end").unwrap()[..2]);
+
+        // stack is carried over between programs, including its overflow part
+        let stack = run(&mut session, "begin push.3 push.4 push.5 push.6 end").unwrap();
+        assert_eq!(&[6, 5, 4, 3, 2, 1], &stack[..6]);
+        let stack = run(&mut session, "begin repeat.14 push.0 end end").unwrap();
+        assert_eq!(34, stack.len());
+        let stack = run(&mut session, "begin repeat.14 drop end end").unwrap();
+        assert_eq!(&[6, 5, 4, 3, 2, 1], &stack[..6]);
+
+        // memory of the root context is carried over between programs
+        run(&mut session, "begin mem_storew.10 end").unwrap();
+        assert_eq!(
+            Some([Felt::new(3), Felt::new(4), Felt::new(5), Felt::new(6)]),
+            session.get_mem_value(10)
+        );
+        let stack = run(&mut session, "begin dropw padw mem_loadw.10 end").unwrap();
+        assert_eq!(&[6, 5, 4, 3, 2, 1], &stack[..6]);
+
+        // advice stack is not replayed by subsequent programs
+        assert_eq!(7, run(&mut session, "begin adv_push.1 end").unwrap()[0]);
+        assert_eq!(8, run(&mut session, "begin adv_push.1 end").unwrap()[0]);
+    }
+
+    #[test]
+    fn session_undo() {
+        let mut session = build_session(&[], &[7, 8]);
+        run(&mut session, "begin push.1 push.2 mem_storew.3 end").unwrap();
+        run(&mut session, "begin adv_push.1 end").unwrap();
+        assert_eq!(2, session.num_snapshots());
+
+        // a failing program does not change the state of the session
+        let stack = session.stack().to_vec();
+        assert!(run(&mut session, "begin push.5 push.0 assert end").is_err());
+        assert_eq!(stack, session.stack());
+        assert_eq!(2, session.num_snapshots());
+
+        // undo restores the stack, memory, and advice provider
+        assert!(session.undo());
+        assert_eq!(2, session.stack()[0].as_int());
+        assert_eq!(7, run(&mut session, "begin adv_push.1 end").unwrap()[0]);
+        assert!(session.undo());
+        assert!(session.undo());
+        assert_eq!(0, session.stack()[0].as_int());
+        assert!(session.get_mem_value(3).is_none());
+        assert!(!session.undo());
+
+        // replacing the host can be undone as well
+        session.set_host(DefaultHost::new(MemAdviceProvider::from(
+            AdviceInputs::default().with_stack_values([9]).unwrap(),
+        )));
+        assert_eq!(9, run(&mut session, "begin adv_push.1 end").unwrap()[0]);
+        assert!(session.undo());
+        assert!(session.undo());
+        assert_eq!(7, run(&mut session, "begin adv_push.1 end").unwrap()[0]);
+    }
+
+    #[test]
+    fn session_max_snapshots() {
+        let mut session = build_session(&[], &[]).with_max_snapshots(2);
+        for i in 1..=4 {
+            run(&mut session, &format!("begin push.{i} end")).unwrap();
+        }
+        assert_eq!(2, session.num_snapshots());
+
+        // only the two most recent programs can be undone
+        assert!(session.undo());
+        assert!(session.undo());
+        assert_eq!(
+            &[2, 1],
+            &session.stack()[..2].iter().map(|v| v.as_int()).collect::<Vec<_>>()[..]
+        );
+        assert!(!session.undo());
+
+        // no snapshots are taken if the limit is 0
+        let mut session = build_session(&[], &[]).with_max_snapshots(0);
+        run(&mut session, "begin push.1 end").unwrap();
+        assert_eq!(0, session.num_snapshots());
+        assert!(!session.undo());
+    }
+}
@@ -1,7 +1,8 @@
 use super::{Felt, FieldElement, OverflowTableRow};
-use crate::trace::AuxColumnBuilder;
+use crate::{trace::AuxColumnBuilder, utils::read_vec_bounded};
 use alloc::vec::Vec;
 use miden_air::trace::main_trace::MainTrace;
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 // AUXILIARY TRACE BUILDER
 // ================================================================================================
@@ -72,3 +73,32 @@ impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for AuxTraceBuilder
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AuxTraceBuilder {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.overflow_table_rows.write_into(target);
+        target.write_usize(self.num_init_rows);
+    }
+}
+
+impl Deserializable for AuxTraceBuilder {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // each row consists of 3 field elements
+        let overflow_table_rows: Vec<OverflowTableRow> =
+            read_vec_bounded(source, 3 * Felt::ELEMENT_BYTES)?;
+        let num_init_rows = source.read_usize()?;
+        if num_init_rows > overflow_table_rows.len() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "number of initial overflow table rows ({num_init_rows}) exceeds the total number of rows ({})",
+                overflow_table_rows.len()
+            )));
+        }
+        Ok(Self {
+            overflow_table_rows,
+            num_init_rows,
+        })
+    }
+}
@@ -1,7 +1,12 @@
 use super::{AuxTraceBuilder, Felt, FieldElement, ZERO};
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
-use vm_core::{utils::uninit_vector, StarkField};
+use vm_core::{
+    utils::{
+        uninit_vector, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+    },
+    StarkField,
+};
 
 // OVERFLOW TABLE
 // ================================================================================================
@@ -258,3 +263,23 @@ impl OverflowTableRow {
             + alphas[3].mul_base(self.prev)
     }
 }
+
+// SERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for OverflowTableRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.val.write_into(target);
+        self.clk.write_into(target);
+        self.prev.write_into(target);
+    }
+}
+
+impl Deserializable for OverflowTableRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let val = source.read()?;
+        let clk = source.read()?;
+        let prev = source.read()?;
+        Ok(Self { val, clk, prev })
+    }
+}
@@ -1,5 +1,5 @@
 use super::{ExecutionError, Felt, FieldElement, SysTrace, Word, EMPTY_WORD, ONE, ZERO};
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::fmt::{self, Display};
 
 #[cfg(test)]
@@ -12,6 +12,8 @@ mod tests;
 // - First 2^30 addresses (0 to 2^30 - 1) are reserved for global memory.
 // - The next 2^30 addresses (2^30 to 2^31 - 1) are reserved for procedure locals.
 // - The next 2^30 addresses (2^31 to 3 * 2^30 - 1) are reserved for procedure locals of SYSCALLs.
+// - The last two addresses (2^32 - 2 and 2^32 - 1) are used by the VM to record memory lending
+//   (see MLEND and MLOADWB operations).
 // - All remaining addresses do not have any special meaning.
 //
 // Note that the above assignment is purely conventional: a program can read from and write to any
@@ -23,6 +25,10 @@ pub const FMP_MIN: u64 = 2_u64.pow(30);
 pub const SYSCALL_FMP_MIN: u32 = 2_u32.pow(31);
 /// Value of FMP register should not exceed 3 * 2^30 - 1.
 pub const FMP_MAX: u64 = 3 * 2_u64.pow(30) - 1;
+/// Memory address at which a context records the segment of its memory lent to its callees.
+pub const LEND_SEGMENT_ADDR: u32 = u32::MAX - 1;
+/// Memory address at which a context records the ID of the last context it called.
+pub const LEND_CALLEE_ADDR: u32 = u32::MAX;
 
 // SYSTEM INFO
 // ================================================================================================
@@ -37,6 +43,8 @@ pub const FMP_MAX: u64 = 3 * 2_u64.pow(30) - 1;
 /// - in_syscall flag which indicates whether the execution is currently in a SYSCALL block.
 /// - hash of the function which initiated the current execution context. if the context was
 ///   initiated from the root context, this will be set to ZEROs.
+///
+/// In addition, the system keeps track of the context which called each context created by a CALL.
 pub struct System {
     clk: u32,
     ctx: ContextId,
@@ -48,6 +56,7 @@ pub struct System {
     fmp_trace: Vec<Felt>,
     in_syscall_trace: Vec<Felt>,
     fn_hash_trace: [Vec<Felt>; 4],
+    callers: BTreeMap<ContextId, ContextId>,
 }
 
 impl System {
@@ -78,6 +87,7 @@ impl System {
                 Felt::zeroed_vector(init_trace_capacity),
                 Felt::zeroed_vector(init_trace_capacity),
             ],
+            callers: BTreeMap::new(),
         }
     }
 
@@ -96,6 +106,12 @@ impl System {
         self.ctx
     }
 
+    /// Returns the ID of the context which created the current execution context via a CALL, or
+    /// None if the current context is the root context.
+    pub fn caller_ctx(&self) -> Option<ContextId> {
+        self.callers.get(&self.ctx).copied()
+    }
+
     /// Returns the current value of the free memory pointer for a process.
     #[inline(always)]
     pub fn fmp(&self) -> Felt {
@@ -174,11 +190,14 @@ impl System {
     ///   is globally unique as is never set to 0.
     /// - Sets the free memory pointer to its initial value (FMP_MIN).
     /// - Sets the hash of the function which initiated the current context to the provided value.
+    /// - Records the current context as the caller of the new context.
     ///
     /// A CALL cannot be started when the VM is executing a SYSCALL.
     pub fn start_call(&mut self, fn_hash: Word) {
         debug_assert!(!self.in_syscall, "call in syscall");
+        let caller_ctx = self.ctx;
         self.ctx = (self.clk + 1).into();
+        self.callers.insert(self.ctx, caller_ctx);
         self.fmp = Felt::new(FMP_MIN);
         self.fn_hash = fn_hash;
     }
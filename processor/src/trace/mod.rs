@@ -3,8 +3,9 @@ use super::{
     decoder::AuxTraceBuilder as DecoderAuxTraceBuilder,
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
     stack::AuxTraceBuilder as StackAuxTraceBuilder, ColMatrix, Digest, Felt, FieldElement, Host,
-    Process, StackTopState,
+    MemoryAccessReport, Process, StackTopState,
 };
+use crate::utils::{join, read_many_bounded};
 use alloc::vec::Vec;
 use miden_air::trace::{
     decoder::{NUM_USER_OP_HELPERS, USER_OP_HELPERS_OFFSET},
@@ -12,7 +13,11 @@ use miden_air::trace::{
     AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
     STACK_TRACE_OFFSET, TRACE_WIDTH,
 };
-use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackOutputs, ZERO};
+use vm_core::{
+    stack::STACK_TOP_SIZE,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    ProgramInfo, StackOutputs, ZERO,
+};
 use winter_prover::{crypto::RandomCoin, EvaluationFrame, Trace, TraceLayout};
 
 mod utils;
@@ -39,6 +44,28 @@ pub struct AuxTraceBuilders {
     pub(crate) chiplets: ChipletsAuxTraceBuilder,
 }
 
+impl Serializable for AuxTraceBuilders {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // decoder and chiplets builders are stateless, so only the stack and range checker
+        // builders need to be serialized
+        self.stack.write_into(target);
+        self.range.write_into(target);
+    }
+}
+
+impl Deserializable for AuxTraceBuilders {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack = source.read()?;
+        let range = source.read()?;
+        Ok(Self {
+            decoder: DecoderAuxTraceBuilder::default(),
+            stack,
+            range,
+            chiplets: ChipletsAuxTraceBuilder::default(),
+        })
+    }
+}
+
 /// Execution trace which is generated when a program is executed on the VM.
 ///
 /// The trace consists of the following components:
@@ -46,6 +73,12 @@ pub struct AuxTraceBuilders {
 ///   components.
 /// - Hints used during auxiliary trace segment construction.
 /// - Metadata needed by the STARK prover.
+///
+/// An execution trace can be serialized into a versioned binary format, which makes it possible to
+/// execute a program on one machine and to generate a proof of its execution on another one.
+///
+/// If memory tracing was enabled during execution, the trace also holds a report on all memory
+/// accesses made by the program. The report is not a part of the serialized trace.
 pub struct ExecutionTrace {
     meta: Vec<u8>,
     layout: TraceLayout,
@@ -54,6 +87,7 @@ pub struct ExecutionTrace {
     program_info: ProgramInfo,
     stack_outputs: StackOutputs,
     trace_len_summary: TraceLenSummary,
+    memory_access_report: Option<MemoryAccessReport>,
 }
 
 impl ExecutionTrace {
@@ -63,6 +97,17 @@ impl ExecutionTrace {
     /// Number of rows at the end of an execution trace which are injected with random values.
     pub const NUM_RAND_ROWS: usize = NUM_RAND_ROWS;
 
+    /// Version of the binary format into which execution traces are serialized.
+    ///
+    /// The version must be incremented whenever the layout of the execution trace or the
+    /// serialization format changes, so that traces produced by an incompatible version of the VM
+    /// are rejected during deserialization.
+    pub const SERIALIZATION_VERSION: u8 = 1;
+
+    /// Maximum length of a deserialized execution trace; this matches the maximum number of cycles
+    /// a program can be executed for.
+    pub const MAX_SERIALIZED_TRACE_LEN: u64 = 1 << 32;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
@@ -80,6 +125,7 @@ impl ExecutionTrace {
         // create a new program info instance with the underlying kernel
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash.into(), kernel);
+        let memory_access_report = process.memory_access_report();
         let (main_trace, aux_trace_hints, trace_len_summary) = finalize_trace(process, rng);
 
         Self {
@@ -90,6 +136,7 @@ impl ExecutionTrace {
             program_info,
             stack_outputs,
             trace_len_summary,
+            memory_access_report,
         }
     }
 
@@ -149,6 +196,35 @@ impl ExecutionTrace {
         &self.trace_len_summary
     }
 
+    /// Returns a report on the memory accesses made during execution, or None if memory tracing
+    /// was not enabled or if this trace was deserialized.
+    pub fn memory_access_report(&self) -> Option<&MemoryAccessReport> {
+        self.memory_access_report.as_ref()
+    }
+
+    // MAIN SEGMENT DETACHMENT
+    // --------------------------------------------------------------------------------------------
+
+    /// Detaches the main segment from this trace, and returns it together with the rest of the
+    /// trace.
+    ///
+    /// This allows a prover to keep the main segment outside of memory while committing to it.
+    /// The segment can be attached back via [DetachedExecutionTrace::attach_main_segment()].
+    pub fn detach_main_segment(self) -> (ColMatrix<Felt>, DetachedExecutionTrace) {
+        let trace_len = self.main_trace.num_rows();
+        let detached = DetachedExecutionTrace {
+            meta: self.meta,
+            layout: self.layout,
+            aux_trace_builders: self.aux_trace_builders,
+            program_info: self.program_info,
+            stack_outputs: self.stack_outputs,
+            trace_len_summary: self.trace_len_summary,
+            memory_access_report: self.memory_access_report,
+            trace_len,
+        };
+        (self.main_trace.into_columns(), detached)
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -181,6 +257,63 @@ impl ExecutionTrace {
     }
 }
 
+// DETACHED EXECUTION TRACE
+// ================================================================================================
+
+/// Execution trace whose main segment was detached via [ExecutionTrace::detach_main_segment()].
+pub struct DetachedExecutionTrace {
+    meta: Vec<u8>,
+    layout: TraceLayout,
+    aux_trace_builders: AuxTraceBuilders,
+    program_info: ProgramInfo,
+    stack_outputs: StackOutputs,
+    trace_len_summary: TraceLenSummary,
+    memory_access_report: Option<MemoryAccessReport>,
+    trace_len: usize,
+}
+
+impl DetachedExecutionTrace {
+    /// Returns the number of columns in the detached main segment.
+    pub fn main_trace_width(&self) -> usize {
+        self.layout.main_trace_width()
+    }
+
+    /// Returns the number of rows in the detached main segment.
+    pub fn trace_len(&self) -> usize {
+        self.trace_len
+    }
+
+    /// Attaches the specified main segment to this trace, and returns the resulting execution
+    /// trace.
+    ///
+    /// # Panics
+    /// Panics if the dimensions of the specified segment differ from the dimensions of the
+    /// detached one.
+    pub fn attach_main_segment(self, main_segment: ColMatrix<Felt>) -> ExecutionTrace {
+        assert_eq!(
+            main_segment.num_cols(),
+            self.main_trace_width(),
+            "number of columns in the main segment must not change"
+        );
+        assert_eq!(
+            main_segment.num_rows(),
+            self.trace_len,
+            "number of rows in the main segment must not change"
+        );
+
+        ExecutionTrace {
+            meta: self.meta,
+            layout: self.layout,
+            main_trace: MainTrace::new(main_segment),
+            aux_trace_builders: self.aux_trace_builders,
+            program_info: self.program_info,
+            stack_outputs: self.stack_outputs,
+            trace_len_summary: self.trace_len_summary,
+            memory_access_report: self.memory_access_report,
+        }
+    }
+}
+
 // TRACE TRAIT IMPLEMENTATION
 // ================================================================================================
 
@@ -213,27 +346,28 @@ impl Trace for ExecutionTrace {
             return None;
         }
 
-        // TODO: build auxiliary columns in multiple threads
-
-        // add decoder's running product columns
-        let decoder_aux_columns = self
-            .aux_trace_builders
-            .decoder
-            .build_aux_columns(&self.main_trace, rand_elements);
-
-        // add stack's running product columns
-        let stack_aux_columns =
-            self.aux_trace_builders.stack.build_aux_columns(&self.main_trace, rand_elements);
-
-        // add the range checker's running product columns
-        let range_aux_columns =
-            self.aux_trace_builders.range.build_aux_columns(&self.main_trace, rand_elements);
-
-        // add the running product columns for the chiplets
-        let chiplets = self
-            .aux_trace_builders
-            .chiplets
-            .build_aux_columns(&self.main_trace, rand_elements);
+        // build the auxiliary columns of each component of the trace; the columns of different
+        // components are independent of each other, and thus can be built in parallel
+        let builders = &self.aux_trace_builders;
+        let main_trace = &self.main_trace;
+        let ((decoder_aux_columns, stack_aux_columns), (range_aux_columns, chiplets)) = join(
+            || {
+                join(
+                    // add decoder's running product columns
+                    || builders.decoder.build_aux_columns(main_trace, rand_elements),
+                    // add stack's running product columns
+                    || builders.stack.build_aux_columns(main_trace, rand_elements),
+                )
+            },
+            || {
+                join(
+                    // add the range checker's running product columns
+                    || builders.range.build_aux_columns(main_trace, rand_elements),
+                    // add the running product columns for the chiplets
+                    || builders.chiplets.build_aux_columns(main_trace, rand_elements),
+                )
+            },
+        );
 
         // combine all auxiliary columns into a single vector
         let mut aux_columns = decoder_aux_columns
@@ -261,6 +395,82 @@ impl Trace for ExecutionTrace {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ExecutionTrace {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(Self::SERIALIZATION_VERSION);
+        self.meta.write_into(target);
+
+        target.write_usize(self.main_trace.num_cols());
+        target.write_usize(self.main_trace.num_rows());
+        for column in self.main_trace.columns() {
+            target.write_many(column);
+        }
+
+        self.aux_trace_builders.write_into(target);
+        self.program_info.write_into(target);
+        self.stack_outputs.write_into(target);
+        self.trace_len_summary.write_into(target);
+    }
+}
+
+impl Deserializable for ExecutionTrace {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let version = source.read_u8()?;
+        if version != Self::SERIALIZATION_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported execution trace version: expected {}, but was {version}",
+                Self::SERIALIZATION_VERSION
+            )));
+        }
+        let meta = source.read()?;
+
+        let num_cols = source.read_usize()?;
+        if num_cols != TRACE_WIDTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "execution trace must have {TRACE_WIDTH} columns, but had {num_cols}"
+            )));
+        }
+        let num_rows = source.read_usize()?;
+        if !num_rows.is_power_of_two()
+            || num_rows < MIN_TRACE_LEN
+            || num_rows as u64 > Self::MAX_SERIALIZED_TRACE_LEN
+        {
+            return Err(DeserializationError::InvalidValue(format!(
+                "execution trace length must be a power of two between {MIN_TRACE_LEN} and {}, but was {num_rows}",
+                Self::MAX_SERIALIZED_TRACE_LEN
+            )));
+        }
+        // make sure the source holds all columns before allocating memory for any of them
+        let num_bytes = (num_cols * Felt::ELEMENT_BYTES)
+            .checked_mul(num_rows)
+            .ok_or(DeserializationError::UnexpectedEOF)?;
+        source.check_eor(num_bytes)?;
+        let columns = (0..num_cols)
+            .map(|_| read_many_bounded::<_, Felt>(source, num_rows, Felt::ELEMENT_BYTES))
+            .collect::<Result<Vec<_>, _>>()?;
+        let main_trace = MainTrace::new(ColMatrix::new(columns));
+
+        let aux_trace_builders = source.read()?;
+        let program_info = source.read()?;
+        let stack_outputs = source.read()?;
+        let trace_len_summary = source.read()?;
+
+        Ok(Self {
+            meta,
+            layout: TraceLayout::new(TRACE_WIDTH, [AUX_TRACE_WIDTH], [AUX_TRACE_RAND_ELEMENTS]),
+            main_trace,
+            aux_trace_builders,
+            program_info,
+            stack_outputs,
+            trace_len_summary,
+            memory_access_report: None,
+        })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -309,15 +519,30 @@ where
     let trace_len_summary =
         TraceLenSummary::new(clk as usize, range_table_len, ChipletsLengths::new(&chiplets));
 
-    // combine all trace segments into the main trace
-    let system_trace = system.into_trace(trace_len, NUM_RAND_ROWS);
-    let decoder_trace = decoder.into_trace(trace_len, NUM_RAND_ROWS);
-    let stack_trace = stack.into_trace(trace_len, NUM_RAND_ROWS);
-    let chiplets_trace = chiplets.into_trace(trace_len, NUM_RAND_ROWS);
-
-    // combine the range trace segment using the support lookup table
-    let range_check_trace = range.into_trace_with_table(range_table_len, trace_len, NUM_RAND_ROWS);
+    // build the trace segments of all components; the segments are independent of each other,
+    // and thus can be built in parallel
+    let ((system_trace, decoder_trace), (stack_trace, (chiplets_trace, range_check_trace))) = join(
+        || {
+            join(
+                || system.into_trace(trace_len, NUM_RAND_ROWS),
+                || decoder.into_trace(trace_len, NUM_RAND_ROWS),
+            )
+        },
+        || {
+            join(
+                || stack.into_trace(trace_len, NUM_RAND_ROWS),
+                || {
+                    join(
+                        || chiplets.into_trace(trace_len, NUM_RAND_ROWS),
+                        // combine the range trace segment using the support lookup table
+                        || range.into_trace_with_table(range_table_len, trace_len, NUM_RAND_ROWS),
+                    )
+                },
+            )
+        },
+    );
 
+    // combine all trace segments into the main trace
     let mut trace = system_trace
         .into_iter()
         .chain(decoder_trace.trace)
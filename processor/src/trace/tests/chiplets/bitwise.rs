@@ -1,10 +1,15 @@
 use super::{
     build_trace_from_ops, rand_array, rand_value, ExecutionTrace, Felt, FieldElement, Operation,
     Trace, AUX_TRACE_RAND_ELEMENTS, CHIPLETS_AUX_TRACE_OFFSET, HASH_CYCLE_LEN, NUM_RAND_ROWS, ONE,
+    ZERO,
 };
 use miden_air::trace::chiplets::{
-    bitwise::{BITWISE_AND, BITWISE_AND_LABEL, BITWISE_XOR, BITWISE_XOR_LABEL, OP_CYCLE_LEN},
-    BITWISE_A_COL_IDX, BITWISE_B_COL_IDX, BITWISE_OUTPUT_COL_IDX, BITWISE_TRACE_OFFSET,
+    bitwise::{
+        BITWISE_AND, BITWISE_AND_LABEL, BITWISE_MASK, BITWISE_MASK_LABEL, BITWISE_POPCNT,
+        BITWISE_POPCNT_LABEL, BITWISE_XOR, BITWISE_XOR_LABEL, OP_CYCLE_LEN,
+    },
+    BITWISE_A_COL_IDX, BITWISE_B_COL_IDX, BITWISE_MASK_LEN_COL_IDX, BITWISE_OUTPUT_COL_IDX,
+    BITWISE_TRACE_OFFSET,
 };
 
 /// Tests the generation of the `b_chip` bus column when only bitwise lookups are included. It
@@ -161,6 +166,128 @@ fn b_chip_trace_bitwise() {
     }
 }
 
+/// Tests the generation of the `b_chip` bus column for the `U32popcnt` operation. The request sent
+/// by the stack must omit the `b` input, and must be matched by the response of the Bitwise
+/// chiplet at the end of its operation cycle.
+#[test]
+#[allow(clippy::needless_range_loop)]
+fn b_chip_trace_popcnt() {
+    let a = rand_value::<u32>();
+    let stack = [a as u64];
+    let operations = vec![Operation::U32popcnt];
+    let mut trace = build_trace_from_ops(operations, &stack);
+
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    let b_chip = aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET);
+
+    assert_eq!(trace.length(), b_chip.len());
+    assert_eq!(ONE, b_chip[0]);
+
+    // At cycle 0 the span hash initialization is requested from the decoder and provided by the
+    // hash chiplet, so the trace should still equal one.
+    assert_eq!(ONE, b_chip[1]);
+
+    // The popcount request from the stack is sent when the `U32popcnt` operation is executed at
+    // cycle 1, so the request is included in the next row.
+    let value = build_expected_bitwise(
+        &rand_elements,
+        BITWISE_POPCNT_LABEL,
+        Felt::from(a),
+        ZERO,
+        Felt::from(a.count_ones()),
+    );
+    let expected = value.inv();
+    assert_eq!(expected, b_chip[2]);
+
+    // The response of the Bitwise chiplet must match the request, so it must be the same value.
+    let response_row = HASH_CYCLE_LEN + OP_CYCLE_LEN;
+    assert_eq!(
+        value,
+        build_expected_bitwise_from_trace(&trace, &rand_elements, response_row - 1)
+    );
+
+    // The value in b_chip should be ONE once all requests have been matched by responses.
+    for row in response_row..trace.length() - NUM_RAND_ROWS {
+        assert_eq!(ONE, b_chip[row]);
+    }
+}
+
+/// Tests the generation of the `b_chip` bus column for the `U32shl` and `U32shr` operations. Both
+/// operations request a MASK operation from the Bitwise chiplet, which must be matched by the
+/// responses of the Bitwise chiplet at the end of its operation cycles.
+#[test]
+#[allow(clippy::needless_range_loop)]
+fn b_chip_trace_shift() {
+    let a = rand_value::<u32>();
+    let shift = rand_value::<u32>() % 33;
+    let stack = [a as u64, shift as u64];
+    let operations = vec![
+        Operation::U32shl,
+        Operation::Drop,
+        Operation::Push(Felt::from(shift)),
+        Operation::U32shr,
+    ];
+    let mut trace = build_trace_from_ops(operations, &stack);
+
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    let b_chip = aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET);
+
+    assert_eq!(trace.length(), b_chip.len());
+    assert_eq!(ONE, b_chip[0]);
+
+    // At cycle 0 the span hash initialization is requested from the decoder and provided by the
+    // hash chiplet, so the trace should still equal one.
+    assert_eq!(ONE, b_chip[1]);
+
+    // The `U32shl` operation executed at cycle 1 requests the `32 - shift` least significant bits
+    // of `a`, so the request is included in the next row.
+    let shl_mask_len = 32 - shift;
+    let shl_value = build_expected_bitwise_mask(
+        &rand_elements,
+        Felt::from(a),
+        shl_mask_len,
+        Felt::new(a as u64 % (1 << shl_mask_len)),
+    );
+    let mut expected = shl_value.inv();
+    assert_eq!(expected, b_chip[2]);
+
+    // Nothing changes during user operations with no requests to the Chiplets.
+    for row in 3..5 {
+        assert_eq!(expected, b_chip[row]);
+    }
+
+    // The `U32shr` operation executed at cycle 4 requests the `shift` least significant bits of
+    // the shifted value, so the request is included in the next row.
+    let shifted = ((a as u64) << shift) as u32;
+    let shr_value = build_expected_bitwise_mask(
+        &rand_elements,
+        Felt::from(shifted),
+        shift,
+        Felt::new(shifted as u64 % (1 << shift)),
+    );
+    expected *= shr_value.inv();
+    assert_eq!(expected, b_chip[5]);
+
+    // The responses of the Bitwise chiplet must match the requests.
+    let response_1_row = HASH_CYCLE_LEN + OP_CYCLE_LEN;
+    let response_2_row = response_1_row + OP_CYCLE_LEN;
+    assert_eq!(
+        shl_value,
+        build_expected_bitwise_from_trace(&trace, &rand_elements, response_1_row - 1)
+    );
+    assert_eq!(
+        shr_value,
+        build_expected_bitwise_from_trace(&trace, &rand_elements, response_2_row - 1)
+    );
+
+    // The value in b_chip should be ONE once all requests have been matched by responses.
+    for row in response_2_row..trace.length() - NUM_RAND_ROWS {
+        assert_eq!(ONE, b_chip[row]);
+    }
+}
+
 // TEST HELPERS
 // ================================================================================================
 
@@ -168,13 +295,26 @@ fn build_expected_bitwise(alphas: &[Felt], label: Felt, a: Felt, b: Felt, result
     alphas[0] + alphas[1] * label + alphas[2] * a + alphas[3] * b + alphas[4] * result
 }
 
+fn build_expected_bitwise_mask(alphas: &[Felt], a: Felt, mask_len: u32, result: Felt) -> Felt {
+    let mask = Felt::new((1 << mask_len) - 1);
+    build_expected_bitwise(alphas, BITWISE_MASK_LABEL, a, mask, result)
+        + alphas[5] * Felt::from(mask_len)
+}
+
 fn build_expected_bitwise_from_trace(trace: &ExecutionTrace, alphas: &[Felt], row: usize) -> Felt {
-    let selector = trace.main_trace.get_column(BITWISE_TRACE_OFFSET)[row];
+    let selector = [
+        trace.main_trace.get_column(BITWISE_TRACE_OFFSET)[row],
+        trace.main_trace.get_column(BITWISE_TRACE_OFFSET + 1)[row],
+    ];
 
     let op_id = if selector == BITWISE_AND {
         BITWISE_AND_LABEL
     } else if selector == BITWISE_XOR {
         BITWISE_XOR_LABEL
+    } else if selector == BITWISE_POPCNT {
+        BITWISE_POPCNT_LABEL
+    } else if selector == BITWISE_MASK {
+        BITWISE_MASK_LABEL
     } else {
         panic!("Execution trace contains an invalid bitwise operation.")
     };
@@ -182,6 +322,7 @@ fn build_expected_bitwise_from_trace(trace: &ExecutionTrace, alphas: &[Felt], ro
     let a = trace.main_trace.get_column(BITWISE_A_COL_IDX)[row];
     let b = trace.main_trace.get_column(BITWISE_B_COL_IDX)[row];
     let output = trace.main_trace.get_column(BITWISE_OUTPUT_COL_IDX)[row];
+    let mask_len = trace.main_trace.get_column(BITWISE_MASK_LEN_COL_IDX)[row];
 
-    build_expected_bitwise(alphas, op_id, a, b, output)
+    build_expected_bitwise(alphas, op_id, a, b, output) + alphas[5] * mask_len
 }
@@ -0,0 +1,201 @@
+use super::{ExecutionTrace, Felt, Operation, Trace, ONE};
+use crate::{DefaultHost, ExecutionOptions, Process, StackInputs};
+use alloc::vec::Vec;
+use miden_air::{
+    trace::{
+        chiplets::{KERNEL_ROM_ADDR_COL_IDX, KERNEL_ROM_ROOT_COL_RANGE, KERNEL_ROM_S0_COL_IDX},
+        decoder::DECODER_HASHER_STATE_OFFSET,
+        main_trace::MainTrace,
+        AUX_TRACE_RAND_ELEMENTS,
+    },
+    ProcessorAir, ProvingOptions, PublicInputs,
+};
+use test_utils::rand::rand_array;
+use vm_core::{code_blocks::CodeBlock, CodeBlockTable, Kernel, ProgramInfo, StackOutputs, ZERO};
+use winter_prover::{matrix::ColMatrix, Air, AuxTraceRandElements};
+
+// KERNEL ROM TRACE VALIDATION TESTS
+// ================================================================================================
+
+#[test]
+fn kernel_rom_trace_is_valid() {
+    let (trace, kernel) = build_kernel_trace();
+    validate_trace(trace, kernel);
+}
+
+#[test]
+fn kernel_rom_trace_is_valid_for_empty_kernel() {
+    let program = CodeBlock::new_span(vec![Operation::Pad, Operation::Drop]);
+    let trace = build_trace(&program, Kernel::default(), &CodeBlockTable::default());
+    validate_trace(trace, Kernel::default());
+}
+
+#[test]
+#[should_panic(expected = "main transition constraint")]
+fn kernel_rom_trace_with_inconsistent_root_is_invalid() {
+    let (mut trace, kernel) = build_kernel_trace();
+
+    // the procedure accessed twice occupies two rows of the kernel ROM trace; change the root in
+    // the first one only, so that the procedure provided to the kernel procedure table (which is
+    // taken from the last row of the procedure) remains unchanged.
+    let start = kernel_rom_start(&trace);
+    let mut columns = main_trace_columns(&trace);
+    let row = (start..start + 2)
+        .find(|&row| {
+            columns[KERNEL_ROM_ADDR_COL_IDX][row + 1] == columns[KERNEL_ROM_ADDR_COL_IDX][row]
+        })
+        .expect("no repeated procedure in the kernel ROM trace");
+    columns[KERNEL_ROM_ROOT_COL_RANGE.start][row] += ONE;
+
+    // change the first SYSCALL, which calls the repeated procedure, to request the changed root,
+    // so that the kernel ROM bus remains balanced.
+    let syscall_row = find_syscall_row(&trace);
+    columns[DECODER_HASHER_STATE_OFFSET][syscall_row] += ONE;
+    trace.main_trace = MainTrace::new(ColMatrix::new(columns));
+
+    validate_trace(trace, kernel);
+}
+
+#[test]
+#[should_panic(expected = "trace does not satisfy assertion aux_trace")]
+fn kernel_rom_trace_with_foreign_procedure_is_invalid() {
+    let (mut trace, kernel) = build_kernel_trace();
+
+    // replace the root of a kernel procedure consistently across all rows of the kernel ROM trace,
+    // as would be required to execute a procedure which is not a part of the kernel.
+    let start = kernel_rom_start(&trace);
+    let mut columns = main_trace_columns(&trace);
+    let rows: Vec<usize> = (start..start + 3)
+        .filter(|&row| columns[KERNEL_ROM_ADDR_COL_IDX][row] == ZERO)
+        .collect();
+    for row in rows {
+        columns[KERNEL_ROM_ROOT_COL_RANGE.start][row] += ONE;
+    }
+    trace.main_trace = MainTrace::new(ColMatrix::new(columns));
+
+    validate_trace(trace, kernel);
+}
+
+// the kernel ROM bus is the auxiliary column with index 7.
+#[test]
+#[should_panic(expected = "trace does not satisfy assertion aux_trace(7, ")]
+fn kernel_rom_trace_with_changed_syscall_target_is_invalid() {
+    let (mut trace, kernel) = build_kernel_trace();
+
+    // change the root of the procedure requested by the first SYSCALL operation, as would be
+    // required to call a procedure which is not a part of the kernel.
+    let mut columns = main_trace_columns(&trace);
+    let row = find_syscall_row(&trace);
+    columns[DECODER_HASHER_STATE_OFFSET][row] += ONE;
+    trace.main_trace = MainTrace::new(ColMatrix::new(columns));
+
+    validate_trace(trace, kernel);
+}
+
+#[test]
+#[should_panic(expected = "trace does not satisfy assertion aux_trace(7, ")]
+fn kernel_rom_trace_with_missing_procedure_access_is_invalid() {
+    let (mut trace, kernel) = build_kernel_trace();
+
+    // stop the kernel ROM from providing the procedure accessed in its first row.
+    let start = kernel_rom_start(&trace);
+    let mut columns = main_trace_columns(&trace);
+    assert_eq!(columns[KERNEL_ROM_S0_COL_IDX][start], ONE);
+    columns[KERNEL_ROM_S0_COL_IDX][start] = ZERO;
+    trace.main_trace = MainTrace::new(ColMatrix::new(columns));
+
+    validate_trace(trace, kernel);
+}
+
+#[test]
+#[should_panic(expected = "trace does not satisfy assertion aux_trace")]
+fn kernel_rom_trace_with_different_kernel_is_invalid() {
+    let (trace, kernel) = build_kernel_trace();
+
+    // validate the trace against a kernel which does not contain the second procedure.
+    let kernel = Kernel::new(&kernel.proc_hashes()[..1]).unwrap();
+    validate_trace(trace, kernel);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds an execution trace of a program which calls the first of the two kernel procedures
+/// twice and the second one once, resulting in a kernel ROM trace with 3 rows.
+fn build_kernel_trace() -> (ExecutionTrace, Kernel) {
+    let foo = CodeBlock::new_span(vec![Operation::Pad, Operation::Drop]);
+    let bar = CodeBlock::new_span(vec![Operation::Pad, Operation::Incr, Operation::Drop]);
+    let kernel = Kernel::new(&[foo.hash(), bar.hash()]).unwrap();
+
+    let first_proc = if kernel.proc_hashes()[0] == foo.hash() {
+        &foo
+    } else {
+        &bar
+    };
+    let second_proc = if kernel.proc_hashes()[0] == foo.hash() {
+        &bar
+    } else {
+        &foo
+    };
+    let program = CodeBlock::new_join([
+        CodeBlock::new_join([
+            CodeBlock::new_syscall(first_proc.hash()),
+            CodeBlock::new_syscall(first_proc.hash()),
+        ]),
+        CodeBlock::new_syscall(second_proc.hash()),
+    ]);
+
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(foo);
+    cb_table.insert(bar);
+
+    let trace = build_trace(&program, kernel.clone(), &cb_table);
+    (trace, kernel)
+}
+
+/// Executes the specified program against the specified kernel and returns the resulting trace.
+fn build_trace(program: &CodeBlock, kernel: Kernel, cb_table: &CodeBlockTable) -> ExecutionTrace {
+    let host = DefaultHost::default();
+    let mut process =
+        Process::new(kernel, StackInputs::default(), host, ExecutionOptions::default());
+    process.execute_code_block(program, cb_table).unwrap();
+    ExecutionTrace::new(process, StackOutputs::default())
+}
+
+/// Checks the specified trace against the constraints of [ProcessorAir] instantiated with the
+/// specified kernel, and panics if the trace is not valid.
+fn validate_trace(mut trace: ExecutionTrace, kernel: Kernel) {
+    let program_info = ProgramInfo::new(*trace.program_hash(), kernel);
+    // the programs used in these tests leave the stack empty
+    let stack_outputs = StackOutputs::new(Vec::new(), Vec::new()).unwrap();
+    let pub_inputs = PublicInputs::new(program_info, StackInputs::default(), stack_outputs);
+    let air = ProcessorAir::new(trace.get_info(), pub_inputs, ProvingOptions::REGULAR_96_BITS);
+
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_segment = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    let mut aux_rand_elements = AuxTraceRandElements::new();
+    aux_rand_elements.add_segment_elements(rand_elements.to_vec());
+
+    trace.validate(&air, &[aux_segment], &aux_rand_elements);
+}
+
+/// Returns the index of the first row of the kernel ROM trace.
+fn kernel_rom_start(trace: &ExecutionTrace) -> usize {
+    (0..trace.length())
+        .find(|&row| trace.main_trace.is_kernel_row(row))
+        .expect("no kernel ROM rows in the trace")
+}
+
+/// Returns the index of the row in which the first SYSCALL operation is executed.
+fn find_syscall_row(trace: &ExecutionTrace) -> usize {
+    (0..trace.length())
+        .find(|&row| trace.main_trace.get_op_code(row) == Felt::from(Operation::SysCall.op_code()))
+        .expect("no SYSCALL operation in the trace")
+}
+
+/// Returns the columns of the main trace of the specified execution trace.
+fn main_trace_columns(trace: &ExecutionTrace) -> Vec<Vec<Felt>> {
+    (0..trace.main_trace.num_cols())
+        .map(|col| trace.main_trace.get_column(col).to_vec())
+        .collect()
+}
@@ -2,11 +2,21 @@ use super::{
     build_trace_from_ops, rand_array, ExecutionTrace, Felt, FieldElement, Operation, Trace, Word,
     AUX_TRACE_RAND_ELEMENTS, CHIPLETS_AUX_TRACE_OFFSET, NUM_RAND_ROWS, ONE, ZERO,
 };
-use miden_air::trace::chiplets::{
-    memory::{MEMORY_READ_LABEL, MEMORY_WRITE, MEMORY_WRITE_LABEL, NUM_ELEMENTS},
-    MEMORY_ADDR_COL_IDX, MEMORY_CLK_COL_IDX, MEMORY_CTX_COL_IDX, MEMORY_SELECTORS_COL_IDX,
-    MEMORY_V_COL_RANGE,
+use crate::{DefaultHost, ExecutionOptions, Process, StackInputs};
+use alloc::vec::Vec;
+use miden_air::trace::{
+    chiplets::{
+        bitwise::BITWISE_AND_LABEL,
+        memory::{MEMORY_READ_LABEL, MEMORY_WRITE, MEMORY_WRITE_LABEL, NUM_ELEMENTS},
+        MEMORY_ADDR_COL_IDX, MEMORY_CLK_COL_IDX, MEMORY_CTX_COL_IDX, MEMORY_SELECTORS_COL_IDX,
+        MEMORY_V_COL_RANGE,
+    },
+    decoder::DECODER_USER_OP_HELPERS_OFFSET,
+    main_trace::MainTrace,
+    STACK_TRACE_OFFSET,
 };
+use vm_core::{code_blocks::CodeBlock, CodeBlockTable, Kernel, StackOutputs};
+use winter_prover::matrix::ColMatrix;
 
 /// Tests the generation of the `b_chip` bus column when only memory lookups are included. It ensures
 /// that trace generation is correct when all of the following are true.
@@ -140,9 +150,228 @@ fn b_chip_trace_mem() {
     }
 }
 
+/// Tests that the `b_chip` bus requests sent by the stack for the element-addressed memory
+/// operations `MStoreE` and `MLoadE` match the responses provided by the memory and bitwise
+/// chiplets.
+#[test]
+fn b_chip_trace_mem_element() {
+    // store 7 at element address 6, which is the third element of the word at address 1
+    let stack = [7, 6];
+    let word = [ZERO, ZERO, Felt::new(7), ZERO];
+    let operations = vec![
+        Operation::MStoreE,            // store 7 at element address 6
+        Operation::Push(Felt::new(6)), // push the element address back onto the stack
+        Operation::MLoadE,             // load the element at element address 6
+    ];
+    let mut trace = build_trace_from_ops(operations, &stack);
+    let last_row = trace.length() - NUM_RAND_ROWS - 1;
+
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    let b_chip = aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET);
+
+    assert_eq!(ONE, b_chip[1]);
+
+    // both operations range-check the word address 1 via a bitwise AND of the address with itself
+    let range_check = build_expected_bitwise_and(&rand_elements, ONE, ONE, ONE);
+
+    // `MStoreE` is executed at cycle 1; it reads the word at word address 1 and writes the whole
+    // word with the third element replaced in the same cycle.
+    let old_read =
+        build_expected_memory(&rand_elements, MEMORY_READ_LABEL, ZERO, ONE, ONE, [ZERO; 4]);
+    let write = build_expected_memory(&rand_elements, MEMORY_WRITE_LABEL, ZERO, ONE, ONE, word);
+    let mut expected = (old_read * write * range_check).inv();
+    assert_eq!(expected, b_chip[2]);
+    assert_eq!(expected, b_chip[3]);
+
+    // `MLoadE` is executed at cycle 3 and reads the whole word at word address 1.
+    let read =
+        build_expected_memory(&rand_elements, MEMORY_READ_LABEL, ZERO, ONE, Felt::new(3), word);
+    expected *= (read * range_check).inv();
+    assert_eq!(expected, b_chip[4]);
+
+    // the memory chiplet section starts after the 8 rows of the span hash and the 16 rows of the
+    // two bitwise operations, and provides the read and the write of `MStoreE` at rows 24 and 25,
+    // and the read of `MLoadE` at row 26.
+    assert_eq!(old_read, build_expected_memory_from_trace(&trace, &rand_elements, 24));
+    assert_eq!(write, build_expected_memory_from_trace(&trace, &rand_elements, 25));
+    assert_eq!(read, build_expected_memory_from_trace(&trace, &rand_elements, 26));
+
+    // all requests are matched by the responses of the chiplets
+    assert_eq!(ONE, b_chip[last_row]);
+}
+
+/// Tests that the `b_chip` bus is not balanced when the prover uses index bits which are not the
+/// lower bits of the element address in `MLoadE`, even when the memory chiplet provides the word
+/// at the resulting word address.
+#[test]
+fn b_chip_trace_mem_element_wrong_index_bits() {
+    // the word [1, 2, 3, 4] is stored at word address 1 (element addresses 4 - 7), and the element
+    // at element address 6 is loaded
+    let stack = [4, 3, 2, 1, 1];
+    let operations = vec![
+        Operation::MStoreW,            // store [1, 2, 3, 4] at word address 1
+        Operation::Drop,               // drop the word from the stack
+        Operation::Drop,               //
+        Operation::Drop,               //
+        Operation::Drop,               //
+        Operation::Push(Felt::new(6)), // push the element address
+        Operation::MLoadE,             // load the element at element address 6
+    ];
+    let mut trace = build_trace_from_ops(operations, &stack);
+    let last_row = trace.length() - NUM_RAND_ROWS - 1;
+
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_eq!(ONE, aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET)[last_row]);
+
+    let main_trace = &trace.main_trace;
+    let mloade_row = (0..main_trace.num_rows())
+        .find(|&row| main_trace.get_op_code(row) == Felt::from(Operation::MLoadE.op_code()))
+        .unwrap();
+    let mloade_clk = main_trace.clk(mloade_row);
+    let word_row = (0..main_trace.num_rows())
+        .find(|&row| {
+            main_trace.get_column(MEMORY_CLK_COL_IDX)[row] == mloade_clk
+                && main_trace.get_column(MEMORY_ADDR_COL_IDX)[row] == ONE
+        })
+        .unwrap();
+    let mut columns: Vec<Vec<Felt>> = (0..main_trace.num_cols())
+        .map(|col| main_trace.get_column(col).to_vec())
+        .collect();
+
+    // claim that element address 6 refers to the last element of a word, i.e., set both index bits
+    // to 1, and make the memory chiplet read this word at the resulting word address (6 - 3) / 4,
+    // which is not a 32-bit value
+    let phantom_addr = Felt::new(3) * Felt::new(4).inv();
+    columns[DECODER_USER_OP_HELPERS_OFFSET + 4][mloade_row] = ONE;
+    columns[DECODER_USER_OP_HELPERS_OFFSET + 5][mloade_row] = ONE;
+    columns[MEMORY_ADDR_COL_IDX][word_row] = phantom_addr;
+    trace.main_trace = MainTrace::new(ColMatrix::new(columns));
+
+    // the memory requests match the memory chiplet, but the range check of the word address does
+    // not match any of the responses of the bitwise chiplet
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_ne!(ONE, aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET)[last_row]);
+}
+
+/// Tests that the `b_chip` bus is not balanced when the prover changes an element of the word
+/// which is not addressed by `MStoreE`, even when the change is applied consistently to the helper
+/// registers and to the memory chiplet.
+#[test]
+fn b_chip_trace_mem_element_tampered_neighbour() {
+    let stack = [7, 6];
+    let operations = vec![
+        Operation::MStoreE,            // store 7 at element address 6
+        Operation::Push(Felt::new(6)), // push the element address back onto the stack
+        Operation::MLoadE,             // load the element at element address 6
+    ];
+    let mut trace = build_trace_from_ops(operations, &stack);
+    let last_row = trace.length() - NUM_RAND_ROWS - 1;
+
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_eq!(ONE, aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET)[last_row]);
+
+    // claim that the first element of the word was 5 before `MStoreE` (executed at cycle 1), and
+    // carry this value over into the word written by `MStoreE` (memory row 9) and the word read
+    // by `MLoadE` (executed at cycle 3, memory row 10).
+    let mut columns: Vec<Vec<Felt>> = (0..trace.main_trace.num_cols())
+        .map(|col| trace.main_trace.get_column(col).to_vec())
+        .collect();
+    let tampered = Felt::new(5);
+    columns[DECODER_USER_OP_HELPERS_OFFSET][1] = tampered;
+    columns[DECODER_USER_OP_HELPERS_OFFSET][3] = tampered;
+    columns[MEMORY_V_COL_RANGE.start][9] = tampered;
+    columns[MEMORY_V_COL_RANGE.start][10] = tampered;
+    trace.main_trace = MainTrace::new(ColMatrix::new(columns));
+
+    // the read of the old word made by `MStoreE` does not match the memory anymore
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_ne!(ONE, aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET)[last_row]);
+}
+
+/// Tests that the `b_chip` bus is balanced when a called context reads a word lent by its caller,
+/// and that it is not balanced when the prover changes the lending context, the bounds of the lent
+/// segment, or moves the address outside of the segment.
+#[test]
+fn b_chip_trace_mem_borrowed_word() {
+    let mut trace = build_borrowed_word_trace();
+    let last_row = trace.length() - NUM_RAND_ROWS - 1;
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_eq!(ONE, aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET)[last_row]);
+
+    let main_trace = &trace.main_trace;
+    let mloadwb_row = (0..main_trace.num_rows())
+        .find(|&row| main_trace.get_op_code(row) == Felt::from(Operation::MLoadWB.op_code()))
+        .unwrap();
+    let mloadwb_clk = main_trace.clk(mloadwb_row);
+    let word_row = (0..main_trace.num_rows())
+        .find(|&row| {
+            main_trace.get_column(MEMORY_CLK_COL_IDX)[row] == mloadwb_clk
+                && main_trace.get_column(MEMORY_ADDR_COL_IDX)[row] == Felt::new(3)
+        })
+        .unwrap();
+    let columns: Vec<Vec<Felt>> = (0..main_trace.num_cols())
+        .map(|col| main_trace.get_column(col).to_vec())
+        .collect();
+
+    // --- the lending context is not the caller ---------------------------------------------------
+    let mut tampered = columns.clone();
+    tampered[DECODER_USER_OP_HELPERS_OFFSET][mloadwb_row] = Felt::new(5);
+    trace.main_trace = MainTrace::new(ColMatrix::new(tampered));
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_ne!(ONE, aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET)[last_row]);
+
+    // --- the end of the lent segment is extended -------------------------------------------------
+    let mut tampered = columns.clone();
+    tampered[DECODER_USER_OP_HELPERS_OFFSET + 2][mloadwb_row] = Felt::new(8);
+    trace.main_trace = MainTrace::new(ColMatrix::new(tampered));
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_ne!(ONE, aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET)[last_row]);
+
+    // --- the address is moved outside of the segment, consistently with the memory chiplet ------
+    // the memory requests still match the memory chiplet, but (end - 1 - addr) is not a 32-bit
+    // value, and thus, the request to the bitwise chiplet does not match any of its responses
+    let mut tampered = columns;
+    tampered[STACK_TRACE_OFFSET][mloadwb_row] = Felt::new(5);
+    tampered[MEMORY_ADDR_COL_IDX][word_row] = Felt::new(5);
+    trace.main_trace = MainTrace::new(ColMatrix::new(tampered));
+    let aux_columns = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    assert_ne!(ONE, aux_columns.get_column(CHIPLETS_AUX_TRACE_OFFSET)[last_row]);
+}
+
 // TEST HELPERS
 // ================================================================================================
 
+/// Builds the trace of a program in which the root context stores 7 at address 3, lends words at
+/// addresses [2, 4) and calls a procedure which reads the word at address 3 via `MLoadWB`.
+fn build_borrowed_word_trace() -> ExecutionTrace {
+    let lend = CodeBlock::new_span(vec![
+        Operation::Push(Felt::new(7)),
+        Operation::Push(Felt::new(3)),
+        Operation::MStore,
+        Operation::Drop,
+        Operation::Push(Felt::new(4)),
+        Operation::Push(Felt::new(2)),
+        Operation::MLend,
+        Operation::Drop,
+        Operation::Drop,
+    ]);
+    let foo = CodeBlock::new_span(vec![Operation::Push(Felt::new(3)), Operation::MLoadWB]);
+    let program = CodeBlock::new_join([lend, CodeBlock::new_call(foo.hash())]);
+
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(foo);
+
+    let host = DefaultHost::default();
+    let mut process =
+        Process::new(Kernel::default(), StackInputs::default(), host, ExecutionOptions::default());
+    process.execute_code_block(&program, &cb_table).unwrap();
+    ExecutionTrace::new(process, StackOutputs::default())
+}
+
 fn build_expected_memory(
     alphas: &[Felt],
     op_label: u8,
@@ -164,6 +393,10 @@ fn build_expected_memory(
         + word_value
 }
 
+fn build_expected_bitwise_and(alphas: &[Felt], a: Felt, b: Felt, result: Felt) -> Felt {
+    alphas[0] + alphas[1] * BITWISE_AND_LABEL + alphas[2] * a + alphas[3] * b + alphas[4] * result
+}
+
 fn build_expected_memory_from_trace(trace: &ExecutionTrace, alphas: &[Felt], row: usize) -> Felt {
     // get the memory access operation
     let s0 = trace.main_trace.get_column(MEMORY_SELECTORS_COL_IDX)[row];
@@ -11,4 +11,5 @@ use test_utils::rand::rand_value;
 
 mod bitwise;
 mod hasher;
+mod kernel_rom;
 mod memory;
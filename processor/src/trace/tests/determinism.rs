@@ -0,0 +1,74 @@
+use super::{build_trace_from_ops, ExecutionTrace, Felt, Operation, Trace};
+use alloc::{string::String, vec::Vec};
+use miden_air::trace::AUX_TRACE_RAND_ELEMENTS;
+use vm_core::{crypto::hash::Blake3_256, utils::Serializable};
+
+/// Blake3 digest of the serialized sample trace and its auxiliary columns. The trace must be the
+/// same regardless of whether it was built with or without the `concurrent` feature, and thus,
+/// this test is expected to pass under both feature sets.
+const SAMPLE_TRACE_DIGEST: &str =
+    "0xdbabf395dd876425612cc43c2bf17d4d408394451d9054f5a0d7453bf2b3e99e";
+
+// TRACE DETERMINISM TESTS
+// ================================================================================================
+
+#[test]
+fn trace_generation_matches_fixture() {
+    let (trace, aux_columns) = build_sample_trace();
+    assert_eq!(SAMPLE_TRACE_DIGEST, hash_trace(&trace, &aux_columns));
+}
+
+#[cfg(feature = "concurrent")]
+#[test]
+fn concurrent_trace_generation_is_deterministic() {
+    use winter_utils::rayon::ThreadPoolBuilder;
+
+    // with a single thread, all trace segments and auxiliary columns are built sequentially
+    let pool = ThreadPoolBuilder::new().num_threads(1).build().unwrap();
+    let (sequential_trace, sequential_aux_columns) = pool.install(build_sample_trace);
+
+    let pool = ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+    let (concurrent_trace, concurrent_aux_columns) = pool.install(build_sample_trace);
+
+    assert_eq!(sequential_trace, concurrent_trace);
+    assert_eq!(sequential_aux_columns, concurrent_aux_columns);
+    assert_eq!(SAMPLE_TRACE_DIGEST, hash_trace(&concurrent_trace, &concurrent_aux_columns));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds an execution trace of a program which makes use of all chiplets and of the range
+/// checker, and returns the serialized trace together with its auxiliary columns.
+///
+/// Fixed random elements are used to build the auxiliary columns so that the result can be
+/// compared against [SAMPLE_TRACE_DIGEST].
+fn build_sample_trace() -> (Vec<u8>, Vec<Vec<Felt>>) {
+    let ops = vec![
+        Operation::Pad,
+        Operation::Pad,
+        Operation::U32add,
+        Operation::U32and,
+        Operation::Push(Felt::new(7)),
+        Operation::MStore,
+        Operation::Drop,
+        Operation::Push(Felt::new(7)),
+        Operation::MLoad,
+        Operation::HPerm,
+        Operation::Drop,
+    ];
+    let alphas: Vec<Felt> = (1..=AUX_TRACE_RAND_ELEMENTS as u64).map(Felt::new).collect();
+
+    let mut trace: ExecutionTrace = build_trace_from_ops(ops, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    let aux_columns = trace.build_aux_segment(&[], &alphas).unwrap().into_columns();
+    (trace.to_bytes(), aux_columns)
+}
+
+/// Returns a hex-encoded Blake3 digest of the serialized trace and its auxiliary columns.
+fn hash_trace(trace: &[u8], aux_columns: &[Vec<Felt>]) -> String {
+    let mut bytes = trace.to_vec();
+    for column in aux_columns {
+        column.write_into(&mut bytes);
+    }
+    Blake3_256::hash(&bytes).into()
+}
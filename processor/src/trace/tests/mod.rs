@@ -11,8 +11,10 @@ use vm_core::{
 
 mod chiplets;
 mod decoder;
+mod determinism;
 mod hasher;
 mod range;
+mod serialization;
 mod stack;
 
 // TEST HELPERS
@@ -0,0 +1,118 @@
+use super::{
+    super::AuxTraceBuilders, build_trace_from_ops, rand_array, ExecutionTrace, Felt, Operation,
+    Trace,
+};
+use alloc::vec::Vec;
+use miden_air::trace::{AUX_TRACE_RAND_ELEMENTS, TRACE_WIDTH};
+use vm_core::utils::{ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// EXECUTION TRACE SERIALIZATION TESTS
+// ================================================================================================
+
+#[test]
+fn execution_trace_serialization_roundtrip() {
+    // the ops push and pop values from the overflow table and perform range checks, so that the
+    // hints of the stack and range checker auxiliary trace builders are not empty
+    let ops = vec![
+        Operation::Pad,
+        Operation::Pad,
+        Operation::U32add,
+        Operation::Drop,
+        Operation::Push(Felt::new(7)),
+        Operation::U32split,
+        Operation::Drop,
+        Operation::Drop,
+    ];
+    let mut trace = build_trace_from_ops(ops, &[1, 2, 3, 4]);
+
+    let bytes = trace.to_bytes();
+    let mut deserialized = ExecutionTrace::read_from_bytes(&bytes).unwrap();
+    assert_eq!(bytes, deserialized.to_bytes());
+
+    assert_eq!(trace.program_info(), deserialized.program_info());
+    assert_eq!(trace.stack_outputs(), deserialized.stack_outputs());
+    assert_eq!(trace.trace_len_summary(), deserialized.trace_len_summary());
+    assert_eq!(trace.layout(), deserialized.layout());
+    assert_eq!(trace.main_segment().num_rows(), deserialized.main_segment().num_rows());
+    for (col, deserialized_col) in
+        trace.main_segment().columns().zip(deserialized.main_segment().columns())
+    {
+        assert_eq!(col, deserialized_col);
+    }
+
+    // the auxiliary trace segment built from the deserialized trace must be the same
+    let alphas = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &alphas).unwrap();
+    let deserialized_aux_columns = deserialized.build_aux_segment(&[], &alphas).unwrap();
+    assert_eq!(aux_columns.into_columns(), deserialized_aux_columns.into_columns());
+}
+
+#[test]
+fn execution_trace_deserialization_errors() {
+    let trace = build_trace_from_ops(vec![Operation::Pad, Operation::Drop], &[]);
+    let bytes = trace.to_bytes();
+
+    // traces serialized using a different format version are rejected
+    let mut invalid_bytes = bytes.clone();
+    invalid_bytes[0] = ExecutionTrace::SERIALIZATION_VERSION + 1;
+    assert!(matches!(
+        ExecutionTrace::read_from_bytes(&invalid_bytes),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+
+    // traces with an unexpected number of columns are rejected; the number of columns is encoded
+    // as a single-byte variable-length integer right after the version and the empty metadata
+    let mut invalid_bytes = bytes.clone();
+    assert_eq!(invalid_bytes[2], ((TRACE_WIDTH as u8) << 1) | 1);
+    invalid_bytes[2] = ((TRACE_WIDTH as u8 + 1) << 1) | 1;
+    assert!(matches!(
+        ExecutionTrace::read_from_bytes(&invalid_bytes),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+
+    // truncated traces are rejected
+    assert!(matches!(
+        ExecutionTrace::read_from_bytes(&bytes[..bytes.len() - 1]),
+        Err(DeserializationError::UnexpectedEOF)
+    ));
+}
+
+#[test]
+fn execution_trace_deserialization_malformed_lengths() {
+    let trace = build_trace_from_ops(vec![Operation::Pad, Operation::Drop], &[]);
+    let bytes = trace.to_bytes();
+
+    // replaces the number of rows, which follows the version, the empty metadata, and the number
+    // of columns, with the specified value
+    let with_num_rows = |num_rows: usize| {
+        let mut invalid_bytes = bytes[..3].to_vec();
+        invalid_bytes.write_usize(num_rows);
+        invalid_bytes.extend_from_slice(&[0; 64]);
+        invalid_bytes
+    };
+
+    // traces longer than the maximum number of cycles are rejected
+    let num_rows = (ExecutionTrace::MAX_SERIALIZED_TRACE_LEN as usize) << 1;
+    assert!(matches!(
+        ExecutionTrace::read_from_bytes(&with_num_rows(num_rows)),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+
+    // traces claiming more rows than the input holds are rejected before any memory is allocated
+    // for their columns
+    let num_rows = ExecutionTrace::MAX_SERIALIZED_TRACE_LEN as usize;
+    assert!(matches!(
+        ExecutionTrace::read_from_bytes(&with_num_rows(num_rows)),
+        Err(DeserializationError::UnexpectedEOF)
+    ));
+
+    // the same applies to the hints of the auxiliary trace builders: here, the number of rows in
+    // the overflow table of the stack
+    let mut invalid_bytes = Vec::new();
+    invalid_bytes.write_usize(1 << 40);
+    invalid_bytes.extend_from_slice(&[0; 64]);
+    assert!(matches!(
+        AuxTraceBuilders::read_from_bytes(&invalid_bytes),
+        Err(DeserializationError::UnexpectedEOF)
+    ));
+}
@@ -1,8 +1,12 @@
 use super::{Felt, FieldElement, NUM_RAND_ROWS};
 use crate::{chiplets::Chiplets, utils::uninit_vector};
 use alloc::vec::Vec;
-use core::slice;
 use miden_air::trace::main_trace::MainTrace;
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use winter_utils::iter_mut;
+
+#[cfg(feature = "concurrent")]
+use winter_utils::iterators::*;
 
 #[cfg(test)]
 use vm_core::{utils::ToElements, Operation};
@@ -45,9 +49,17 @@ impl<'a> TraceFragment<'a> {
         self.data[col_idx][row_idx] = value;
     }
 
-    /// Returns a mutable iterator to the columns of this fragment.
-    pub fn columns(&mut self) -> slice::IterMut<'_, &'a mut [Felt]> {
-        self.data.iter_mut()
+    /// Copies the provided columns into this fragment column-by-column.
+    ///
+    /// When the `concurrent` feature is enabled, the columns are copied in multiple threads.
+    ///
+    /// # Panics
+    /// Panics if the length of any of the provided columns is different from the length of this
+    /// fragment.
+    pub fn copy_columns<C: AsRef<[Felt]> + Sync>(&mut self, columns: &[C]) {
+        iter_mut!(self.data).zip(columns).for_each(|(out_column, column)| {
+            out_column.copy_from_slice(column.as_ref());
+        });
     }
 
     /// Adds a new column to this fragment by pushing a mutable slice with the first `len`
@@ -201,6 +213,45 @@ impl ChipletsLengths {
     }
 }
 
+// SERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for TraceLenSummary {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.main_trace_len);
+        target.write_usize(self.range_trace_len);
+        self.chiplets_trace_len.write_into(target);
+    }
+}
+
+impl Deserializable for TraceLenSummary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let main_trace_len = source.read_usize()?;
+        let range_trace_len = source.read_usize()?;
+        let chiplets_trace_len = source.read()?;
+        Ok(Self::new(main_trace_len, range_trace_len, chiplets_trace_len))
+    }
+}
+
+impl Serializable for ChipletsLengths {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.hash_chiplet_len);
+        target.write_usize(self.bitwise_chiplet_len);
+        target.write_usize(self.memory_chiplet_len);
+        target.write_usize(self.kernel_rom_len);
+    }
+}
+
+impl Deserializable for ChipletsLengths {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let hash_len = source.read_usize()?;
+        let bitwise_len = source.read_usize()?;
+        let memory_len = source.read_usize()?;
+        let kernel_len = source.read_usize()?;
+        Ok(Self::from_parts(hash_len, bitwise_len, memory_len, kernel_len))
+    }
+}
+
 // AUXILIARY COLUMN BUILDER
 // ================================================================================================
 
@@ -6,6 +6,13 @@ use alloc::vec::Vec;
 
 pub use vm_core::utils::*;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum number of elements for which memory is allocated up front when reading a list of
+/// elements whose length comes from an untrusted source.
+const MAX_PREALLOCATED_ELEMENTS: usize = 1 << 16;
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -44,3 +51,63 @@ pub(crate) fn split_u32_into_u16(value: u64) -> (u16, u16) {
 
     (hi, lo)
 }
+
+/// Executes the two provided closures and returns their results.
+///
+/// When the `concurrent` feature is enabled, the closures are executed in parallel using
+/// `rayon::join()`; otherwise, they are executed sequentially in the order in which they were
+/// provided. Since the closures do not share any mutable state, the results are the same in both
+/// cases.
+#[inline]
+pub(crate) fn join<A, B, RA, RB>(oper_a: A, oper_b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    #[cfg(feature = "concurrent")]
+    {
+        winter_utils::rayon::join(oper_a, oper_b)
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    {
+        (oper_a(), oper_b())
+    }
+}
+
+/// Reads `num_elements` values from the provided source, assuming that each value takes at least
+/// `min_element_bytes` bytes in serialized form.
+///
+/// Unlike [ByteReader::read_many()], this function does not trust `num_elements`: it fails if the
+/// source is known to hold fewer bytes than the values require, and it grows the result as values
+/// are read, so that a malformed length can never cause a large allocation.
+pub(crate) fn read_many_bounded<R: ByteReader, D: Deserializable>(
+    source: &mut R,
+    num_elements: usize,
+    min_element_bytes: usize,
+) -> Result<Vec<D>, DeserializationError> {
+    let num_bytes = num_elements
+        .checked_mul(min_element_bytes)
+        .ok_or(DeserializationError::UnexpectedEOF)?;
+    source.check_eor(num_bytes)?;
+
+    let mut result = Vec::with_capacity(num_elements.min(MAX_PREALLOCATED_ELEMENTS));
+    for _ in 0..num_elements {
+        result.push(D::read_from(source)?);
+    }
+    Ok(result)
+}
+
+/// Reads a vector serialized as its length followed by its values, assuming that each value takes
+/// at least `min_element_bytes` bytes in serialized form.
+///
+/// See [read_many_bounded()] for how the length of the vector is validated.
+pub(crate) fn read_vec_bounded<R: ByteReader, D: Deserializable>(
+    source: &mut R,
+    min_element_bytes: usize,
+) -> Result<Vec<D>, DeserializationError> {
+    let num_elements = source.read_usize()?;
+    read_many_bounded(source, num_elements, min_element_bytes)
+}
@@ -0,0 +1,288 @@
+use super::{
+    ExecutionError, ExecutionTrace, FieldExtension, HashFunction, Host, Program, ProvingOptions,
+    StackInputs,
+};
+use air::{ProcessorAir, PublicInputs};
+use processor::TraceLenSummary;
+use winter_prover::{Air, ProofOptions as WinterProofOptions, Trace, TraceInfo};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of bytes needed to encode a single base field element.
+const FELT_BYTES: usize = 8;
+
+// PROOF ESTIMATE
+// ================================================================================================
+
+/// An estimate of the resources required to generate a STARK proof of a program's execution.
+///
+/// The estimate is derived from the execution trace dimensions and the AIR layout, without
+/// actually committing to the trace or running the FRI protocol. Proof size is an upper bound
+/// assuming no query positions collide and no Merkle authentication paths are shared beyond the
+/// expected overlap of a batched Merkle proof. Prover memory is an approximation of the peak
+/// memory needed to hold the main and auxiliary traces, their low-degree extensions, the
+/// constraint evaluations and the Merkle trees committing to them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofEstimate {
+    trace_len_summary: TraceLenSummary,
+    trace_len: usize,
+    main_trace_width: usize,
+    aux_trace_width: usize,
+    num_main_constraints: usize,
+    num_aux_constraints: usize,
+    num_assertions: usize,
+    num_composition_columns: usize,
+    lde_domain_size: usize,
+    num_fri_layers: usize,
+    proof_size: usize,
+    prover_memory: usize,
+}
+
+impl ProofEstimate {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ProofEstimate] for proving the provided execution trace with the specified
+    /// options.
+    pub fn new(
+        trace: &ExecutionTrace,
+        stack_inputs: StackInputs,
+        options: &ProvingOptions,
+    ) -> Self {
+        let trace_info = TraceInfo::new_multi_segment(
+            trace.layout().clone(),
+            trace.length(),
+            trace.meta().to_vec(),
+        );
+        let pub_inputs = PublicInputs::new(
+            trace.program_info().clone(),
+            stack_inputs,
+            trace.stack_outputs().clone(),
+        );
+        let proof_options: WinterProofOptions = options.clone().into();
+        let air = ProcessorAir::new(trace_info, pub_inputs, proof_options.clone());
+        let context = air.context();
+
+        let trace_len = trace.length();
+        let main_trace_width = trace.layout().main_trace_width();
+        let aux_trace_width = trace.layout().aux_trace_width();
+        let num_composition_columns = context.num_constraint_composition_columns();
+        let lde_domain_size = context.lde_domain_size();
+        let ce_domain_size = context.ce_domain_size();
+        let num_fri_layers = proof_options.to_fri_options().num_fri_layers(lde_domain_size);
+
+        let extension_bytes = FELT_BYTES * extension_degree(proof_options.field_extension());
+        let digest_bytes = digest_bytes(options.hash_fn());
+
+        // --- proof size -------------------------------------------------------------------------
+        let num_queries = proof_options.num_queries().min(lde_domain_size);
+        let lde_path_len = batch_merkle_proof_len(num_queries, lde_domain_size);
+
+        // commitments to the main trace, the auxiliary trace, the constraint composition
+        // polynomial and each of the FRI layers
+        let commitments = (3 + num_fri_layers) * digest_bytes;
+
+        // out-of-domain frame contains current and next rows of the main and auxiliary traces as
+        // well as evaluations of the composition polynomial columns, all in the extension field
+        let ood_frame =
+            (2 * (main_trace_width + aux_trace_width) + num_composition_columns) * extension_bytes;
+
+        // trace and constraint queries contain the queried rows together with batched Merkle
+        // authentication paths against the respective commitments
+        let trace_queries = num_queries * main_trace_width * FELT_BYTES
+            + num_queries * aux_trace_width * extension_bytes
+            + 2 * lde_path_len * digest_bytes;
+        let constraint_queries =
+            num_queries * num_composition_columns * extension_bytes + lde_path_len * digest_bytes;
+
+        // each FRI layer opens `folding_factor` evaluations per query against a commitment to a
+        // domain `folding_factor` times smaller than the previous one
+        let fri_options = proof_options.to_fri_options();
+        let folding_factor = fri_options.folding_factor();
+        let mut fri_layers = 0;
+        let mut domain_size = lde_domain_size;
+        for _ in 0..num_fri_layers {
+            let num_leaves = domain_size / folding_factor;
+            let layer_queries = num_queries.min(num_leaves);
+            fri_layers += layer_queries * folding_factor * extension_bytes
+                + batch_merkle_proof_len(layer_queries, num_leaves) * digest_bytes;
+            domain_size = num_leaves;
+        }
+        let fri_remainder = (fri_options.remainder_max_degree() + 1) * extension_bytes;
+
+        // proof context (trace layout, options, field modulus) and the proof-of-work nonce
+        let context_and_nonce = 64;
+
+        let proof_size = commitments
+            + ood_frame
+            + trace_queries
+            + constraint_queries
+            + fri_layers
+            + fri_remainder
+            + context_and_nonce;
+
+        // --- prover memory ----------------------------------------------------------------------
+        let main_row_bytes = main_trace_width * FELT_BYTES;
+        let aux_row_bytes = aux_trace_width * extension_bytes;
+
+        // execution trace, trace polynomials and their low-degree extensions
+        let traces = 2 * trace_len * (main_row_bytes + aux_row_bytes)
+            + lde_domain_size * (main_row_bytes + aux_row_bytes);
+
+        // constraint evaluations over the constraint evaluation domain and the low-degree
+        // extension of the composition polynomial columns
+        let constraints = ce_domain_size * extension_bytes
+            + lde_domain_size * num_composition_columns * extension_bytes;
+
+        // Merkle trees committing to the main trace, the auxiliary trace and the composition
+        // polynomial; each tree holds 2 * n digests for n leaves
+        let merkle_trees = 3 * 2 * lde_domain_size * digest_bytes;
+
+        let prover_memory = traces + constraints + merkle_trees;
+
+        Self {
+            trace_len_summary: *trace.trace_len_summary(),
+            trace_len,
+            main_trace_width,
+            aux_trace_width,
+            num_main_constraints: context.num_main_transition_constraints(),
+            num_aux_constraints: context.num_aux_transition_constraints(),
+            num_assertions: context.num_assertions(),
+            num_composition_columns,
+            lde_domain_size,
+            num_fri_layers,
+            proof_size,
+            prover_memory,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the summary of the lengths of main, range and chiplet traces.
+    pub fn trace_len_summary(&self) -> &TraceLenSummary {
+        &self.trace_len_summary
+    }
+
+    /// Returns the number of executed VM cycles.
+    pub fn num_cycles(&self) -> usize {
+        self.trace_len_summary.main_trace_len()
+    }
+
+    /// Returns the length of the execution trace after padding it to the next power of two.
+    ///
+    /// This may exceed the padded length of the trace length summary if the expected number of
+    /// cycles specified in the execution options is larger than the number of executed cycles.
+    pub fn padded_trace_len(&self) -> usize {
+        self.trace_len
+    }
+
+    /// Returns the number of columns in the main segment of the execution trace.
+    pub fn main_trace_width(&self) -> usize {
+        self.main_trace_width
+    }
+
+    /// Returns the number of columns in the auxiliary segment of the execution trace.
+    pub fn aux_trace_width(&self) -> usize {
+        self.aux_trace_width
+    }
+
+    /// Returns the number of transition constraints applied to the main trace segment.
+    pub fn num_main_constraints(&self) -> usize {
+        self.num_main_constraints
+    }
+
+    /// Returns the number of transition constraints applied to the auxiliary trace segment.
+    pub fn num_aux_constraints(&self) -> usize {
+        self.num_aux_constraints
+    }
+
+    /// Returns the total number of boundary constraints applied to the execution trace.
+    pub fn num_assertions(&self) -> usize {
+        self.num_assertions
+    }
+
+    /// Returns the number of columns of the constraint composition polynomial.
+    pub fn num_composition_columns(&self) -> usize {
+        self.num_composition_columns
+    }
+
+    /// Returns the size of the low-degree extension domain.
+    pub fn lde_domain_size(&self) -> usize {
+        self.lde_domain_size
+    }
+
+    /// Returns the number of FRI layers committed to in the proof.
+    pub fn num_fri_layers(&self) -> usize {
+        self.num_fri_layers
+    }
+
+    /// Returns the estimated size of the proof in bytes.
+    pub fn proof_size(&self) -> usize {
+        self.proof_size
+    }
+
+    /// Returns the approximate peak memory in bytes needed to generate the proof.
+    pub fn prover_memory(&self) -> usize {
+        self.prover_memory
+    }
+}
+
+// ESTIMATOR
+// ================================================================================================
+
+/// Executes the specified `program` and returns an estimate of the resources required to prove
+/// its execution with the specified `options`.
+///
+/// The program is executed to build the execution trace, but no proof is generated.
+///
+/// # Errors
+/// Returns an error if program execution fails for any reason.
+pub fn estimate<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: &ProvingOptions,
+) -> Result<ProofEstimate, ExecutionError>
+where
+    H: Host,
+{
+    let trace =
+        processor::execute(program, stack_inputs.clone(), host, *options.execution_options())?;
+    Ok(ProofEstimate::new(&trace, stack_inputs, options))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the degree of the specified field extension.
+fn extension_degree(field_extension: FieldExtension) -> usize {
+    match field_extension {
+        FieldExtension::None => 1,
+        FieldExtension::Quadratic => 2,
+        FieldExtension::Cubic => 3,
+    }
+}
+
+/// Returns the number of bytes in a digest produced by the specified hash function.
+fn digest_bytes(hash_fn: HashFunction) -> usize {
+    match hash_fn {
+        HashFunction::Blake3_192 => 24,
+        HashFunction::Blake3_256 | HashFunction::Rpo256 => 32,
+    }
+}
+
+/// Returns the expected number of nodes in a batched Merkle proof opening `num_queries` leaves of
+/// a tree with `num_leaves` leaves.
+///
+/// Paths of randomly selected leaves overlap near the root, so the first log2(num_queries) levels
+/// of the tree contribute almost no nodes to the proof.
+fn batch_merkle_proof_len(num_queries: usize, num_leaves: usize) -> usize {
+    if num_queries == 0 || num_leaves <= 1 {
+        return 0;
+    }
+    let depth = num_leaves.ilog2() as usize;
+    let shared = num_queries.ilog2() as usize;
+    num_queries * depth.saturating_sub(shared)
+}
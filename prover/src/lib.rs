@@ -7,7 +7,6 @@ use processor::{
         Blake3_192, Blake3_256, ElementHasher, RandomCoin, Rpo256, RpoRandomCoin, WinterRandomCoin,
     },
     math::{Felt, FieldElement},
-    ExecutionTrace,
 };
 use tracing::{event, instrument, Level};
 use winter_prover::{
@@ -22,14 +21,25 @@ use {std::time::Instant, winter_prover::Trace};
 #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
 mod gpu;
 
+mod estimate;
+
+#[cfg(feature = "std")]
+mod storage;
+
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions};
+pub use air::{
+    DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofReport, ProofSizes,
+    ProvingOptions,
+};
+pub use estimate::{estimate, ProofEstimate};
 pub use processor::{
-    crypto, math, utils, AdviceInputs, Digest, ExecutionError, Host, InputError, MemAdviceProvider,
-    Program, StackInputs, StackOutputs, Word,
+    crypto, math, utils, AdviceInputs, Digest, ExecutionError, ExecutionTrace, Host, InputError,
+    MemAdviceProvider, Program, StackInputs, StackOutputs, Word,
 };
+#[cfg(feature = "std")]
+pub use storage::TraceStorage;
 pub use winter_prover::StarkProof;
 
 // PROVER
@@ -55,20 +65,31 @@ where
     H: Host,
 {
     // execute the program to create an execution trace
-    #[cfg(feature = "std")]
-    let now = Instant::now();
-    let trace =
-        processor::execute(program, stack_inputs.clone(), host, *options.execution_options())?;
-    #[cfg(feature = "std")]
-    event!(
-        Level::INFO,
-        "Generated execution trace of {} columns and {} steps ({}% padded) in {} ms",
-        trace.layout().main_trace_width(),
-        trace.trace_len_summary().padded_trace_len(),
-        trace.trace_len_summary().padding_percentage(),
-        now.elapsed().as_millis()
-    );
+    let trace = execute_program(program, stack_inputs.clone(), host, &options)?;
 
+    // generate STARK proof
+    prove_trace(trace, stack_inputs, options)
+}
+
+/// Generates a STARK-based proof of the program execution described by the provided execution
+/// trace, and returns it together with the outputs of the execution.
+///
+/// The trace can be generated via [processor::execute()] in the same process, or it can be
+/// deserialized from bytes produced by a different process (e.g., on a different machine). This
+/// allows execution of programs to be decoupled from generation of proofs.
+///
+/// * `stack_inputs` specifies the initial state of the stack with which the program was executed.
+/// * `options` defines parameters for STARK proof generation; execution options contained in
+///   `options` are ignored as the program has already been executed.
+///
+/// # Errors
+/// Returns an error if STARK proof generation fails for any reason.
+#[instrument("prove_trace", skip_all)]
+pub fn prove_trace(
+    trace: ExecutionTrace,
+    stack_inputs: StackInputs,
+    options: ProvingOptions,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
     let stack_outputs = trace.stack_outputs().clone();
     let hash_fn = options.hash_fn();
 
@@ -103,6 +124,101 @@ where
     Ok((stack_outputs, proof))
 }
 
+/// Executes and proves the specified `program` and returns the result together with a STARK-based
+/// proof of the program's execution.
+///
+/// Unlike [prove()], the low-degree extension of the execution trace and the lower levels of the
+/// Merkle trees committing to it are never fully held in memory; instead, they are written to the
+/// files in the specified `storage` as they are computed, and read back from these files in blocks
+/// when needed. The main segment of the execution trace is written to the storage as well, and it
+/// is held only on disk while its low-degree extension is computed. The trace polynomials are
+/// still held in memory, and thus the memory used by the trace commitments is proportional to the
+/// length of the execution trace rather than to the size of the LDE domain, at the expense of disk
+/// I/O. The generated proof is identical to the one generated by [prove()].
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason, including
+/// failures to write the low-degree extension of the execution trace to the storage or to read it
+/// back.
+#[cfg(feature = "std")]
+#[instrument("prove_program_with_storage", skip_all)]
+pub fn prove_with_storage<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ProvingOptions,
+    storage: TraceStorage,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    H: Host,
+{
+    // execute the program to create an execution trace
+    let trace = execute_program(program, stack_inputs.clone(), host, &options)?;
+
+    let stack_outputs = trace.stack_outputs().clone();
+    let hash_fn = options.hash_fn();
+
+    // generate STARK proof
+    let proof = match hash_fn {
+        HashFunction::Blake3_192 => storage::DiskExecutionProver::new(
+            ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            ),
+            storage,
+        )
+        .prove_trace(trace),
+        HashFunction::Blake3_256 => storage::DiskExecutionProver::new(
+            ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            ),
+            storage,
+        )
+        .prove_trace(trace),
+        HashFunction::Rpo256 => storage::DiskExecutionProver::new(
+            ExecutionProver::<Rpo256, RpoRandomCoin>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            ),
+            storage,
+        )
+        .prove_trace(trace),
+    }?;
+    let proof = ExecutionProof::new(proof, hash_fn);
+
+    Ok((stack_outputs, proof))
+}
+
+/// Executes the specified `program` and returns the resulting execution trace.
+fn execute_program<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: &ProvingOptions,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    H: Host,
+{
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    let trace = processor::execute(program, stack_inputs, host, *options.execution_options())?;
+    #[cfg(feature = "std")]
+    event!(
+        Level::INFO,
+        "Generated execution trace of {} columns and {} steps ({}% padded) in {} ms",
+        trace.layout().main_trace_width(),
+        trace.trace_len_summary().padded_trace_len(),
+        trace.trace_len_summary().padding_percentage(),
+        now.elapsed().as_millis()
+    );
+
+    Ok(trace)
+}
+
 // PROVER
 // ================================================================================================
 
@@ -0,0 +1,1150 @@
+//! This module contains logic for generating STARK proofs with a bounded amount of memory. The
+//! main segment of the execution trace is written to disk before it is committed to, and is read
+//! back one column at a time to be interpolated. The low-degree extensions of the trace segments
+//! and the leaves of the Merkle trees committing to them are written to disk as they are computed,
+//! and are read back from disk in blocks during constraint evaluation and query generation.
+
+use super::{
+    crypto::ElementHasher, event, ExecutionError, ExecutionProver, ExecutionTrace, Felt,
+    FieldElement, Level, ProcessorAir, PublicInputs, StarkProof, WinterProofOptions,
+};
+use processor::{
+    crypto::RandomCoin,
+    math::{fft, StarkField},
+    utils::{ByteReader, Serializable, SliceReader},
+};
+use std::{
+    collections::VecDeque,
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::Instant,
+};
+use winter_prover::{
+    crypto::{BatchMerkleProof, MerkleTree},
+    matrix::ColMatrix,
+    proof::Queries,
+    Air, AuxTraceRandElements, ConstraintCompositionCoefficients, DefaultConstraintEvaluator,
+    EvaluationFrame, Prover, StarkDomain, Trace, TraceInfo, TraceLayout, TraceLde, TracePolyTable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of positions of each coset covered by a block of rows read from disk at once.
+const BLOCK_POSITIONS: usize = 128;
+
+// TRACE STORAGE
+// ================================================================================================
+
+/// Counter used to derive unique names for the files created by the prover.
+static NEXT_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Specifies a directory in which the prover stores the execution trace and its low-degree
+/// extension.
+///
+/// When proving with trace storage, the main segment of the execution trace is written to disk
+/// before the prover commits to it, and it is held only on disk while its low-degree extension is
+/// computed. The extended trace (which is `blowup_factor` times larger than the execution trace
+/// itself) is never fully held in memory. Instead, it is computed one coset of the trace domain at
+/// a time, and each coset is hashed and written to disk before the next one is computed.
+/// Similarly, only the part of the Merkle tree committing to the extended trace which is
+/// proportional to the length of the execution trace is held in memory. Files created by the
+/// prover are removed once the proof is generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStorage {
+    dir: PathBuf,
+}
+
+impl TraceStorage {
+    /// Returns a new [TraceStorage] backed by the specified directory.
+    ///
+    /// The directory is created if it does not exist yet.
+    ///
+    /// # Errors
+    /// Returns an error if the directory could not be created.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Returns the directory in which the execution trace and its low-degree extension are
+    /// stored.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns a path to a new file in this storage.
+    fn new_file_path(&self, name: &str) -> PathBuf {
+        let id = NEXT_FILE_ID.fetch_add(1, Ordering::Relaxed);
+        self.dir.join(format!("{name}-{}-{id}.lde", std::process::id()))
+    }
+}
+
+// DISK-BACKED PROVER
+// ================================================================================================
+
+/// Wraps an [ExecutionProver] and stores the execution trace and its low-degree extension on disk.
+pub(crate) struct DiskExecutionProver<H, R>
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    prover: ExecutionProver<H, R>,
+    storage: TraceStorage,
+    // commitment to the main segment of the trace, built before the proof generation starts
+    main_segment: Mutex<Option<MainSegmentCommitment<H>>>,
+    // first error which occurred while accessing the storage during the proof generation
+    failure: StorageFailure,
+}
+
+impl<H, R> DiskExecutionProver<H, R>
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    pub fn new(prover: ExecutionProver<H, R>, storage: TraceStorage) -> Self {
+        Self {
+            prover,
+            storage,
+            main_segment: Mutex::new(None),
+            failure: StorageFailure::default(),
+        }
+    }
+
+    /// Generates a STARK proof for the provided execution trace.
+    ///
+    /// The main segment of the trace is committed to before the proof generation starts; see
+    /// [commit_to_main_segment()].
+    ///
+    /// # Errors
+    /// Returns an error if proof generation fails, or if the execution trace or its low-degree
+    /// extension could not be written to or read from the storage.
+    pub fn prove_trace(&self, trace: ExecutionTrace) -> Result<StarkProof, ExecutionError> {
+        let air = ProcessorAir::new(
+            trace.get_info(),
+            self.get_pub_inputs(&trace),
+            self.options().clone(),
+        );
+        let domain = StarkDomain::new(&air);
+        let (trace, main_segment) =
+            commit_to_main_segment(trace, &domain, &self.storage).map_err(storage_error)?;
+        *lock(&self.main_segment) = Some(main_segment);
+
+        let result = self.prove(trace);
+
+        // a proof generated after a storage failure is based on placeholder values
+        if let Some(err) = self.failure.take() {
+            return Err(storage_error(err));
+        }
+        result.map_err(ExecutionError::ProverError)
+    }
+}
+
+impl<H, R> Prover for DiskExecutionProver<H, R>
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    type BaseField = Felt;
+    type Air = ProcessorAir;
+    type Trace = ExecutionTrace;
+    type HashFn = H;
+    type RandomCoin = R;
+    type TraceLde<E: FieldElement<BaseField = Felt>> = DiskTraceLde<E, H>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Felt>> =
+        DefaultConstraintEvaluator<'a, ProcessorAir, E>;
+
+    fn options(&self) -> &WinterProofOptions {
+        self.prover.options()
+    }
+
+    fn get_pub_inputs(&self, trace: &ExecutionTrace) -> PublicInputs {
+        self.prover.get_pub_inputs(trace)
+    }
+
+    /// Returns the trace LDE built from the commitment to the main trace segment, which is
+    /// computed by [DiskExecutionProver::prove_trace()] before the proof generation starts; the
+    /// provided main trace segment is not used.
+    ///
+    /// # Panics
+    /// Panics if the main trace segment has not been committed to.
+    fn new_trace_lde<E: FieldElement<BaseField = Felt>>(
+        &self,
+        trace_info: &TraceInfo,
+        _main_trace: &ColMatrix<Felt>,
+        domain: &StarkDomain<Felt>,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        let main_segment = lock(&self.main_segment)
+            .take()
+            .expect("main trace segment has not been committed to");
+        DiskTraceLde::new(
+            trace_info,
+            main_segment,
+            domain,
+            self.storage.clone(),
+            self.failure.clone(),
+        )
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Felt>>(
+        &self,
+        air: &'a ProcessorAir,
+        aux_rand_elements: AuxTraceRandElements<E>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        self.prover.new_evaluator(air, aux_rand_elements, composition_coefficients)
+    }
+}
+
+// MAIN SEGMENT COMMITMENT
+// ================================================================================================
+
+/// Low-degree extension of the main trace segment stored on disk, together with the commitment to
+/// it and the polynomials of the segment.
+struct MainSegmentCommitment<H: ElementHasher<BaseField = Felt>> {
+    lde: SegmentFile<Felt>,
+    tree: SegmentTree<H>,
+    polys: ColMatrix<Felt>,
+}
+
+/// Writes the main segment of the provided trace to the specified storage, and commits to the
+/// segment while it is held only on disk.
+///
+/// The columns of the segment are read back one at a time and interpolated into polynomials,
+/// which are then extended and committed to via [extend_and_commit()]. Once the commitment is
+/// built, the segment is read back into memory, since it is needed to build the auxiliary trace
+/// segment.
+fn commit_to_main_segment<H>(
+    trace: ExecutionTrace,
+    domain: &StarkDomain<Felt>,
+    storage: &TraceStorage,
+) -> io::Result<(ExecutionTrace, MainSegmentCommitment<H>)>
+where
+    H: ElementHasher<BaseField = Felt>,
+{
+    let now = Instant::now();
+    let (main_segment, trace) = trace.detach_main_segment();
+    let columns = ColumnFile::write(storage, "main-trace", main_segment)?;
+    event!(
+        Level::INFO,
+        "Stored {} main trace columns on disk in {} ms",
+        columns.num_cols,
+        now.elapsed().as_millis()
+    );
+
+    let inv_twiddles = fft::get_inv_twiddles::<Felt>(columns.num_rows);
+    let polys = (0..columns.num_cols)
+        .map(|col_idx| {
+            let mut column = columns.read_column(col_idx)?;
+            fft::interpolate_poly(&mut column, &inv_twiddles);
+            Ok(column)
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+    let polys = ColMatrix::new(polys);
+    let (lde, tree) = extend_and_commit(&polys, domain, storage, "main")?;
+
+    let main_segment = (0..columns.num_cols)
+        .map(|col_idx| columns.read_column(col_idx))
+        .collect::<io::Result<Vec<_>>>()?;
+    let trace = trace.attach_main_segment(ColMatrix::new(main_segment));
+
+    Ok((trace, MainSegmentCommitment { lde, tree, polys }))
+}
+
+// TRACE LOW DEGREE EXTENSION (DISK)
+// ================================================================================================
+
+/// Contains the commitments to all segments of the extended execution trace, the LDE blowup
+/// factor, and the [TraceInfo], while the extended segments themselves are stored on disk.
+///
+/// Segments are stored in two groups:
+/// - Main segment: this is the first trace segment generated by the prover. Values in this segment
+///   will always be elements in the base field (even when an extension field is used).
+/// - Auxiliary segments: a list of 0 or more segments for traces generated after the prover
+///   commits to the first trace segment. Currently, at most 1 auxiliary segment is possible.
+///
+/// Methods of the [TraceLde] trait cannot fail, and thus errors which occur while accessing the
+/// storage are recorded in a [StorageFailure]; see its description for details.
+pub struct DiskTraceLde<E: FieldElement<BaseField = Felt>, H: ElementHasher<BaseField = Felt>> {
+    // low-degree extension of the main segment of the trace
+    main_segment_lde: SegmentFile<Felt>,
+    // commitment to the main segment of the trace
+    main_segment_tree: SegmentTree<H>,
+    // low-degree extensions of the auxiliary segments of the trace
+    aux_segment_ldes: Vec<SegmentFile<E>>,
+    // commitment to the auxiliary segments of the trace
+    aux_segment_trees: Vec<SegmentTree<H>>,
+    blowup: usize,
+    trace_info: TraceInfo,
+    storage: TraceStorage,
+    failure: StorageFailure,
+}
+
+impl<E, H> DiskTraceLde<E, H>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    /// Creates a new [DiskTraceLde] from the specified commitment to the main trace segment.
+    ///
+    /// Returns a tuple containing a [TracePolyTable] with the trace polynomials for the main trace
+    /// segment and the new [DiskTraceLde].
+    fn new(
+        trace_info: &TraceInfo,
+        main_segment: MainSegmentCommitment<H>,
+        domain: &StarkDomain<Felt>,
+        storage: TraceStorage,
+        failure: StorageFailure,
+    ) -> (Self, TracePolyTable<E>) {
+        let MainSegmentCommitment { lde, tree, polys } = main_segment;
+
+        let trace_poly_table = TracePolyTable::new(polys);
+        let trace_lde = DiskTraceLde {
+            main_segment_lde: lde,
+            main_segment_tree: tree,
+            aux_segment_ldes: Vec::new(),
+            aux_segment_trees: Vec::new(),
+            blowup: domain.trace_to_lde_blowup(),
+            trace_info: trace_info.clone(),
+            storage,
+            failure,
+        };
+
+        (trace_lde, trace_poly_table)
+    }
+
+    /// Builds queries for the rows of the specified segment at the specified positions, or
+    /// placeholder queries if the storage cannot be accessed.
+    fn query_segment<T>(
+        &self,
+        segment_lde: &SegmentFile<T>,
+        segment_tree: &SegmentTree<H>,
+        positions: &[usize],
+    ) -> Queries
+    where
+        T: FieldElement<BaseField = Felt>,
+    {
+        if !self.failure.is_set() {
+            match build_segment_queries(segment_lde, segment_tree, positions) {
+                Ok(queries) => return queries,
+                Err(err) => self.failure.record(err),
+            }
+        }
+        let depth = segment_lde.num_rows().ilog2() as usize;
+        build_placeholder_queries::<T, H>(positions, segment_lde.num_cols, depth)
+    }
+}
+
+impl<E, H> TraceLde<E> for DiskTraceLde<E, H>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    type HashFn = H;
+
+    /// Returns the commitment to the low-degree extension of the main trace segment.
+    fn get_main_trace_commitment(&self) -> H::Digest {
+        *self.main_segment_tree.root()
+    }
+
+    /// Takes auxiliary trace segment columns as input, interpolates them into polynomials in
+    /// coefficient form, evaluates the polynomials over the LDE domain, and commits to the
+    /// polynomial evaluations.
+    ///
+    /// Returns a tuple containing the column polynomials in coefficient from and the commitment
+    /// to the polynomial evaluations over the LDE domain. If the storage cannot be accessed, the
+    /// returned commitment is a placeholder.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the following are true:
+    /// - the number of rows in the provided `aux_trace` does not match the main trace.
+    /// - this segment would exceed the number of segments specified by the trace layout.
+    fn add_aux_segment(
+        &mut self,
+        aux_trace: &ColMatrix<E>,
+        domain: &StarkDomain<Felt>,
+    ) -> (ColMatrix<E>, H::Digest) {
+        // check errors
+        assert!(
+            self.aux_segment_ldes.len() < self.trace_info.layout().num_aux_segments(),
+            "the specified number of auxiliary segments has already been added"
+        );
+        assert_eq!(
+            self.main_segment_lde.num_rows(),
+            aux_trace.num_rows() * domain.trace_to_lde_blowup(),
+            "the number of rows in the auxiliary segment must be the same as in the main segment"
+        );
+
+        // extend the auxiliary trace segment and build a Merkle tree from the extended trace
+        let aux_segment_polys = aux_trace.interpolate_columns();
+        if self.failure.is_set() {
+            return (aux_segment_polys, H::Digest::default());
+        }
+        let name = format!("aux{}", self.aux_segment_ldes.len());
+        let (aux_segment_lde, aux_segment_tree) =
+            match extend_and_commit(&aux_segment_polys, domain, &self.storage, &name) {
+                Ok(commitment) => commitment,
+                Err(err) => {
+                    self.failure.record(err);
+                    return (aux_segment_polys, H::Digest::default());
+                }
+            };
+
+        // save the lde and commitment
+        self.aux_segment_ldes.push(aux_segment_lde);
+        let root_hash = *aux_segment_tree.root();
+        self.aux_segment_trees.push(aux_segment_tree);
+
+        (aux_segment_polys, root_hash)
+    }
+
+    /// Reads current and next rows from the main trace segment into the specified frame.
+    fn read_main_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<Felt>) {
+        if !self.failure.is_set() {
+            match self.main_segment_lde.read_frame_into(lde_step, frame) {
+                Ok(()) => return,
+                Err(err) => self.failure.record(err),
+            }
+        }
+        clear_frame(frame);
+    }
+
+    /// Reads current and next rows from the auxiliary trace segment into the specified frame.
+    ///
+    /// # Panics
+    /// This currently assumes that there is exactly one auxiliary trace segment, and will panic
+    /// otherwise.
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+        if !self.failure.is_set() {
+            match self.aux_segment_ldes[0].read_frame_into(lde_step, frame) {
+                Ok(()) => return,
+                Err(err) => self.failure.record(err),
+            }
+        }
+        clear_frame(frame);
+    }
+
+    /// Returns trace table rows at the specified positions along with Merkle authentication paths
+    /// from the commitment root to these rows.
+    fn query(&self, positions: &[usize]) -> Vec<Queries> {
+        // build queries for the main trace segment
+        let mut result =
+            vec![self.query_segment(&self.main_segment_lde, &self.main_segment_tree, positions)];
+
+        // build queries for auxiliary trace segments
+        for (segment_lde, segment_tree) in self.aux_segment_ldes.iter().zip(&self.aux_segment_trees)
+        {
+            result.push(self.query_segment(segment_lde, segment_tree, positions));
+        }
+
+        result
+    }
+
+    /// Returns the number of rows in the execution trace.
+    fn trace_len(&self) -> usize {
+        self.main_segment_lde.num_rows()
+    }
+
+    /// Returns blowup factor which was used to extend original execution trace into trace LDE.
+    fn blowup(&self) -> usize {
+        self.blowup
+    }
+
+    /// Returns the trace layout of the execution trace.
+    fn trace_layout(&self) -> &TraceLayout {
+        self.trace_info.layout()
+    }
+}
+
+// STORAGE FAILURE
+// ================================================================================================
+
+/// The first I/O error which occurred while accessing the trace storage during proof generation.
+///
+/// The methods of the [TraceLde] trait cannot fail, and thus [DiskTraceLde] records the error here
+/// instead. Once an error is recorded, the storage is not accessed anymore, and placeholder values
+/// are returned in place of the values which would have been read from it, so that the proof
+/// generation can run to completion. [DiskExecutionProver::prove_trace()] then discards the
+/// generated proof and returns the recorded error.
+#[derive(Clone, Default)]
+struct StorageFailure(Arc<FailureState>);
+
+#[derive(Default)]
+struct FailureState {
+    is_set: AtomicBool,
+    error: Mutex<Option<io::Error>>,
+}
+
+impl StorageFailure {
+    /// Returns true if an error has been recorded.
+    fn is_set(&self) -> bool {
+        self.0.is_set.load(Ordering::Acquire)
+    }
+
+    /// Records the specified error unless an error has already been recorded.
+    fn record(&self, err: io::Error) {
+        let mut error = lock(&self.0.error);
+        if error.is_none() {
+            *error = Some(err);
+            self.0.is_set.store(true, Ordering::Release);
+        }
+    }
+
+    /// Removes the recorded error and returns it, if any.
+    fn take(&self) -> Option<io::Error> {
+        self.0.is_set.store(false, Ordering::Release);
+        lock(&self.0.error).take()
+    }
+}
+
+/// Converts an error which occurred while accessing the trace storage into an [ExecutionError].
+fn storage_error(err: io::Error) -> ExecutionError {
+    ExecutionError::TraceStorageFailed(err.to_string())
+}
+
+// STORAGE FILE
+// ================================================================================================
+
+/// A file created by the prover in the trace storage; the file is removed when dropped.
+struct StorageFile {
+    file: File,
+    path: PathBuf,
+}
+
+impl StorageFile {
+    /// Creates a new empty file in the specified storage.
+    fn create(storage: &TraceStorage, name: &str) -> io::Result<Self> {
+        let path = storage.new_file_path(name);
+        let file = OpenOptions::new().read(true).write(true).create_new(true).open(&path)?;
+        Ok(Self { file, path })
+    }
+
+    /// Reads the exact number of bytes required to fill `buf` from the specified offset.
+    fn read_exact_at(&self, buf: &mut [u8], offset: u64) -> io::Result<()> {
+        read_exact_at(&self.file, buf, offset).map_err(|err| {
+            io::Error::new(err.kind(), format!("failed to read {}: {err}", self.path.display()))
+        })
+    }
+
+    /// Returns a buffered writer which appends data to the end of the file.
+    fn writer(&self) -> BufWriter<&File> {
+        BufWriter::new(&self.file)
+    }
+}
+
+impl Drop for StorageFile {
+    fn drop(&mut self) {
+        // failing to remove the file does not affect the generated proof
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+// COLUMN FILE
+// ================================================================================================
+
+/// Columns of a trace segment stored on disk one after another.
+struct ColumnFile {
+    file: StorageFile,
+    num_cols: usize,
+    num_rows: usize,
+}
+
+impl ColumnFile {
+    /// Writes the columns of the specified segment into a new file in the specified storage.
+    ///
+    /// The memory occupied by each column is released as soon as the column is written.
+    fn write(storage: &TraceStorage, name: &str, segment: ColMatrix<Felt>) -> io::Result<Self> {
+        let num_cols = segment.num_cols();
+        let num_rows = segment.num_rows();
+        let file = StorageFile::create(storage, name)?;
+
+        let mut writer = file.writer();
+        let mut bytes = Vec::with_capacity(num_rows * Felt::ELEMENT_BYTES);
+        for column in segment.into_columns() {
+            bytes.clear();
+            column.iter().for_each(|element| element.write_into(&mut bytes));
+            writer.write_all(&bytes)?;
+        }
+        writer.flush()?;
+        drop(writer);
+
+        Ok(Self {
+            file,
+            num_cols,
+            num_rows,
+        })
+    }
+
+    /// Reads the column at the specified index.
+    fn read_column(&self, col_idx: usize) -> io::Result<Vec<Felt>> {
+        let mut bytes = vec![0u8; self.num_rows * Felt::ELEMENT_BYTES];
+        let offset = (col_idx * self.num_rows * Felt::ELEMENT_BYTES) as u64;
+        self.file.read_exact_at(&mut bytes, offset)?;
+
+        let mut reader = SliceReader::new(&bytes);
+        reader.read_many::<Felt>(self.num_rows).map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("invalid trace column: {err}"))
+        })
+    }
+}
+
+// SEGMENT FILE
+// ================================================================================================
+
+/// Low-degree extension of a single trace segment stored on disk.
+///
+/// The extension is stored in row-major order, one coset of the trace domain after another: row
+/// `i` of the extended segment is located at position `i / blowup` of the coset `i % blowup`.
+///
+/// Evaluation frames are read for consecutive LDE steps, and thus the rows are read from disk in
+/// blocks: a block covers [BLOCK_POSITIONS] consecutive positions of every coset (i.e., the rows
+/// of `BLOCK_POSITIONS * blowup` consecutive steps) plus the position following them, so that the
+/// next rows of all frames in the block are included as well. The most recently read blocks are
+/// cached, one for each thread which may evaluate constraints concurrently.
+struct SegmentFile<E: FieldElement> {
+    file: StorageFile,
+    num_cols: usize,
+    coset_size: usize,
+    blowup: usize,
+    block_positions: usize,
+    blocks: Mutex<VecDeque<Arc<RowBlock<E>>>>,
+    max_cached_blocks: usize,
+}
+
+/// Rows of a trace segment read from disk; see [SegmentFile] for a description of the rows which
+/// a block contains.
+struct RowBlock<E> {
+    idx: usize,
+    rows: Vec<E>,
+}
+
+impl<E: FieldElement> SegmentFile<E> {
+    /// Creates a new empty segment file in the specified storage.
+    fn create(
+        storage: &TraceStorage,
+        name: &str,
+        num_cols: usize,
+        coset_size: usize,
+        blowup: usize,
+    ) -> io::Result<Self> {
+        let max_cached_blocks = std::thread::available_parallelism().map_or(1, |n| n.get());
+        Ok(Self {
+            file: StorageFile::create(storage, name)?,
+            num_cols,
+            coset_size,
+            blowup,
+            block_positions: BLOCK_POSITIONS.min(coset_size),
+            blocks: Mutex::new(VecDeque::with_capacity(max_cached_blocks)),
+            max_cached_blocks,
+        })
+    }
+
+    /// Returns the number of rows in the extended segment.
+    fn num_rows(&self) -> usize {
+        self.coset_size * self.blowup
+    }
+
+    /// Returns the number of bytes in a single row of the extended segment.
+    fn row_size(&self) -> usize {
+        self.num_cols * E::ELEMENT_BYTES
+    }
+
+    /// Returns the offset in the file at which the specified position of the specified coset
+    /// starts.
+    fn position_offset(&self, coset_idx: usize, position: usize) -> u64 {
+        ((coset_idx * self.coset_size + position) * self.row_size()) as u64
+    }
+
+    /// Reads `num_rows` consecutive rows of a coset starting at the specified position and appends
+    /// them to `rows`.
+    fn read_rows_into(
+        &self,
+        coset_idx: usize,
+        position: usize,
+        num_rows: usize,
+        rows: &mut Vec<E>,
+    ) -> io::Result<()> {
+        let mut bytes = vec![0u8; num_rows * self.row_size()];
+        self.file.read_exact_at(&mut bytes, self.position_offset(coset_idx, position))?;
+
+        let mut reader = SliceReader::new(&bytes);
+        for _ in 0..num_rows * self.num_cols {
+            let element = E::read_from(&mut reader).map_err(|err| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("invalid trace LDE: {err}"))
+            })?;
+            rows.push(element);
+        }
+        Ok(())
+    }
+
+    /// Returns the row of the extended segment at the specified index.
+    fn row(&self, row_idx: usize) -> io::Result<Vec<E>> {
+        let mut row = Vec::with_capacity(self.num_cols);
+        self.read_rows_into(row_idx % self.blowup, row_idx / self.blowup, 1, &mut row)?;
+        Ok(row)
+    }
+
+    /// Reads the row at the specified index and the row `blowup` steps after it into the frame.
+    fn read_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) -> io::Result<()> {
+        let block_idx = lde_step / (self.block_positions * self.blowup);
+        let block = self.get_block(block_idx)?;
+
+        // at the end of the trace, next state wraps around to the first step of the same coset;
+        // blocks already account for this
+        let coset_idx = lde_step % self.blowup;
+        let position = lde_step / self.blowup - block_idx * self.block_positions;
+        let row_idx = coset_idx * (self.block_positions + 1) + position;
+        let current = &block.rows[row_idx * self.num_cols..(row_idx + 1) * self.num_cols];
+        let next = &block.rows[(row_idx + 1) * self.num_cols..(row_idx + 2) * self.num_cols];
+        frame.current_mut().copy_from_slice(current);
+        frame.next_mut().copy_from_slice(next);
+        Ok(())
+    }
+
+    /// Returns the block with the specified index, reading it from disk if it is not cached.
+    fn get_block(&self, block_idx: usize) -> io::Result<Arc<RowBlock<E>>> {
+        if let Some(block) = self.lock_blocks().iter().find(|block| block.idx == block_idx) {
+            return Ok(block.clone());
+        }
+
+        // read the block without holding the lock, so that other threads can use cached blocks
+        let start = block_idx * self.block_positions;
+        let end = start + self.block_positions;
+        let mut rows = Vec::with_capacity(self.blowup * (self.block_positions + 1) * self.num_cols);
+        for coset_idx in 0..self.blowup {
+            if end < self.coset_size {
+                self.read_rows_into(coset_idx, start, self.block_positions + 1, &mut rows)?;
+            } else {
+                self.read_rows_into(coset_idx, start, self.block_positions, &mut rows)?;
+                self.read_rows_into(coset_idx, 0, 1, &mut rows)?;
+            }
+        }
+        let block = Arc::new(RowBlock {
+            idx: block_idx,
+            rows,
+        });
+
+        let mut blocks = self.lock_blocks();
+        if blocks.len() == self.max_cached_blocks {
+            blocks.pop_front();
+        }
+        blocks.push_back(block.clone());
+        Ok(block)
+    }
+
+    fn lock_blocks(&self) -> MutexGuard<'_, VecDeque<Arc<RowBlock<E>>>> {
+        lock(&self.blocks)
+    }
+}
+
+// SEGMENT TREE
+// ================================================================================================
+
+/// Merkle tree committing to the rows of an extended trace segment, where only the upper part of
+/// the tree is held in memory.
+///
+/// Leaf `i` of the tree is the hash of row `i` of the extended segment. Since the rows at
+/// position `p` of all cosets are the consecutive rows `p * blowup..(p + 1) * blowup`, their
+/// hashes form a subtree of the Merkle tree whose root is the node at index `p` of the level
+/// `log2(blowup)` above the leaves. The tree above this level is held in memory; its size is
+/// proportional to the length of the trace rather than to the size of the LDE domain. The leaves
+/// are stored on disk in the same order as the rows of the segment (i.e., one coset after
+/// another), and the levels below are recomputed from the leaves whenever a Merkle path is
+/// requested.
+struct SegmentTree<H: ElementHasher> {
+    leaves: LeafFile<H>,
+    upper_tree: MerkleTree<H>,
+    blowup: usize,
+}
+
+impl<H: ElementHasher> SegmentTree<H> {
+    /// Builds the tree from the leaves stored in the specified file.
+    fn build(leaves: LeafFile<H>, blowup: usize) -> io::Result<Self> {
+        // compute the roots of the subtrees, reading the leaves one block of positions at a time
+        let coset_size = leaves.coset_size;
+        let block_positions = BLOCK_POSITIONS.min(coset_size);
+        let mut subtree_roots = Vec::with_capacity(coset_size);
+        for start in (0..coset_size).step_by(block_positions) {
+            let cosets = (0..blowup)
+                .map(|coset_idx| leaves.read(coset_idx, start, block_positions))
+                .collect::<io::Result<Vec<_>>>()?;
+            for position in 0..block_positions {
+                let subtree_leaves = cosets.iter().map(|coset| coset[position]).collect();
+                subtree_roots.push(merge_subtree::<H>(subtree_leaves, 0).0);
+            }
+        }
+
+        let upper_tree =
+            MerkleTree::new(subtree_roots).expect("failed to construct trace Merkle tree");
+        Ok(Self {
+            leaves,
+            upper_tree,
+            blowup,
+        })
+    }
+
+    /// Returns the root of the tree.
+    fn root(&self) -> &H::Digest {
+        self.upper_tree.root()
+    }
+
+    /// Returns the Merkle path for the leaf at the specified index, starting with the leaf itself.
+    fn prove(&self, index: usize) -> io::Result<Vec<H::Digest>> {
+        let position = index / self.blowup;
+        let coset_idx = index % self.blowup;
+        let subtree_leaves = (0..self.blowup)
+            .map(|coset_idx| Ok(self.leaves.read(coset_idx, position, 1)?[0]))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        // the path consists of the leaf, its siblings within the subtree, and the path of the
+        // subtree root within the upper tree (without the subtree root itself)
+        let mut path = vec![subtree_leaves[coset_idx]];
+        path.extend(merge_subtree::<H>(subtree_leaves, coset_idx).1);
+        let upper_path = self.upper_tree.prove(position).expect("failed to generate a Merkle path");
+        path.extend_from_slice(&upper_path[1..]);
+        Ok(path)
+    }
+}
+
+/// Hashes of the rows of an extended trace segment stored on disk in the same order as the rows.
+struct LeafFile<H: ElementHasher> {
+    file: StorageFile,
+    coset_size: usize,
+    digest_size: usize,
+    _hasher: PhantomData<fn() -> H>,
+}
+
+impl<H: ElementHasher> LeafFile<H> {
+    /// Creates a new empty leaf file in the specified storage.
+    fn create(storage: &TraceStorage, name: &str, coset_size: usize) -> io::Result<Self> {
+        Ok(Self {
+            file: StorageFile::create(storage, name)?,
+            coset_size,
+            digest_size: H::Digest::default().to_bytes().len(),
+            _hasher: PhantomData,
+        })
+    }
+
+    /// Reads `num_leaves` consecutive leaves of a coset starting at the specified position.
+    fn read(
+        &self,
+        coset_idx: usize,
+        position: usize,
+        num_leaves: usize,
+    ) -> io::Result<Vec<H::Digest>> {
+        let mut bytes = vec![0u8; num_leaves * self.digest_size];
+        let offset = ((coset_idx * self.coset_size + position) * self.digest_size) as u64;
+        self.file.read_exact_at(&mut bytes, offset)?;
+
+        let mut reader = SliceReader::new(&bytes);
+        reader.read_many::<H::Digest>(num_leaves).map_err(|err| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("invalid trace commitment: {err}"))
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Computes a low-degree extension (LDE) of a trace segment from the provided polynomials of its
+/// columns, writes it into a new file in the specified storage, and builds a commitment to the
+/// extended segment.
+///
+/// The extension is performed by evaluating the polynomials over each of the `blowup` cosets of
+/// the trace domain which together form the LDE domain. Only a single coset of the extended
+/// segment is held in memory at any time.
+///
+/// The commitment is computed by hashing each row of the extended segment, then building a Merkle
+/// tree from the resulting hashes. The hashes are written to disk together with the rows, and only
+/// the upper part of the tree is held in memory; see [SegmentTree].
+fn extend_and_commit<E, H>(
+    trace_polys: &ColMatrix<E>,
+    domain: &StarkDomain<Felt>,
+    storage: &TraceStorage,
+    name: &str,
+) -> io::Result<(SegmentFile<E>, SegmentTree<H>)>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    let now = Instant::now();
+    let coset_size = trace_polys.num_rows();
+    let blowup = domain.trace_to_lde_blowup();
+    let num_cols = trace_polys.num_cols();
+    let lde_generator = Felt::get_root_of_unity(domain.lde_domain_size().ilog2());
+
+    let segment = SegmentFile::create(storage, name, num_cols, coset_size, blowup)?;
+    let leaves = LeafFile::<H>::create(storage, &format!("{name}-leaves"), coset_size)?;
+    let mut segment_writer = segment.file.writer();
+    let mut leaves_writer = leaves.file.writer();
+    let mut row = Vec::with_capacity(num_cols);
+    let mut bytes = Vec::with_capacity(segment.row_size());
+
+    let mut coset_offset = domain.offset();
+    for _ in 0..blowup {
+        // evaluate all trace polynomials over the current coset of the trace domain
+        let columns: Vec<Vec<E>> = trace_polys
+            .columns()
+            .map(|poly| {
+                fft::evaluate_poly_with_offset(poly, domain.trace_twiddles(), coset_offset, 1)
+            })
+            .collect();
+
+        // append the rows of the coset and their hashes to the files
+        for position in 0..coset_size {
+            row.clear();
+            row.extend(columns.iter().map(|column| column[position]));
+            leaves_writer.write_all(&H::hash_elements(&row).to_bytes())?;
+
+            bytes.clear();
+            row.iter().for_each(|element| element.write_into(&mut bytes));
+            segment_writer.write_all(&bytes)?;
+        }
+
+        coset_offset *= lde_generator;
+    }
+    segment_writer.flush()?;
+    leaves_writer.flush()?;
+    drop(segment_writer);
+    drop(leaves_writer);
+
+    let trace_tree = SegmentTree::build(leaves, blowup)?;
+    event!(
+        Level::INFO,
+        "Extended {} trace columns over {} cosets and stored them on disk in {} ms",
+        num_cols,
+        blowup,
+        now.elapsed().as_millis()
+    );
+
+    Ok((segment, trace_tree))
+}
+
+/// Builds queries for the rows of the trace segment at the specified positions.
+///
+/// The positions are expected to be sorted in ascending order and contain no duplicates, which is
+/// how the prover draws them.
+fn build_segment_queries<E, H>(
+    segment_lde: &SegmentFile<E>,
+    segment_tree: &SegmentTree<H>,
+    positions: &[usize],
+) -> io::Result<Queries>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    // for each position, get the corresponding row from the trace segment LDE and put all these
+    // rows into a single vector
+    let trace_states = positions
+        .iter()
+        .map(|&pos| segment_lde.row(pos))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    // build Merkle authentication paths to the leaves specified by positions, and compress them
+    // into a single batch proof
+    let paths = positions
+        .iter()
+        .map(|&pos| segment_tree.prove(pos))
+        .collect::<io::Result<Vec<_>>>()?;
+    let trace_proof = BatchMerkleProof::<H>::from_paths(&paths, positions);
+
+    Ok(Queries::new(trace_proof, trace_states))
+}
+
+/// Returns queries with zero values and default Merkle paths of the specified depth, which are
+/// used in place of the actual queries after a storage failure.
+fn build_placeholder_queries<E, H>(positions: &[usize], num_cols: usize, depth: usize) -> Queries
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    let paths = vec![vec![H::Digest::default(); depth + 1]; positions.len()];
+    let trace_proof = BatchMerkleProof::<H>::from_paths(&paths, positions);
+    Queries::new(trace_proof, vec![vec![E::ZERO; num_cols]; positions.len()])
+}
+
+/// Sets all values of the specified frame to zero; this is used in place of reading the frame
+/// after a storage failure.
+fn clear_frame<E: FieldElement>(frame: &mut EvaluationFrame<E>) {
+    frame.current_mut().fill(E::ZERO);
+    frame.next_mut().fill(E::ZERO);
+}
+
+/// Locks the specified mutex.
+///
+/// The values protected by the mutexes of this module are never left inconsistent, so they are
+/// safe to use even if a thread panicked while holding the lock.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Computes the root of the Merkle tree with the specified leaves, and returns it together with
+/// the siblings of the leaf at the specified index on its path to the root.
+fn merge_subtree<H: ElementHasher>(
+    mut nodes: Vec<H::Digest>,
+    mut index: usize,
+) -> (H::Digest, Vec<H::Digest>) {
+    let mut siblings = Vec::new();
+    while nodes.len() > 1 {
+        siblings.push(nodes[index ^ 1]);
+        nodes = nodes.chunks(2).map(|pair| H::merge(&[pair[0], pair[1]])).collect();
+        index >>= 1;
+    }
+    (nodes[0], siblings)
+}
+
+/// Reads the exact number of bytes required to fill `buf` from the specified offset in the file.
+#[cfg(unix)]
+fn read_exact_at(file: &File, buf: &mut [u8], offset: u64) -> io::Result<()> {
+    std::os::unix::fs::FileExt::read_exact_at(file, buf, offset)
+}
+
+/// Reads the exact number of bytes required to fill `buf` from the specified offset in the file.
+#[cfg(windows)]
+fn read_exact_at(file: &File, mut buf: &mut [u8], mut offset: u64) -> io::Result<()> {
+    use std::os::windows::fs::FileExt;
+    while !buf.is_empty() {
+        match file.seek_read(buf, offset)? {
+            0 => return Err(io::ErrorKind::UnexpectedEof.into()),
+            n => {
+                buf = &mut buf[n..];
+                offset += n as u64;
+            }
+        }
+    }
+    Ok(())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use processor::crypto::Rpo256;
+    use winter_prover::{math::fields::QuadExtension, DefaultTraceLde};
+
+    type QuadFelt = QuadExtension<Felt>;
+
+    #[test]
+    fn disk_trace_lde_matches_default_trace_lde() {
+        let storage = TraceStorage::new(std::env::temp_dir().join("miden-trace-lde-test")).unwrap();
+        let (trace_info, main_trace, aux_trace, domain) = gen_test_inputs();
+
+        let (mut expected_lde, expected_polys) =
+            DefaultTraceLde::<QuadFelt, Rpo256>::new(&trace_info, &main_trace, &domain);
+        let (mut trace_lde, polys) =
+            build_disk_trace_lde(&trace_info, &main_trace, &domain, storage);
+
+        assert_eq!(expected_lde.get_main_trace_commitment(), trace_lde.get_main_trace_commitment());
+        assert_eq!(
+            expected_polys.main_trace_polys().collect::<Vec<_>>(),
+            polys.main_trace_polys().collect::<Vec<_>>()
+        );
+        let (expected_aux_polys, expected_aux_commitment) =
+            expected_lde.add_aux_segment(&aux_trace, &domain);
+        let (aux_polys, aux_commitment) = trace_lde.add_aux_segment(&aux_trace, &domain);
+        assert_eq!(expected_aux_commitment, aux_commitment);
+        assert_eq!(expected_aux_polys.into_columns(), aux_polys.into_columns());
+
+        // frames must match for all steps, including the ones which wrap around
+        let mut expected_main = EvaluationFrame::new(5);
+        let mut main = EvaluationFrame::new(5);
+        let mut expected_aux = EvaluationFrame::new(3);
+        let mut aux = EvaluationFrame::new(3);
+        for lde_step in 0..domain.lde_domain_size() {
+            expected_lde.read_main_trace_frame_into(lde_step, &mut expected_main);
+            trace_lde.read_main_trace_frame_into(lde_step, &mut main);
+            assert_eq!(expected_main.current(), main.current());
+            assert_eq!(expected_main.next(), main.next());
+
+            expected_lde.read_aux_trace_frame_into(lde_step, &mut expected_aux);
+            trace_lde.read_aux_trace_frame_into(lde_step, &mut aux);
+            assert_eq!(expected_aux.current(), aux.current());
+            assert_eq!(expected_aux.next(), aux.next());
+        }
+
+        let positions = [0, 7, 100, 1023, 2047];
+        assert_eq!(expected_lde.query(&positions), trace_lde.query(&positions));
+        assert!(!trace_lde.failure.is_set());
+    }
+
+    #[test]
+    fn disk_trace_lde_records_storage_failures() {
+        let storage =
+            TraceStorage::new(std::env::temp_dir().join("miden-trace-lde-failure-test")).unwrap();
+        let (trace_info, main_trace, aux_trace, domain) = gen_test_inputs();
+        let (mut trace_lde, _) = build_disk_trace_lde(&trace_info, &main_trace, &domain, storage);
+        trace_lde.add_aux_segment(&aux_trace, &domain);
+
+        // the extended main segment is truncated after it has been written
+        trace_lde.main_segment_lde.file.file.set_len(0).unwrap();
+
+        let mut frame = EvaluationFrame::new(5);
+        frame.current_mut().fill(Felt::ONE);
+        trace_lde.read_main_trace_frame_into(0, &mut frame);
+        assert!(trace_lde.failure.is_set());
+        assert!(frame.current().iter().all(|&value| value == Felt::ZERO));
+
+        // once a failure is recorded, placeholder values are returned for all segments
+        let mut aux_frame = EvaluationFrame::<QuadFelt>::new(3);
+        aux_frame.current_mut().fill(QuadFelt::ONE);
+        trace_lde.read_aux_trace_frame_into(0, &mut aux_frame);
+        assert!(aux_frame.current().iter().all(|&value| value == QuadFelt::ZERO));
+        assert_eq!(trace_lde.query(&[0, 7]).len(), 2);
+
+        let err = trace_lde.failure.take().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn column_file_round_trip() {
+        let storage =
+            TraceStorage::new(std::env::temp_dir().join("miden-column-file-test")).unwrap();
+        let segment = gen_trace::<Felt>(1 << 4, 3);
+
+        let file = ColumnFile::write(&storage, "columns", segment.clone()).unwrap();
+        let columns = (0..3).map(|col_idx| file.read_column(col_idx).unwrap()).collect::<Vec<_>>();
+        assert_eq!(segment.into_columns(), columns);
+    }
+
+    fn gen_test_inputs() -> (TraceInfo, ColMatrix<Felt>, ColMatrix<QuadFelt>, StarkDomain<Felt>) {
+        let num_rows = 1 << 8;
+        let blowup = 8;
+        let layout = TraceLayout::new(5, [3], [1]);
+        let trace_info = TraceInfo::new_multi_segment(layout, num_rows, vec![]);
+        let main_trace = gen_trace::<Felt>(num_rows, 5);
+        let aux_trace = gen_trace::<QuadFelt>(num_rows, 3);
+        let domain =
+            StarkDomain::from_twiddles(fft::get_twiddles(num_rows), blowup, Felt::GENERATOR);
+        (trace_info, main_trace, aux_trace, domain)
+    }
+
+    fn gen_trace<E: FieldElement>(num_rows: usize, num_cols: usize) -> ColMatrix<E> {
+        ColMatrix::new(
+            (0..num_cols as u32)
+                .map(|col| (0..num_rows as u32).map(|row| E::from(col * row + row * row)).collect())
+                .collect(),
+        )
+    }
+
+    fn build_disk_trace_lde(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Felt>,
+        domain: &StarkDomain<Felt>,
+        storage: TraceStorage,
+    ) -> (DiskTraceLde<QuadFelt, Rpo256>, TracePolyTable<QuadFelt>) {
+        let polys = main_trace.interpolate_columns();
+        let (lde, tree) = extend_and_commit(&polys, domain, &storage, "main").unwrap();
+        let main_segment = MainSegmentCommitment { lde, tree, polys };
+        DiskTraceLde::new(trace_info, main_segment, domain, storage, StorageFailure::default())
+    }
+}
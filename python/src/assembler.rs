@@ -0,0 +1,63 @@
+use super::{Error, ProgramInfo};
+use pyo3::prelude::*;
+use stdlib::StdLibrary;
+
+// ASSEMBLER
+// ================================================================================================
+
+/// Compiles Miden assembly source code into programs.
+#[pyclass(module = "miden", unsendable)]
+#[derive(Default)]
+pub struct Assembler(assembly::Assembler);
+
+#[pymethods]
+impl Assembler {
+    /// Returns a new assembler; if `with_stdlib` is set, programs compiled by the assembler can
+    /// use procedures from the Miden standard library.
+    #[new]
+    #[pyo3(signature = (with_stdlib = false))]
+    pub fn new(with_stdlib: bool) -> Result<Self, Error> {
+        let assembler = assembly::Assembler::default();
+        if with_stdlib {
+            Ok(Self(assembler.with_library(&StdLibrary::default())?))
+        } else {
+            Ok(Self(assembler))
+        }
+    }
+
+    /// Compiles the provided source code into a program.
+    pub fn compile(&self, source: &str) -> Result<Program, Error> {
+        Ok(Program(self.0.compile(source)?))
+    }
+}
+
+// PROGRAM
+// ================================================================================================
+
+/// A compiled Miden program.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone)]
+pub struct Program(pub(crate) vm_core::Program);
+
+#[pymethods]
+impl Program {
+    /// Hash of the program as a 0x-prefixed hex string.
+    #[getter]
+    pub fn hash(&self) -> String {
+        String::from(&self.0.hash())
+    }
+
+    /// Returns the program info (the program hash together with its kernel) against which proofs
+    /// of the execution of this program are verified.
+    pub fn program_info(&self) -> ProgramInfo {
+        ProgramInfo(self.0.clone().into())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Program(hash={})", self.hash())
+    }
+}
@@ -0,0 +1,89 @@
+use super::{felts_to_ints, Error};
+use pyo3::prelude::*;
+
+// VM STATE
+// ================================================================================================
+
+/// State of the VM at a specific clock cycle.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone)]
+pub struct VmState {
+    /// Clock cycle of this state.
+    #[pyo3(get)]
+    pub clk: u32,
+    /// ID of the execution context.
+    #[pyo3(get)]
+    pub ctx: u32,
+    /// Operation executed at this clock cycle, if any.
+    #[pyo3(get)]
+    pub op: Option<String>,
+    /// Assembly instruction to which the operation belongs, if the program was compiled with
+    /// debug info.
+    #[pyo3(get)]
+    pub asmop: Option<String>,
+    /// Value of the free memory pointer.
+    #[pyo3(get)]
+    pub fmp: u64,
+    /// Values of the stack with the top of the stack first.
+    #[pyo3(get)]
+    pub stack: Vec<u64>,
+    /// Non-empty memory words of the current context as `(address, word)` pairs.
+    #[pyo3(get)]
+    pub memory: Vec<(u64, [u64; 4])>,
+    display: String,
+}
+
+#[pymethods]
+impl VmState {
+    fn __str__(&self) -> String {
+        self.display.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("VmState({})", self.display)
+    }
+}
+
+impl From<processor::VmState> for VmState {
+    fn from(state: processor::VmState) -> Self {
+        Self {
+            clk: state.clk,
+            ctx: state.ctx.into(),
+            op: state.op.map(|op| op.to_string()),
+            asmop: state.asmop.as_ref().map(|asmop| asmop.to_string()),
+            fmp: state.fmp.as_int(),
+            stack: felts_to_ints(&state.stack),
+            memory: state
+                .memory
+                .iter()
+                .map(|(addr, word)| (*addr, word.map(|value| value.as_int())))
+                .collect(),
+            display: state.to_string(),
+        }
+    }
+}
+
+// VM STATE ITERATOR
+// ================================================================================================
+
+/// Iterates over the states of the VM at each clock cycle of a program execution.
+///
+/// If the execution failed, `ExecutionError` is raised when the iterator reaches the clock cycle
+/// at which the execution stopped.
+#[pyclass(module = "miden", unsendable)]
+pub struct VmStateIterator(pub(crate) processor::VmStateIterator);
+
+#[pymethods]
+impl VmStateIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Result<Option<VmState>, Error> {
+        match self.0.next() {
+            Some(Ok(state)) => Ok(Some(state.into())),
+            Some(Err(err)) => Err(err.into()),
+            None => Ok(None),
+        }
+    }
+}
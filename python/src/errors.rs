@@ -0,0 +1,103 @@
+use core::fmt;
+use pyo3::{create_exception, exceptions::PyException, PyErr};
+
+// EXCEPTIONS
+// ================================================================================================
+
+create_exception!(miden, MidenError, PyException, "Base class of all errors raised by Miden VM.");
+create_exception!(miden, AssemblyError, MidenError, "The source code could not be compiled.");
+create_exception!(miden, ExecutionError, MidenError, "The program failed to execute.");
+create_exception!(
+    miden,
+    VerificationError,
+    MidenError,
+    "The proof is not valid for the specified program and public inputs."
+);
+create_exception!(miden, InputError, MidenError, "Inputs of a program are not valid.");
+create_exception!(miden, OutputError, MidenError, "Outputs of a program are not valid.");
+create_exception!(
+    miden,
+    DeserializationError,
+    MidenError,
+    "A proof, program info, or stack outputs could not be deserialized."
+);
+
+// ERROR
+// ================================================================================================
+
+/// An error returned by the bindings.
+///
+/// When crossing into Python, the error is converted into an exception of the matching subclass
+/// of `MidenError` (e.g., `AssemblyError` or `VerificationError`).
+#[derive(Debug)]
+pub enum Error {
+    Assembly(assembly::AssemblyError),
+    Execution(processor::ExecutionError),
+    Verification(verifier::VerificationError),
+    Input(processor::InputError),
+    Output(vm_core::errors::OutputError),
+    Deserialization(processor::DeserializationError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Assembly(err) => write!(f, "{err}"),
+            Self::Execution(err) => write!(f, "{err}"),
+            Self::Verification(err) => write!(f, "{err}"),
+            Self::Input(err) => write!(f, "{err}"),
+            Self::Output(err) => write!(f, "{err}"),
+            Self::Deserialization(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<assembly::AssemblyError> for Error {
+    fn from(err: assembly::AssemblyError) -> Self {
+        Self::Assembly(err)
+    }
+}
+
+impl From<processor::ExecutionError> for Error {
+    fn from(err: processor::ExecutionError) -> Self {
+        Self::Execution(err)
+    }
+}
+
+impl From<verifier::VerificationError> for Error {
+    fn from(err: verifier::VerificationError) -> Self {
+        Self::Verification(err)
+    }
+}
+
+impl From<processor::InputError> for Error {
+    fn from(err: processor::InputError) -> Self {
+        Self::Input(err)
+    }
+}
+
+impl From<vm_core::errors::OutputError> for Error {
+    fn from(err: vm_core::errors::OutputError) -> Self {
+        Self::Output(err)
+    }
+}
+
+impl From<processor::DeserializationError> for Error {
+    fn from(err: processor::DeserializationError) -> Self {
+        Self::Deserialization(err)
+    }
+}
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        let message = err.to_string();
+        match err {
+            Error::Assembly(_) => AssemblyError::new_err(message),
+            Error::Execution(_) => ExecutionError::new_err(message),
+            Error::Verification(_) => VerificationError::new_err(message),
+            Error::Input(_) => InputError::new_err(message),
+            Error::Output(_) => OutputError::new_err(message),
+            Error::Deserialization(_) => DeserializationError::new_err(message),
+        }
+    }
+}
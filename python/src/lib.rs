@@ -0,0 +1,166 @@
+//! Python bindings for Miden VM.
+//!
+//! Field elements cross into Python as ints; ints passed from Python which are not valid field
+//! elements are rejected with `InputError` (or `OutputError` for stack outputs).
+
+use processor::{
+    AdviceInputs, DefaultHost, ExecutionOptions, Felt, MemAdviceProvider, StackInputs,
+};
+use prover::ProvingOptions;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+mod assembler;
+pub use assembler::{Assembler, Program};
+
+mod debug;
+pub use debug::{VmState, VmStateIterator};
+
+mod errors;
+pub use errors::Error;
+
+mod types;
+pub use types::{ExecutionProof, ProgramInfo, StackOutputs};
+
+// MODULE
+// ================================================================================================
+
+#[pymodule]
+fn miden(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Assembler>()?;
+    m.add_class::<Program>()?;
+    m.add_class::<ProgramInfo>()?;
+    m.add_class::<StackOutputs>()?;
+    m.add_class::<ExecutionProof>()?;
+    m.add_class::<VmState>()?;
+    m.add_class::<VmStateIterator>()?;
+
+    m.add_function(wrap_pyfunction!(execute, m)?)?;
+    m.add_function(wrap_pyfunction!(execute_iter, m)?)?;
+    m.add_function(wrap_pyfunction!(prove, m)?)?;
+    m.add_function(wrap_pyfunction!(verify, m)?)?;
+
+    let py = m.py();
+    m.add("MidenError", py.get_type_bound::<errors::MidenError>())?;
+    m.add("AssemblyError", py.get_type_bound::<errors::AssemblyError>())?;
+    m.add("ExecutionError", py.get_type_bound::<errors::ExecutionError>())?;
+    m.add("VerificationError", py.get_type_bound::<errors::VerificationError>())?;
+    m.add("InputError", py.get_type_bound::<errors::InputError>())?;
+    m.add("OutputError", py.get_type_bound::<errors::OutputError>())?;
+    m.add("DeserializationError", py.get_type_bound::<errors::DeserializationError>())?;
+    Ok(())
+}
+
+// EXECUTOR
+// ================================================================================================
+
+/// Executes the program against the provided stack and advice stack inputs, and returns the
+/// resulting stack outputs.
+///
+/// Stack inputs are expected to be ordered as if they would be pushed onto the stack one by one,
+/// and the first value of the advice stack ends up on top of the advice stack.
+#[pyfunction]
+#[pyo3(signature = (program, stack_inputs = Vec::new(), advice_stack = Vec::new()))]
+fn execute(
+    py: Python<'_>,
+    program: &Program,
+    stack_inputs: Vec<u64>,
+    advice_stack: Vec<u64>,
+) -> Result<StackOutputs, Error> {
+    let (stack_inputs, host) = build_inputs(stack_inputs, advice_stack)?;
+    let trace = py.allow_threads(|| {
+        processor::execute(&program.0, stack_inputs, host, ExecutionOptions::default())
+    })?;
+    Ok(StackOutputs(trace.stack_outputs().clone()))
+}
+
+/// Executes the program against the provided inputs, and returns an iterator over the states of
+/// the VM at each clock cycle of the execution.
+#[pyfunction]
+#[pyo3(signature = (program, stack_inputs = Vec::new(), advice_stack = Vec::new()))]
+fn execute_iter(
+    program: &Program,
+    stack_inputs: Vec<u64>,
+    advice_stack: Vec<u64>,
+) -> Result<VmStateIterator, Error> {
+    let (stack_inputs, host) = build_inputs(stack_inputs, advice_stack)?;
+    Ok(VmStateIterator(processor::execute_iter(&program.0, stack_inputs, host)))
+}
+
+// PROVER
+// ================================================================================================
+
+/// Executes the program against the provided inputs and returns the resulting stack outputs
+/// together with a STARK proof of the execution.
+///
+/// `security_level` must be either 96 or 128. If `recursive` is set, the proof is generated using
+/// parameters which make it efficient to verify it inside Miden VM.
+#[pyfunction]
+#[pyo3(signature = (
+    program,
+    stack_inputs = Vec::new(),
+    advice_stack = Vec::new(),
+    security_level = 96,
+    recursive = false,
+))]
+fn prove(
+    py: Python<'_>,
+    program: &Program,
+    stack_inputs: Vec<u64>,
+    advice_stack: Vec<u64>,
+    security_level: u32,
+    recursive: bool,
+) -> PyResult<(StackOutputs, ExecutionProof)> {
+    let options = match security_level {
+        96 => ProvingOptions::with_96_bit_security(recursive),
+        128 => ProvingOptions::with_128_bit_security(recursive),
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "security level must be either 96 or 128, but was {security_level}"
+            )))
+        }
+    };
+    let (stack_inputs, host) = build_inputs(stack_inputs, advice_stack)?;
+    let (outputs, proof) = py
+        .allow_threads(|| prover::prove(&program.0, stack_inputs, host, options))
+        .map_err(Error::from)?;
+    Ok((StackOutputs(outputs), ExecutionProof(proof)))
+}
+
+// VERIFIER
+// ================================================================================================
+
+/// Returns the security level of the proof if the program described by `program_info` was
+/// executed correctly against the specified stack inputs and outputs.
+///
+/// See `miden_verifier::verify()` for the ordering of stack inputs and outputs.
+#[pyfunction]
+fn verify(
+    py: Python<'_>,
+    program_info: &ProgramInfo,
+    stack_inputs: Vec<u64>,
+    stack_outputs: &StackOutputs,
+    proof: &ExecutionProof,
+) -> Result<u32, Error> {
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs)?;
+    let (program_info, stack_outputs, proof) =
+        (program_info.0.clone(), stack_outputs.0.clone(), proof.0.clone());
+    Ok(py.allow_threads(|| verifier::verify(program_info, stack_inputs, stack_outputs, proof))?)
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Converts the provided ints into stack inputs and a host providing the advice stack.
+fn build_inputs(
+    stack_inputs: Vec<u64>,
+    advice_stack: Vec<u64>,
+) -> Result<(StackInputs, DefaultHost<MemAdviceProvider>), Error> {
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs)?;
+    let advice_inputs = AdviceInputs::default().with_stack_values(advice_stack)?;
+    Ok((stack_inputs, DefaultHost::new(MemAdviceProvider::from(advice_inputs))))
+}
+
+/// Converts the provided field elements into ints.
+pub(crate) fn felts_to_ints(felts: &[Felt]) -> Vec<u64> {
+    felts.iter().map(Felt::as_int).collect()
+}
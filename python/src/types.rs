@@ -0,0 +1,123 @@
+use super::{felts_to_ints, Error};
+use pyo3::{prelude::*, types::PyBytes};
+use vm_core::utils::{Deserializable, Serializable};
+
+// PROGRAM INFO
+// ================================================================================================
+
+/// Hash of a program together with the kernel used to compile it.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone)]
+pub struct ProgramInfo(pub(crate) vm_core::ProgramInfo);
+
+#[pymethods]
+impl ProgramInfo {
+    /// Deserializes program info from the provided bytes.
+    #[staticmethod]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self(vm_core::ProgramInfo::read_from_bytes(bytes)?))
+    }
+
+    /// Serializes this program info into bytes.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.to_bytes())
+    }
+
+    /// Hash of the program as a 0x-prefixed hex string.
+    #[getter]
+    pub fn program_hash(&self) -> String {
+        String::from(self.0.program_hash())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ProgramInfo(program_hash={})", self.program_hash())
+    }
+}
+
+// STACK OUTPUTS
+// ================================================================================================
+
+/// State of the operand stack at the end of program execution.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone)]
+pub struct StackOutputs(pub(crate) vm_core::StackOutputs);
+
+#[pymethods]
+impl StackOutputs {
+    /// Returns stack outputs built from the provided stack values (with the top of the stack
+    /// first) and overflow table addresses.
+    #[new]
+    #[pyo3(signature = (stack, overflow_addrs = Vec::new()))]
+    pub fn new(stack: Vec<u64>, overflow_addrs: Vec<u64>) -> Result<Self, Error> {
+        Ok(Self(vm_core::StackOutputs::try_from_ints(stack, overflow_addrs)?))
+    }
+
+    /// Deserializes stack outputs from the provided bytes.
+    #[staticmethod]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self(vm_core::StackOutputs::read_from_bytes(bytes)?))
+    }
+
+    /// Serializes these stack outputs into bytes.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.to_bytes())
+    }
+
+    /// Values of the stack with the top of the stack first.
+    #[getter]
+    pub fn stack(&self) -> Vec<u64> {
+        felts_to_ints(self.0.stack())
+    }
+
+    /// Addresses of the stack overflow table.
+    #[getter]
+    pub fn overflow_addrs(&self) -> Vec<u64> {
+        felts_to_ints(self.0.overflow_addrs())
+    }
+
+    /// Returns the specified number of values from the top of the stack.
+    pub fn stack_truncated(&self, num_outputs: usize) -> Vec<u64> {
+        felts_to_ints(self.0.stack_truncated(num_outputs))
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+
+    fn __repr__(&self) -> String {
+        format!("StackOutputs(stack={:?})", self.stack())
+    }
+}
+
+// EXECUTION PROOF
+// ================================================================================================
+
+/// A proof of correct execution of a Miden program.
+#[pyclass(module = "miden", frozen)]
+#[derive(Debug, Clone)]
+pub struct ExecutionProof(pub(crate) prover::ExecutionProof);
+
+#[pymethods]
+impl ExecutionProof {
+    /// Deserializes a proof from the provided bytes, e.g., from the contents of a proof file
+    /// written by the `miden prove` command.
+    #[staticmethod]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Ok(Self(prover::ExecutionProof::from_bytes(bytes)?))
+    }
+
+    /// Serializes this proof into bytes.
+    pub fn to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new_bound(py, &self.0.to_bytes())
+    }
+
+    /// Conjectured security level of this proof in bits.
+    #[getter]
+    pub fn security_level(&self) -> u32 {
+        self.0.security_level()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ExecutionProof(security_level={})", self.security_level())
+    }
+}
@@ -0,0 +1,118 @@
+# Tests of the Python bindings against the example programs of Miden VM. Run `make test-python` to
+# build the module into a virtual environment and run these tests.
+
+from pathlib import Path
+
+import pytest
+
+import miden
+
+EXAMPLES = Path(__file__).resolve().parents[2] / "miden" / "examples"
+MODULUS = 2**64 - 2**32 + 1
+
+BLAKE3_SOURCE = """
+use.std::crypto::hashes::blake3
+
+begin
+    repeat.2
+        exec.blake3::hash_1to1
+    end
+end
+"""
+BLAKE3_INPUTS = [2**32 - 1] * 8
+# the 2nd hash of the BLAKE3 1-to-1 hash chain starting from `BLAKE3_INPUTS`, as computed by the
+# `blake3` example of the `miden` crate
+BLAKE3_RESULT = [
+    1948395633, 2685038801, 3545416308, 3654376703, 2482946843, 2969816279, 3045553116, 809901083,
+]
+
+
+def fibonacci(n):
+    t0, t1 = 0, 1
+    for _ in range(n):
+        t0, t1 = t1, (t0 + t1) % MODULUS
+    return t0
+
+
+@pytest.fixture(scope="module")
+def fib_program():
+    return miden.Assembler().compile((EXAMPLES / "fib" / "fib.masm").read_text())
+
+
+@pytest.fixture(scope="module")
+def blake3_program():
+    return miden.Assembler(with_stdlib=True).compile(BLAKE3_SOURCE)
+
+
+def test_program(fib_program):
+    assert fib_program.hash.startswith("0x")
+    assert len(fib_program.hash) == 66
+    assert fib_program.program_info().program_hash == fib_program.hash
+    assert str(fib_program).startswith("begin")
+    assert fib_program.hash in repr(fib_program)
+
+
+def test_execute_fibonacci(fib_program):
+    outputs = miden.execute(fib_program, [1])
+    assert outputs.stack[0] == fibonacci(1001)
+    assert outputs.stack_truncated(1) == [fibonacci(1001)]
+
+
+def test_execute_iter_fibonacci(fib_program):
+    states = list(miden.execute_iter(fib_program, [1]))
+    assert [state.clk for state in states] == list(range(len(states)))
+    assert states[0].op is None
+    assert states[0].stack[0] == 1
+    assert states[-1].stack[0] == fibonacci(1001)
+    assert all(state.ctx == 0 for state in states)
+    assert "add" in {state.op.lower() for state in states if state.op is not None}
+    assert str(states[-1]).startswith(f"clk={states[-1].clk}")
+
+
+def test_prove_and_verify_blake3(blake3_program):
+    outputs, proof = miden.prove(blake3_program, BLAKE3_INPUTS)
+    assert outputs.stack_truncated(8) == BLAKE3_RESULT
+    assert proof.security_level == 96
+
+    # program info, stack outputs, and proofs survive serialization
+    program_info = miden.ProgramInfo.from_bytes(blake3_program.program_info().to_bytes())
+    outputs = miden.StackOutputs.from_bytes(outputs.to_bytes())
+    proof = miden.ExecutionProof.from_bytes(proof.to_bytes())
+    assert miden.verify(program_info, BLAKE3_INPUTS, outputs, proof) == 96
+
+    # stack outputs can be built from ints
+    rebuilt = miden.StackOutputs(outputs.stack, outputs.overflow_addrs)
+    assert rebuilt == outputs
+
+    # wrong public inputs or outputs are rejected
+    with pytest.raises(miden.VerificationError):
+        miden.verify(program_info, BLAKE3_INPUTS[:-1] + [0], outputs, proof)
+    wrong_stack = [outputs.stack[0] + 1] + outputs.stack[1:]
+    wrong_outputs = miden.StackOutputs(wrong_stack, outputs.overflow_addrs)
+    with pytest.raises(miden.VerificationError):
+        miden.verify(program_info, BLAKE3_INPUTS, wrong_outputs, proof)
+
+
+def test_errors(fib_program):
+    with pytest.raises(miden.AssemblyError):
+        miden.Assembler().compile("begin push end")
+
+    program = miden.Assembler().compile("begin push.1 assertz end")
+    with pytest.raises(miden.ExecutionError):
+        miden.execute(program)
+    with pytest.raises(miden.ExecutionError):
+        list(miden.execute_iter(program))
+
+    with pytest.raises(miden.InputError):
+        miden.execute(fib_program, [MODULUS])
+    with pytest.raises(OverflowError):
+        miden.execute(fib_program, [-1])
+    with pytest.raises(miden.OutputError):
+        miden.StackOutputs([MODULUS])
+    with pytest.raises(miden.DeserializationError):
+        miden.ExecutionProof.from_bytes(b"\x01\x02\x03")
+    with pytest.raises(ValueError):
+        miden.prove(fib_program, [1], security_level=100)
+
+    for error in [miden.AssemblyError, miden.ExecutionError, miden.VerificationError]:
+        assert issubclass(error, miden.MidenError)
@@ -6,7 +6,7 @@ use test_utils::{
     crypto::{BatchMerkleProof, MerklePath, PartialMerkleTree, Rpo256, RpoDigest},
     group_vector_elements,
     math::{FieldElement, QuadExtension, StarkField},
-    Felt, VerifierError, EMPTY_WORD,
+    Felt, VerifierError,
 };
 use winter_air::{
     proof::{Queries, StarkProof, Table},
@@ -272,8 +272,7 @@ impl VerifierChannel {
                 .into_iter()
                 .zip(x.iter())
                 .map(|(a, b)| {
-                    let mut value = QuadExt::slice_as_base_elements(b).to_owned();
-                    value.extend(EMPTY_WORD);
+                    let value = QuadExt::slice_as_base_elements(b).to_owned();
 
                     adv_key_map.push((a.to_owned().into(), value));
                 })
@@ -116,9 +116,12 @@ pub fn generate_advice_inputs(
     // the LDE domain from the public coin.
     // This is needed in order to construct Partial Merkle Trees
     let pow_nonce = channel.read_pow_nonce();
-    let query_positions = public_coin
+    tape.push(pow_nonce);
+    let mut query_positions = public_coin
         .draw_integers(air.options().num_queries(), air.lde_domain_size(), pow_nonce)
         .map_err(|_| VerifierError::RandomCoinError)?;
+    query_positions.sort_unstable();
+    query_positions.dedup();
 
     // read advice maps and Merkle paths related to trace and constraint composition polynomial evaluations
     let (mut advice_map, mut partial_trees_traces) =
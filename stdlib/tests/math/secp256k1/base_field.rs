@@ -183,8 +183,18 @@ impl Mul for BaseField {
         pc = d[8];
         c[8..16].copy_from_slice(&d[0..8]);
 
-        c[8] += pc * 977;
-        c[9] += pc;
+        let one = Self::one().limbs.map(|limb| limb * pc);
+
+        pc = 0;
+
+        (pc, c[8]) = Self::adc(c[8], one[0], pc);
+        (pc, c[9]) = Self::adc(c[9], one[1], pc);
+        (pc, c[10]) = Self::adc(c[10], one[2], pc);
+        (pc, c[11]) = Self::adc(c[11], one[3], pc);
+        (pc, c[12]) = Self::adc(c[12], one[4], pc);
+        (pc, c[13]) = Self::adc(c[13], one[5], pc);
+        (pc, c[14]) = Self::adc(c[14], one[6], pc);
+        (_, c[15]) = Self::adc(c[15], one[7], pc);
 
         Self::Output {
             limbs: c[8..16].try_into().expect("incorrect length"),
@@ -516,3 +526,17 @@ fn test_secp256k1_base_field_inv() {
     let test = build_test!(source, &stack);
     test.expect_stack(&elm1.limbs.map(|v| v as u64));
 }
+
+#[test]
+fn test_secp256k1_base_field_inv_zero() {
+    let source = "
+    use.std::math::secp256k1::base_field
+
+    begin
+        exec.base_field::inv
+    end";
+
+    // the inverse of zero is defined to be zero
+    let test = build_test!(source, &[0u64; 8]);
+    test.expect_stack(&[0u64; 8]);
+}
@@ -307,3 +307,17 @@ fn test_secp256k1_scalar_field_inv() {
     let test = build_test!(source, &stack);
     test.expect_stack(&elm2.limbs.map(|v| v as u64));
 }
+
+#[test]
+fn test_secp256k1_scalar_field_inv_zero() {
+    let source = "
+    use.std::math::secp256k1::scalar_field
+
+    begin
+        exec.scalar_field::inv
+    end";
+
+    // the inverse of zero is defined to be zero
+    let test = build_test!(source, &[0u64; 8]);
+    test.expect_stack(&[0u64; 8]);
+}
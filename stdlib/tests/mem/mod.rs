@@ -89,6 +89,27 @@ fn test_memcopy() {
     );
 }
 
+#[test]
+fn test_memcopy_elements() {
+    // copy 6 elements starting at the second element of word 1000 (element address 4001) to the
+    // third element of word 2000 (element address 8002)
+    let source = "
+    use.std::mem
+
+    begin
+        push.4.3.2.1.1000 mem_storew dropw
+        push.8.7.6.5.1001 mem_storew dropw
+
+        push.8002.4001.6 exec.mem::memcopy_elements
+    end
+    ";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack_and_memory(&[], 2000, &[0, 0, 3, 2]);
+    test.expect_stack_and_memory(&[], 2001, &[1, 8, 7, 6]);
+    test.expect_stack_and_memory(&[], 1001, &[8, 7, 6, 5]);
+}
+
 #[test]
 fn test_pipe_double_words_to_memory() {
     let mem_addr = 1000;
@@ -17,7 +17,7 @@ use vm_core::chiplets::hasher::apply_permutation;
 // EXPORTS
 // ================================================================================================
 
-pub use assembly::{Library, MaslLibrary};
+pub use assembly::{Library, MaslLibrary, MemoryMode};
 pub use processor::{
     AdviceInputs, AdviceProvider, ContextId, DefaultHost, ExecutionError, ExecutionOptions,
     ExecutionTrace, Process, ProcessState, StackInputs, VmStateIterator,
@@ -99,6 +99,7 @@ pub struct Test {
     pub advice_inputs: AdviceInputs,
     pub in_debug_mode: bool,
     pub libraries: Vec<MaslLibrary>,
+    pub memory_mode: MemoryMode,
 }
 
 impl Test {
@@ -114,6 +115,7 @@ impl Test {
             advice_inputs: AdviceInputs::default(),
             in_debug_mode,
             libraries: Vec::default(),
+            memory_mode: MemoryMode::default(),
         }
     }
 
@@ -205,6 +207,7 @@ impl Test {
     pub fn compile(&self) -> Result<Program, AssemblyError> {
         let assembler = assembly::Assembler::default()
             .with_debug_mode(self.in_debug_mode)
+            .with_memory_mode(self.memory_mode)
             .with_libraries(self.libraries.iter())
             .expect("failed to load stdlib");
 
@@ -92,6 +92,7 @@ macro_rules! build_test_by_mode {
             advice_inputs,
             in_debug_mode: $in_debug_mode,
             libraries: Vec::default(),
+            memory_mode: $crate::MemoryMode::default(),
         }
     }};
     (
@@ -113,6 +114,7 @@ macro_rules! build_test_by_mode {
             advice_inputs,
             in_debug_mode: $in_debug_mode,
             libraries: Vec::default(),
+            memory_mode: $crate::MemoryMode::default(),
         }
     }};
     (
@@ -133,6 +135,7 @@ macro_rules! build_test_by_mode {
             advice_inputs,
             in_debug_mode: $in_debug_mode,
             libraries: Vec::default(),
+            memory_mode: $crate::MemoryMode::default(),
         }
     }};
     ($in_debug_mode:expr, $source:expr, $stack_inputs:expr, $advice_stack:expr, $advice_merkle_store:expr, $advice_map:expr) => {{
@@ -152,6 +155,7 @@ macro_rules! build_test_by_mode {
             advice_inputs,
             in_debug_mode: $in_debug_mode,
             libraries: Vec::default(),
+            memory_mode: $crate::MemoryMode::default(),
         }
     }};
 }
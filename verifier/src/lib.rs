@@ -22,7 +22,7 @@ pub use winter_verifier::{AcceptableOptions, VerifierError};
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{ExecutionProof, ProofReport, ProofSizes};
 
 // VERIFIER
 // ================================================================================================
@@ -0,0 +1,31 @@
+use super::{Error, Program};
+use stdlib::StdLibrary;
+use wasm_bindgen::prelude::*;
+
+// ASSEMBLER
+// ================================================================================================
+
+/// Compiles Miden assembly source code into programs.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct Assembler(assembly::Assembler);
+
+#[wasm_bindgen]
+impl Assembler {
+    /// Returns a new assembler without any libraries.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a new assembler with the Miden standard library.
+    #[wasm_bindgen(js_name = withStdlib)]
+    pub fn with_stdlib() -> Result<Assembler, Error> {
+        Ok(Self(assembly::Assembler::default().with_library(&StdLibrary::default())?))
+    }
+
+    /// Compiles the provided source code into a program.
+    pub fn compile(&self, source: &str) -> Result<Program, Error> {
+        Ok(Program(self.0.compile(source)?))
+    }
+}
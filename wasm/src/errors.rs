@@ -0,0 +1,96 @@
+use alloc::string::ToString;
+use assembly::AssemblyError;
+use core::fmt;
+use processor::{DeserializationError, ExecutionError, InputError};
+use verifier::VerificationError;
+use vm_core::errors::OutputError;
+use wasm_bindgen::JsValue;
+
+// ERROR
+// ================================================================================================
+
+/// An error returned by the bindings.
+///
+/// When crossing into JavaScript, the error is converted into a JavaScript `Error` whose `name`
+/// identifies the kind of the error (e.g., `AssemblyError` or `VerificationError`), so that callers
+/// can distinguish between them without parsing error messages.
+#[derive(Debug)]
+pub enum Error {
+    Assembly(AssemblyError),
+    Execution(ExecutionError),
+    Verification(VerificationError),
+    Input(InputError),
+    Output(OutputError),
+    Deserialization(DeserializationError),
+}
+
+impl Error {
+    /// Returns the name of the JavaScript error this error is converted into.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Assembly(_) => "AssemblyError",
+            Self::Execution(_) => "ExecutionError",
+            Self::Verification(_) => "VerificationError",
+            Self::Input(_) => "InputError",
+            Self::Output(_) => "OutputError",
+            Self::Deserialization(_) => "DeserializationError",
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Assembly(err) => write!(f, "{err}"),
+            Self::Execution(err) => write!(f, "{err}"),
+            Self::Verification(err) => write!(f, "{err}"),
+            Self::Input(err) => write!(f, "{err}"),
+            Self::Output(err) => write!(f, "{err}"),
+            Self::Deserialization(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl From<AssemblyError> for Error {
+    fn from(err: AssemblyError) -> Self {
+        Self::Assembly(err)
+    }
+}
+
+impl From<ExecutionError> for Error {
+    fn from(err: ExecutionError) -> Self {
+        Self::Execution(err)
+    }
+}
+
+impl From<VerificationError> for Error {
+    fn from(err: VerificationError) -> Self {
+        Self::Verification(err)
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(err: InputError) -> Self {
+        Self::Input(err)
+    }
+}
+
+impl From<OutputError> for Error {
+    fn from(err: OutputError) -> Self {
+        Self::Output(err)
+    }
+}
+
+impl From<DeserializationError> for Error {
+    fn from(err: DeserializationError) -> Self {
+        Self::Deserialization(err)
+    }
+}
+
+impl From<Error> for JsValue {
+    fn from(err: Error) -> Self {
+        let js_error = js_sys::Error::new(&err.to_string());
+        js_error.set_name(err.name());
+        js_error.into()
+    }
+}
@@ -0,0 +1,138 @@
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use processor::{AdviceInputs, DefaultHost, ExecutionOptions, MemAdviceProvider, StackInputs};
+use verifier::ExecutionProof;
+use wasm_bindgen::prelude::*;
+
+mod assembler;
+pub use assembler::Assembler;
+
+mod errors;
+pub use errors::Error;
+
+mod types;
+pub use types::{Program, ProgramInfo, StackOutputs};
+
+// EXECUTOR
+// ================================================================================================
+
+/// Executes the provided program against the provided stack and advice stack inputs, and returns
+/// the resulting stack outputs.
+///
+/// Stack inputs are expected to be ordered as if they would be pushed onto the stack one by one,
+/// and the first value of the advice stack ends up on top of the advice stack.
+#[wasm_bindgen]
+pub fn execute(
+    program: &Program,
+    stack_inputs: Vec<u64>,
+    advice_stack: Vec<u64>,
+) -> Result<StackOutputs, Error> {
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs)?;
+    let advice_inputs = AdviceInputs::default().with_stack_values(advice_stack)?;
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+
+    let trace = processor::execute(&program.0, stack_inputs, host, ExecutionOptions::default())?;
+    Ok(StackOutputs(trace.stack_outputs().clone()))
+}
+
+// VERIFIER
+// ================================================================================================
+
+/// Returns the security level of the proof if the program described by `program_info` was
+/// executed correctly against the specified stack inputs and outputs.
+///
+/// The proof is expected to be serialized as produced by `ExecutionProof::to_bytes()` (e.g., the
+/// contents of a proof file written by the `miden prove` command). See `miden_verifier::verify()`
+/// for the ordering of stack inputs and outputs.
+#[wasm_bindgen]
+pub fn verify(
+    program_info: &ProgramInfo,
+    stack_inputs: Vec<u64>,
+    stack_outputs: &StackOutputs,
+    proof: &[u8],
+) -> Result<u32, Error> {
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs)?;
+    let proof = ExecutionProof::from_bytes(proof)?;
+
+    Ok(verifier::verify(
+        program_info.0.clone(),
+        stack_inputs,
+        stack_outputs.0.clone(),
+        proof,
+    )?)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{execute, verify, Assembler, Error, ProgramInfo, StackOutputs};
+    use alloc::vec;
+    use processor::{DefaultHost, MemAdviceProvider, StackInputs};
+    use prover::ProvingOptions;
+
+    const SOURCE: &str = "
+        use.std::math::u64
+        begin
+            adv_push.2 exec.u64::wrapping_add
+        end";
+
+    #[test]
+    fn execute_and_verify() {
+        let program = Assembler::with_stdlib().unwrap().compile(SOURCE).unwrap();
+        let outputs = execute(&program, vec![3, 4], vec![5, 6]).unwrap();
+        assert_eq!(&[10, 8], &outputs.stack()[..2]);
+
+        // program info and stack outputs survive serialization
+        let program_info = ProgramInfo::from_bytes(&program.program_info().to_bytes()).unwrap();
+        assert_eq!(program.hash(), program_info.program_hash());
+        let outputs = StackOutputs::from_bytes(&outputs.to_bytes()).unwrap();
+
+        let stack_inputs = StackInputs::try_from_ints([3, 4]).unwrap();
+        let advice = MemAdviceProvider::from(
+            processor::AdviceInputs::default().with_stack_values([5, 6]).unwrap(),
+        );
+        let (proof_outputs, proof) = prover::prove(
+            &program.0,
+            stack_inputs,
+            DefaultHost::new(advice),
+            ProvingOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(outputs.0, proof_outputs);
+
+        let proof = proof.to_bytes();
+        assert_eq!(96, verify(&program_info, vec![3, 4], &outputs, &proof).unwrap());
+
+        // wrong public inputs are rejected
+        let err = verify(&program_info, vec![3, 5], &outputs, &proof).unwrap_err();
+        assert!(matches!(err, Error::Verification(_)));
+        assert_eq!("VerificationError", err.name());
+    }
+
+    #[test]
+    fn typed_errors() {
+        let err = Assembler::new().compile("begin push end").unwrap_err();
+        assert_eq!("AssemblyError", err.name());
+
+        let program = Assembler::new().compile("begin push.1 assertz end").unwrap();
+        let err = execute(&program, vec![], vec![]).unwrap_err();
+        assert_eq!("ExecutionError", err.name());
+
+        let err = execute(&program, vec![u64::MAX], vec![]).unwrap_err();
+        assert_eq!("InputError", err.name());
+
+        let err = StackOutputs::new(vec![u64::MAX], vec![]).unwrap_err();
+        assert_eq!("OutputError", err.name());
+
+        let outputs = StackOutputs::new(vec![1], vec![]).unwrap();
+        let err = verify(&program.program_info(), vec![], &outputs, &[1, 2, 3]).unwrap_err();
+        assert_eq!("DeserializationError", err.name());
+        let err = ProgramInfo::from_bytes(&[1, 2, 3]).unwrap_err();
+        assert_eq!("DeserializationError", err.name());
+    }
+}
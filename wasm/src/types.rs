@@ -0,0 +1,98 @@
+use super::Error;
+use alloc::{string::String, vec::Vec};
+use processor::Felt;
+use vm_core::utils::{Deserializable, Serializable};
+use wasm_bindgen::prelude::*;
+
+// PROGRAM
+// ================================================================================================
+
+/// A compiled Miden program.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Program(pub(crate) vm_core::Program);
+
+#[wasm_bindgen]
+impl Program {
+    /// Returns the hash of the program as a 0x-prefixed hex string.
+    pub fn hash(&self) -> String {
+        String::from(&self.0.hash())
+    }
+
+    /// Returns the program info (the program hash together with its kernel) against which proofs
+    /// of the execution of this program are verified.
+    #[wasm_bindgen(js_name = programInfo)]
+    pub fn program_info(&self) -> ProgramInfo {
+        ProgramInfo(self.0.clone().into())
+    }
+}
+
+// PROGRAM INFO
+// ================================================================================================
+
+/// Hash of a program together with the kernel used to compile it.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct ProgramInfo(pub(crate) vm_core::ProgramInfo);
+
+#[wasm_bindgen]
+impl ProgramInfo {
+    /// Deserializes program info from the provided bytes.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<ProgramInfo, Error> {
+        Ok(Self(vm_core::ProgramInfo::read_from_bytes(bytes)?))
+    }
+
+    /// Serializes this program info into bytes.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Returns the hash of the program as a 0x-prefixed hex string.
+    #[wasm_bindgen(js_name = programHash)]
+    pub fn program_hash(&self) -> String {
+        String::from(self.0.program_hash())
+    }
+}
+
+// STACK OUTPUTS
+// ================================================================================================
+
+/// State of the operand stack at the end of program execution.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct StackOutputs(pub(crate) vm_core::StackOutputs);
+
+#[wasm_bindgen]
+impl StackOutputs {
+    /// Returns stack outputs built from the provided stack values (with the top of the stack
+    /// first) and overflow table addresses.
+    #[wasm_bindgen(constructor)]
+    pub fn new(stack: Vec<u64>, overflow_addrs: Vec<u64>) -> Result<StackOutputs, Error> {
+        Ok(Self(vm_core::StackOutputs::try_from_ints(stack, overflow_addrs)?))
+    }
+
+    /// Deserializes stack outputs from the provided bytes.
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<StackOutputs, Error> {
+        Ok(Self(vm_core::StackOutputs::read_from_bytes(bytes)?))
+    }
+
+    /// Serializes these stack outputs into bytes.
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes()
+    }
+
+    /// Returns the values of the stack with the top of the stack first.
+    pub fn stack(&self) -> Vec<u64> {
+        self.0.stack().iter().map(Felt::as_int).collect()
+    }
+
+    /// Returns the addresses of the stack overflow table.
+    #[wasm_bindgen(js_name = overflowAddrs)]
+    pub fn overflow_addrs(&self) -> Vec<u64> {
+        self.0.overflow_addrs().iter().map(Felt::as_int).collect()
+    }
+}
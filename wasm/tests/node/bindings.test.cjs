@@ -0,0 +1,61 @@
+// Tests of the WebAssembly bindings, executed by the Node test runner against the package built by
+// `wasm-pack build --target nodejs`. The proof verified by these tests is generated by the `miden`
+// CLI; run `make test-wasm` to build the package, generate the proof, and run the tests.
+
+const { test } = require("node:test");
+const assert = require("node:assert/strict");
+const fs = require("node:fs");
+const path = require("node:path");
+
+const { Assembler, StackOutputs, execute, verify } = require("../../pkg");
+
+const FIXTURES = path.join(__dirname, "fixtures");
+const SOURCE = fs.readFileSync(path.join(FIXTURES, "mul.masm"), "utf8");
+const STACK_INPUTS = BigUint64Array.from([3n, 4n]);
+
+function readOutputs() {
+    const outputs = JSON.parse(fs.readFileSync(path.join(FIXTURES, "mul.outputs"), "utf8"));
+    return new StackOutputs(
+        BigUint64Array.from(outputs.stack.map(BigInt)),
+        BigUint64Array.from(outputs.overflow_addrs.map(BigInt)),
+    );
+}
+
+test("compiles and executes a program", () => {
+    const program = new Assembler().compile(SOURCE);
+    const outputs = execute(program, STACK_INPUTS, new BigUint64Array());
+    assert.equal(outputs.stack()[0], 12n);
+    assert.equal(program.programInfo().programHash(), program.hash());
+});
+
+test("verifies a proof generated by the prover", () => {
+    const programInfo = new Assembler().compile(SOURCE).programInfo();
+    const proof = fs.readFileSync(path.join(FIXTURES, "mul.proof"));
+    assert.equal(verify(programInfo, STACK_INPUTS, readOutputs(), proof), 96);
+});
+
+test("rejects a proof for different public inputs", () => {
+    const programInfo = new Assembler().compile(SOURCE).programInfo();
+    const proof = fs.readFileSync(path.join(FIXTURES, "mul.proof"));
+    assert.throws(
+        () => verify(programInfo, BigUint64Array.from([3n, 5n]), readOutputs(), proof),
+        { name: "VerificationError" },
+    );
+});
+
+test("reports typed errors", () => {
+    assert.throws(() => new Assembler().compile("begin push end"), { name: "AssemblyError" });
+
+    const program = new Assembler().compile("begin push.1 assertz end");
+    assert.throws(
+        () => execute(program, new BigUint64Array(), new BigUint64Array()),
+        { name: "ExecutionError" },
+    );
+
+    const programInfo = program.programInfo();
+    const outputs = new StackOutputs(BigUint64Array.from([1n]), new BigUint64Array());
+    assert.throws(
+        () => verify(programInfo, new BigUint64Array(), outputs, new Uint8Array([1, 2, 3])),
+        { name: "DeserializationError" },
+    );
+});